@@ -183,12 +183,10 @@ class AccountNotifier extends Notifier<AccountState> {
             return;
           }
 
-          final serverUrl = await getDefaultBlossomServerUrl();
           final publicKey = await publicKeyFromString(publicKeyString: activeAccount.pubkey);
 
           profilePictureUrl = await uploadProfilePicture(
             pubkey: publicKey,
-            serverUrl: serverUrl,
             filePath: profilePicPath,
             imageType: imageType,
           );
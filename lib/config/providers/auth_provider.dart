@@ -8,6 +8,7 @@ import 'package:whitenoise/config/providers/active_account_provider.dart';
 import 'package:whitenoise/config/states/auth_state.dart';
 import 'package:whitenoise/src/rust/api.dart';
 import 'package:whitenoise/src/rust/api/accounts.dart';
+import 'package:whitenoise/src/rust/api/errors.dart';
 import 'package:whitenoise/src/rust/api/utils.dart';
 
 /// Auth Provider
@@ -196,18 +197,11 @@ class AuthNotifier extends Notifier<AuthState> {
     } catch (e, st) {
       String errorMessage;
 
-      // Check if it's a WhitenoiseError and convert it to a readable message
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-          if (errorMessage.contains('InvalidSecretKey')) {
-            errorMessage = 'Invalid nsec or private key';
-          }
-        } catch (conversionError) {
-          // Fallback if conversion fails
-          errorMessage = 'Invalid nsec or private key';
-        }
-        // Log the user-friendly error message for WhitenoiseError instead of the raw exception
+      // Check if it's a BridgeError and use its readable message
+      if (e is BridgeError) {
+        errorMessage =
+            e.code == BridgeErrorCode.invalidPublicKey ? 'Invalid nsec or private key' : e.message;
+        // Log the user-friendly error message for BridgeError instead of the raw exception
         _logger.warning('loginWithKey failed: $errorMessage');
       } else {
         errorMessage = e.toString();
@@ -246,18 +240,11 @@ class AuthNotifier extends Notifier<AuthState> {
     } catch (e, st) {
       String errorMessage;
 
-      // Check if it's a WhitenoiseError and convert it to a readable message
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-          if (errorMessage.contains('InvalidSecretKey')) {
-            errorMessage = 'Invalid nsec or private key';
-          }
-        } catch (conversionError) {
-          // Fallback if conversion fails
-          errorMessage = 'Invalid nsec or private key';
-        }
-        // Log the user-friendly error message for WhitenoiseError instead of the raw exception
+      // Check if it's a BridgeError and use its readable message
+      if (e is BridgeError) {
+        errorMessage =
+            e.code == BridgeErrorCode.invalidPublicKey ? 'Invalid nsec or private key' : e.message;
+        // Log the user-friendly error message for BridgeError instead of the raw exception
         _logger.warning('loginWithKeyInBackground failed: $errorMessage');
       } else {
         errorMessage = e.toString();
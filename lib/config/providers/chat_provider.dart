@@ -8,9 +8,11 @@ import 'package:whitenoise/config/providers/auth_provider.dart';
 import 'package:whitenoise/config/providers/group_provider.dart';
 import 'package:whitenoise/config/states/chat_state.dart';
 import 'package:whitenoise/domain/models/message_model.dart';
-import 'package:whitenoise/src/rust/api.dart';
+import 'package:whitenoise/src/rust/api/errors.dart';
 import 'package:whitenoise/src/rust/api/groups.dart';
+import 'package:whitenoise/src/rust/api/images.dart';
 import 'package:whitenoise/src/rust/api/messages.dart';
+import 'package:whitenoise/src/rust/api/messages.dart' as rust_messages show deleteMessage;
 import 'package:whitenoise/src/rust/api/utils.dart';
 import 'package:whitenoise/utils/message_converter.dart';
 
@@ -112,13 +114,8 @@ class ChatNotifier extends Notifier<ChatState> {
     } catch (e, st) {
       _logger.severe('ChatProvider.loadMessagesForGroup', e, st);
       String errorMessage = 'Failed to load messages';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to load messages due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -239,13 +236,8 @@ class ChatNotifier extends Notifier<ChatState> {
     } catch (e, st) {
       _logger.severe('ChatProvider.sendMessage', e, st);
       String errorMessage = 'Failed to send message';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to send message due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -298,13 +290,8 @@ class ChatNotifier extends Notifier<ChatState> {
     } catch (e, st) {
       _logger.severe('ChatProvider.sendLegacyNip04Message', e, st);
       String errorMessage = 'Failed to send legacy NIP-04 message';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to send message due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -562,7 +549,6 @@ class ChatNotifier extends Notifier<ChatState> {
   Future<bool> updateMessageReaction({
     required MessageModel message,
     required String reaction,
-    int? messageKind,
   }) async {
     if (!_isAuthAvailable()) {
       return false;
@@ -579,53 +565,40 @@ class ChatNotifier extends Notifier<ChatState> {
       final publicKey = await publicKeyFromString(publicKeyString: activeAccountData.pubkey);
       final groupIdObj = await groupIdFromString(hexString: message.groupId ?? '');
 
-      _logger.info('ChatProvider: Adding reaction "$reaction" to message ${message.id}');
-
-      // Create reaction content (emoji) - NIP-25 compliant
-      final reactionContent = reaction; // This should be an emoji like 👍, ❤️, etc.
-
-      // Use the message's actual kind (now stored in MessageModel)
-      final originalMessageKind = messageKind ?? message.kind;
-
-      // NIP-25 compliant reaction tags
-      // According to NIP-25:
-      // - MUST have e tag with event id being reacted to
-      // - SHOULD have p tag with pubkey of event being reacted to
-      // - SHOULD have k tag with stringified kind number of reacted event
-      final reactionTags = [
-        // e tag: ["e", <event-id>]
-        await tagFromVec(vec: ['e', message.id]),
-        // p tag: ["p", <pubkey>, <relay-hint>]
-        await tagFromVec(vec: ['p', message.sender.publicKey, '']),
-        // k tag: ["k", <kind-number>]
-        await tagFromVec(vec: ['k', originalMessageKind.toString()]),
-      ];
-
-      // Send reaction message (kind 7 for reactions in Nostr)
-      await sendMessageToGroup(
-        pubkey: publicKey,
-        groupId: groupIdObj,
-        message: reactionContent,
-        kind: 7, // Nostr kind 7 = reaction
-        tags: reactionTags,
+      // Reacting again with the same emoji retracts the reaction
+      final hasReacted = message.reactions.any(
+        (existing) =>
+            existing.emoji == reaction && existing.user.publicKey == activeAccountData.pubkey,
       );
+      if (hasReacted) {
+        _logger.info('ChatProvider: Removing reaction "$reaction" from message ${message.id}');
+        await removeReaction(
+          pubkey: publicKey,
+          groupId: groupIdObj,
+          messageId: message.id,
+          emoji: reaction,
+        );
+      } else {
+        _logger.info('ChatProvider: Adding reaction "$reaction" to message ${message.id}');
+        await reactToMessage(
+          pubkey: publicKey,
+          groupId: groupIdObj,
+          messageId: message.id,
+          emoji: reaction,
+        );
+      }
 
       // Refresh messages to get updated reactions
       await refreshMessagesForGroup(message.groupId ?? '');
 
-      _logger.info('ChatProvider: Reaction added successfully');
+      _logger.info('ChatProvider: Reaction updated successfully');
       return true;
     } catch (e, st) {
       _logger.severe('ChatProvider.updateMessageReaction', e, st);
 
       String errorMessage = 'Failed to update reaction';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to update reaction due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -658,18 +631,12 @@ class ChatNotifier extends Notifier<ChatState> {
 
       _logger.info('ChatProvider: Sending reply to message $replyToMessageId');
 
-      // Create tags for reply
-      final replyTags = [
-        await tagFromVec(vec: ['e', replyToMessageId]),
-      ];
-
-      // Send the reply message using rust API
-      final sentMessage = await sendMessageToGroup(
+      // The bridge adds the NIP-10 thread tags and mentions the parent's author
+      final sentMessage = await replyToMessage(
         pubkey: publicKey,
         groupId: groupIdObj,
-        message: message,
-        kind: 9, // Kind 9 for replies
-        tags: replyTags,
+        parentMessageId: replyToMessageId,
+        content: message,
       );
 
       // Convert to MessageModel and add to local state
@@ -732,13 +699,8 @@ class ChatNotifier extends Notifier<ChatState> {
     } catch (e, st) {
       _logger.severe('ChatProvider.sendReplyMessage', e, st);
       String errorMessage = 'Failed to send reply';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to send reply due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -751,8 +713,6 @@ class ChatNotifier extends Notifier<ChatState> {
   Future<bool> deleteMessage({
     required String groupId,
     required String messageId,
-    required int messageKind,
-    required String messagePubkey,
   }) async {
     if (!_isAuthAvailable()) {
       return false;
@@ -771,20 +731,11 @@ class ChatNotifier extends Notifier<ChatState> {
 
       _logger.info('ChatProvider: Deleting message $messageId');
 
-      // Create tags for deletion (NIP-09)
-      final deleteTags = [
-        await tagFromVec(vec: ['e', messageId]),
-        await tagFromVec(vec: ['p', messagePubkey]), // Author of the message being deleted
-        await tagFromVec(vec: ['k', messageKind.toString()]), // Kind of the message being deleted
-      ];
-
-      // Send deletion message using rust API
-      await sendMessageToGroup(
+      // The bridge sends the NIP-09 deletion and checks the account may delete it
+      await rust_messages.deleteMessage(
         pubkey: publicKey,
         groupId: groupIdObj,
-        message: '', // Empty content for deletion
-        kind: 5, // Nostr kind 5 = deletion
-        tags: deleteTags,
+        messageId: messageId,
       );
 
       // Refresh messages to get updated state
@@ -795,13 +746,8 @@ class ChatNotifier extends Notifier<ChatState> {
     } catch (e, st) {
       _logger.severe('ChatProvider.deleteMessage', e, st);
       String errorMessage = 'Failed to delete message';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to delete message due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
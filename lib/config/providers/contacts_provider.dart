@@ -6,9 +6,9 @@ import 'package:whitenoise/config/providers/active_account_provider.dart';
 import 'package:whitenoise/config/providers/auth_provider.dart';
 import 'package:whitenoise/config/providers/metadata_cache_provider.dart';
 import 'package:whitenoise/domain/models/contact_model.dart';
-import 'package:whitenoise/src/rust/api.dart';
 import 'package:whitenoise/src/rust/api/accounts.dart';
 import 'package:whitenoise/src/rust/api/contacts.dart';
+import 'package:whitenoise/src/rust/api/errors.dart';
 import 'package:whitenoise/src/rust/api/utils.dart';
 
 class ContactsState {
@@ -309,13 +309,8 @@ class ContactsNotifier extends Notifier<ContactsState> {
     } catch (e, st) {
       _logger.severe('ContactsProvider: loadContacts failed: $e\n$st');
       String errorMessage = 'Failed to load contacts';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to load contacts due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -373,13 +368,8 @@ class ContactsNotifier extends Notifier<ContactsState> {
     } catch (e, st) {
       _logger.severe('addContact', e, st);
       String errorMessage = 'Failed to add contact';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to add contact due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -421,13 +411,8 @@ class ContactsNotifier extends Notifier<ContactsState> {
     } catch (e, st) {
       _logger.severe('removeContact', e, st);
       String errorMessage = 'Failed to remove contact';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to remove contact due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -473,13 +458,8 @@ class ContactsNotifier extends Notifier<ContactsState> {
     } catch (e, st) {
       _logger.severe('replaceContacts', e, st);
       String errorMessage = 'Failed to update contacts';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to update contacts due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -530,13 +510,8 @@ class ContactsNotifier extends Notifier<ContactsState> {
     } catch (e, st) {
       _logger.severe('removeContactByPublicKey', e, st);
       String errorMessage = 'Failed to remove contact';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to remove contact due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -10,8 +10,8 @@ import 'package:whitenoise/config/providers/metadata_cache_provider.dart';
 import 'package:whitenoise/config/states/group_state.dart';
 import 'package:whitenoise/domain/models/contact_model.dart';
 import 'package:whitenoise/domain/models/user_model.dart';
-import 'package:whitenoise/src/rust/api.dart';
 import 'package:whitenoise/src/rust/api/accounts.dart';
+import 'package:whitenoise/src/rust/api/errors.dart';
 import 'package:whitenoise/src/rust/api/groups.dart';
 import 'package:whitenoise/src/rust/api/utils.dart';
 import 'package:whitenoise/utils/error_handling.dart';
@@ -19,22 +19,16 @@ import 'package:whitenoise/utils/error_handling.dart';
 class GroupsNotifier extends Notifier<GroupsState> {
   final _logger = Logger('GroupsNotifier');
 
-  /// Helper function to log WhitenoiseError details synchronously
+  /// Helper function to log BridgeError details synchronously
   /// This is used in catchError blocks where async operations aren't supported
   void _logErrorSync(String methodName, dynamic error) {
     final logMessage = '$methodName - Exception: $error (Type: ${error.runtimeType})';
     _logger.warning(logMessage, error);
 
-    // For WhitenoiseError, we schedule an async operation to get detailed error info
-    if (error is WhitenoiseError) {
-      Future.microtask(() async {
-        try {
-          final errorDetails = await whitenoiseErrorToString(error: error);
-          _logger.info('$methodName - Detailed WhitenoiseError: $errorDetails');
-        } catch (conversionError) {
-          _logger.warning('$methodName - WhitenoiseError conversion failed: $conversionError');
-        }
-      });
+    if (error is BridgeError) {
+      _logger.info(
+        '$methodName - Detailed BridgeError: ${error.code.name}: ${error.message} (details: ${error.details})',
+      );
     }
   }
 
@@ -131,16 +125,10 @@ class GroupsNotifier extends Notifier<GroupsState> {
 
       state = state.copyWith(isLoading: false);
     } catch (e, st) {
-      // Log the full exception details with proper WhitenoiseError unpacking
+      // Log the full exception details with proper BridgeError unpacking
       String logMessage = 'GroupsProvider.loadGroups - Exception: ';
-      if (e is WhitenoiseError) {
-        try {
-          final errorDetails = await whitenoiseErrorToString(error: e);
-          logMessage += '$errorDetails (Type: ${e.runtimeType})';
-        } catch (conversionError) {
-          logMessage +=
-              'WhitenoiseError conversion failed: $conversionError (Type: ${e.runtimeType})';
-        }
+      if (e is BridgeError) {
+        logMessage += '${e.code.name}: ${e.message} (details: ${e.details})';
       } else {
         logMessage += '$e (Type: ${e.runtimeType})';
       }
@@ -236,16 +224,10 @@ class GroupsNotifier extends Notifier<GroupsState> {
 
       return newGroup;
     } catch (e, st) {
-      // Log the full exception details with proper WhitenoiseError unpacking
+      // Log the full exception details with proper BridgeError unpacking
       String logMessage = 'GroupsProvider.createNewGroup - Exception: ';
-      if (e is WhitenoiseError) {
-        try {
-          final errorDetails = await whitenoiseErrorToString(error: e);
-          logMessage += '$errorDetails (Type: ${e.runtimeType})';
-        } catch (conversionError) {
-          logMessage +=
-              'WhitenoiseError conversion failed: $conversionError (Type: ${e.runtimeType})';
-        }
+      if (e is BridgeError) {
+        logMessage += '${e.code.name}: ${e.message} (details: ${e.details})';
       } else {
         logMessage += '$e (Type: ${e.runtimeType})';
       }
@@ -316,16 +298,10 @@ class GroupsNotifier extends Notifier<GroupsState> {
               members.add(fallbackUser);
             }
           } catch (metadataError) {
-            // Log the full exception details with proper WhitenoiseError unpacking
+            // Log the full exception details with proper BridgeError unpacking
             String logMessage = 'Failed to fetch metadata for member - Exception: ';
-            if (metadataError is WhitenoiseError) {
-              try {
-                final errorDetails = await whitenoiseErrorToString(error: metadataError);
-                logMessage += '$errorDetails (Type: ${metadataError.runtimeType})';
-              } catch (conversionError) {
-                logMessage +=
-                    'WhitenoiseError conversion failed: $conversionError (Type: ${metadataError.runtimeType})';
-              }
+            if (metadataError is BridgeError) {
+              logMessage += '${metadataError.code.name}: ${metadataError.message} (details: ${metadataError.details})';
             } else {
               logMessage += '$metadataError (Type: ${metadataError.runtimeType})';
             }
@@ -340,16 +316,10 @@ class GroupsNotifier extends Notifier<GroupsState> {
             members.add(fallbackUser);
           }
         } catch (e) {
-          // Log the full exception details with proper WhitenoiseError unpacking
+          // Log the full exception details with proper BridgeError unpacking
           String logMessage = 'Failed to process member pubkey - Exception: ';
-          if (e is WhitenoiseError) {
-            try {
-              final errorDetails = await whitenoiseErrorToString(error: e);
-              logMessage += '$errorDetails (Type: ${e.runtimeType})';
-            } catch (conversionError) {
-              logMessage +=
-                  'WhitenoiseError conversion failed: $conversionError (Type: ${e.runtimeType})';
-            }
+          if (e is BridgeError) {
+            logMessage += '${e.code.name}: ${e.message} (details: ${e.details})';
           } else {
             logMessage += '$e (Type: ${e.runtimeType})';
           }
@@ -369,16 +339,8 @@ class GroupsNotifier extends Notifier<GroupsState> {
         st,
       );
       String errorMessage = 'Failed to load group members';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning(
-            'Failed to convert WhitenoiseError to string: $conversionError',
-            conversionError,
-          );
-          errorMessage = 'Failed to load group members due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -428,16 +390,10 @@ class GroupsNotifier extends Notifier<GroupsState> {
               admins.add(fallbackUser);
             }
           } catch (metadataError) {
-            // Log the full exception details with proper WhitenoiseError unpacking
+            // Log the full exception details with proper BridgeError unpacking
             String logMessage = 'Failed to fetch metadata for admin - Exception: ';
-            if (metadataError is WhitenoiseError) {
-              try {
-                final errorDetails = await whitenoiseErrorToString(error: metadataError);
-                logMessage += '$errorDetails (Type: ${metadataError.runtimeType})';
-              } catch (conversionError) {
-                logMessage +=
-                    'WhitenoiseError conversion failed: $conversionError (Type: ${metadataError.runtimeType})';
-              }
+            if (metadataError is BridgeError) {
+              logMessage += '${metadataError.code.name}: ${metadataError.message} (details: ${metadataError.details})';
             } else {
               logMessage += '$metadataError (Type: ${metadataError.runtimeType})';
             }
@@ -452,16 +408,10 @@ class GroupsNotifier extends Notifier<GroupsState> {
             admins.add(fallbackUser);
           }
         } catch (e) {
-          // Log the full exception details with proper WhitenoiseError unpacking
+          // Log the full exception details with proper BridgeError unpacking
           String logMessage = 'Failed to process admin pubkey - Exception: ';
-          if (e is WhitenoiseError) {
-            try {
-              final errorDetails = await whitenoiseErrorToString(error: e);
-              logMessage += '$errorDetails (Type: ${e.runtimeType})';
-            } catch (conversionError) {
-              logMessage +=
-                  'WhitenoiseError conversion failed: $conversionError (Type: ${e.runtimeType})';
-            }
+          if (e is BridgeError) {
+            logMessage += '${e.code.name}: ${e.message} (details: ${e.details})';
           } else {
             logMessage += '$e (Type: ${e.runtimeType})';
           }
@@ -481,16 +431,8 @@ class GroupsNotifier extends Notifier<GroupsState> {
         st,
       );
       String errorMessage = 'Failed to load group admins';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning(
-            'Failed to convert WhitenoiseError to string: $conversionError',
-            conversionError,
-          );
-          errorMessage = 'Failed to load group admins due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -549,14 +491,8 @@ class GroupsNotifier extends Notifier<GroupsState> {
       state = state.copyWith(groupDisplayNames: updatedDisplayNames);
     } catch (e) {
       String logMessage = 'Failed to calculate display name for group $groupId - Exception: ';
-      if (e is WhitenoiseError) {
-        try {
-          final errorDetails = await whitenoiseErrorToString(error: e);
-          logMessage += '$errorDetails (Type: ${e.runtimeType})';
-        } catch (conversionError) {
-          logMessage +=
-              'WhitenoiseError conversion failed: $conversionError (Type: ${e.runtimeType})';
-        }
+      if (e is BridgeError) {
+        logMessage += '${e.code.name}: ${e.message} (details: ${e.details})';
       } else {
         logMessage += '$e (Type: ${e.runtimeType})';
       }
@@ -586,16 +522,10 @@ class GroupsNotifier extends Notifier<GroupsState> {
 
       _logger.info('GroupsProvider: Loaded members for ${groups.length} groups');
     } catch (e) {
-      // Log the full exception details with proper WhitenoiseError unpacking
+      // Log the full exception details with proper BridgeError unpacking
       String logMessage = 'GroupsProvider: Error loading members for groups - Exception: ';
-      if (e is WhitenoiseError) {
-        try {
-          final errorDetails = await whitenoiseErrorToString(error: e);
-          logMessage += '$errorDetails (Type: ${e.runtimeType})';
-        } catch (conversionError) {
-          logMessage +=
-              'WhitenoiseError conversion failed: $conversionError (Type: ${e.runtimeType})';
-        }
+      if (e is BridgeError) {
+        logMessage += '${e.code.name}: ${e.message} (details: ${e.details})';
       } else {
         logMessage += '$e (Type: ${e.runtimeType})';
       }
@@ -626,14 +556,8 @@ class GroupsNotifier extends Notifier<GroupsState> {
       } catch (e) {
         String logMessage =
             'Failed to get other member name for DM group ${group.mlsGroupId} - Exception: ';
-        if (e is WhitenoiseError) {
-          try {
-            final errorDetails = await whitenoiseErrorToString(error: e);
-            logMessage += '$errorDetails (Type: ${e.runtimeType})';
-          } catch (conversionError) {
-            logMessage +=
-                'WhitenoiseError conversion failed: $conversionError (Type: ${e.runtimeType})';
-          }
+        if (e is BridgeError) {
+          logMessage += '${e.code.name}: ${e.message} (details: ${e.details})';
         } else {
           logMessage += '$e (Type: ${e.runtimeType})';
         }
@@ -715,16 +639,10 @@ class GroupsNotifier extends Notifier<GroupsState> {
 
       return group.adminPubkeys.contains(activeAccountData.pubkey);
     } catch (e) {
-      // Log the full exception details with proper WhitenoiseError unpacking
+      // Log the full exception details with proper BridgeError unpacking
       String logMessage = 'GroupsProvider: Error checking admin status - Exception: ';
-      if (e is WhitenoiseError) {
-        try {
-          final errorDetails = await whitenoiseErrorToString(error: e);
-          logMessage += '$errorDetails (Type: ${e.runtimeType})';
-        } catch (conversionError) {
-          logMessage +=
-              'WhitenoiseError conversion failed: $conversionError (Type: ${e.runtimeType})';
-        }
+      if (e is BridgeError) {
+        logMessage += '${e.code.name}: ${e.message} (details: ${e.details})';
       } else {
         logMessage += '$e (Type: ${e.runtimeType})';
       }
@@ -804,16 +722,10 @@ class GroupsNotifier extends Notifier<GroupsState> {
         _logger.info('GroupsProvider: Added ${actuallyNewGroups.length} new groups');
       }
     } catch (e, st) {
-      // Log the full exception details with proper WhitenoiseError unpacking
+      // Log the full exception details with proper BridgeError unpacking
       String logMessage = 'GroupsProvider.checkForNewGroups - Exception: ';
-      if (e is WhitenoiseError) {
-        try {
-          final errorDetails = await whitenoiseErrorToString(error: e);
-          logMessage += '$errorDetails (Type: ${e.runtimeType})';
-        } catch (conversionError) {
-          logMessage +=
-              'WhitenoiseError conversion failed: $conversionError (Type: ${e.runtimeType})';
-        }
+      if (e is BridgeError) {
+        logMessage += '${e.code.name}: ${e.message} (details: ${e.details})';
       } else {
         logMessage += '$e (Type: ${e.runtimeType})';
       }
@@ -837,16 +749,10 @@ class GroupsNotifier extends Notifier<GroupsState> {
 
       await Future.wait(loadTasks);
     } catch (e) {
-      // Log the full exception details with proper WhitenoiseError unpacking
+      // Log the full exception details with proper BridgeError unpacking
       String logMessage = 'GroupsProvider: Error loading members for new groups - Exception: ';
-      if (e is WhitenoiseError) {
-        try {
-          final errorDetails = await whitenoiseErrorToString(error: e);
-          logMessage += '$errorDetails (Type: ${e.runtimeType})';
-        } catch (conversionError) {
-          logMessage +=
-              'WhitenoiseError conversion failed: $conversionError (Type: ${e.runtimeType})';
-        }
+      if (e is BridgeError) {
+        logMessage += '${e.code.name}: ${e.message} (details: ${e.details})';
       } else {
         logMessage += '$e (Type: ${e.runtimeType})';
       }
@@ -5,10 +5,11 @@ import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:logging/logging.dart';
 import 'package:whitenoise/domain/models/contact_model.dart';
 import 'package:whitenoise/src/rust/api/accounts.dart';
+import 'package:whitenoise/src/rust/api/metadata_cache.dart';
 import 'package:whitenoise/src/rust/api/utils.dart';
 import 'package:whitenoise/utils/public_key_validation_extension.dart';
 
-/// Cached metadata with basic expiration
+/// Contact model built from an entry of the Rust metadata cache, with basic expiration
 class CachedMetadata {
   final ContactModel contactModel;
   final DateTime cachedAt;
@@ -53,6 +54,10 @@ class MetadataCacheState {
 }
 
 /// Notifier for metadata cache management
+///
+/// The cache itself lives in Rust (`fetchMetadataBatch`), which persists it, de-duplicates
+/// concurrent lookups and batches relay queries. This notifier only keeps the contact
+/// models built from it, keyed by npub.
 class MetadataCacheNotifier extends Notifier<MetadataCacheState> {
   final _logger = Logger('MetadataCacheNotifier');
 
@@ -98,50 +103,63 @@ class MetadataCacheNotifier extends Notifier<MetadataCacheState> {
     }
   }
 
-  /// Fetch metadata for a public key
-  Future<ContactModel> _fetchMetadataForKey(String publicKey) async {
-    try {
-      _logger.info('Fetching metadata for: $publicKey');
-
-      // Convert to hex for fetching if needed
-      String fetchKey = publicKey;
-      if (publicKey.startsWith('npub1')) {
-        fetchKey = await _safeNpubToHex(publicKey);
-      }
-
-      // Create PublicKey object and fetch metadata
-      final contactPk = await publicKeyFromString(publicKeyString: fetchKey);
-      final metadata = await fetchMetadata(pubkey: contactPk);
+  /// Convert a public key to hex for the Rust cache, which keys its results by hex
+  Future<String> _toHex(String publicKey) async {
+    final normalized = _normalizePublicKey(publicKey);
+    return normalized.startsWith('npub1') ? await _safeNpubToHex(normalized) : normalized;
+  }
 
-      // Get standardized npub for consistent identification
-      final standardNpub = await _getStandardizedNpub(publicKey);
+  /// Fetch metadata for many public keys with one call to the Rust cache
+  ///
+  /// Returns cache entries keyed by standardized npub. Keys the Rust cache cannot resolve
+  /// get an "Unknown User" contact model.
+  Future<Map<String, CachedMetadata>> _fetchFromRustCache(List<String> publicKeys) async {
+    final hexKeys = <String, String>{};
+    for (final publicKey in publicKeys) {
+      hexKeys[publicKey] = await _toHex(publicKey);
+    }
 
-      // Create contact model
-      final contactModel = ContactModel.fromMetadata(
-        publicKey: standardNpub,
-        metadata: metadata,
+    Map<String, CachedMetadataData> fetched = {};
+    try {
+      _logger.info('Fetching metadata for ${hexKeys.length} keys');
+      fetched = await fetchMetadataBatch(
+        // One unparsable key would fail the whole batch
+        pubkeys: hexKeys.values.where((key) => key.isValidHexPublicKey).toSet().toList(),
+        forceRefresh: false,
       );
-
-      _logger.info('Fetched metadata for $standardNpub: ${contactModel.displayNameOrName}');
-      return contactModel;
     } catch (e, st) {
-      _logger.warning('Failed to fetch metadata for $publicKey: $e\n$st');
+      _logger.warning('Failed to fetch metadata batch: $e\n$st');
+    }
 
-      // Create fallback contact model
-      try {
-        final standardNpub = await _getStandardizedNpub(publicKey);
-        return ContactModel(
-          name: 'Unknown User',
-          publicKey: standardNpub,
-        );
-      } catch (fallbackError) {
-        _logger.severe('Fallback failed for $publicKey: $fallbackError');
-        return ContactModel(
-          name: 'Unknown User',
-          publicKey: _normalizePublicKey(publicKey),
-        );
-      }
+    final results = <String, CachedMetadata>{};
+    for (final entry in hexKeys.entries) {
+      final standardNpub = await _getStandardizedNpub(entry.key);
+      final cached = fetched[entry.value];
+      results[standardNpub] = CachedMetadata(
+        contactModel:
+            cached == null
+                ? ContactModel(name: 'Unknown User', publicKey: standardNpub)
+                : ContactModel.fromMetadata(publicKey: standardNpub, metadata: cached.metadata),
+        cachedAt:
+            cached == null || cached.isStale
+                ? DateTime.fromMillisecondsSinceEpoch(0)
+                : DateTime.fromMillisecondsSinceEpoch(cached.fetchedAt.toInt() * 1000),
+      );
     }
+    return results;
+  }
+
+  /// Fetch metadata for a public key
+  Future<ContactModel> _fetchMetadataForKey(String publicKey) async {
+    final results = await _fetchFromRustCache([publicKey]);
+    _storeInCache(results);
+    final contactModel = results.values.single.contactModel;
+    _logger.info('Fetched metadata for $publicKey: ${contactModel.displayNameOrName}');
+    return contactModel;
+  }
+
+  void _storeInCache(Map<String, CachedMetadata> entries) {
+    state = state.copyWith(cache: {...state.cache, ...entries});
   }
 
   /// Get contact model from cache or fetch if needed
@@ -171,23 +189,14 @@ class MetadataCacheNotifier extends Notifier<MetadataCacheState> {
     state = state.copyWith(pendingFetches: newPendingFetches);
 
     try {
+      // The result is cached by _fetchMetadataForKey
       final contactModel = await futureContactModel;
 
-      // Cache the result
-      final newCache = Map<String, CachedMetadata>.from(state.cache);
-      newCache[standardNpub] = CachedMetadata(
-        contactModel: contactModel,
-        cachedAt: DateTime.now(),
-      );
-
       // Remove from pending fetches
       final updatedPendingFetches = Map<String, Future<ContactModel>>.from(state.pendingFetches);
       updatedPendingFetches.remove(standardNpub);
 
-      state = state.copyWith(
-        cache: newCache,
-        pendingFetches: updatedPendingFetches,
-      );
+      state = state.copyWith(pendingFetches: updatedPendingFetches);
 
       return contactModel;
     } catch (e) {
@@ -257,26 +266,29 @@ class MetadataCacheNotifier extends Notifier<MetadataCacheState> {
   }
 
   /// Get multiple contact models efficiently
+  ///
+  /// Keys missing from the cache are fetched with a single call to the Rust cache.
   Future<List<ContactModel>> getContactModels(List<String> publicKeys) async {
-    final results = <ContactModel>[];
-
+    final standardNpubs = <String>[];
+    final missing = <String>[];
     for (final publicKey in publicKeys) {
-      try {
-        final contactModel = await getContactModel(publicKey);
-        results.add(contactModel);
-      } catch (e) {
-        _logger.warning('Failed to get contact model for $publicKey: $e');
-        // Add fallback model to maintain list integrity
-        results.add(
-          ContactModel(
-            name: 'Unknown User',
-            publicKey: _normalizePublicKey(publicKey),
-          ),
-        );
+      final standardNpub = await _getStandardizedNpub(publicKey);
+      standardNpubs.add(standardNpub);
+      final cached = state.cache[standardNpub];
+      if (cached == null || cached.isExpired) {
+        missing.add(publicKey);
       }
     }
 
-    return results;
+    if (missing.isNotEmpty) {
+      _storeInCache(await _fetchFromRustCache(missing));
+    }
+
+    return [
+      for (final standardNpub in standardNpubs)
+        state.cache[standardNpub]?.contactModel ??
+            ContactModel(name: 'Unknown User', publicKey: standardNpub),
+    ];
   }
 
   /// Check if a contact is cached and not expired
@@ -7,8 +7,8 @@ import 'package:whitenoise/config/providers/auth_provider.dart';
 import 'package:whitenoise/config/providers/metadata_cache_provider.dart';
 import 'package:whitenoise/config/states/profile_state.dart';
 import 'package:whitenoise/domain/models/contact_model.dart';
-import 'package:whitenoise/src/rust/api.dart';
 import 'package:whitenoise/src/rust/api/accounts.dart';
+import 'package:whitenoise/src/rust/api/errors.dart';
 import 'package:whitenoise/src/rust/api/utils.dart';
 
 class ProfileNotifier extends AsyncNotifier<ProfileState> {
@@ -151,12 +151,10 @@ class ProfileNotifier extends AsyncNotifier<ProfileState> {
           return;
         }
 
-        final serverUrl = await getDefaultBlossomServerUrl();
         final publicKey = await publicKeyFromString(publicKeyString: activeAccount.pubkey);
 
         profilePictureUrl = await uploadProfilePicture(
           pubkey: publicKey,
-          serverUrl: serverUrl,
           filePath: state.value!.selectedImagePath!,
           imageType: imageType,
         );
@@ -199,12 +197,8 @@ class ProfileNotifier extends AsyncNotifier<ProfileState> {
 
       // Handle error messaging
       String? errorMessage;
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          errorMessage = 'Failed to update profile due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
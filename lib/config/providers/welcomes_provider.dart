@@ -4,7 +4,7 @@ import 'package:logging/logging.dart';
 import 'package:whitenoise/config/providers/active_account_provider.dart';
 import 'package:whitenoise/config/providers/auth_provider.dart';
 import 'package:whitenoise/config/states/welcome_state.dart';
-import 'package:whitenoise/src/rust/api.dart';
+import 'package:whitenoise/src/rust/api/errors.dart';
 import 'package:whitenoise/src/rust/api/utils.dart';
 import 'package:whitenoise/src/rust/api/welcomes.dart';
 
@@ -99,13 +99,8 @@ class WelcomesNotifier extends Notifier<WelcomesState> {
     } catch (e, st) {
       _logger.severe('WelcomesProvider.loadWelcomes', e, st);
       String errorMessage = 'Failed to load welcomes';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to load welcomes due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -137,13 +132,8 @@ class WelcomesNotifier extends Notifier<WelcomesState> {
     } catch (e, st) {
       _logger.severe('WelcomesProvider.fetchWelcomeById', e, st);
       String errorMessage = 'Failed to fetch welcome';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to fetch welcome due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -176,13 +166,8 @@ class WelcomesNotifier extends Notifier<WelcomesState> {
     } catch (e, st) {
       _logger.severe('WelcomesProvider.acceptWelcomeInvitation', e, st);
       String errorMessage = 'Failed to accept welcome';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to accept welcome due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
@@ -215,13 +200,8 @@ class WelcomesNotifier extends Notifier<WelcomesState> {
     } catch (e, st) {
       _logger.severe('WelcomesProvider.declineWelcomeInvitation', e, st);
       String errorMessage = 'Failed to decline welcome';
-      if (e is WhitenoiseError) {
-        try {
-          errorMessage = await whitenoiseErrorToString(error: e);
-        } catch (conversionError) {
-          _logger.warning('Failed to convert WhitenoiseError to string: $conversionError');
-          errorMessage = 'Failed to decline welcome due to an internal error';
-        }
+      if (e is BridgeError) {
+        errorMessage = e.message;
       } else {
         errorMessage = e.toString();
       }
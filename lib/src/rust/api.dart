@@ -5,6 +5,8 @@
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+import 'api/errors.dart';
+import 'api/events.dart';
 import 'frb_generated.dart';
 
 /// Initializes the Whitenoise system with the provided configuration.
@@ -12,14 +14,15 @@ import 'frb_generated.dart';
 /// # CRITICAL: Must be called first
 /// This function MUST be called before any other Whitenoise methods are used.
 /// It sets up the global singleton instance, creates necessary directories,
-/// and initializes the database connections.
+/// and initializes the database connections. It also starts the background tasks that
+/// send queued messages and feed `subscribe_whitenoise_events`.
 ///
 /// # Parameters
 /// * `config` - WhitenoiseConfig object containing setup parameters
 ///
 /// # Returns
 /// * `Ok(())` - System successfully initialized
-/// * `Err(WhitenoiseError)` - If initialization fails (directory creation, database setup, etc.)
+/// * `Err(BridgeError)` - If initialization fails (directory creation, database setup, etc.)
 ///
 /// # Example
 /// ```rust
@@ -48,7 +51,7 @@ Future<void> initializeWhitenoise({required WhitenoiseConfig config}) =>
 ///
 /// # Returns
 /// * `Ok(())` - All data successfully deleted
-/// * `Err(WhitenoiseError)` - If deletion fails or instance not initialized
+/// * `Err(BridgeError)` - If deletion fails or instance not initialized
 ///
 /// # Usage
 /// Typically used for:
@@ -73,8 +76,43 @@ Future<void> initializeWhitenoise({required WhitenoiseConfig config}) =>
 /// - Database locks or corruption
 Future<void> deleteAllData() => RustLib.instance.api.crateApiDeleteAllData();
 
+/// Subscribes to the global Whitenoise event bus.
+///
+/// Opens a stream of typed `WhitenoiseEvent`s covering everything that changes outside
+/// of a single chat: new welcomes, groups being created, updated or changing state,
+/// members being added or removed, relay status changes, metadata updates and accounts
+/// logging in or out. Flutter providers can apply these incrementally instead of
+/// re-fetching all state after every action.
+///
+/// # Parameters
+/// * `sink` - The stream sink the events are pushed to (provided by flutter_rust_bridge)
+///
+/// # Returns
+/// * `Ok(())` - The subscription was started; events arrive on the stream
+/// * `Err(BridgeError)` - If Whitenoise has not been initialized
+///
+/// # Notes
+/// * The first item on the stream is always `WhitenoiseEvent::Subscribed`, carrying the
+///   handle to pass to `unsubscribe`
+/// * State that exists when the stream is opened is not replayed; load it with the
+///   regular `fetch_*` functions first
+/// * Subscribers that fall too far behind skip the oldest events rather than blocking
+///   the bus
+///
+/// # Example
+/// ```dart
+/// subscribeWhitenoiseEvents().listen((event) {
+///   switch (event) {
+///     case WhitenoiseEvent_WelcomeReceived(:final welcome):
+///       ref.read(welcomesProvider.notifier).addWelcome(welcome);
+///     case WhitenoiseEvent_GroupUpdated(:final group):
+///       ref.read(groupsProvider.notifier).updateGroup(group);
+///     // ...
+///   }
+/// });
+/// ```
+Stream<WhitenoiseEvent> subscribeWhitenoiseEvents() =>
+    RustLib.instance.api.crateApiSubscribeWhitenoiseEvents();
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WhitenoiseConfig>>
 abstract class WhitenoiseConfig implements RustOpaqueInterface {}
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WhitenoiseError>>
-abstract class WhitenoiseError implements RustOpaqueInterface {}
@@ -5,8 +5,9 @@
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-import '../api.dart';
 import '../frb_generated.dart';
+import 'errors.dart';
+import 'images.dart';
 import 'utils.dart';
 
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
@@ -32,11 +33,11 @@ Future<AccountData> convertAccountToData({required Account account}) =>
 /// Flutter-compatible AccountData structure for use in the UI.
 ///
 /// # Returns
-/// * `Result<Vec<AccountData>, WhitenoiseError>` - A vector of all available accounts,
+/// * `Result<Vec<AccountData>, BridgeError>` - A vector of all available accounts,
 ///   or an error if the operation fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the Whitenoise instance cannot be accessed or if
+/// * Returns `BridgeError` if the Whitenoise instance cannot be accessed or if
 ///   there's an issue fetching the accounts
 Future<List<AccountData>> fetchAccounts() => RustLib.instance.api.crateApiAccountsFetchAccounts();
 
@@ -49,11 +50,11 @@ Future<List<AccountData>> fetchAccounts() => RustLib.instance.api.crateApiAccoun
 /// * `pubkey` - The public key of the account to fetch
 ///
 /// # Returns
-/// * `Result<AccountData, WhitenoiseError>` - The account data for the specified
+/// * `Result<AccountData, BridgeError>` - The account data for the specified
 ///   public key, or an error if the operation fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the account doesn't exist, the Whitenoise instance
+/// * Returns `BridgeError` if the account doesn't exist, the Whitenoise instance
 ///   cannot be accessed, or if there's an issue fetching the account
 Future<AccountData> fetchAccount({required PublicKey pubkey}) =>
     RustLib.instance.api.crateApiAccountsFetchAccount(pubkey: pubkey);
@@ -65,11 +66,11 @@ Future<AccountData> fetchAccount({required PublicKey pubkey}) =>
 /// be ready for participation in secure group conversations.
 ///
 /// # Returns
-/// * `Result<Account, WhitenoiseError>` - The newly created account with full MLS capabilities,
+/// * `Result<Account, BridgeError>` - The newly created account with full MLS capabilities,
 ///   or an error if the creation process fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if there's an issue with key generation, MLS setup,
+/// * Returns `BridgeError` if there's an issue with key generation, MLS setup,
 ///   or if the Whitenoise instance cannot be accessed
 Future<Account> createIdentity() => RustLib.instance.api.crateApiAccountsCreateIdentity();
 
@@ -83,12 +84,12 @@ Future<Account> createIdentity() => RustLib.instance.api.crateApiAccountsCreateI
 /// * `nsec_or_hex_privkey` - The private key in either nsec (bech32) format or hexadecimal format
 ///
 /// # Returns
-/// * `Result<Account, WhitenoiseError>` - The successfully logged-in account,
+/// * `Result<Account, BridgeError>` - The successfully logged-in account,
 ///   or an error if authentication fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the private key is invalid, malformed, or if there's
-///   an issue with the login process
+/// * Returns `InvalidInput` if the private key is invalid or malformed, or another
+///   `BridgeError` if there's an issue with the login process
 Future<Account> login({required String nsecOrHexPrivkey}) =>
     RustLib.instance.api.crateApiAccountsLogin(nsecOrHexPrivkey: nsecOrHexPrivkey);
 
@@ -102,10 +103,10 @@ Future<Account> login({required String nsecOrHexPrivkey}) =>
 /// * `pubkey` - The public key of the account to log out
 ///
 /// # Returns
-/// * `Result<(), WhitenoiseError>` - Success (empty result) or an error if logout fails
+/// * `Result<(), BridgeError>` - Success (empty result) or an error if logout fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the account doesn't exist, is not currently logged in,
+/// * Returns `BridgeError` if the account doesn't exist, is not currently logged in,
 ///   or if there's an issue with the logout process
 Future<void> logout({required PublicKey pubkey}) =>
     RustLib.instance.api.crateApiAccountsLogout(pubkey: pubkey);
@@ -120,11 +121,11 @@ Future<void> logout({required PublicKey pubkey}) =>
 /// * `pubkey` - The public key of the account whose private key should be exported
 ///
 /// # Returns
-/// * `Result<String, WhitenoiseError>` - The private key in nsec format,
+/// * `Result<String, BridgeError>` - The private key in nsec format,
 ///   or an error if the export fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the account doesn't exist, cannot be accessed,
+/// * Returns `BridgeError` if the account doesn't exist, cannot be accessed,
 ///   or if there's an issue with the key export process
 ///
 /// # Security Note
@@ -142,11 +143,11 @@ Future<String> exportAccountNsec({required PublicKey pubkey}) =>
 /// * `pubkey` - The public key of the account whose npub should be exported
 ///
 /// # Returns
-/// * `Result<String, WhitenoiseError>` - The public key in npub format,
+/// * `Result<String, BridgeError>` - The public key in npub format,
 ///   or an error if the export fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the account doesn't exist, cannot be accessed,
+/// * Returns `BridgeError` if the account doesn't exist, cannot be accessed,
 ///   or if there's an issue with the key export process
 Future<String> exportAccountNpub({required PublicKey pubkey}) =>
     RustLib.instance.api.crateApiAccountsExportAccountNpub(pubkey: pubkey);
@@ -161,11 +162,11 @@ Future<String> exportAccountNpub({required PublicKey pubkey}) =>
 /// * `pubkey` - The public key of the account whose metadata should be fetched
 ///
 /// # Returns
-/// * `Result<Option<MetadataData>, WhitenoiseError>` - The account's metadata if it exists,
+/// * `Result<Option<MetadataData>, BridgeError>` - The account's metadata if it exists,
 ///   None if no metadata is found, or an error if the operation fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if there's an issue accessing the Whitenoise instance
+/// * Returns `BridgeError` if there's an issue accessing the Whitenoise instance
 ///   or fetching the metadata from the network
 Future<MetadataData?> fetchMetadata({required PublicKey pubkey}) =>
     RustLib.instance.api.crateApiAccountsFetchMetadata(pubkey: pubkey);
@@ -181,11 +182,12 @@ Future<MetadataData?> fetchMetadata({required PublicKey pubkey}) =>
 /// * `pubkey` - The public key of the account whose metadata should be updated
 ///
 /// # Returns
-/// * `Result<(), WhitenoiseError>` - Success (empty result) or an error if the update fails
+/// * `Result<(), BridgeError>` - Success (empty result) or an error if the update fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the account doesn't exist, cannot be accessed,
-///   or if there's an issue publishing the metadata to the network
+/// * Returns `BridgeError` if the account doesn't exist, cannot be accessed,
+///   or if there's an issue publishing the metadata to the network. Failing to update
+///   the local metadata cache afterwards is not an error
 Future<void> updateMetadata({
   required MetadataData metadata,
   required PublicKey pubkey,
@@ -204,44 +206,53 @@ Future<void> updateMetadata({
 /// * `pubkey` - The public key of the account whose onboarding state should be fetched
 ///
 /// # Returns
-/// * `Result<OnboardingState, WhitenoiseError>` - The current onboarding state,
+/// * `Result<OnboardingState, BridgeError>` - The current onboarding state,
 ///   or an error if the operation fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the account doesn't exist or there's an issue
+/// * Returns `BridgeError` if the account doesn't exist or there's an issue
 ///   accessing the account's onboarding information
 Future<OnboardingState> fetchOnboardingState({required PublicKey pubkey}) =>
     RustLib.instance.api.crateApiAccountsFetchOnboardingState(pubkey: pubkey);
 
-/// Uploads a profile picture for a specific account to a media server.
+/// Uploads a profile picture for a specific account to its Blossom servers.
 ///
-/// This function takes a local image file and uploads it to the specified media server,
-/// making it available as a profile picture for the account. The function supports
-/// different image types and returns the URL where the uploaded image can be accessed.
+/// This function takes a local image file and uploads it to the account's Blossom servers
+/// (see `fetch_blossom_servers`), making it available as a profile picture for the
+/// account. Servers are tried in order, and the upload is mirrored when the account has
+/// mirroring enabled. The function supports different image types and returns the URL
+/// where the uploaded image can be accessed.
+///
+/// The image is run through `process_image` before upload, so metadata such as the GPS
+/// location is stripped and large photos are scaled down. Only the processed copy is
+/// uploaded; the original file is left untouched.
 ///
 /// # Parameters
 /// * `pubkey` - The public key of the account whose profile picture should be updated
-/// * `server_url` - The URL string of the media server where the image will be uploaded
 /// * `file_path` - The local path to the image file that should be uploaded
 /// * `image_type` - The type of image being uploaded (e.g., avatar, banner)
+/// * `options` - Processing options, or `None` to scale the image down to 1024 px and
+///   keep its format
 ///
 /// # Returns
-/// * `Result<String, WhitenoiseError>` - The URL of the uploaded image if successful,
+/// * `Result<String, BridgeError>` - The URL of the uploaded image if successful,
 ///   or an error if the upload fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the file cannot be read, the server is unreachable,
-///   the upload fails, the URL is invalid, or if there's an issue with the account access
+/// * Returns `BridgeError` if the file cannot be read, no server accepts the upload, or
+///   if there's an issue with the account access
+/// * Returns `InvalidInput` if `image_type` does not match the file contents (see
+///   `detect_image_type`), or `Unsupported` if the file is not a supported image
 Future<String> uploadProfilePicture({
   required PublicKey pubkey,
-  required String serverUrl,
   required String filePath,
   required ImageType imageType,
+  ImageProcessingOptions? options,
 }) => RustLib.instance.api.crateApiAccountsUploadProfilePicture(
   pubkey: pubkey,
-  serverUrl: serverUrl,
   filePath: filePath,
   imageType: imageType,
+  options: options,
 );
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Account>>
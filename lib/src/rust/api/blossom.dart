@@ -0,0 +1,82 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+import 'accounts.dart';
+import 'errors.dart';
+
+// These functions are ignored because they are not marked as `pub`: `load_server_list`, `load_stored_list`, `normalize_server_url`, `normalize_server_urls`, `save_stored_list`, `server_list_relays`, `servers_from_event`, `upload_to_blossom_servers`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `default`, `fmt`
+
+/// Fetches the Blossom servers an account uploads media to.
+///
+/// The list is read from the account's latest kind-10063 event (BUD-03) on its relays,
+/// so changes made by other clients are picked up, and kept on this device for uploads.
+/// When the relays cannot be reached, the list last seen on this device is returned.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+///
+/// # Returns
+/// * `Ok(BlossomServerListData)` - The servers in order of preference; if the account has
+///   not configured any, the list contains only `get_default_blossom_server_url()`
+/// * `Err(BridgeError)` - If the list could not be read
+Future<BlossomServerListData> fetchBlossomServers({
+  required PublicKey pubkey,
+}) => RustLib.instance.api.crateApiBlossomFetchBlossomServers(pubkey: pubkey);
+
+/// Updates the Blossom servers an account uploads media to.
+///
+/// The list is published as a kind-10063 event (BUD-03) to the account's relays, then
+/// kept on this device. URLs are validated and normalized (trailing slashes removed);
+/// duplicates are dropped, keeping the first occurrence. An empty list resets the account
+/// to the default server.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `server_list` - The servers in order of preference, and whether to mirror uploads
+///
+/// # Returns
+/// * `Ok(BlossomServerListData)` - The list as saved
+/// * `Err(BridgeError)` - `InvalidInput` if a URL is not a valid http(s) URL, a network
+///   error if no relay accepted the list (nothing is changed then), or a `Storage` error
+///
+/// # Notes
+/// * `mirror_uploads` is a preference of this device and is not published
+Future<BlossomServerListData> updateBlossomServers({
+  required PublicKey pubkey,
+  required BlossomServerListData serverList,
+}) => RustLib.instance.api.crateApiBlossomUpdateBlossomServers(
+  pubkey: pubkey,
+  serverList: serverList,
+);
+
+/// The Blossom servers an account uploads media to (BUD-03).
+///
+/// Servers are tried in order: the first one that accepts an upload hosts the file. With
+/// `mirror_uploads` set, the file is also uploaded to every other server in the list, and
+/// those copies are used as fallbacks when downloading.
+class BlossomServerListData {
+  final List<String> servers;
+  final bool mirrorUploads;
+
+  const BlossomServerListData({
+    required this.servers,
+    required this.mirrorUploads,
+  });
+
+  @override
+  int get hashCode => servers.hashCode ^ mirrorUploads.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BlossomServerListData &&
+          runtimeType == other.runtimeType &&
+          servers == other.servers &&
+          mirrorUploads == other.mirrorUploads;
+}
@@ -0,0 +1,137 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+import 'accounts.dart';
+import 'errors.dart';
+import 'groups.dart';
+import 'utils.dart';
+
+// These functions are ignored because they are not marked as `pub`: `clear_chat_list_caches`, `count_unread`, `forget_group_activity`, `group_activity`, `load_visible_messages`, `message_preview`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Fetches the chat list of an account.
+///
+/// Returns one summary per active group, with the last message preview, unread count,
+/// member count and, for direct messages, the other participant's public key and
+/// metadata. Everything is computed in a single call so Flutter does not have to query
+/// each group separately.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account whose chat list to fetch
+///
+/// # Returns
+/// * `Ok(Vec<ChatSummaryData>)` - One summary per group, most recent activity first
+/// * `Err(BridgeError)` - If the groups or their messages could not be loaded
+///
+/// # Notes
+/// * Unread counts are based on the read markers set with `mark_group_read`
+/// * Groups without messages are ordered last
+/// * Messages and members are only loaded again for groups whose last message or epoch
+///   changed since the previous call, or in which the bridge sent or saw (in an open
+///   message stream or event stream) an event that may edit or delete a message
+/// * Direct message metadata comes from the metadata cache; it is `None` if it was never
+///   fetched and cannot be fetched now
+Future<List<ChatSummaryData>> fetchChatList({required PublicKey pubkey}) =>
+    RustLib.instance.api.crateApiChatsFetchChatList(pubkey: pubkey);
+
+/// Marks a group as read up to a message.
+///
+/// The read marker is persisted per account, so unread counts survive restarts and are
+/// the same in `fetch_chat_list` and `fetch_unread_counts`. The marker never moves
+/// backwards: marking an older message as read has no effect.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `group_id` - The MLS group ID
+/// * `up_to_message_id` - The event ID (hex) of the newest message that has been read
+///
+/// # Returns
+/// * `Ok(())` - If the marker was saved
+/// * `Err(BridgeError)` - `MessageNotFound` if the message is not in the group, or a
+///   `Storage` error if the marker could not be saved
+///
+/// # Example
+/// ```dart
+/// // When the group screen shows its newest message
+/// await markGroupRead(pubkey: pubkey, groupId: groupId, upToMessageId: messages.last.id);
+/// ```
+Future<void> markGroupRead({
+  required PublicKey pubkey,
+  required GroupId groupId,
+  required String upToMessageId,
+}) => RustLib.instance.api.crateApiChatsMarkGroupRead(
+  pubkey: pubkey,
+  groupId: groupId,
+  upToMessageId: upToMessageId,
+);
+
+/// Fetches the number of unread messages in each of the account's active groups.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+///
+/// # Returns
+/// * `Ok(HashMap<String, u32>)` - Unread counts keyed by MLS group ID (hex)
+/// * `Err(BridgeError)` - If the groups, messages or read markers could not be loaded
+Future<Map<String, int>> fetchUnreadCounts({required PublicKey pubkey}) =>
+    RustLib.instance.api.crateApiChatsFetchUnreadCounts(pubkey: pubkey);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GroupId>>
+abstract class GroupId implements RustOpaqueInterface {}
+
+/// Everything the chat list needs to render one group.
+class ChatSummaryData {
+  final GroupData group;
+  final String? lastMessageId;
+  final String? lastMessagePreview;
+  final String? lastMessageAuthor;
+  final BigInt? lastActivityAt;
+  final int unreadCount;
+  final int memberCount;
+  final String? dmCounterpartPubkey;
+  final MetadataData? dmCounterpartMetadata;
+
+  const ChatSummaryData({
+    required this.group,
+    this.lastMessageId,
+    this.lastMessagePreview,
+    this.lastMessageAuthor,
+    this.lastActivityAt,
+    required this.unreadCount,
+    required this.memberCount,
+    this.dmCounterpartPubkey,
+    this.dmCounterpartMetadata,
+  });
+
+  @override
+  int get hashCode =>
+      group.hashCode ^
+      lastMessageId.hashCode ^
+      lastMessagePreview.hashCode ^
+      lastMessageAuthor.hashCode ^
+      lastActivityAt.hashCode ^
+      unreadCount.hashCode ^
+      memberCount.hashCode ^
+      dmCounterpartPubkey.hashCode ^
+      dmCounterpartMetadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChatSummaryData &&
+          runtimeType == other.runtimeType &&
+          group == other.group &&
+          lastMessageId == other.lastMessageId &&
+          lastMessagePreview == other.lastMessagePreview &&
+          lastMessageAuthor == other.lastMessageAuthor &&
+          lastActivityAt == other.lastActivityAt &&
+          unreadCount == other.unreadCount &&
+          memberCount == other.memberCount &&
+          dmCounterpartPubkey == other.dmCounterpartPubkey &&
+          dmCounterpartMetadata == other.dmCounterpartMetadata;
+}
@@ -5,11 +5,14 @@
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-import '../api.dart';
 import '../frb_generated.dart';
 import 'accounts.dart';
+import 'errors.dart';
 import 'utils.dart';
 
+// These functions are ignored because they are not marked as `pub`: `normalize_for_search`, `score_name`, `score_profile`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
 /// Fetches all contacts associated with an account.
 ///
 /// This function retrieves the complete contact list for a specified account,
@@ -23,7 +26,7 @@ import 'utils.dart';
 ///
 /// # Returns
 /// * `Ok(HashMap<PublicKey, Option<MetadataData>>)` - Map of contact public keys to their metadata
-/// * `Err(WhitenoiseError)` - If there was an error fetching contacts or account not found
+/// * `Err(BridgeError)` - If there was an error fetching contacts or account not found
 ///
 /// # Example
 /// ```rust
@@ -47,7 +50,7 @@ Future<Map<PublicKey, MetadataData?>> fetchContacts({
 ///
 /// # Returns
 /// * `Ok(HashMap<PublicKey, Option<MetadataData>>)` - Map of contact public keys to their metadata
-/// * `Err(WhitenoiseError)` - If there was an error fetching contacts or account not found
+/// * `Err(BridgeError)` - If there was an error fetching contacts or account not found
 ///
 /// # Example
 /// ```rust
@@ -69,7 +72,7 @@ Future<Map<PublicKey, MetadataData?>> queryContacts({
 ///
 /// # Returns
 /// * `Ok(())` - If the contact was successfully added
-/// * `Err(WhitenoiseError)` - If there was an error adding the contact (e.g., account not found, network error)
+/// * `Err(BridgeError)` - If there was an error adding the contact (e.g., account not found, network error)
 ///
 /// # Example
 /// ```rust
@@ -95,7 +98,7 @@ Future<void> addContact({
 ///
 /// # Returns
 /// * `Ok(())` - If the contact was successfully removed
-/// * `Err(WhitenoiseError)` - If there was an error removing the contact (e.g., account not found, contact not in list)
+/// * `Err(BridgeError)` - If there was an error removing the contact (e.g., account not found, contact not in list)
 ///
 /// # Example
 /// ```rust
@@ -122,7 +125,7 @@ Future<void> removeContact({
 ///
 /// # Returns
 /// * `Ok(())` - If the contact list was successfully updated
-/// * `Err(WhitenoiseError)` - If there was an error updating the contacts
+/// * `Err(BridgeError)` - If there was an error updating the contacts
 ///
 /// # Warning
 /// This operation completely replaces the existing contact list. Use with caution.
@@ -140,3 +143,69 @@ Future<void> updateContacts({
   pubkey: pubkey,
   contactPubkeys: contactPubkeys,
 );
+
+/// Searches an account's contacts by name, display name, NIP-05 identifier or key.
+///
+/// Matching is case- and accent-insensitive ("jose" finds "José"). Names match anywhere,
+/// but exact matches rank first, followed by matches at the start of the name, at the
+/// start of a word, and anywhere else. Public keys match by hex prefix once the query is
+/// at least 4 characters long, and by npub prefix once it goes past "npub1". Contacts
+/// rank above other profiles with an equally good match.
+///
+/// # Parameters
+/// * `pubkey` - The public key of the account whose contacts to search
+/// * `query` - The search text
+/// * `include_cached_profiles` - Also search profiles that are not contacts but are in
+///   the metadata cache (e.g. members of the account's groups)
+///
+/// # Returns
+/// * `Ok(Vec<ContactSearchResultData>)` - Matching profiles, best match first; empty if
+///   the query is blank
+/// * `Err(BridgeError)` - If the contacts or the metadata cache could not be read
+///
+/// # Example
+/// ```rust
+/// let results = search_contacts(&my_pubkey, "alice".to_string(), true).await?;
+/// println!("Found {} matches", results.len());
+/// ```
+Future<List<ContactSearchResultData>> searchContacts({
+  required PublicKey pubkey,
+  required String query,
+  required bool includeCachedProfiles,
+}) => RustLib.instance.api.crateApiContactsSearchContacts(
+  pubkey: pubkey,
+  query: query,
+  includeCachedProfiles: includeCachedProfiles,
+);
+
+/// A profile matching a `search_contacts` query.
+class ContactSearchResultData {
+  final String pubkey;
+  final String npub;
+  final MetadataData? metadata;
+  final bool isContact;
+  final int score;
+
+  const ContactSearchResultData({
+    required this.pubkey,
+    required this.npub,
+    this.metadata,
+    required this.isContact,
+    required this.score,
+  });
+
+  @override
+  int get hashCode =>
+      pubkey.hashCode ^ npub.hashCode ^ metadata.hashCode ^ isContact.hashCode ^ score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ContactSearchResultData &&
+          runtimeType == other.runtimeType &&
+          pubkey == other.pubkey &&
+          npub == other.npub &&
+          metadata == other.metadata &&
+          isContact == other.isContact &&
+          score == other.score;
+}
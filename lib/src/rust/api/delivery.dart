@@ -0,0 +1,96 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+import 'accounts.dart';
+import 'errors.dart';
+
+// These functions are ignored because they are not marked as `pub`: `announce_delivery_status`, `insert_sent`, `outbox_delivery_status`, `record_sent_message`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+/// Fetches the delivery status of a message sent by the account.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account that sent the message
+/// * `message_id` - The outbox ID of the message (see `BridgeError::outbox_id` and
+///   `OutboxMessageData::id`) or, once sent, its event ID (hex)
+///
+/// # Returns
+/// * `Ok(Some(DeliveryStatusData))` - The status: pending or failed while the message is
+///   in the outbox, sent once the core has published it
+/// * `Ok(None)` - If the message is unknown: sent by someone else, cancelled, sent
+///   without the outbox (reactions and deletions) or older than the last 1000 sent
+///   messages
+/// * `Err(BridgeError)` - If the status could not be read
+///
+/// # Notes
+/// * Status changes are pushed on the global event stream as
+///   `WhitenoiseEvent::MessageDeliveryStatusChanged`
+/// * The core publishes to all of the group's relays at once and reports neither which
+///   relays accepted the message nor why single relays refused it, so there is no
+///   per-relay status: `Sent` means the core reported the publish as successful
+Future<DeliveryStatusData?> fetchMessageDeliveryStatus({
+  required PublicKey pubkey,
+  required String messageId,
+}) => RustLib.instance.api.crateApiDeliveryFetchMessageDeliveryStatus(
+  pubkey: pubkey,
+  messageId: messageId,
+);
+
+/// Delivery state of a message sent through the outbox.
+enum DeliveryState {
+  /// The message is queued and will be sent (again) automatically
+  pending,
+  /// The core published the message
+  sent,
+  /// Automatic retries gave up; use `retry_message` or `cancel_pending_message`
+  failed,
+}
+
+/// Delivery status of a message sent by the account.
+class DeliveryStatusData {
+  final String outboxId;
+  final String? messageId;
+  final String groupId;
+  final DeliveryState state;
+  final int attempts;
+  final String? error;
+  final BigInt updatedAt;
+
+  const DeliveryStatusData({
+    required this.outboxId,
+    this.messageId,
+    required this.groupId,
+    required this.state,
+    required this.attempts,
+    this.error,
+    required this.updatedAt,
+  });
+
+  @override
+  int get hashCode =>
+      outboxId.hashCode ^
+      messageId.hashCode ^
+      groupId.hashCode ^
+      state.hashCode ^
+      attempts.hashCode ^
+      error.hashCode ^
+      updatedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DeliveryStatusData &&
+          runtimeType == other.runtimeType &&
+          outboxId == other.outboxId &&
+          messageId == other.messageId &&
+          groupId == other.groupId &&
+          state == other.state &&
+          attempts == other.attempts &&
+          error == other.error &&
+          updatedAt == other.updatedAt;
+}
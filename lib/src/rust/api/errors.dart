@@ -0,0 +1,92 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `new`, `with_details`, `with_outbox_id`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `from`, `from`, `hash`
+
+/// Error returned by bridge functions.
+///
+/// # Fields
+/// * `code` - Stable code to branch on in Flutter
+/// * `message` - Default user-facing message (English); Flutter should prefer a localized
+///   message chosen by `code`
+/// * `details` - Optional technical details (e.g. the underlying error) for logs
+/// * `outbox_id` - Set when a message could not be sent but stays in the outbox and is
+///   retried automatically; track it with `fetch_outbox` and the outbox events
+class BridgeError implements FrbException {
+  final BridgeErrorCode code;
+  final String message;
+  final String? details;
+  final String? outboxId;
+
+  const BridgeError({
+    required this.code,
+    required this.message,
+    this.details,
+    this.outboxId,
+  });
+
+  @override
+  int get hashCode => code.hashCode ^ message.hashCode ^ details.hashCode ^ outboxId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BridgeError &&
+          runtimeType == other.runtimeType &&
+          code == other.code &&
+          message == other.message &&
+          details == other.details &&
+          outboxId == other.outboxId;
+}
+
+/// Stable, machine-readable error codes.
+///
+/// These codes are part of the bridge contract: existing variants must not be renamed or
+/// repurposed, new ones may be added.
+enum BridgeErrorCode {
+  /// `initialize_whitenoise` has not been called (or failed)
+  notInitialized,
+  /// The account is not logged in on this device
+  accountNotFound,
+  /// The account is not allowed to perform this action (e.g. not a group admin)
+  unauthorized,
+  /// The group does not exist or the account is not a member
+  groupNotFound,
+  /// The welcome does not exist
+  welcomeNotFound,
+  /// The message does not exist in the group
+  messageNotFound,
+  /// One or more of the given members are not in the group
+  memberNotInGroup,
+  /// A public key could not be parsed (hex or npub)
+  invalidPublicKey,
+  /// A hex string could not be decoded
+  invalidHex,
+  /// A relay URL could not be parsed
+  invalidRelayUrl,
+  /// Any other invalid argument
+  invalidInput,
+  /// The operation is not supported (e.g. an unsupported file format)
+  unsupported,
+  /// None of the relays needed for the operation could be reached
+  relayUnreachable,
+  /// A user has not published a key package, so they cannot be added to a group
+  noKeyPackage,
+  /// An error in the MLS layer
+  mlsError,
+  /// A network or Nostr client error
+  network,
+  /// A local database, keychain or filesystem error
+  storage,
+  /// Invalid configuration or logging setup
+  configuration,
+  /// An unexpected internal error
+  internal,
+}
@@ -0,0 +1,114 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+
+import '../frb_generated.dart';
+import 'accounts.dart';
+import 'delivery.dart';
+import 'groups.dart';
+import 'messages.dart';
+import 'outbox.dart';
+import 'utils.dart';
+import 'welcomes.dart';
+
+part 'events.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `carry_over`, `diff_snapshots`, `emit_event`, `event_bus`, `extend`, `forget_messages_of`, `load_snapshot`, `next_event`, `refresh_events`, `start_event_watcher`, `subscribe_events`, `update`, `watch_state`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// An event pushed on the stream created by `subscribe_whitenoise_events`.
+///
+/// Every event that concerns a single account carries that account's public key
+/// (hex) so multi-account setups can route it to the right providers.
+@freezed
+sealed class WhitenoiseEvent with _$WhitenoiseEvent {
+  const WhitenoiseEvent._();
+
+  /// First item on every stream; carries the handle to pass to `unsubscribe`
+  const factory WhitenoiseEvent.subscribed({required BigInt subscriptionId}) =
+      WhitenoiseEvent_Subscribed;
+
+  /// A new pending welcome (group invitation) was received
+  const factory WhitenoiseEvent.welcomeReceived({
+    required String accountPubkey,
+    required WelcomeData welcome,
+  }) = WhitenoiseEvent_WelcomeReceived;
+
+  /// The account joined or created a group
+  const factory WhitenoiseEvent.groupCreated({
+    required String accountPubkey,
+    required GroupData group,
+  }) = WhitenoiseEvent_GroupCreated;
+
+  /// Group details (name, description, image, admins, epoch) changed
+  const factory WhitenoiseEvent.groupUpdated({
+    required String accountPubkey,
+    required GroupData group,
+  }) = WhitenoiseEvent_GroupUpdated;
+
+  /// A group moved to another `GroupState`
+  const factory WhitenoiseEvent.groupStateChanged({
+    required String accountPubkey,
+    required GroupData group,
+  }) = WhitenoiseEvent_GroupStateChanged;
+
+  /// A member was added to a group
+  const factory WhitenoiseEvent.memberAdded({
+    required String accountPubkey,
+    required String groupId,
+    required String memberPubkey,
+  }) = WhitenoiseEvent_MemberAdded;
+
+  /// A member was removed from a group
+  const factory WhitenoiseEvent.memberRemoved({
+    required String accountPubkey,
+    required String groupId,
+    required String memberPubkey,
+  }) = WhitenoiseEvent_MemberRemoved;
+
+  /// The connection status of one of the account's relays changed. Noticed whenever the
+  /// state is checked (after bridge actions and incoming events), not on a timer
+  const factory WhitenoiseEvent.relayStatusChanged({
+    required String accountPubkey,
+    required String relayUrl,
+    required String status,
+  }) = WhitenoiseEvent_RelayStatusChanged;
+
+  /// Profile metadata for a public key was updated
+  const factory WhitenoiseEvent.metadataUpdated({
+    required String pubkey,
+    required MetadataData metadata,
+  }) = WhitenoiseEvent_MetadataUpdated;
+
+  /// An account was logged in
+  const factory WhitenoiseEvent.accountLoggedIn({
+    required AccountData account,
+  }) = WhitenoiseEvent_AccountLoggedIn;
+
+  /// An account was logged out
+  const factory WhitenoiseEvent.accountLoggedOut({required String pubkey}) =
+      WhitenoiseEvent_AccountLoggedOut;
+
+  /// The delivery status of a message sent by the account changed
+  const factory WhitenoiseEvent.messageDeliveryStatusChanged({
+    required String accountPubkey,
+    required DeliveryStatusData status,
+  }) = WhitenoiseEvent_MessageDeliveryStatusChanged;
+
+  /// A queued message failed to send and will be retried (or has been given up on)
+  const factory WhitenoiseEvent.outboxMessageUpdated({
+    required String accountPubkey,
+    required OutboxMessageData message,
+  }) = WhitenoiseEvent_OutboxMessageUpdated;
+
+  /// A queued message was sent and removed from the outbox
+  const factory WhitenoiseEvent.outboxMessageSent({
+    required String accountPubkey,
+    required String outboxId,
+    required MessageWithTokensData message,
+  }) = WhitenoiseEvent_OutboxMessageSent;
+}
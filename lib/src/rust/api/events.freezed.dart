@@ -0,0 +1,4119 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'events.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$WhitenoiseEvent {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $WhitenoiseEventCopyWith<$Res> {
+  factory $WhitenoiseEventCopyWith(
+    WhitenoiseEvent value,
+    $Res Function(WhitenoiseEvent) then,
+  ) = _$WhitenoiseEventCopyWithImpl<$Res, WhitenoiseEvent>;
+}
+
+/// @nodoc
+class _$WhitenoiseEventCopyWithImpl<$Res, $Val extends WhitenoiseEvent>
+    implements $WhitenoiseEventCopyWith<$Res> {
+  _$WhitenoiseEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_SubscribedImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_SubscribedImplCopyWith(
+    _$WhitenoiseEvent_SubscribedImpl value,
+    $Res Function(_$WhitenoiseEvent_SubscribedImpl) then,
+  ) = __$$WhitenoiseEvent_SubscribedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt subscriptionId});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_SubscribedImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_SubscribedImpl>
+    implements _$$WhitenoiseEvent_SubscribedImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_SubscribedImplCopyWithImpl(
+    _$WhitenoiseEvent_SubscribedImpl _value,
+    $Res Function(_$WhitenoiseEvent_SubscribedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? subscriptionId = null}) {
+    return _then(
+      _$WhitenoiseEvent_SubscribedImpl(
+        subscriptionId: null == subscriptionId
+            ? _value.subscriptionId
+            : subscriptionId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_SubscribedImpl extends WhitenoiseEvent_Subscribed {
+  const _$WhitenoiseEvent_SubscribedImpl({required this.subscriptionId}) : super._();
+
+  @override
+  final BigInt subscriptionId;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.subscribed(subscriptionId: $subscriptionId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_SubscribedImpl &&
+            (identical(other.subscriptionId, subscriptionId) ||
+                other.subscriptionId == subscriptionId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, subscriptionId);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_SubscribedImplCopyWith<_$WhitenoiseEvent_SubscribedImpl> get copyWith =>
+      __$$WhitenoiseEvent_SubscribedImplCopyWithImpl<_$WhitenoiseEvent_SubscribedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return subscribed(subscriptionId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return subscribed?.call(subscriptionId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (subscribed != null) {
+      return subscribed(subscriptionId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return subscribed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return subscribed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (subscribed != null) {
+      return subscribed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_Subscribed extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_Subscribed({
+    required final BigInt subscriptionId,
+  }) = _$WhitenoiseEvent_SubscribedImpl;
+  const WhitenoiseEvent_Subscribed._() : super._();
+
+  BigInt get subscriptionId;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_SubscribedImplCopyWith<_$WhitenoiseEvent_SubscribedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_WelcomeReceivedImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_WelcomeReceivedImplCopyWith(
+    _$WhitenoiseEvent_WelcomeReceivedImpl value,
+    $Res Function(_$WhitenoiseEvent_WelcomeReceivedImpl) then,
+  ) = __$$WhitenoiseEvent_WelcomeReceivedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String accountPubkey, WelcomeData welcome});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_WelcomeReceivedImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_WelcomeReceivedImpl>
+    implements _$$WhitenoiseEvent_WelcomeReceivedImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_WelcomeReceivedImplCopyWithImpl(
+    _$WhitenoiseEvent_WelcomeReceivedImpl _value,
+    $Res Function(_$WhitenoiseEvent_WelcomeReceivedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? accountPubkey = null, Object? welcome = null}) {
+    return _then(
+      _$WhitenoiseEvent_WelcomeReceivedImpl(
+        accountPubkey: null == accountPubkey
+            ? _value.accountPubkey
+            : accountPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        welcome: null == welcome
+            ? _value.welcome
+            : welcome // ignore: cast_nullable_to_non_nullable
+              as WelcomeData,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_WelcomeReceivedImpl extends WhitenoiseEvent_WelcomeReceived {
+  const _$WhitenoiseEvent_WelcomeReceivedImpl({required this.accountPubkey, required this.welcome})
+    : super._();
+
+  @override
+  final String accountPubkey;
+
+  @override
+  final WelcomeData welcome;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.welcomeReceived(accountPubkey: $accountPubkey, welcome: $welcome)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_WelcomeReceivedImpl &&
+            (identical(other.accountPubkey, accountPubkey) ||
+                other.accountPubkey == accountPubkey) &&
+            (identical(other.welcome, welcome) || other.welcome == welcome));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, accountPubkey, welcome);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_WelcomeReceivedImplCopyWith<_$WhitenoiseEvent_WelcomeReceivedImpl> get copyWith =>
+      __$$WhitenoiseEvent_WelcomeReceivedImplCopyWithImpl<_$WhitenoiseEvent_WelcomeReceivedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return welcomeReceived(accountPubkey, welcome);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return welcomeReceived?.call(accountPubkey, welcome);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (welcomeReceived != null) {
+      return welcomeReceived(accountPubkey, welcome);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return welcomeReceived(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return welcomeReceived?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (welcomeReceived != null) {
+      return welcomeReceived(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_WelcomeReceived extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_WelcomeReceived({
+    required final String accountPubkey,
+    required final WelcomeData welcome,
+  }) = _$WhitenoiseEvent_WelcomeReceivedImpl;
+  const WhitenoiseEvent_WelcomeReceived._() : super._();
+
+  String get accountPubkey;
+  WelcomeData get welcome;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_WelcomeReceivedImplCopyWith<_$WhitenoiseEvent_WelcomeReceivedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_GroupCreatedImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_GroupCreatedImplCopyWith(
+    _$WhitenoiseEvent_GroupCreatedImpl value,
+    $Res Function(_$WhitenoiseEvent_GroupCreatedImpl) then,
+  ) = __$$WhitenoiseEvent_GroupCreatedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String accountPubkey, GroupData group});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_GroupCreatedImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_GroupCreatedImpl>
+    implements _$$WhitenoiseEvent_GroupCreatedImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_GroupCreatedImplCopyWithImpl(
+    _$WhitenoiseEvent_GroupCreatedImpl _value,
+    $Res Function(_$WhitenoiseEvent_GroupCreatedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? accountPubkey = null, Object? group = null}) {
+    return _then(
+      _$WhitenoiseEvent_GroupCreatedImpl(
+        accountPubkey: null == accountPubkey
+            ? _value.accountPubkey
+            : accountPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        group: null == group
+            ? _value.group
+            : group // ignore: cast_nullable_to_non_nullable
+              as GroupData,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_GroupCreatedImpl extends WhitenoiseEvent_GroupCreated {
+  const _$WhitenoiseEvent_GroupCreatedImpl({required this.accountPubkey, required this.group})
+    : super._();
+
+  @override
+  final String accountPubkey;
+
+  @override
+  final GroupData group;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.groupCreated(accountPubkey: $accountPubkey, group: $group)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_GroupCreatedImpl &&
+            (identical(other.accountPubkey, accountPubkey) ||
+                other.accountPubkey == accountPubkey) &&
+            (identical(other.group, group) || other.group == group));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, accountPubkey, group);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_GroupCreatedImplCopyWith<_$WhitenoiseEvent_GroupCreatedImpl> get copyWith =>
+      __$$WhitenoiseEvent_GroupCreatedImplCopyWithImpl<_$WhitenoiseEvent_GroupCreatedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return groupCreated(accountPubkey, group);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return groupCreated?.call(accountPubkey, group);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (groupCreated != null) {
+      return groupCreated(accountPubkey, group);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return groupCreated(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return groupCreated?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (groupCreated != null) {
+      return groupCreated(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_GroupCreated extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_GroupCreated({
+    required final String accountPubkey,
+    required final GroupData group,
+  }) = _$WhitenoiseEvent_GroupCreatedImpl;
+  const WhitenoiseEvent_GroupCreated._() : super._();
+
+  String get accountPubkey;
+  GroupData get group;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_GroupCreatedImplCopyWith<_$WhitenoiseEvent_GroupCreatedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_GroupUpdatedImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_GroupUpdatedImplCopyWith(
+    _$WhitenoiseEvent_GroupUpdatedImpl value,
+    $Res Function(_$WhitenoiseEvent_GroupUpdatedImpl) then,
+  ) = __$$WhitenoiseEvent_GroupUpdatedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String accountPubkey, GroupData group});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_GroupUpdatedImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_GroupUpdatedImpl>
+    implements _$$WhitenoiseEvent_GroupUpdatedImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_GroupUpdatedImplCopyWithImpl(
+    _$WhitenoiseEvent_GroupUpdatedImpl _value,
+    $Res Function(_$WhitenoiseEvent_GroupUpdatedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? accountPubkey = null, Object? group = null}) {
+    return _then(
+      _$WhitenoiseEvent_GroupUpdatedImpl(
+        accountPubkey: null == accountPubkey
+            ? _value.accountPubkey
+            : accountPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        group: null == group
+            ? _value.group
+            : group // ignore: cast_nullable_to_non_nullable
+              as GroupData,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_GroupUpdatedImpl extends WhitenoiseEvent_GroupUpdated {
+  const _$WhitenoiseEvent_GroupUpdatedImpl({required this.accountPubkey, required this.group})
+    : super._();
+
+  @override
+  final String accountPubkey;
+
+  @override
+  final GroupData group;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.groupUpdated(accountPubkey: $accountPubkey, group: $group)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_GroupUpdatedImpl &&
+            (identical(other.accountPubkey, accountPubkey) ||
+                other.accountPubkey == accountPubkey) &&
+            (identical(other.group, group) || other.group == group));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, accountPubkey, group);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_GroupUpdatedImplCopyWith<_$WhitenoiseEvent_GroupUpdatedImpl> get copyWith =>
+      __$$WhitenoiseEvent_GroupUpdatedImplCopyWithImpl<_$WhitenoiseEvent_GroupUpdatedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return groupUpdated(accountPubkey, group);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return groupUpdated?.call(accountPubkey, group);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (groupUpdated != null) {
+      return groupUpdated(accountPubkey, group);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return groupUpdated(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return groupUpdated?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (groupUpdated != null) {
+      return groupUpdated(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_GroupUpdated extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_GroupUpdated({
+    required final String accountPubkey,
+    required final GroupData group,
+  }) = _$WhitenoiseEvent_GroupUpdatedImpl;
+  const WhitenoiseEvent_GroupUpdated._() : super._();
+
+  String get accountPubkey;
+  GroupData get group;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_GroupUpdatedImplCopyWith<_$WhitenoiseEvent_GroupUpdatedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_GroupStateChangedImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_GroupStateChangedImplCopyWith(
+    _$WhitenoiseEvent_GroupStateChangedImpl value,
+    $Res Function(_$WhitenoiseEvent_GroupStateChangedImpl) then,
+  ) = __$$WhitenoiseEvent_GroupStateChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String accountPubkey, GroupData group});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_GroupStateChangedImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_GroupStateChangedImpl>
+    implements _$$WhitenoiseEvent_GroupStateChangedImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_GroupStateChangedImplCopyWithImpl(
+    _$WhitenoiseEvent_GroupStateChangedImpl _value,
+    $Res Function(_$WhitenoiseEvent_GroupStateChangedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? accountPubkey = null, Object? group = null}) {
+    return _then(
+      _$WhitenoiseEvent_GroupStateChangedImpl(
+        accountPubkey: null == accountPubkey
+            ? _value.accountPubkey
+            : accountPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        group: null == group
+            ? _value.group
+            : group // ignore: cast_nullable_to_non_nullable
+              as GroupData,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_GroupStateChangedImpl extends WhitenoiseEvent_GroupStateChanged {
+  const _$WhitenoiseEvent_GroupStateChangedImpl({required this.accountPubkey, required this.group})
+    : super._();
+
+  @override
+  final String accountPubkey;
+
+  @override
+  final GroupData group;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.groupStateChanged(accountPubkey: $accountPubkey, group: $group)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_GroupStateChangedImpl &&
+            (identical(other.accountPubkey, accountPubkey) ||
+                other.accountPubkey == accountPubkey) &&
+            (identical(other.group, group) || other.group == group));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, accountPubkey, group);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_GroupStateChangedImplCopyWith<_$WhitenoiseEvent_GroupStateChangedImpl> get copyWith =>
+      __$$WhitenoiseEvent_GroupStateChangedImplCopyWithImpl<_$WhitenoiseEvent_GroupStateChangedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return groupStateChanged(accountPubkey, group);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return groupStateChanged?.call(accountPubkey, group);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (groupStateChanged != null) {
+      return groupStateChanged(accountPubkey, group);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return groupStateChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return groupStateChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (groupStateChanged != null) {
+      return groupStateChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_GroupStateChanged extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_GroupStateChanged({
+    required final String accountPubkey,
+    required final GroupData group,
+  }) = _$WhitenoiseEvent_GroupStateChangedImpl;
+  const WhitenoiseEvent_GroupStateChanged._() : super._();
+
+  String get accountPubkey;
+  GroupData get group;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_GroupStateChangedImplCopyWith<_$WhitenoiseEvent_GroupStateChangedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_MemberAddedImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_MemberAddedImplCopyWith(
+    _$WhitenoiseEvent_MemberAddedImpl value,
+    $Res Function(_$WhitenoiseEvent_MemberAddedImpl) then,
+  ) = __$$WhitenoiseEvent_MemberAddedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String accountPubkey, String groupId, String memberPubkey});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_MemberAddedImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_MemberAddedImpl>
+    implements _$$WhitenoiseEvent_MemberAddedImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_MemberAddedImplCopyWithImpl(
+    _$WhitenoiseEvent_MemberAddedImpl _value,
+    $Res Function(_$WhitenoiseEvent_MemberAddedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? accountPubkey = null, Object? groupId = null, Object? memberPubkey = null}) {
+    return _then(
+      _$WhitenoiseEvent_MemberAddedImpl(
+        accountPubkey: null == accountPubkey
+            ? _value.accountPubkey
+            : accountPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        groupId: null == groupId
+            ? _value.groupId
+            : groupId // ignore: cast_nullable_to_non_nullable
+              as String,
+        memberPubkey: null == memberPubkey
+            ? _value.memberPubkey
+            : memberPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_MemberAddedImpl extends WhitenoiseEvent_MemberAdded {
+  const _$WhitenoiseEvent_MemberAddedImpl({
+    required this.accountPubkey,
+    required this.groupId,
+    required this.memberPubkey,
+  }) : super._();
+
+  @override
+  final String accountPubkey;
+
+  @override
+  final String groupId;
+
+  @override
+  final String memberPubkey;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.memberAdded(accountPubkey: $accountPubkey, groupId: $groupId, memberPubkey: $memberPubkey)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_MemberAddedImpl &&
+            (identical(other.accountPubkey, accountPubkey) ||
+                other.accountPubkey == accountPubkey) &&
+            (identical(other.groupId, groupId) || other.groupId == groupId) &&
+            (identical(other.memberPubkey, memberPubkey) || other.memberPubkey == memberPubkey));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, accountPubkey, groupId, memberPubkey);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_MemberAddedImplCopyWith<_$WhitenoiseEvent_MemberAddedImpl> get copyWith =>
+      __$$WhitenoiseEvent_MemberAddedImplCopyWithImpl<_$WhitenoiseEvent_MemberAddedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return memberAdded(accountPubkey, groupId, memberPubkey);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return memberAdded?.call(accountPubkey, groupId, memberPubkey);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (memberAdded != null) {
+      return memberAdded(accountPubkey, groupId, memberPubkey);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return memberAdded(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return memberAdded?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (memberAdded != null) {
+      return memberAdded(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_MemberAdded extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_MemberAdded({
+    required final String accountPubkey,
+    required final String groupId,
+    required final String memberPubkey,
+  }) = _$WhitenoiseEvent_MemberAddedImpl;
+  const WhitenoiseEvent_MemberAdded._() : super._();
+
+  String get accountPubkey;
+  String get groupId;
+  String get memberPubkey;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_MemberAddedImplCopyWith<_$WhitenoiseEvent_MemberAddedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_MemberRemovedImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_MemberRemovedImplCopyWith(
+    _$WhitenoiseEvent_MemberRemovedImpl value,
+    $Res Function(_$WhitenoiseEvent_MemberRemovedImpl) then,
+  ) = __$$WhitenoiseEvent_MemberRemovedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String accountPubkey, String groupId, String memberPubkey});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_MemberRemovedImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_MemberRemovedImpl>
+    implements _$$WhitenoiseEvent_MemberRemovedImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_MemberRemovedImplCopyWithImpl(
+    _$WhitenoiseEvent_MemberRemovedImpl _value,
+    $Res Function(_$WhitenoiseEvent_MemberRemovedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? accountPubkey = null, Object? groupId = null, Object? memberPubkey = null}) {
+    return _then(
+      _$WhitenoiseEvent_MemberRemovedImpl(
+        accountPubkey: null == accountPubkey
+            ? _value.accountPubkey
+            : accountPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        groupId: null == groupId
+            ? _value.groupId
+            : groupId // ignore: cast_nullable_to_non_nullable
+              as String,
+        memberPubkey: null == memberPubkey
+            ? _value.memberPubkey
+            : memberPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_MemberRemovedImpl extends WhitenoiseEvent_MemberRemoved {
+  const _$WhitenoiseEvent_MemberRemovedImpl({
+    required this.accountPubkey,
+    required this.groupId,
+    required this.memberPubkey,
+  }) : super._();
+
+  @override
+  final String accountPubkey;
+
+  @override
+  final String groupId;
+
+  @override
+  final String memberPubkey;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.memberRemoved(accountPubkey: $accountPubkey, groupId: $groupId, memberPubkey: $memberPubkey)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_MemberRemovedImpl &&
+            (identical(other.accountPubkey, accountPubkey) ||
+                other.accountPubkey == accountPubkey) &&
+            (identical(other.groupId, groupId) || other.groupId == groupId) &&
+            (identical(other.memberPubkey, memberPubkey) || other.memberPubkey == memberPubkey));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, accountPubkey, groupId, memberPubkey);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_MemberRemovedImplCopyWith<_$WhitenoiseEvent_MemberRemovedImpl> get copyWith =>
+      __$$WhitenoiseEvent_MemberRemovedImplCopyWithImpl<_$WhitenoiseEvent_MemberRemovedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return memberRemoved(accountPubkey, groupId, memberPubkey);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return memberRemoved?.call(accountPubkey, groupId, memberPubkey);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (memberRemoved != null) {
+      return memberRemoved(accountPubkey, groupId, memberPubkey);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return memberRemoved(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return memberRemoved?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (memberRemoved != null) {
+      return memberRemoved(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_MemberRemoved extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_MemberRemoved({
+    required final String accountPubkey,
+    required final String groupId,
+    required final String memberPubkey,
+  }) = _$WhitenoiseEvent_MemberRemovedImpl;
+  const WhitenoiseEvent_MemberRemoved._() : super._();
+
+  String get accountPubkey;
+  String get groupId;
+  String get memberPubkey;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_MemberRemovedImplCopyWith<_$WhitenoiseEvent_MemberRemovedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_RelayStatusChangedImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_RelayStatusChangedImplCopyWith(
+    _$WhitenoiseEvent_RelayStatusChangedImpl value,
+    $Res Function(_$WhitenoiseEvent_RelayStatusChangedImpl) then,
+  ) = __$$WhitenoiseEvent_RelayStatusChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String accountPubkey, String relayUrl, String status});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_RelayStatusChangedImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_RelayStatusChangedImpl>
+    implements _$$WhitenoiseEvent_RelayStatusChangedImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_RelayStatusChangedImplCopyWithImpl(
+    _$WhitenoiseEvent_RelayStatusChangedImpl _value,
+    $Res Function(_$WhitenoiseEvent_RelayStatusChangedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? accountPubkey = null, Object? relayUrl = null, Object? status = null}) {
+    return _then(
+      _$WhitenoiseEvent_RelayStatusChangedImpl(
+        accountPubkey: null == accountPubkey
+            ? _value.accountPubkey
+            : accountPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        relayUrl: null == relayUrl
+            ? _value.relayUrl
+            : relayUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+        status: null == status
+            ? _value.status
+            : status // ignore: cast_nullable_to_non_nullable
+              as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_RelayStatusChangedImpl extends WhitenoiseEvent_RelayStatusChanged {
+  const _$WhitenoiseEvent_RelayStatusChangedImpl({
+    required this.accountPubkey,
+    required this.relayUrl,
+    required this.status,
+  }) : super._();
+
+  @override
+  final String accountPubkey;
+
+  @override
+  final String relayUrl;
+
+  @override
+  final String status;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.relayStatusChanged(accountPubkey: $accountPubkey, relayUrl: $relayUrl, status: $status)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_RelayStatusChangedImpl &&
+            (identical(other.accountPubkey, accountPubkey) ||
+                other.accountPubkey == accountPubkey) &&
+            (identical(other.relayUrl, relayUrl) || other.relayUrl == relayUrl) &&
+            (identical(other.status, status) || other.status == status));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, accountPubkey, relayUrl, status);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_RelayStatusChangedImplCopyWith<_$WhitenoiseEvent_RelayStatusChangedImpl> get copyWith =>
+      __$$WhitenoiseEvent_RelayStatusChangedImplCopyWithImpl<_$WhitenoiseEvent_RelayStatusChangedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return relayStatusChanged(accountPubkey, relayUrl, status);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return relayStatusChanged?.call(accountPubkey, relayUrl, status);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (relayStatusChanged != null) {
+      return relayStatusChanged(accountPubkey, relayUrl, status);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return relayStatusChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return relayStatusChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (relayStatusChanged != null) {
+      return relayStatusChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_RelayStatusChanged extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_RelayStatusChanged({
+    required final String accountPubkey,
+    required final String relayUrl,
+    required final String status,
+  }) = _$WhitenoiseEvent_RelayStatusChangedImpl;
+  const WhitenoiseEvent_RelayStatusChanged._() : super._();
+
+  String get accountPubkey;
+  String get relayUrl;
+  String get status;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_RelayStatusChangedImplCopyWith<_$WhitenoiseEvent_RelayStatusChangedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_MetadataUpdatedImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_MetadataUpdatedImplCopyWith(
+    _$WhitenoiseEvent_MetadataUpdatedImpl value,
+    $Res Function(_$WhitenoiseEvent_MetadataUpdatedImpl) then,
+  ) = __$$WhitenoiseEvent_MetadataUpdatedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String pubkey, MetadataData metadata});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_MetadataUpdatedImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_MetadataUpdatedImpl>
+    implements _$$WhitenoiseEvent_MetadataUpdatedImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_MetadataUpdatedImplCopyWithImpl(
+    _$WhitenoiseEvent_MetadataUpdatedImpl _value,
+    $Res Function(_$WhitenoiseEvent_MetadataUpdatedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? pubkey = null, Object? metadata = null}) {
+    return _then(
+      _$WhitenoiseEvent_MetadataUpdatedImpl(
+        pubkey: null == pubkey
+            ? _value.pubkey
+            : pubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        metadata: null == metadata
+            ? _value.metadata
+            : metadata // ignore: cast_nullable_to_non_nullable
+              as MetadataData,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_MetadataUpdatedImpl extends WhitenoiseEvent_MetadataUpdated {
+  const _$WhitenoiseEvent_MetadataUpdatedImpl({required this.pubkey, required this.metadata})
+    : super._();
+
+  @override
+  final String pubkey;
+
+  @override
+  final MetadataData metadata;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.metadataUpdated(pubkey: $pubkey, metadata: $metadata)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_MetadataUpdatedImpl &&
+            (identical(other.pubkey, pubkey) || other.pubkey == pubkey) &&
+            (identical(other.metadata, metadata) || other.metadata == metadata));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, pubkey, metadata);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_MetadataUpdatedImplCopyWith<_$WhitenoiseEvent_MetadataUpdatedImpl> get copyWith =>
+      __$$WhitenoiseEvent_MetadataUpdatedImplCopyWithImpl<_$WhitenoiseEvent_MetadataUpdatedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return metadataUpdated(pubkey, metadata);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return metadataUpdated?.call(pubkey, metadata);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (metadataUpdated != null) {
+      return metadataUpdated(pubkey, metadata);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return metadataUpdated(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return metadataUpdated?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (metadataUpdated != null) {
+      return metadataUpdated(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_MetadataUpdated extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_MetadataUpdated({
+    required final String pubkey,
+    required final MetadataData metadata,
+  }) = _$WhitenoiseEvent_MetadataUpdatedImpl;
+  const WhitenoiseEvent_MetadataUpdated._() : super._();
+
+  String get pubkey;
+  MetadataData get metadata;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_MetadataUpdatedImplCopyWith<_$WhitenoiseEvent_MetadataUpdatedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_AccountLoggedInImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_AccountLoggedInImplCopyWith(
+    _$WhitenoiseEvent_AccountLoggedInImpl value,
+    $Res Function(_$WhitenoiseEvent_AccountLoggedInImpl) then,
+  ) = __$$WhitenoiseEvent_AccountLoggedInImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({AccountData account});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_AccountLoggedInImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_AccountLoggedInImpl>
+    implements _$$WhitenoiseEvent_AccountLoggedInImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_AccountLoggedInImplCopyWithImpl(
+    _$WhitenoiseEvent_AccountLoggedInImpl _value,
+    $Res Function(_$WhitenoiseEvent_AccountLoggedInImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? account = null}) {
+    return _then(
+      _$WhitenoiseEvent_AccountLoggedInImpl(
+        account: null == account
+            ? _value.account
+            : account // ignore: cast_nullable_to_non_nullable
+              as AccountData,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_AccountLoggedInImpl extends WhitenoiseEvent_AccountLoggedIn {
+  const _$WhitenoiseEvent_AccountLoggedInImpl({required this.account}) : super._();
+
+  @override
+  final AccountData account;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.accountLoggedIn(account: $account)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_AccountLoggedInImpl &&
+            (identical(other.account, account) || other.account == account));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, account);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_AccountLoggedInImplCopyWith<_$WhitenoiseEvent_AccountLoggedInImpl> get copyWith =>
+      __$$WhitenoiseEvent_AccountLoggedInImplCopyWithImpl<_$WhitenoiseEvent_AccountLoggedInImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return accountLoggedIn(account);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return accountLoggedIn?.call(account);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (accountLoggedIn != null) {
+      return accountLoggedIn(account);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return accountLoggedIn(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return accountLoggedIn?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (accountLoggedIn != null) {
+      return accountLoggedIn(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_AccountLoggedIn extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_AccountLoggedIn({
+    required final AccountData account,
+  }) = _$WhitenoiseEvent_AccountLoggedInImpl;
+  const WhitenoiseEvent_AccountLoggedIn._() : super._();
+
+  AccountData get account;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_AccountLoggedInImplCopyWith<_$WhitenoiseEvent_AccountLoggedInImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_AccountLoggedOutImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_AccountLoggedOutImplCopyWith(
+    _$WhitenoiseEvent_AccountLoggedOutImpl value,
+    $Res Function(_$WhitenoiseEvent_AccountLoggedOutImpl) then,
+  ) = __$$WhitenoiseEvent_AccountLoggedOutImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String pubkey});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_AccountLoggedOutImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_AccountLoggedOutImpl>
+    implements _$$WhitenoiseEvent_AccountLoggedOutImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_AccountLoggedOutImplCopyWithImpl(
+    _$WhitenoiseEvent_AccountLoggedOutImpl _value,
+    $Res Function(_$WhitenoiseEvent_AccountLoggedOutImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? pubkey = null}) {
+    return _then(
+      _$WhitenoiseEvent_AccountLoggedOutImpl(
+        pubkey: null == pubkey
+            ? _value.pubkey
+            : pubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_AccountLoggedOutImpl extends WhitenoiseEvent_AccountLoggedOut {
+  const _$WhitenoiseEvent_AccountLoggedOutImpl({required this.pubkey}) : super._();
+
+  @override
+  final String pubkey;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.accountLoggedOut(pubkey: $pubkey)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_AccountLoggedOutImpl &&
+            (identical(other.pubkey, pubkey) || other.pubkey == pubkey));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, pubkey);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_AccountLoggedOutImplCopyWith<_$WhitenoiseEvent_AccountLoggedOutImpl> get copyWith =>
+      __$$WhitenoiseEvent_AccountLoggedOutImplCopyWithImpl<_$WhitenoiseEvent_AccountLoggedOutImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return accountLoggedOut(pubkey);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return accountLoggedOut?.call(pubkey);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (accountLoggedOut != null) {
+      return accountLoggedOut(pubkey);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return accountLoggedOut(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return accountLoggedOut?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (accountLoggedOut != null) {
+      return accountLoggedOut(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_AccountLoggedOut extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_AccountLoggedOut({
+    required final String pubkey,
+  }) = _$WhitenoiseEvent_AccountLoggedOutImpl;
+  const WhitenoiseEvent_AccountLoggedOut._() : super._();
+
+  String get pubkey;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_AccountLoggedOutImplCopyWith<_$WhitenoiseEvent_AccountLoggedOutImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_MessageDeliveryStatusChangedImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_MessageDeliveryStatusChangedImplCopyWith(
+    _$WhitenoiseEvent_MessageDeliveryStatusChangedImpl value,
+    $Res Function(_$WhitenoiseEvent_MessageDeliveryStatusChangedImpl) then,
+  ) = __$$WhitenoiseEvent_MessageDeliveryStatusChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String accountPubkey, DeliveryStatusData status});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_MessageDeliveryStatusChangedImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_MessageDeliveryStatusChangedImpl>
+    implements _$$WhitenoiseEvent_MessageDeliveryStatusChangedImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_MessageDeliveryStatusChangedImplCopyWithImpl(
+    _$WhitenoiseEvent_MessageDeliveryStatusChangedImpl _value,
+    $Res Function(_$WhitenoiseEvent_MessageDeliveryStatusChangedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? accountPubkey = null, Object? status = null}) {
+    return _then(
+      _$WhitenoiseEvent_MessageDeliveryStatusChangedImpl(
+        accountPubkey: null == accountPubkey
+            ? _value.accountPubkey
+            : accountPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        status: null == status
+            ? _value.status
+            : status // ignore: cast_nullable_to_non_nullable
+              as DeliveryStatusData,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_MessageDeliveryStatusChangedImpl extends WhitenoiseEvent_MessageDeliveryStatusChanged {
+  const _$WhitenoiseEvent_MessageDeliveryStatusChangedImpl({
+    required this.accountPubkey,
+    required this.status,
+  }) : super._();
+
+  @override
+  final String accountPubkey;
+
+  @override
+  final DeliveryStatusData status;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.messageDeliveryStatusChanged(accountPubkey: $accountPubkey, status: $status)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_MessageDeliveryStatusChangedImpl &&
+            (identical(other.accountPubkey, accountPubkey) ||
+                other.accountPubkey == accountPubkey) &&
+            (identical(other.status, status) || other.status == status));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, accountPubkey, status);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_MessageDeliveryStatusChangedImplCopyWith<_$WhitenoiseEvent_MessageDeliveryStatusChangedImpl> get copyWith =>
+      __$$WhitenoiseEvent_MessageDeliveryStatusChangedImplCopyWithImpl<_$WhitenoiseEvent_MessageDeliveryStatusChangedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return messageDeliveryStatusChanged(accountPubkey, status);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return messageDeliveryStatusChanged?.call(accountPubkey, status);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (messageDeliveryStatusChanged != null) {
+      return messageDeliveryStatusChanged(accountPubkey, status);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return messageDeliveryStatusChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return messageDeliveryStatusChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (messageDeliveryStatusChanged != null) {
+      return messageDeliveryStatusChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_MessageDeliveryStatusChanged extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_MessageDeliveryStatusChanged({
+    required final String accountPubkey,
+    required final DeliveryStatusData status,
+  }) = _$WhitenoiseEvent_MessageDeliveryStatusChangedImpl;
+  const WhitenoiseEvent_MessageDeliveryStatusChanged._() : super._();
+
+  String get accountPubkey;
+  DeliveryStatusData get status;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_MessageDeliveryStatusChangedImplCopyWith<_$WhitenoiseEvent_MessageDeliveryStatusChangedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_OutboxMessageUpdatedImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_OutboxMessageUpdatedImplCopyWith(
+    _$WhitenoiseEvent_OutboxMessageUpdatedImpl value,
+    $Res Function(_$WhitenoiseEvent_OutboxMessageUpdatedImpl) then,
+  ) = __$$WhitenoiseEvent_OutboxMessageUpdatedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String accountPubkey, OutboxMessageData message});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_OutboxMessageUpdatedImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_OutboxMessageUpdatedImpl>
+    implements _$$WhitenoiseEvent_OutboxMessageUpdatedImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_OutboxMessageUpdatedImplCopyWithImpl(
+    _$WhitenoiseEvent_OutboxMessageUpdatedImpl _value,
+    $Res Function(_$WhitenoiseEvent_OutboxMessageUpdatedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? accountPubkey = null, Object? message = null}) {
+    return _then(
+      _$WhitenoiseEvent_OutboxMessageUpdatedImpl(
+        accountPubkey: null == accountPubkey
+            ? _value.accountPubkey
+            : accountPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        message: null == message
+            ? _value.message
+            : message // ignore: cast_nullable_to_non_nullable
+              as OutboxMessageData,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_OutboxMessageUpdatedImpl extends WhitenoiseEvent_OutboxMessageUpdated {
+  const _$WhitenoiseEvent_OutboxMessageUpdatedImpl({
+    required this.accountPubkey,
+    required this.message,
+  }) : super._();
+
+  @override
+  final String accountPubkey;
+
+  @override
+  final OutboxMessageData message;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.outboxMessageUpdated(accountPubkey: $accountPubkey, message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_OutboxMessageUpdatedImpl &&
+            (identical(other.accountPubkey, accountPubkey) ||
+                other.accountPubkey == accountPubkey) &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, accountPubkey, message);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_OutboxMessageUpdatedImplCopyWith<_$WhitenoiseEvent_OutboxMessageUpdatedImpl> get copyWith =>
+      __$$WhitenoiseEvent_OutboxMessageUpdatedImplCopyWithImpl<_$WhitenoiseEvent_OutboxMessageUpdatedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return outboxMessageUpdated(accountPubkey, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return outboxMessageUpdated?.call(accountPubkey, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (outboxMessageUpdated != null) {
+      return outboxMessageUpdated(accountPubkey, message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return outboxMessageUpdated(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return outboxMessageUpdated?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (outboxMessageUpdated != null) {
+      return outboxMessageUpdated(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_OutboxMessageUpdated extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_OutboxMessageUpdated({
+    required final String accountPubkey,
+    required final OutboxMessageData message,
+  }) = _$WhitenoiseEvent_OutboxMessageUpdatedImpl;
+  const WhitenoiseEvent_OutboxMessageUpdated._() : super._();
+
+  String get accountPubkey;
+  OutboxMessageData get message;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_OutboxMessageUpdatedImplCopyWith<_$WhitenoiseEvent_OutboxMessageUpdatedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$WhitenoiseEvent_OutboxMessageSentImplCopyWith<$Res> {
+  factory _$$WhitenoiseEvent_OutboxMessageSentImplCopyWith(
+    _$WhitenoiseEvent_OutboxMessageSentImpl value,
+    $Res Function(_$WhitenoiseEvent_OutboxMessageSentImpl) then,
+  ) = __$$WhitenoiseEvent_OutboxMessageSentImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String accountPubkey, String outboxId, MessageWithTokensData message});
+}
+
+/// @nodoc
+class __$$WhitenoiseEvent_OutboxMessageSentImplCopyWithImpl<$Res>
+    extends _$WhitenoiseEventCopyWithImpl<$Res, _$WhitenoiseEvent_OutboxMessageSentImpl>
+    implements _$$WhitenoiseEvent_OutboxMessageSentImplCopyWith<$Res> {
+  __$$WhitenoiseEvent_OutboxMessageSentImplCopyWithImpl(
+    _$WhitenoiseEvent_OutboxMessageSentImpl _value,
+    $Res Function(_$WhitenoiseEvent_OutboxMessageSentImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? accountPubkey = null, Object? outboxId = null, Object? message = null}) {
+    return _then(
+      _$WhitenoiseEvent_OutboxMessageSentImpl(
+        accountPubkey: null == accountPubkey
+            ? _value.accountPubkey
+            : accountPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        outboxId: null == outboxId
+            ? _value.outboxId
+            : outboxId // ignore: cast_nullable_to_non_nullable
+              as String,
+        message: null == message
+            ? _value.message
+            : message // ignore: cast_nullable_to_non_nullable
+              as MessageWithTokensData,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$WhitenoiseEvent_OutboxMessageSentImpl extends WhitenoiseEvent_OutboxMessageSent {
+  const _$WhitenoiseEvent_OutboxMessageSentImpl({
+    required this.accountPubkey,
+    required this.outboxId,
+    required this.message,
+  }) : super._();
+
+  @override
+  final String accountPubkey;
+
+  @override
+  final String outboxId;
+
+  @override
+  final MessageWithTokensData message;
+
+  @override
+  String toString() {
+    return 'WhitenoiseEvent.outboxMessageSent(accountPubkey: $accountPubkey, outboxId: $outboxId, message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WhitenoiseEvent_OutboxMessageSentImpl &&
+            (identical(other.accountPubkey, accountPubkey) ||
+                other.accountPubkey == accountPubkey) &&
+            (identical(other.outboxId, outboxId) || other.outboxId == outboxId) &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, accountPubkey, outboxId, message);
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WhitenoiseEvent_OutboxMessageSentImplCopyWith<_$WhitenoiseEvent_OutboxMessageSentImpl> get copyWith =>
+      __$$WhitenoiseEvent_OutboxMessageSentImplCopyWithImpl<_$WhitenoiseEvent_OutboxMessageSentImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String accountPubkey, WelcomeData welcome) welcomeReceived,
+    required TResult Function(String accountPubkey, GroupData group) groupCreated,
+    required TResult Function(String accountPubkey, GroupData group) groupUpdated,
+    required TResult Function(String accountPubkey, GroupData group) groupStateChanged,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberAdded,
+    required TResult Function(
+      String accountPubkey,
+      String groupId,
+      String memberPubkey,
+    )
+    memberRemoved,
+    required TResult Function(
+      String accountPubkey,
+      String relayUrl,
+      String status,
+    )
+    relayStatusChanged,
+    required TResult Function(String pubkey, MetadataData metadata) metadataUpdated,
+    required TResult Function(AccountData account) accountLoggedIn,
+    required TResult Function(String pubkey) accountLoggedOut,
+    required TResult Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(String accountPubkey, OutboxMessageData message) outboxMessageUpdated,
+    required TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )
+    outboxMessageSent,
+  }) {
+    return outboxMessageSent(accountPubkey, outboxId, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult? Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult? Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult? Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult? Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult? Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult? Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult? Function(AccountData account)? accountLoggedIn,
+    TResult? Function(String pubkey)? accountLoggedOut,
+    TResult? Function(
+      String accountPubkey,
+      DeliveryStatusData status,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult? Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+  }) {
+    return outboxMessageSent?.call(accountPubkey, outboxId, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String accountPubkey, WelcomeData welcome)? welcomeReceived,
+    TResult Function(String accountPubkey, GroupData group)? groupCreated,
+    TResult Function(String accountPubkey, GroupData group)? groupUpdated,
+    TResult Function(String accountPubkey, GroupData group)? groupStateChanged,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberAdded,
+    TResult Function(String accountPubkey, String groupId, String memberPubkey)? memberRemoved,
+    TResult Function(String accountPubkey, String relayUrl, String status)? relayStatusChanged,
+    TResult Function(String pubkey, MetadataData metadata)? metadataUpdated,
+    TResult Function(AccountData account)? accountLoggedIn,
+    TResult Function(String pubkey)? accountLoggedOut,
+    TResult Function(String accountPubkey, DeliveryStatusData status)? messageDeliveryStatusChanged,
+    TResult Function(String accountPubkey, OutboxMessageData message)? outboxMessageUpdated,
+    TResult Function(
+      String accountPubkey,
+      String outboxId,
+      MessageWithTokensData message,
+    )?
+    outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (outboxMessageSent != null) {
+      return outboxMessageSent(accountPubkey, outboxId, message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WhitenoiseEvent_Subscribed value) subscribed,
+    required TResult Function(WhitenoiseEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(WhitenoiseEvent_GroupCreated value) groupCreated,
+    required TResult Function(WhitenoiseEvent_GroupUpdated value) groupUpdated,
+    required TResult Function(WhitenoiseEvent_GroupStateChanged value) groupStateChanged,
+    required TResult Function(WhitenoiseEvent_MemberAdded value) memberAdded,
+    required TResult Function(WhitenoiseEvent_MemberRemoved value) memberRemoved,
+    required TResult Function(WhitenoiseEvent_RelayStatusChanged value) relayStatusChanged,
+    required TResult Function(WhitenoiseEvent_MetadataUpdated value) metadataUpdated,
+    required TResult Function(WhitenoiseEvent_AccountLoggedIn value) accountLoggedIn,
+    required TResult Function(WhitenoiseEvent_AccountLoggedOut value) accountLoggedOut,
+    required TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )
+    messageDeliveryStatusChanged,
+    required TResult Function(WhitenoiseEvent_OutboxMessageUpdated value) outboxMessageUpdated,
+    required TResult Function(WhitenoiseEvent_OutboxMessageSent value) outboxMessageSent,
+  }) {
+    return outboxMessageSent(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult? Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult? Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult? Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult? Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult? Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult? Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult? Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult? Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult? Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult? Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult? Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult? Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+  }) {
+    return outboxMessageSent?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WhitenoiseEvent_Subscribed value)? subscribed,
+    TResult Function(WhitenoiseEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(WhitenoiseEvent_GroupCreated value)? groupCreated,
+    TResult Function(WhitenoiseEvent_GroupUpdated value)? groupUpdated,
+    TResult Function(WhitenoiseEvent_GroupStateChanged value)? groupStateChanged,
+    TResult Function(WhitenoiseEvent_MemberAdded value)? memberAdded,
+    TResult Function(WhitenoiseEvent_MemberRemoved value)? memberRemoved,
+    TResult Function(WhitenoiseEvent_RelayStatusChanged value)? relayStatusChanged,
+    TResult Function(WhitenoiseEvent_MetadataUpdated value)? metadataUpdated,
+    TResult Function(WhitenoiseEvent_AccountLoggedIn value)? accountLoggedIn,
+    TResult Function(WhitenoiseEvent_AccountLoggedOut value)? accountLoggedOut,
+    TResult Function(
+      WhitenoiseEvent_MessageDeliveryStatusChanged value,
+    )?
+    messageDeliveryStatusChanged,
+    TResult Function(WhitenoiseEvent_OutboxMessageUpdated value)? outboxMessageUpdated,
+    TResult Function(WhitenoiseEvent_OutboxMessageSent value)? outboxMessageSent,
+    required TResult orElse(),
+  }) {
+    if (outboxMessageSent != null) {
+      return outboxMessageSent(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WhitenoiseEvent_OutboxMessageSent extends WhitenoiseEvent {
+  const factory WhitenoiseEvent_OutboxMessageSent({
+    required final String accountPubkey,
+    required final String outboxId,
+    required final MessageWithTokensData message,
+  }) = _$WhitenoiseEvent_OutboxMessageSentImpl;
+  const WhitenoiseEvent_OutboxMessageSent._() : super._();
+
+  String get accountPubkey;
+  String get outboxId;
+  MessageWithTokensData get message;
+
+  /// Create a copy of WhitenoiseEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WhitenoiseEvent_OutboxMessageSentImplCopyWith<_$WhitenoiseEvent_OutboxMessageSentImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -5,10 +5,12 @@
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-import '../api.dart';
 import '../frb_generated.dart';
 import 'accounts.dart';
+import 'chats.dart';
+import 'errors.dart';
 
+// These functions are ignored because they are not marked as `pub`: `clear_epoch_members`, `epoch_members`, `explain_member_error`, `find_group`, `is_dm_shape`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
 
 /// Converts a `GroupId` to its hexadecimal string representation.
@@ -34,7 +36,7 @@ Future<String> groupIdToString({required GroupId groupId}) =>
 ///
 /// # Returns
 /// * `Ok(GroupId)` - Successfully parsed group ID
-/// * `Err(WhitenoiseError)` - If the hex string is invalid or malformed
+/// * `Err(BridgeError)` - If the hex string is invalid or malformed
 Future<GroupId> groupIdFromString({required String hexString}) =>
     RustLib.instance.api.crateApiGroupsGroupIdFromString(hexString: hexString);
 
@@ -61,7 +63,7 @@ Future<GroupData> convertGroupToData({required Group group}) =>
 ///
 /// # Returns
 /// * `Ok(Vec<GroupData>)` - Vector of group data for all active groups
-/// * `Err(WhitenoiseError)` - If there was an error fetching groups or account not found
+/// * `Err(BridgeError)` - If there was an error fetching groups or account not found
 Future<List<GroupData>> fetchGroups({required PublicKey pubkey}) =>
     RustLib.instance.api.crateApiGroupsFetchGroups(pubkey: pubkey);
 
@@ -76,7 +78,7 @@ Future<List<GroupData>> fetchGroups({required PublicKey pubkey}) =>
 ///
 /// # Returns
 /// * `Ok(Vec<PublicKey>)` - Vector of public keys for all group members
-/// * `Err(WhitenoiseError)` - If there was an error fetching members or insufficient permissions
+/// * `Err(BridgeError)` - If there was an error fetching members or insufficient permissions
 Future<List<PublicKey>> fetchGroupMembers({
   required PublicKey pubkey,
   required GroupId groupId,
@@ -96,7 +98,7 @@ Future<List<PublicKey>> fetchGroupMembers({
 ///
 /// # Returns
 /// * `Ok(Vec<PublicKey>)` - Vector of public keys for all group administrators
-/// * `Err(WhitenoiseError)` - If there was an error fetching admins or insufficient permissions
+/// * `Err(BridgeError)` - If there was an error fetching admins or insufficient permissions
 Future<List<PublicKey>> fetchGroupAdmins({
   required PublicKey pubkey,
   required GroupId groupId,
@@ -120,7 +122,8 @@ Future<List<PublicKey>> fetchGroupAdmins({
 ///
 /// # Returns
 /// * `Ok(GroupData)` - The created group data if successful
-/// * `Err(WhitenoiseError)` - If there was an error creating the group
+/// * `Err(BridgeError)` - `NoKeyPackage` if a member has not published a key package, or
+///   another error if the group could not be created
 ///
 /// # Notes
 /// * All members must have published key packages to relays
@@ -151,7 +154,8 @@ Future<GroupData> createGroup({
 ///
 /// # Returns
 /// * `Ok(())` - If the members were successfully added to the group
-/// * `Err(WhitenoiseError)` - If there was an error adding members (e.g., insufficient permissions, invalid group ID, or MLS protocol errors)
+/// * `Err(BridgeError)` - `NoKeyPackage` if a new member has not published a key package,
+///   or another error (e.g., insufficient permissions, invalid group ID, or MLS protocol errors)
 ///
 /// # Notes
 /// * Only group administrators can add new members
@@ -179,7 +183,7 @@ Future<void> addMembersToGroup({
 ///
 /// # Returns
 /// * `Ok(())` - If the members were successfully removed from the group
-/// * `Err(WhitenoiseError)` - If there was an error removing members (e.g., insufficient permissions, invalid group ID, member not found, or MLS protocol errors)
+/// * `Err(BridgeError)` - If there was an error removing members (e.g., insufficient permissions, invalid group ID, member not found, or MLS protocol errors)
 ///
 /// # Notes
 /// * Only group administrators can remove members
@@ -198,17 +202,43 @@ Future<void> removeMembersFromGroup({
   memberPubkeys: memberPubkeys,
 );
 
+/// Returns the direct message group with a contact, creating it if needed.
+///
+/// Looks for an active `GroupType::DirectMessage` group of the two accounts with the
+/// shape of a DM (see below). If there is none but the contact has already invited the
+/// account to such a group, that pending welcome is accepted and its group returned, if
+/// the core types it as a DM. Otherwise a new DM group is created with both participants
+/// as members and admins and no name or description.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account starting the conversation
+/// * `other_pubkey` - The public key of the contact
+///
+/// # Returns
+/// * `Ok(GroupData)` - The existing or newly created DM group
+/// * `Err(BridgeError)` - `InvalidInput` if both keys are the same, or any error from
+///   accepting the welcome or creating the group
+///
+/// # Notes
+/// * The contact must have published a key package for a new group to be created
+/// * Concurrent calls are serialized, so they return the same group
+Future<GroupData> getOrCreateDmGroup({
+  required PublicKey pubkey,
+  required PublicKey otherPubkey,
+}) => RustLib.instance.api.crateApiGroupsGetOrCreateDmGroup(
+  pubkey: pubkey,
+  otherPubkey: otherPubkey,
+);
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Group>>
 abstract class Group implements RustOpaqueInterface {}
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GroupId>>
-abstract class GroupId implements RustOpaqueInterface {}
-
 class GroupData {
   final String mlsGroupId;
   final String nostrGroupId;
   final String name;
   final String description;
+  final String? imageUrl;
   final List<String> adminPubkeys;
   final String? lastMessageId;
   final BigInt? lastMessageAt;
@@ -221,6 +251,7 @@ class GroupData {
     required this.nostrGroupId,
     required this.name,
     required this.description,
+    this.imageUrl,
     required this.adminPubkeys,
     this.lastMessageId,
     this.lastMessageAt,
@@ -235,6 +266,7 @@ class GroupData {
       nostrGroupId.hashCode ^
       name.hashCode ^
       description.hashCode ^
+      imageUrl.hashCode ^
       adminPubkeys.hashCode ^
       lastMessageId.hashCode ^
       lastMessageAt.hashCode ^
@@ -251,6 +283,7 @@ class GroupData {
           nostrGroupId == other.nostrGroupId &&
           name == other.name &&
           description == other.description &&
+          imageUrl == other.imageUrl &&
           adminPubkeys == other.adminPubkeys &&
           lastMessageId == other.lastMessageId &&
           lastMessageAt == other.lastMessageAt &&
@@ -0,0 +1,188 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+import 'accounts.dart';
+import 'errors.dart';
+
+// These functions are ignored because they are not marked as `pub`: `compute_blurhash`, `encode_image`, `image_error`, `process_image_blocking`, `processed_images_dir`, `read_processed`, `remove_processed_files`, `remove_stale_processed_images`, `resize_gif`, `write_processed`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `default`, `fmt`, `fmt`, `fmt`
+
+/// Prepares an image for upload.
+///
+/// * Strips all metadata (EXIF, including GPS location), after applying the EXIF
+///   orientation so the image is still shown the right way up
+/// * Scales the image down so its longest side is at most `max_dimension`
+/// * Optionally converts it to (lossy) WebP
+/// * Computes a blurhash and writes a thumbnail for use as a placeholder
+///
+/// The processed image and thumbnail are written to a temporary directory; the original
+/// file is not modified. `upload_image` deletes them once uploaded; call
+/// `discard_processed_image` for an image that is not uploaded. Leftovers are deleted
+/// after an hour.
+///
+/// # Arguments
+/// * `file_path` - The local path to the image
+/// * `options` - Processing options, or `None` for the defaults (2048 px, no WebP
+///   conversion, quality 85, 256 px thumbnail)
+///
+/// # Returns
+/// * `Ok(ProcessedImageData)` - The processed image, its dimensions, hash and blurhash
+/// * `Err(BridgeError)` - `Unsupported` if the file is not a PNG, JPEG, GIF or WebP
+///   image, or `InvalidInput` if it could not be decoded
+///
+/// # Notes
+/// * GIFs stay GIFs so they keep their animation; every frame is re-encoded, and scaled
+///   down when larger than `max_dimension`
+Future<ProcessedImageData> processImage({
+  required String filePath,
+  ImageProcessingOptions? options,
+}) => RustLib.instance.api.crateApiImagesProcessImage(
+  filePath: filePath,
+  options: options,
+);
+
+/// Uploads an image processed by `process_image`, and its thumbnail, to the account's
+/// Blossom servers, then deletes the processed files.
+///
+/// The upload is public: anyone with the URL can fetch the image. Use it for images that
+/// are public anyway, such as profile pictures and banners, never for chat media, which
+/// must be encrypted for the group (see `docs/bridge_core_limitations.md`).
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account uploading the image
+/// * `image` - The processed image
+///
+/// # Returns
+/// * `Ok(UploadedImageData)` - The URLs of the image, its mirrors and its thumbnail
+/// * `Err(BridgeError)` - If the image could not be read or no server accepted it
+///
+/// # Notes
+/// * A thumbnail that fails to upload is left out rather than failing the upload
+Future<UploadedImageData> uploadImage({
+  required PublicKey pubkey,
+  required ProcessedImageData image,
+}) => RustLib.instance.api.crateApiImagesUploadImage(
+  pubkey: pubkey,
+  image: image,
+);
+
+/// Deletes the files `process_image` wrote for an image that will not be uploaded.
+///
+/// # Arguments
+/// * `image` - The processed image
+Future<void> discardProcessedImage({required ProcessedImageData image}) =>
+    RustLib.instance.api.crateApiImagesDiscardProcessedImage(image: image);
+
+/// Options for `process_image`.
+class ImageProcessingOptions {
+  final int maxDimension;
+  final bool convertToWebp;
+  final int quality;
+  final int thumbnailDimension;
+
+  const ImageProcessingOptions({
+    required this.maxDimension,
+    required this.convertToWebp,
+    required this.quality,
+    required this.thumbnailDimension,
+  });
+
+  @override
+  int get hashCode =>
+      maxDimension.hashCode ^
+      convertToWebp.hashCode ^
+      quality.hashCode ^
+      thumbnailDimension.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImageProcessingOptions &&
+          runtimeType == other.runtimeType &&
+          maxDimension == other.maxDimension &&
+          convertToWebp == other.convertToWebp &&
+          quality == other.quality &&
+          thumbnailDimension == other.thumbnailDimension;
+}
+
+/// An image prepared for upload by `process_image`.
+class ProcessedImageData {
+  final String filePath;
+  final ImageType imageType;
+  final String mimeType;
+  final int width;
+  final int height;
+  final BigInt size;
+  final String sha256;
+  final String blurhash;
+  final String? thumbnailPath;
+
+  const ProcessedImageData({
+    required this.filePath,
+    required this.imageType,
+    required this.mimeType,
+    required this.width,
+    required this.height,
+    required this.size,
+    required this.sha256,
+    required this.blurhash,
+    this.thumbnailPath,
+  });
+
+  @override
+  int get hashCode =>
+      filePath.hashCode ^
+      imageType.hashCode ^
+      mimeType.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      size.hashCode ^
+      sha256.hashCode ^
+      blurhash.hashCode ^
+      thumbnailPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessedImageData &&
+          runtimeType == other.runtimeType &&
+          filePath == other.filePath &&
+          imageType == other.imageType &&
+          mimeType == other.mimeType &&
+          width == other.width &&
+          height == other.height &&
+          size == other.size &&
+          sha256 == other.sha256 &&
+          blurhash == other.blurhash &&
+          thumbnailPath == other.thumbnailPath;
+}
+
+/// Where `upload_image` stored an image and its thumbnail.
+class UploadedImageData {
+  final String url;
+  final List<String> mirrorUrls;
+  final String? thumbnailUrl;
+
+  const UploadedImageData({
+    required this.url,
+    required this.mirrorUrls,
+    this.thumbnailUrl,
+  });
+
+  @override
+  int get hashCode => url.hashCode ^ mirrorUrls.hashCode ^ thumbnailUrl.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UploadedImageData &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          mirrorUrls == other.mirrorUrls &&
+          thumbnailUrl == other.thumbnailUrl;
+}
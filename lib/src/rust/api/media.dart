@@ -0,0 +1,97 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+import 'accounts.dart';
+import 'chats.dart';
+import 'errors.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_media_url`, `download_blob`, `expected_sha256`, `extension_for_mime_type`, `image_mime_type`, `is_non_public_address`, `media_dir`, `parse_imeta`, `read_blob`, `sha256_from_hex`, `sha256_hex`, `upload_blob`, `url_sha256`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// Downloads a media attachment of a message.
+///
+/// The file is fetched from its Blossom server (falling back to its mirrors when the server
+/// is unavailable), checked against the hashes in the `imeta` tag and stored in the local
+/// media cache. Files that are still cached are not fetched again.
+///
+/// Only HTTPS URLs of public hosts are fetched, redirects are not followed and the download
+/// stops at the size given in the `imeta` tag (at most 100 MiB).
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account (must be a group member)
+/// * `group_id` - The MLS group ID the message belongs to
+/// * `message_id` - The event ID (hex) of the message
+/// * `attachment_index` - The index of the attachment in the message's `attachments`
+///
+/// # Returns
+/// * `Ok(String)` - The local path of the file
+/// * `Err(BridgeError)` - `MessageNotFound` if the message or attachment does not exist,
+///   `Unsupported` for encrypted attachments, `InvalidInput` if the attachment has no hash,
+///   its URL is not allowed, it is too large or a hash does not match, or a `Network` error
+///
+/// # Notes
+/// * Only unencrypted attachments are supported, and the bridge cannot send media. Encrypted
+///   attachments need a key derived from the group's MLS exporter secret, which the core
+///   does not expose (see `docs/bridge_core_limitations.md`)
+Future<String> downloadMedia({
+  required PublicKey pubkey,
+  required GroupId groupId,
+  required String messageId,
+  required int attachmentIndex,
+}) => RustLib.instance.api.crateApiMediaDownloadMedia(
+  pubkey: pubkey,
+  groupId: groupId,
+  messageId: messageId,
+  attachmentIndex: attachmentIndex,
+);
+
+/// A media file attached to a chat message with an `imeta` tag.
+///
+/// Use `download_media` to get the file.
+class MediaAttachmentData {
+  final String url;
+  final String mimeType;
+  final String sha256;
+  final BigInt? size;
+  final String? dimensions;
+  final String? blurhash;
+  final bool isEncrypted;
+
+  const MediaAttachmentData({
+    required this.url,
+    required this.mimeType,
+    required this.sha256,
+    this.size,
+    this.dimensions,
+    this.blurhash,
+    required this.isEncrypted,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      mimeType.hashCode ^
+      sha256.hashCode ^
+      size.hashCode ^
+      dimensions.hashCode ^
+      blurhash.hashCode ^
+      isEncrypted.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaAttachmentData &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          mimeType == other.mimeType &&
+          sha256 == other.sha256 &&
+          size == other.size &&
+          dimensions == other.dimensions &&
+          blurhash == other.blurhash &&
+          isEncrypted == other.isEncrypted;
+}
@@ -0,0 +1,83 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+import 'chats.dart';
+import 'errors.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cache_insert`, `cache_lookup`, `evict`, `insert`, `purge_group_media`, `remove_files`, `total_size`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `default`, `fmt`
+
+/// Returns the size and usage of the local media cache.
+///
+/// # Returns
+/// * `Ok(MediaCacheStatsData)` - Number of cached files, their total size and the limit
+/// * `Err(BridgeError)` - If the cache index could not be read
+Future<MediaCacheStatsData> mediaCacheStats() =>
+    RustLib.instance.api.crateApiMediaCacheMediaCacheStats();
+
+/// Sets the size limit of the local media cache.
+///
+/// Least recently used files are removed right away if the cache is larger than the new
+/// limit.
+///
+/// # Arguments
+/// * `max_size_bytes` - The new limit in bytes
+///
+/// # Returns
+/// * `Ok(MediaCacheStatsData)` - The cache usage after applying the limit
+/// * `Err(BridgeError)` - If the cache could not be updated
+Future<MediaCacheStatsData> setMediaCacheMaxSize({
+  required BigInt maxSizeBytes,
+}) => RustLib.instance.api.crateApiMediaCacheSetMediaCacheMaxSize(
+  maxSizeBytes: maxSizeBytes,
+);
+
+/// Removes the cached media of a single group.
+///
+/// # Arguments
+/// * `group_id` - The MLS group ID whose media to remove
+///
+/// # Returns
+/// * `Ok(())` - If the media was removed
+/// * `Err(BridgeError)` - If the cache could not be updated
+Future<void> clearGroupMediaCache({required GroupId groupId}) =>
+    RustLib.instance.api.crateApiMediaCacheClearGroupMediaCache(groupId: groupId);
+
+/// Removes all cached media. The size limit is kept.
+///
+/// Downloaded media is fetched again from its Blossom server the next time it is shown.
+///
+/// # Returns
+/// * `Ok(())` - If the cache was cleared
+/// * `Err(BridgeError)` - If the cache could not be cleared
+Future<void> clearMediaCache() => RustLib.instance.api.crateApiMediaCacheClearMediaCache();
+
+/// Size and usage of the local media cache.
+class MediaCacheStatsData {
+  final int fileCount;
+  final BigInt totalSizeBytes;
+  final BigInt maxSizeBytes;
+
+  const MediaCacheStatsData({
+    required this.fileCount,
+    required this.totalSizeBytes,
+    required this.maxSizeBytes,
+  });
+
+  @override
+  int get hashCode => fileCount.hashCode ^ totalSizeBytes.hashCode ^ maxSizeBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaCacheStatsData &&
+          runtimeType == other.runtimeType &&
+          fileCount == other.fileCount &&
+          totalSizeBytes == other.totalSizeBytes &&
+          maxSizeBytes == other.maxSizeBytes;
+}
@@ -4,14 +4,18 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 
-import '../api.dart';
 import '../frb_generated.dart';
 import 'accounts.dart';
-import 'groups.dart';
+import 'chats.dart';
+import 'errors.dart';
+import 'media.dart';
 
-// These functions are ignored because they are not marked as `pub`: `convert_reaction_summary`, `convert_serializable_token`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+part 'messages.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `advance`, `advance_cursor`, `apply`, `apply_edits`, `collect`, `compare_to_cursor`, `convert_reaction_summary`, `convert_serializable_token`, `cursor_for_message`, `deletion_is_authorized`, `diff_group_messages`, `edit_tag_target`, `find_group_message`, `group_admins`, `hides`, `is_emoji_element`, `is_pictographic`, `is_presence_kind`, `is_regional_indicator`, `is_single_emoji`, `is_skin_tone`, `load_chat_messages`, `load_chat_messages_with_raw`, `retried_duplicate_ids`, `select_page`, `send_group_event`, `send_group_message`, `send_inner_event`, `tag_value`, `thread_tags`, `undeleted_reaction_ids`, `validate_reaction`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 /// Converts a core `MessageWithTokens` object to a Flutter-compatible `MessageWithTokensData` structure.
 ///
@@ -69,7 +73,16 @@ Future<ChatMessageData> convertChatMessageToData({
 ///
 /// # Returns
 /// * `Ok(MessageWithTokensData)` - The sent message and parsed tokens if successful
-/// * `Err(WhitenoiseError)` - If there was an error sending the message
+/// * `Err(BridgeError)` - If there was an error sending the message
+///
+/// # Notes
+/// * The core publishes the message without reporting which relays accepted it, so there
+///   is no per-relay status; `fetch_message_delivery_status` reports whether the message
+///   is pending, sent or failed
+/// * The message goes through the outbox: when sending fails, the error is returned with
+///   its `outbox_id` set, and the message stays queued and is retried automatically (see
+///   `fetch_outbox`); use `enqueue_message_to_group` to return without waiting for the
+///   first attempt
 Future<MessageWithTokensData> sendMessageToGroup({
   required PublicKey pubkey,
   required GroupId groupId,
@@ -84,6 +97,70 @@ Future<MessageWithTokensData> sendMessageToGroup({
   tags: tags,
 );
 
+/// Reacts to a message in a group.
+///
+/// Sends a NIP-25 reaction (kind 7) as an inner event of the MLS group. The reaction
+/// references the message with an `e` tag, its author with a `p` tag and the reacted
+/// kind with a `k` tag, so it is aggregated into the message's `ReactionSummaryData`.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account reacting (must be a group member)
+/// * `group_id` - The MLS group ID the message belongs to
+/// * `message_id` - The event ID (hex) of the message to react to
+/// * `emoji` - The reaction: `+`, `-`, a single emoji or a NIP-30 `:shortcode:`
+/// * `custom_emoji_url` - Image URL for a NIP-30 custom emoji; required for shortcodes
+///   and not allowed otherwise
+///
+/// # Returns
+/// * `Ok(MessageWithTokensData)` - The sent reaction event
+/// * `Err(BridgeError)` - `InvalidInput` if the emoji is invalid, `MessageNotFound` if the
+///   message is not in the group, or any error from sending the message
+///
+/// # Example
+/// ```dart
+/// await reactToMessage(pubkey: pubkey, groupId: groupId, messageId: message.id, emoji: '👍');
+/// ```
+Future<MessageWithTokensData> reactToMessage({
+  required PublicKey pubkey,
+  required GroupId groupId,
+  required String messageId,
+  required String emoji,
+  String? customEmojiUrl,
+}) => RustLib.instance.api.crateApiMessagesReactToMessage(
+  pubkey: pubkey,
+  groupId: groupId,
+  messageId: messageId,
+  emoji: emoji,
+  customEmojiUrl: customEmojiUrl,
+);
+
+/// Removes the account's own reaction from a message in a group.
+///
+/// Per NIP-25 a reaction is retracted by deleting the reaction event, so this sends a
+/// NIP-09 deletion (kind 5) referencing the account's matching kind-7 events that are
+/// not deleted yet. Removing a reaction that does not exist (anymore) does nothing.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account that reacted
+/// * `group_id` - The MLS group ID the message belongs to
+/// * `message_id` - The event ID (hex) of the message the reaction was made on
+/// * `emoji` - The reaction to remove, exactly as it was sent
+///
+/// # Returns
+/// * `Ok(())` - If the deletion for the reaction was sent, or there was nothing to remove
+/// * `Err(BridgeError)` - If the messages could not be read or the deletion not sent
+Future<void> removeReaction({
+  required PublicKey pubkey,
+  required GroupId groupId,
+  required String messageId,
+  required String emoji,
+}) => RustLib.instance.api.crateApiMessagesRemoveReaction(
+  pubkey: pubkey,
+  groupId: groupId,
+  messageId: messageId,
+  emoji: emoji,
+);
+
 /// Fetches all messages for a specific MLS group.
 ///
 /// This function retrieves messages that have been sent to the specified group,
@@ -101,7 +178,7 @@ Future<MessageWithTokensData> sendMessageToGroup({
 ///
 /// Returns a `Result` containing:
 /// - `Ok(Vec<MessageWithTokensData>)` - A vector of messages with their token data
-/// - `Err(WhitenoiseError)` - If the operation fails (e.g., network error, access denied,
+/// - `Err(BridgeError)` - If the operation fails (e.g., network error, access denied,
 ///   group not found, or user not a member of the group)
 ///
 /// # Examples
@@ -159,7 +236,7 @@ Future<List<MessageWithTokensData>> fetchMessagesForGroup({
 ///
 /// Returns a `Result` containing:
 /// - `Ok(Vec<ChatMessage>)` - A vector of processed chat messages ready for display
-/// - `Err(WhitenoiseError)` - If the operation fails (e.g., network error, access denied,
+/// - `Err(BridgeError)` - If the operation fails (e.g., network error, access denied,
 ///   group not found, user not a member of the group, or message processing error)
 ///
 /// # Examples
@@ -194,6 +271,68 @@ Future<List<ChatMessageData>> fetchAggregatedMessagesForGroup({
   groupId: groupId,
 );
 
+/// Fetches one page of aggregated messages for a specific MLS group.
+///
+/// This is the paginated counterpart of `fetch_aggregated_messages_for_group`. Instead of
+/// returning the whole history of a group across the bridge, it returns at most `limit`
+/// messages around a cursor, so the chat screen can show the latest messages first and
+/// lazily load older ones while the user scrolls.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account requesting the messages (must be a group member)
+/// * `group_id` - The MLS group ID to fetch messages from
+/// * `before` - Only return messages older than this cursor
+/// * `after` - Only return messages newer than this cursor
+/// * `limit` - Maximum number of messages in the page (at least one message is returned
+///   if any match)
+///
+/// # Paging direction
+/// * Only `after` set - pages forward: returns the oldest `limit` messages newer than
+///   `after`, and `next_cursor` points at the newest message in the page
+/// * Otherwise - pages backward: returns the newest `limit` messages older than `before`
+///   (or the latest messages when no cursor is given), and `next_cursor` points at the
+///   oldest message in the page
+///
+/// When both cursors are given, only messages between them are considered.
+///
+/// # Returns
+/// * `Ok(MessagePageData)` - The page, with messages in chronological order (oldest first)
+/// * `Err(BridgeError)` - If the messages could not be fetched
+///
+/// # Example
+/// ```dart
+/// // Initial load: latest 50 messages
+/// var page = await fetchAggregatedMessagesPage(
+///   pubkey: pubkey, groupId: groupId, limit: 50);
+///
+/// // User scrolled to the top: load older messages
+/// if (page.hasMore) {
+///   page = await fetchAggregatedMessagesPage(
+///     pubkey: pubkey, groupId: groupId, before: page.nextCursor, limit: 50);
+/// }
+/// ```
+///
+/// # Notes
+/// * Edits, deletions and reactions are already aggregated into the returned messages
+/// * Use `subscribe_to_group_messages` to receive messages that arrive after the first page
+/// * The core has no paged message query, so every call still reads the group's whole
+///   history from local storage and its cost grows with the history; only the messages
+///   in the page are converted and sent to Flutter. See
+///   `docs/bridge_core_limitations.md`
+Future<MessagePageData> fetchAggregatedMessagesPage({
+  required PublicKey pubkey,
+  required GroupId groupId,
+  MessageCursorData? before,
+  MessageCursorData? after,
+  required int limit,
+}) => RustLib.instance.api.crateApiMessagesFetchAggregatedMessagesPage(
+  pubkey: pubkey,
+  groupId: groupId,
+  before: before,
+  after: after,
+  limit: limit,
+);
+
 /// Send an encrypted direct message using NIP-04
 ///
 /// This method sends a private direct message to another user using the NIP-04 encryption
@@ -221,7 +360,7 @@ Future<List<ChatMessageData>> fetchAggregatedMessagesForGroup({
 ///
 /// Returns a `Result` containing:
 /// - `Ok(())` - If the message was successfully encrypted and sent
-/// - `Err(WhitenoiseError)` - If the operation fails (e.g., network error, encryption failure,
+/// - `Err(BridgeError)` - If the operation fails (e.g., network error, encryption failure,
 ///   sender's private key not found, or invalid recipient public key)
 ///
 /// # Examples
@@ -263,6 +402,171 @@ Future<void> sendDirectMessageNip04({
   tags: tags,
 );
 
+/// Subscribes to live message updates for a specific MLS group.
+///
+/// Instead of repeatedly calling `fetch_aggregated_messages_for_group` from Flutter, this
+/// function opens a stream that pushes every change to the conversation as soon as the
+/// Whitenoise event processor has decrypted and stored it: new messages, edits, deletions,
+/// removals and reaction updates. Messages that already exist when the stream is opened
+/// are not replayed; load them first with `fetch_aggregated_messages_for_group`.
+///
+/// # Arguments
+/// * `sink` - The stream sink the updates are pushed to (provided by flutter_rust_bridge)
+/// * `pubkey` - The public key of the account subscribing (must be a group member)
+/// * `group_id` - The MLS group ID to watch
+///
+/// # Returns
+/// * `Ok(())` - The subscription was started; updates arrive on the stream
+/// * `Err(BridgeError)` - If the initial message snapshot could not be loaded or the
+///   group's relays could not be subscribed to
+///
+/// # Notes
+/// * The first item on the stream is always `GroupMessageUpdate::Subscribed`, carrying the
+///   handle to pass to `unsubscribe` when the chat screen is closed
+/// * The subscription also ends on its own once the Dart stream is cancelled
+/// * The store is not polled: it is re-read when the bridge sends to the group or one of
+///   the group's relays announces a new event for it (the stream keeps a subscription to
+///   them open), and a few more times over the next 3 seconds, since the core may store
+///   the event a moment later
+/// * Each check only reads the raw events of the group and compares them with a cursor
+///   (the newest `created_at` seen so far); messages are re-aggregated only when an event
+///   the stream has not seen yet was stored
+///
+/// # Example
+/// ```dart
+/// subscribeToGroupMessages(pubkey: pubkey, groupId: groupId).listen((update) {
+///   switch (update) {
+///     case GroupMessageUpdate_Subscribed(:final subscriptionId):
+///       _subscriptionId = subscriptionId;
+///     case GroupMessageUpdate_NewMessage(:final message):
+///       _addMessage(message);
+///     // ...
+///   }
+/// });
+/// ```
+Stream<GroupMessageUpdate> subscribeToGroupMessages({
+  required PublicKey pubkey,
+  required GroupId groupId,
+}) => RustLib.instance.api.crateApiMessagesSubscribeToGroupMessages(
+  pubkey: pubkey,
+  groupId: groupId,
+);
+
+/// Replies to a message in a group.
+///
+/// Sends a chat message (kind 9) with NIP-10 marked tags so it is aggregated as a reply:
+/// an `e` tag with the `root` marker pointing at the start of the thread and, when the
+/// parent is itself a reply, an `e` tag with the `reply` marker pointing at the parent.
+/// The parent's author and everyone the parent mentioned are added as `p` tags.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account replying (must be a group member)
+/// * `group_id` - The MLS group ID the parent message belongs to
+/// * `parent_message_id` - The event ID (hex) of the message being replied to
+/// * `content` - The reply text
+///
+/// # Returns
+/// * `Ok(MessageWithTokensData)` - The sent reply
+/// * `Err(BridgeError)` - `MessageNotFound` if the parent is not in the group, or any
+///   error from sending the message
+///
+/// # Example
+/// ```dart
+/// await replyToMessage(
+///   pubkey: pubkey, groupId: groupId, parentMessageId: message.id, content: 'Agreed!');
+/// ```
+Future<MessageWithTokensData> replyToMessage({
+  required PublicKey pubkey,
+  required GroupId groupId,
+  required String parentMessageId,
+  required String content,
+}) => RustLib.instance.api.crateApiMessagesReplyToMessage(
+  pubkey: pubkey,
+  groupId: groupId,
+  parentMessageId: parentMessageId,
+  content: content,
+);
+
+/// Fetches a message and every reply below it.
+///
+/// Returns the root message followed by all direct and indirect replies to it, i.e. the
+/// full reply chain as resolved by the message aggregator (`reply_to_id`).
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account requesting the thread (must be a group member)
+/// * `group_id` - The MLS group ID the thread belongs to
+/// * `root_message_id` - The event ID (hex) of the message that starts the thread
+///
+/// # Returns
+/// * `Ok(Vec<ChatMessageData>)` - The root message and its replies, in chronological order
+/// * `Err(BridgeError)` - `MessageNotFound` if the root message is not in the group
+Future<List<ChatMessageData>> fetchThread({
+  required PublicKey pubkey,
+  required GroupId groupId,
+  required String rootMessageId,
+}) => RustLib.instance.api.crateApiMessagesFetchThread(
+  pubkey: pubkey,
+  groupId: groupId,
+  rootMessageId: rootMessageId,
+);
+
+/// Deletes a message in a group.
+///
+/// Sends a NIP-09 deletion request (kind 5) for the message as an inner event of the MLS
+/// group. Once processed, the message is returned with `is_deleted` set.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account deleting the message
+/// * `group_id` - The MLS group ID the message belongs to
+/// * `message_id` - The event ID (hex) of the message to delete
+///
+/// # Returns
+/// * `Ok(())` - If the deletion was sent
+/// * `Err(BridgeError)` - `MessageNotFound` if the message is not in the group,
+///   `Unauthorized` if the account is neither the author nor a group admin, or any error
+///   from sending the deletion
+///
+/// # Notes
+/// * Authors can delete their own messages; group admins can delete any message
+Future<void> deleteMessage({
+  required PublicKey pubkey,
+  required GroupId groupId,
+  required String messageId,
+}) => RustLib.instance.api.crateApiMessagesDeleteMessage(
+  pubkey: pubkey,
+  groupId: groupId,
+  messageId: messageId,
+);
+
+/// Edits one of the account's own messages in a group.
+///
+/// Sends a chat message (kind 9) carrying the complete new content and referencing the
+/// original message with an `e` tag marked `edit`. When messages are fetched the edit is applied: the
+/// message shows the new content, `is_edited` is set and the previous content is added to
+/// `edit_history`.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the message author
+/// * `group_id` - The MLS group ID the message belongs to
+/// * `message_id` - The event ID (hex) of the original message
+/// * `new_content` - The complete new message text
+///
+/// # Returns
+/// * `Ok(MessageWithTokensData)` - The sent edit event
+/// * `Err(BridgeError)` - `MessageNotFound` if the message is not in the group or has been
+///   deleted, `Unauthorized` if the account is not the author, or any error from sending
+Future<MessageWithTokensData> editMessage({
+  required PublicKey pubkey,
+  required GroupId groupId,
+  required String messageId,
+  required String newContent,
+}) => RustLib.instance.api.crateApiMessagesEditMessage(
+  pubkey: pubkey,
+  groupId: groupId,
+  messageId: messageId,
+  newContent: newContent,
+);
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChatMessage>>
 abstract class ChatMessage implements RustOpaqueInterface {}
 
@@ -284,6 +588,9 @@ class ChatMessageData {
   final List<SerializableTokenData> contentTokens;
   final ReactionSummaryData reactions;
   final int kind;
+  final bool isEdited;
+  final List<MessageVersionData> editHistory;
+  final List<MediaAttachmentData> attachments;
 
   const ChatMessageData({
     required this.id,
@@ -297,6 +604,9 @@ class ChatMessageData {
     required this.contentTokens,
     required this.reactions,
     required this.kind,
+    required this.isEdited,
+    required this.editHistory,
+    required this.attachments,
   });
 
   @override
@@ -311,7 +621,10 @@ class ChatMessageData {
       isDeleted.hashCode ^
       contentTokens.hashCode ^
       reactions.hashCode ^
-      kind.hashCode;
+      kind.hashCode ^
+      isEdited.hashCode ^
+      editHistory.hashCode ^
+      attachments.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -328,7 +641,10 @@ class ChatMessageData {
           isDeleted == other.isDeleted &&
           contentTokens == other.contentTokens &&
           reactions == other.reactions &&
-          kind == other.kind;
+          kind == other.kind &&
+          isEdited == other.isEdited &&
+          editHistory == other.editHistory &&
+          attachments == other.attachments;
 }
 
 /// Flutter-compatible emoji reaction details
@@ -356,6 +672,120 @@ class EmojiReactionData {
           users == other.users;
 }
 
+/// An update pushed on a group message stream created by `subscribe_to_group_messages`.
+@freezed
+sealed class GroupMessageUpdate with _$GroupMessageUpdate {
+  const GroupMessageUpdate._();
+
+  /// First item on every stream; carries the handle to pass to `unsubscribe`
+  const factory GroupMessageUpdate.subscribed({
+    required BigInt subscriptionId,
+  }) = GroupMessageUpdate_Subscribed;
+
+  /// A message that was not part of the conversation before
+  const factory GroupMessageUpdate.newMessage({
+    required ChatMessageData message,
+  }) = GroupMessageUpdate_NewMessage;
+
+  /// An existing message whose content was edited
+  const factory GroupMessageUpdate.messageEdited({
+    required ChatMessageData message,
+  }) = GroupMessageUpdate_MessageEdited;
+
+  /// An existing message that has been deleted by its author
+  const factory GroupMessageUpdate.messageDeleted({required String messageId}) =
+      GroupMessageUpdate_MessageDeleted;
+
+  /// A message that is no longer part of the conversation at all (e.g. the duplicate of
+  /// a message that was sent twice)
+  const factory GroupMessageUpdate.messageRemoved({required String messageId}) =
+      GroupMessageUpdate_MessageRemoved;
+
+  /// The reactions on an existing message changed
+  const factory GroupMessageUpdate.reactionsUpdated({
+    required String messageId,
+    required ReactionSummaryData reactions,
+  }) = GroupMessageUpdate_ReactionsUpdated;
+}
+
+/// Position of a message in a conversation, used to page through group history.
+///
+/// Messages are ordered by `created_at` and then by `message_id`, so a cursor taken from
+/// a message identifies a unique position even when several messages share a timestamp.
+/// When `message_id` is `None` the cursor is a plain timestamp boundary.
+class MessageCursorData {
+  final BigInt createdAt;
+  final String? messageId;
+
+  const MessageCursorData({
+    required this.createdAt,
+    this.messageId,
+  });
+
+  @override
+  int get hashCode => createdAt.hashCode ^ messageId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageCursorData &&
+          runtimeType == other.runtimeType &&
+          createdAt == other.createdAt &&
+          messageId == other.messageId;
+}
+
+/// A page of aggregated chat messages returned by `fetch_aggregated_messages_page`.
+class MessagePageData {
+  /// Messages in this page, in chronological order (oldest first)
+  final List<ChatMessageData> messages;
+
+  /// Cursor to pass to the next call to keep paging in the same direction
+  final MessageCursorData? nextCursor;
+
+  /// Whether more messages exist beyond `next_cursor`
+  final bool hasMore;
+
+  const MessagePageData({
+    required this.messages,
+    this.nextCursor,
+    required this.hasMore,
+  });
+
+  @override
+  int get hashCode => messages.hashCode ^ nextCursor.hashCode ^ hasMore.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessagePageData &&
+          runtimeType == other.runtimeType &&
+          messages == other.messages &&
+          nextCursor == other.nextCursor &&
+          hasMore == other.hasMore;
+}
+
+/// A previous version of an edited message
+class MessageVersionData {
+  final String content;
+  final BigInt createdAt;
+
+  const MessageVersionData({
+    required this.content,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode => content.hashCode ^ createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageVersionData &&
+          runtimeType == other.runtimeType &&
+          content == other.content &&
+          createdAt == other.createdAt;
+}
+
 class MessageWithTokensData {
   final String id;
   final String pubkey;
@@ -0,0 +1,1144 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'messages.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$GroupMessageUpdate {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(ChatMessageData message) newMessage,
+    required TResult Function(ChatMessageData message) messageEdited,
+    required TResult Function(String messageId) messageDeleted,
+    required TResult Function(String messageId) messageRemoved,
+    required TResult Function(String messageId, ReactionSummaryData reactions) reactionsUpdated,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(ChatMessageData message)? newMessage,
+    TResult? Function(ChatMessageData message)? messageEdited,
+    TResult? Function(String messageId)? messageDeleted,
+    TResult? Function(String messageId)? messageRemoved,
+    TResult? Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(ChatMessageData message)? newMessage,
+    TResult Function(ChatMessageData message)? messageEdited,
+    TResult Function(String messageId)? messageDeleted,
+    TResult Function(String messageId)? messageRemoved,
+    TResult Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupMessageUpdate_Subscribed value) subscribed,
+    required TResult Function(GroupMessageUpdate_NewMessage value) newMessage,
+    required TResult Function(GroupMessageUpdate_MessageEdited value) messageEdited,
+    required TResult Function(GroupMessageUpdate_MessageDeleted value) messageDeleted,
+    required TResult Function(GroupMessageUpdate_MessageRemoved value) messageRemoved,
+    required TResult Function(GroupMessageUpdate_ReactionsUpdated value) reactionsUpdated,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult? Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult? Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult? Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult? Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult? Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $GroupMessageUpdateCopyWith<$Res> {
+  factory $GroupMessageUpdateCopyWith(
+    GroupMessageUpdate value,
+    $Res Function(GroupMessageUpdate) then,
+  ) = _$GroupMessageUpdateCopyWithImpl<$Res, GroupMessageUpdate>;
+}
+
+/// @nodoc
+class _$GroupMessageUpdateCopyWithImpl<$Res, $Val extends GroupMessageUpdate>
+    implements $GroupMessageUpdateCopyWith<$Res> {
+  _$GroupMessageUpdateCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$GroupMessageUpdate_SubscribedImplCopyWith<$Res> {
+  factory _$$GroupMessageUpdate_SubscribedImplCopyWith(
+    _$GroupMessageUpdate_SubscribedImpl value,
+    $Res Function(_$GroupMessageUpdate_SubscribedImpl) then,
+  ) = __$$GroupMessageUpdate_SubscribedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt subscriptionId});
+}
+
+/// @nodoc
+class __$$GroupMessageUpdate_SubscribedImplCopyWithImpl<$Res>
+    extends _$GroupMessageUpdateCopyWithImpl<$Res, _$GroupMessageUpdate_SubscribedImpl>
+    implements _$$GroupMessageUpdate_SubscribedImplCopyWith<$Res> {
+  __$$GroupMessageUpdate_SubscribedImplCopyWithImpl(
+    _$GroupMessageUpdate_SubscribedImpl _value,
+    $Res Function(_$GroupMessageUpdate_SubscribedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? subscriptionId = null}) {
+    return _then(
+      _$GroupMessageUpdate_SubscribedImpl(
+        subscriptionId: null == subscriptionId
+            ? _value.subscriptionId
+            : subscriptionId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GroupMessageUpdate_SubscribedImpl extends GroupMessageUpdate_Subscribed {
+  const _$GroupMessageUpdate_SubscribedImpl({required this.subscriptionId}) : super._();
+
+  @override
+  final BigInt subscriptionId;
+
+  @override
+  String toString() {
+    return 'GroupMessageUpdate.subscribed(subscriptionId: $subscriptionId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupMessageUpdate_SubscribedImpl &&
+            (identical(other.subscriptionId, subscriptionId) ||
+                other.subscriptionId == subscriptionId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, subscriptionId);
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupMessageUpdate_SubscribedImplCopyWith<_$GroupMessageUpdate_SubscribedImpl> get copyWith =>
+      __$$GroupMessageUpdate_SubscribedImplCopyWithImpl<_$GroupMessageUpdate_SubscribedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(ChatMessageData message) newMessage,
+    required TResult Function(ChatMessageData message) messageEdited,
+    required TResult Function(String messageId) messageDeleted,
+    required TResult Function(String messageId) messageRemoved,
+    required TResult Function(String messageId, ReactionSummaryData reactions) reactionsUpdated,
+  }) {
+    return subscribed(subscriptionId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(ChatMessageData message)? newMessage,
+    TResult? Function(ChatMessageData message)? messageEdited,
+    TResult? Function(String messageId)? messageDeleted,
+    TResult? Function(String messageId)? messageRemoved,
+    TResult? Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+  }) {
+    return subscribed?.call(subscriptionId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(ChatMessageData message)? newMessage,
+    TResult Function(ChatMessageData message)? messageEdited,
+    TResult Function(String messageId)? messageDeleted,
+    TResult Function(String messageId)? messageRemoved,
+    TResult Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+    required TResult orElse(),
+  }) {
+    if (subscribed != null) {
+      return subscribed(subscriptionId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupMessageUpdate_Subscribed value) subscribed,
+    required TResult Function(GroupMessageUpdate_NewMessage value) newMessage,
+    required TResult Function(GroupMessageUpdate_MessageEdited value) messageEdited,
+    required TResult Function(GroupMessageUpdate_MessageDeleted value) messageDeleted,
+    required TResult Function(GroupMessageUpdate_MessageRemoved value) messageRemoved,
+    required TResult Function(GroupMessageUpdate_ReactionsUpdated value) reactionsUpdated,
+  }) {
+    return subscribed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult? Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult? Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult? Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult? Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult? Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+  }) {
+    return subscribed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+    required TResult orElse(),
+  }) {
+    if (subscribed != null) {
+      return subscribed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupMessageUpdate_Subscribed extends GroupMessageUpdate {
+  const factory GroupMessageUpdate_Subscribed({
+    required final BigInt subscriptionId,
+  }) = _$GroupMessageUpdate_SubscribedImpl;
+  const GroupMessageUpdate_Subscribed._() : super._();
+
+  BigInt get subscriptionId;
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupMessageUpdate_SubscribedImplCopyWith<_$GroupMessageUpdate_SubscribedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupMessageUpdate_NewMessageImplCopyWith<$Res> {
+  factory _$$GroupMessageUpdate_NewMessageImplCopyWith(
+    _$GroupMessageUpdate_NewMessageImpl value,
+    $Res Function(_$GroupMessageUpdate_NewMessageImpl) then,
+  ) = __$$GroupMessageUpdate_NewMessageImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({ChatMessageData message});
+}
+
+/// @nodoc
+class __$$GroupMessageUpdate_NewMessageImplCopyWithImpl<$Res>
+    extends _$GroupMessageUpdateCopyWithImpl<$Res, _$GroupMessageUpdate_NewMessageImpl>
+    implements _$$GroupMessageUpdate_NewMessageImplCopyWith<$Res> {
+  __$$GroupMessageUpdate_NewMessageImplCopyWithImpl(
+    _$GroupMessageUpdate_NewMessageImpl _value,
+    $Res Function(_$GroupMessageUpdate_NewMessageImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? message = null}) {
+    return _then(
+      _$GroupMessageUpdate_NewMessageImpl(
+        message: null == message
+            ? _value.message
+            : message // ignore: cast_nullable_to_non_nullable
+              as ChatMessageData,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GroupMessageUpdate_NewMessageImpl extends GroupMessageUpdate_NewMessage {
+  const _$GroupMessageUpdate_NewMessageImpl({required this.message}) : super._();
+
+  @override
+  final ChatMessageData message;
+
+  @override
+  String toString() {
+    return 'GroupMessageUpdate.newMessage(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupMessageUpdate_NewMessageImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupMessageUpdate_NewMessageImplCopyWith<_$GroupMessageUpdate_NewMessageImpl> get copyWith =>
+      __$$GroupMessageUpdate_NewMessageImplCopyWithImpl<_$GroupMessageUpdate_NewMessageImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(ChatMessageData message) newMessage,
+    required TResult Function(ChatMessageData message) messageEdited,
+    required TResult Function(String messageId) messageDeleted,
+    required TResult Function(String messageId) messageRemoved,
+    required TResult Function(String messageId, ReactionSummaryData reactions) reactionsUpdated,
+  }) {
+    return newMessage(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(ChatMessageData message)? newMessage,
+    TResult? Function(ChatMessageData message)? messageEdited,
+    TResult? Function(String messageId)? messageDeleted,
+    TResult? Function(String messageId)? messageRemoved,
+    TResult? Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+  }) {
+    return newMessage?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(ChatMessageData message)? newMessage,
+    TResult Function(ChatMessageData message)? messageEdited,
+    TResult Function(String messageId)? messageDeleted,
+    TResult Function(String messageId)? messageRemoved,
+    TResult Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+    required TResult orElse(),
+  }) {
+    if (newMessage != null) {
+      return newMessage(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupMessageUpdate_Subscribed value) subscribed,
+    required TResult Function(GroupMessageUpdate_NewMessage value) newMessage,
+    required TResult Function(GroupMessageUpdate_MessageEdited value) messageEdited,
+    required TResult Function(GroupMessageUpdate_MessageDeleted value) messageDeleted,
+    required TResult Function(GroupMessageUpdate_MessageRemoved value) messageRemoved,
+    required TResult Function(GroupMessageUpdate_ReactionsUpdated value) reactionsUpdated,
+  }) {
+    return newMessage(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult? Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult? Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult? Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult? Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult? Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+  }) {
+    return newMessage?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+    required TResult orElse(),
+  }) {
+    if (newMessage != null) {
+      return newMessage(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupMessageUpdate_NewMessage extends GroupMessageUpdate {
+  const factory GroupMessageUpdate_NewMessage({
+    required final ChatMessageData message,
+  }) = _$GroupMessageUpdate_NewMessageImpl;
+  const GroupMessageUpdate_NewMessage._() : super._();
+
+  ChatMessageData get message;
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupMessageUpdate_NewMessageImplCopyWith<_$GroupMessageUpdate_NewMessageImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupMessageUpdate_MessageEditedImplCopyWith<$Res> {
+  factory _$$GroupMessageUpdate_MessageEditedImplCopyWith(
+    _$GroupMessageUpdate_MessageEditedImpl value,
+    $Res Function(_$GroupMessageUpdate_MessageEditedImpl) then,
+  ) = __$$GroupMessageUpdate_MessageEditedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({ChatMessageData message});
+}
+
+/// @nodoc
+class __$$GroupMessageUpdate_MessageEditedImplCopyWithImpl<$Res>
+    extends _$GroupMessageUpdateCopyWithImpl<$Res, _$GroupMessageUpdate_MessageEditedImpl>
+    implements _$$GroupMessageUpdate_MessageEditedImplCopyWith<$Res> {
+  __$$GroupMessageUpdate_MessageEditedImplCopyWithImpl(
+    _$GroupMessageUpdate_MessageEditedImpl _value,
+    $Res Function(_$GroupMessageUpdate_MessageEditedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? message = null}) {
+    return _then(
+      _$GroupMessageUpdate_MessageEditedImpl(
+        message: null == message
+            ? _value.message
+            : message // ignore: cast_nullable_to_non_nullable
+              as ChatMessageData,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GroupMessageUpdate_MessageEditedImpl extends GroupMessageUpdate_MessageEdited {
+  const _$GroupMessageUpdate_MessageEditedImpl({required this.message}) : super._();
+
+  @override
+  final ChatMessageData message;
+
+  @override
+  String toString() {
+    return 'GroupMessageUpdate.messageEdited(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupMessageUpdate_MessageEditedImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupMessageUpdate_MessageEditedImplCopyWith<_$GroupMessageUpdate_MessageEditedImpl> get copyWith =>
+      __$$GroupMessageUpdate_MessageEditedImplCopyWithImpl<_$GroupMessageUpdate_MessageEditedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(ChatMessageData message) newMessage,
+    required TResult Function(ChatMessageData message) messageEdited,
+    required TResult Function(String messageId) messageDeleted,
+    required TResult Function(String messageId) messageRemoved,
+    required TResult Function(String messageId, ReactionSummaryData reactions) reactionsUpdated,
+  }) {
+    return messageEdited(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(ChatMessageData message)? newMessage,
+    TResult? Function(ChatMessageData message)? messageEdited,
+    TResult? Function(String messageId)? messageDeleted,
+    TResult? Function(String messageId)? messageRemoved,
+    TResult? Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+  }) {
+    return messageEdited?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(ChatMessageData message)? newMessage,
+    TResult Function(ChatMessageData message)? messageEdited,
+    TResult Function(String messageId)? messageDeleted,
+    TResult Function(String messageId)? messageRemoved,
+    TResult Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+    required TResult orElse(),
+  }) {
+    if (messageEdited != null) {
+      return messageEdited(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupMessageUpdate_Subscribed value) subscribed,
+    required TResult Function(GroupMessageUpdate_NewMessage value) newMessage,
+    required TResult Function(GroupMessageUpdate_MessageEdited value) messageEdited,
+    required TResult Function(GroupMessageUpdate_MessageDeleted value) messageDeleted,
+    required TResult Function(GroupMessageUpdate_MessageRemoved value) messageRemoved,
+    required TResult Function(GroupMessageUpdate_ReactionsUpdated value) reactionsUpdated,
+  }) {
+    return messageEdited(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult? Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult? Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult? Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult? Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult? Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+  }) {
+    return messageEdited?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+    required TResult orElse(),
+  }) {
+    if (messageEdited != null) {
+      return messageEdited(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupMessageUpdate_MessageEdited extends GroupMessageUpdate {
+  const factory GroupMessageUpdate_MessageEdited({
+    required final ChatMessageData message,
+  }) = _$GroupMessageUpdate_MessageEditedImpl;
+  const GroupMessageUpdate_MessageEdited._() : super._();
+
+  ChatMessageData get message;
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupMessageUpdate_MessageEditedImplCopyWith<_$GroupMessageUpdate_MessageEditedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupMessageUpdate_MessageDeletedImplCopyWith<$Res> {
+  factory _$$GroupMessageUpdate_MessageDeletedImplCopyWith(
+    _$GroupMessageUpdate_MessageDeletedImpl value,
+    $Res Function(_$GroupMessageUpdate_MessageDeletedImpl) then,
+  ) = __$$GroupMessageUpdate_MessageDeletedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String messageId});
+}
+
+/// @nodoc
+class __$$GroupMessageUpdate_MessageDeletedImplCopyWithImpl<$Res>
+    extends _$GroupMessageUpdateCopyWithImpl<$Res, _$GroupMessageUpdate_MessageDeletedImpl>
+    implements _$$GroupMessageUpdate_MessageDeletedImplCopyWith<$Res> {
+  __$$GroupMessageUpdate_MessageDeletedImplCopyWithImpl(
+    _$GroupMessageUpdate_MessageDeletedImpl _value,
+    $Res Function(_$GroupMessageUpdate_MessageDeletedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? messageId = null}) {
+    return _then(
+      _$GroupMessageUpdate_MessageDeletedImpl(
+        messageId: null == messageId
+            ? _value.messageId
+            : messageId // ignore: cast_nullable_to_non_nullable
+              as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GroupMessageUpdate_MessageDeletedImpl extends GroupMessageUpdate_MessageDeleted {
+  const _$GroupMessageUpdate_MessageDeletedImpl({required this.messageId}) : super._();
+
+  @override
+  final String messageId;
+
+  @override
+  String toString() {
+    return 'GroupMessageUpdate.messageDeleted(messageId: $messageId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupMessageUpdate_MessageDeletedImpl &&
+            (identical(other.messageId, messageId) || other.messageId == messageId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, messageId);
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupMessageUpdate_MessageDeletedImplCopyWith<_$GroupMessageUpdate_MessageDeletedImpl> get copyWith =>
+      __$$GroupMessageUpdate_MessageDeletedImplCopyWithImpl<_$GroupMessageUpdate_MessageDeletedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(ChatMessageData message) newMessage,
+    required TResult Function(ChatMessageData message) messageEdited,
+    required TResult Function(String messageId) messageDeleted,
+    required TResult Function(String messageId) messageRemoved,
+    required TResult Function(String messageId, ReactionSummaryData reactions) reactionsUpdated,
+  }) {
+    return messageDeleted(messageId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(ChatMessageData message)? newMessage,
+    TResult? Function(ChatMessageData message)? messageEdited,
+    TResult? Function(String messageId)? messageDeleted,
+    TResult? Function(String messageId)? messageRemoved,
+    TResult? Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+  }) {
+    return messageDeleted?.call(messageId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(ChatMessageData message)? newMessage,
+    TResult Function(ChatMessageData message)? messageEdited,
+    TResult Function(String messageId)? messageDeleted,
+    TResult Function(String messageId)? messageRemoved,
+    TResult Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+    required TResult orElse(),
+  }) {
+    if (messageDeleted != null) {
+      return messageDeleted(messageId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupMessageUpdate_Subscribed value) subscribed,
+    required TResult Function(GroupMessageUpdate_NewMessage value) newMessage,
+    required TResult Function(GroupMessageUpdate_MessageEdited value) messageEdited,
+    required TResult Function(GroupMessageUpdate_MessageDeleted value) messageDeleted,
+    required TResult Function(GroupMessageUpdate_MessageRemoved value) messageRemoved,
+    required TResult Function(GroupMessageUpdate_ReactionsUpdated value) reactionsUpdated,
+  }) {
+    return messageDeleted(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult? Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult? Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult? Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult? Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult? Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+  }) {
+    return messageDeleted?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+    required TResult orElse(),
+  }) {
+    if (messageDeleted != null) {
+      return messageDeleted(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupMessageUpdate_MessageDeleted extends GroupMessageUpdate {
+  const factory GroupMessageUpdate_MessageDeleted({
+    required final String messageId,
+  }) = _$GroupMessageUpdate_MessageDeletedImpl;
+  const GroupMessageUpdate_MessageDeleted._() : super._();
+
+  String get messageId;
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupMessageUpdate_MessageDeletedImplCopyWith<_$GroupMessageUpdate_MessageDeletedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupMessageUpdate_MessageRemovedImplCopyWith<$Res> {
+  factory _$$GroupMessageUpdate_MessageRemovedImplCopyWith(
+    _$GroupMessageUpdate_MessageRemovedImpl value,
+    $Res Function(_$GroupMessageUpdate_MessageRemovedImpl) then,
+  ) = __$$GroupMessageUpdate_MessageRemovedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String messageId});
+}
+
+/// @nodoc
+class __$$GroupMessageUpdate_MessageRemovedImplCopyWithImpl<$Res>
+    extends _$GroupMessageUpdateCopyWithImpl<$Res, _$GroupMessageUpdate_MessageRemovedImpl>
+    implements _$$GroupMessageUpdate_MessageRemovedImplCopyWith<$Res> {
+  __$$GroupMessageUpdate_MessageRemovedImplCopyWithImpl(
+    _$GroupMessageUpdate_MessageRemovedImpl _value,
+    $Res Function(_$GroupMessageUpdate_MessageRemovedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? messageId = null}) {
+    return _then(
+      _$GroupMessageUpdate_MessageRemovedImpl(
+        messageId: null == messageId
+            ? _value.messageId
+            : messageId // ignore: cast_nullable_to_non_nullable
+              as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GroupMessageUpdate_MessageRemovedImpl extends GroupMessageUpdate_MessageRemoved {
+  const _$GroupMessageUpdate_MessageRemovedImpl({required this.messageId}) : super._();
+
+  @override
+  final String messageId;
+
+  @override
+  String toString() {
+    return 'GroupMessageUpdate.messageRemoved(messageId: $messageId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupMessageUpdate_MessageRemovedImpl &&
+            (identical(other.messageId, messageId) || other.messageId == messageId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, messageId);
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupMessageUpdate_MessageRemovedImplCopyWith<_$GroupMessageUpdate_MessageRemovedImpl> get copyWith =>
+      __$$GroupMessageUpdate_MessageRemovedImplCopyWithImpl<_$GroupMessageUpdate_MessageRemovedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(ChatMessageData message) newMessage,
+    required TResult Function(ChatMessageData message) messageEdited,
+    required TResult Function(String messageId) messageDeleted,
+    required TResult Function(String messageId) messageRemoved,
+    required TResult Function(String messageId, ReactionSummaryData reactions) reactionsUpdated,
+  }) {
+    return messageRemoved(messageId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(ChatMessageData message)? newMessage,
+    TResult? Function(ChatMessageData message)? messageEdited,
+    TResult? Function(String messageId)? messageDeleted,
+    TResult? Function(String messageId)? messageRemoved,
+    TResult? Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+  }) {
+    return messageRemoved?.call(messageId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(ChatMessageData message)? newMessage,
+    TResult Function(ChatMessageData message)? messageEdited,
+    TResult Function(String messageId)? messageDeleted,
+    TResult Function(String messageId)? messageRemoved,
+    TResult Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+    required TResult orElse(),
+  }) {
+    if (messageRemoved != null) {
+      return messageRemoved(messageId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupMessageUpdate_Subscribed value) subscribed,
+    required TResult Function(GroupMessageUpdate_NewMessage value) newMessage,
+    required TResult Function(GroupMessageUpdate_MessageEdited value) messageEdited,
+    required TResult Function(GroupMessageUpdate_MessageDeleted value) messageDeleted,
+    required TResult Function(GroupMessageUpdate_MessageRemoved value) messageRemoved,
+    required TResult Function(GroupMessageUpdate_ReactionsUpdated value) reactionsUpdated,
+  }) {
+    return messageRemoved(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult? Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult? Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult? Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult? Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult? Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+  }) {
+    return messageRemoved?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+    required TResult orElse(),
+  }) {
+    if (messageRemoved != null) {
+      return messageRemoved(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupMessageUpdate_MessageRemoved extends GroupMessageUpdate {
+  const factory GroupMessageUpdate_MessageRemoved({
+    required final String messageId,
+  }) = _$GroupMessageUpdate_MessageRemovedImpl;
+  const GroupMessageUpdate_MessageRemoved._() : super._();
+
+  String get messageId;
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupMessageUpdate_MessageRemovedImplCopyWith<_$GroupMessageUpdate_MessageRemovedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupMessageUpdate_ReactionsUpdatedImplCopyWith<$Res> {
+  factory _$$GroupMessageUpdate_ReactionsUpdatedImplCopyWith(
+    _$GroupMessageUpdate_ReactionsUpdatedImpl value,
+    $Res Function(_$GroupMessageUpdate_ReactionsUpdatedImpl) then,
+  ) = __$$GroupMessageUpdate_ReactionsUpdatedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String messageId, ReactionSummaryData reactions});
+}
+
+/// @nodoc
+class __$$GroupMessageUpdate_ReactionsUpdatedImplCopyWithImpl<$Res>
+    extends _$GroupMessageUpdateCopyWithImpl<$Res, _$GroupMessageUpdate_ReactionsUpdatedImpl>
+    implements _$$GroupMessageUpdate_ReactionsUpdatedImplCopyWith<$Res> {
+  __$$GroupMessageUpdate_ReactionsUpdatedImplCopyWithImpl(
+    _$GroupMessageUpdate_ReactionsUpdatedImpl _value,
+    $Res Function(_$GroupMessageUpdate_ReactionsUpdatedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? messageId = null, Object? reactions = null}) {
+    return _then(
+      _$GroupMessageUpdate_ReactionsUpdatedImpl(
+        messageId: null == messageId
+            ? _value.messageId
+            : messageId // ignore: cast_nullable_to_non_nullable
+              as String,
+        reactions: null == reactions
+            ? _value.reactions
+            : reactions // ignore: cast_nullable_to_non_nullable
+              as ReactionSummaryData,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GroupMessageUpdate_ReactionsUpdatedImpl extends GroupMessageUpdate_ReactionsUpdated {
+  const _$GroupMessageUpdate_ReactionsUpdatedImpl({
+    required this.messageId,
+    required this.reactions,
+  }) : super._();
+
+  @override
+  final String messageId;
+
+  @override
+  final ReactionSummaryData reactions;
+
+  @override
+  String toString() {
+    return 'GroupMessageUpdate.reactionsUpdated(messageId: $messageId, reactions: $reactions)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupMessageUpdate_ReactionsUpdatedImpl &&
+            (identical(other.messageId, messageId) || other.messageId == messageId) &&
+            (identical(other.reactions, reactions) || other.reactions == reactions));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, messageId, reactions);
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupMessageUpdate_ReactionsUpdatedImplCopyWith<_$GroupMessageUpdate_ReactionsUpdatedImpl> get copyWith =>
+      __$$GroupMessageUpdate_ReactionsUpdatedImplCopyWithImpl<_$GroupMessageUpdate_ReactionsUpdatedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(ChatMessageData message) newMessage,
+    required TResult Function(ChatMessageData message) messageEdited,
+    required TResult Function(String messageId) messageDeleted,
+    required TResult Function(String messageId) messageRemoved,
+    required TResult Function(String messageId, ReactionSummaryData reactions) reactionsUpdated,
+  }) {
+    return reactionsUpdated(messageId, reactions);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(ChatMessageData message)? newMessage,
+    TResult? Function(ChatMessageData message)? messageEdited,
+    TResult? Function(String messageId)? messageDeleted,
+    TResult? Function(String messageId)? messageRemoved,
+    TResult? Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+  }) {
+    return reactionsUpdated?.call(messageId, reactions);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(ChatMessageData message)? newMessage,
+    TResult Function(ChatMessageData message)? messageEdited,
+    TResult Function(String messageId)? messageDeleted,
+    TResult Function(String messageId)? messageRemoved,
+    TResult Function(String messageId, ReactionSummaryData reactions)? reactionsUpdated,
+    required TResult orElse(),
+  }) {
+    if (reactionsUpdated != null) {
+      return reactionsUpdated(messageId, reactions);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupMessageUpdate_Subscribed value) subscribed,
+    required TResult Function(GroupMessageUpdate_NewMessage value) newMessage,
+    required TResult Function(GroupMessageUpdate_MessageEdited value) messageEdited,
+    required TResult Function(GroupMessageUpdate_MessageDeleted value) messageDeleted,
+    required TResult Function(GroupMessageUpdate_MessageRemoved value) messageRemoved,
+    required TResult Function(GroupMessageUpdate_ReactionsUpdated value) reactionsUpdated,
+  }) {
+    return reactionsUpdated(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult? Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult? Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult? Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult? Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult? Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+  }) {
+    return reactionsUpdated?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupMessageUpdate_Subscribed value)? subscribed,
+    TResult Function(GroupMessageUpdate_NewMessage value)? newMessage,
+    TResult Function(GroupMessageUpdate_MessageEdited value)? messageEdited,
+    TResult Function(GroupMessageUpdate_MessageDeleted value)? messageDeleted,
+    TResult Function(GroupMessageUpdate_MessageRemoved value)? messageRemoved,
+    TResult Function(GroupMessageUpdate_ReactionsUpdated value)? reactionsUpdated,
+    required TResult orElse(),
+  }) {
+    if (reactionsUpdated != null) {
+      return reactionsUpdated(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupMessageUpdate_ReactionsUpdated extends GroupMessageUpdate {
+  const factory GroupMessageUpdate_ReactionsUpdated({
+    required final String messageId,
+    required final ReactionSummaryData reactions,
+  }) = _$GroupMessageUpdate_ReactionsUpdatedImpl;
+  const GroupMessageUpdate_ReactionsUpdated._() : super._();
+
+  String get messageId;
+  ReactionSummaryData get reactions;
+
+  /// Create a copy of GroupMessageUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupMessageUpdate_ReactionsUpdatedImplCopyWith<_$GroupMessageUpdate_ReactionsUpdatedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,89 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+import 'errors.dart';
+import 'utils.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cache_metadata`, `cached`, `cached_profiles`, `claim_keys`, `lookup_metadata`, `metadata_relays`, `needs_fetch`, `newest_metadata`, `persist_memory_cache`, `query_metadata`, `record_fetch`, `reset_metadata_cache`, `wait_for_fetches`, `with_memory_cache`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`, `fmt`
+
+/// Fetches the profile metadata of many public keys at once, using the metadata cache.
+///
+/// Fresh cached metadata is returned right away; missing and stale entries are fetched
+/// from the accounts' relays with one query per 250 keys, at most 4 queries at a time.
+/// Concurrent calls asking for the same key share one fetch.
+/// Results are keyed by hex public key, whatever format the key was passed in, so every
+/// user maps to exactly one entry.
+///
+/// # Arguments
+/// * `pubkeys` - Public keys as hex or npub strings
+/// * `force_refresh` - Fetch every key from relays even if its cached metadata is fresh
+///
+/// # Returns
+/// * `Ok(HashMap<String, CachedMetadataData>)` - Metadata keyed by hex public key. If a
+///   key could not be fetched, its last cached metadata is returned with `is_stale` set
+///   (`fetched_at` is 0 and `metadata` is `None` if it was never fetched)
+/// * `Err(BridgeError)` - `InvalidPublicKey` if a key cannot be parsed, or if the cache
+///   could not be read or written
+///
+/// # Notes
+/// * Metadata stays fresh for 1 hour; the cache is kept on disk, so it survives restarts
+/// * Whenever fetched metadata differs from the cached version, a
+///   `WhitenoiseEvent::MetadataUpdated` is pushed on the global event stream
+/// * A profile the relays do not return keeps its cached metadata
+Future<Map<String, CachedMetadataData>> fetchMetadataBatch({
+  required List<String> pubkeys,
+  required bool forceRefresh,
+}) => RustLib.instance.api.crateApiMetadataCacheFetchMetadataBatch(
+  pubkeys: pubkeys,
+  forceRefresh: forceRefresh,
+);
+
+/// Returns cached profile metadata without contacting any relay.
+///
+/// # Arguments
+/// * `pubkeys` - Public keys as hex or npub strings
+///
+/// # Returns
+/// * `Ok(HashMap<String, CachedMetadataData>)` - Metadata keyed by hex public key; keys
+///   that were never fetched are returned with `metadata` set to `None` and `is_stale` set
+/// * `Err(BridgeError)` - `InvalidPublicKey` if a key cannot be parsed, or if the cache
+///   could not be read
+Future<Map<String, CachedMetadataData>> fetchCachedMetadata({
+  required List<String> pubkeys,
+}) => RustLib.instance.api.crateApiMetadataCacheFetchCachedMetadata(
+  pubkeys: pubkeys,
+);
+
+/// Profile metadata of a public key, as held by the metadata cache.
+class CachedMetadataData {
+  final String pubkey;
+  final MetadataData? metadata;
+  final BigInt fetchedAt;
+  final bool isStale;
+
+  const CachedMetadataData({
+    required this.pubkey,
+    this.metadata,
+    required this.fetchedAt,
+    required this.isStale,
+  });
+
+  @override
+  int get hashCode => pubkey.hashCode ^ metadata.hashCode ^ fetchedAt.hashCode ^ isStale.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CachedMetadataData &&
+          runtimeType == other.runtimeType &&
+          pubkey == other.pubkey &&
+          metadata == other.metadata &&
+          fetchedAt == other.fetchedAt &&
+          isStale == other.isStale;
+}
@@ -0,0 +1,90 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+import 'accounts.dart';
+import 'errors.dart';
+
+// These functions are ignored because they are not marked as `pub`: `domain_base_url`, `fetch_nip05`, `lookup_nip05`, `parse_identifier`, `read_document`, `well_known_url`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Checks that a NIP-05 identifier belongs to a public key.
+///
+/// Fetches `https://<domain>/.well-known/nostr.json?name=<name>` and checks that it maps
+/// the name to the public key. Results are cached: matches for 24 hours, names the domain
+/// does not list for 1 hour.
+///
+/// # Arguments
+/// * `pubkey` - The public key the identifier should belong to
+/// * `identifier` - The identifier from the profile's metadata, e.g. `alice@example.com`
+///   (a bare domain means `_@domain`)
+///
+/// # Returns
+/// * `Ok(true)` - The domain maps the identifier to the public key
+/// * `Ok(false)` - The domain does not list the name, or maps it to another key
+/// * `Err(BridgeError)` - `InvalidInput` if the identifier is malformed or the domain
+///   serves an invalid document, or `Network` if the domain could not be reached (the
+///   identifier is then neither verified nor refuted)
+///
+/// # Notes
+/// * Redirects are not followed, as required by NIP-05
+/// * Documents are only fetched over HTTPS, and ones larger than 64 KiB are rejected
+Future<bool> verifyNip05({
+  required PublicKey pubkey,
+  required String identifier,
+}) => RustLib.instance.api.crateApiNip05VerifyNip05(
+  pubkey: pubkey,
+  identifier: identifier,
+);
+
+/// Resolves a NIP-05 identifier to a public key and relay hints.
+///
+/// Lets users start a chat by typing an address like `alice@example.com` instead of an
+/// npub. Results are cached like those of `verify_nip05`.
+///
+/// # Arguments
+/// * `identifier` - The identifier, e.g. `alice@example.com` (a bare domain means
+///   `_@domain`)
+/// * `force_refresh` - Ignore the cache and ask the domain again
+///
+/// # Returns
+/// * `Ok(Some(Nip05ProfileData))` - The public key and the relays the domain lists for it
+/// * `Ok(None)` - The domain does not list the name
+/// * `Err(BridgeError)` - `InvalidInput` if the identifier is malformed or the domain
+///   serves an invalid document, or `Network` if the domain could not be reached
+Future<Nip05ProfileData?> resolveNip05({
+  required String identifier,
+  required bool forceRefresh,
+}) => RustLib.instance.api.crateApiNip05ResolveNip05(
+  identifier: identifier,
+  forceRefresh: forceRefresh,
+);
+
+/// The account a NIP-05 identifier points to.
+class Nip05ProfileData {
+  final String identifier;
+  final String pubkey;
+  final List<String> relays;
+
+  const Nip05ProfileData({
+    required this.identifier,
+    required this.pubkey,
+    required this.relays,
+  });
+
+  @override
+  int get hashCode => identifier.hashCode ^ pubkey.hashCode ^ relays.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Nip05ProfileData &&
+          runtimeType == other.runtimeType &&
+          identifier == other.identifier &&
+          pubkey == other.pubkey &&
+          relays == other.relays;
+}
@@ -0,0 +1,146 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+
+import '../frb_generated.dart';
+import 'errors.dart';
+
+part 'nip19.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `bech32_decode`, `bech32_encode`, `bytes32`, `decode_entity`, `invalid`, `parse_hex32`, `parse_tlv`, `push_tlv`, `relay_hints`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+/// Parses any NIP-19 entity or NIP-21 `nostr:` URI.
+///
+/// Accepts `npub`, `nprofile`, `note`, `nevent`, `naddr` and `nsec` strings, with or
+/// without a `nostr:` prefix, so it can be used for pasted text, scanned QR codes and
+/// links alike.
+///
+/// # Parameters
+/// * `input` - The text to parse, e.g. `nostr:nprofile1...`
+///
+/// # Returns
+/// * `Ok(ParsedNostrEntityData)` - The decoded entity; `is_sensitive` is set for `nsec`
+/// * `Err(BridgeError)` - `InvalidInput` if the input is not valid bech32 or a field is
+///   malformed, or `Unsupported` for other bech32 prefixes
+///
+/// # Notes
+/// * A private key is never valid in a `nostr:` URI (NIP-21); such input is rejected
+/// * Callers should treat `is_sensitive` results with care: never display, log or share
+///   them, and only use them for logging in
+///
+/// # Example
+/// ```rust
+/// let parsed = parse_nostr_entity("nostr:npub1...".to_string())?;
+/// if let NostrEntity::Pubkey { pubkey } = parsed.entity {
+///     println!("Public key: {}", pubkey);
+/// }
+/// ```
+Future<ParsedNostrEntityData> parseNostrEntity({required String input}) =>
+    RustLib.instance.api.crateApiNip19ParseNostrEntity(input: input);
+
+/// Encodes a public key with relay hints as an `nprofile`.
+///
+/// Share an `nprofile` rather than an `npub` so the recipient knows where to find the
+/// profile.
+///
+/// # Parameters
+/// * `pubkey` - The public key (hex)
+/// * `relays` - Relays where the profile can be found (`ws://` or `wss://` URLs)
+///
+/// # Returns
+/// * `Ok(String)` - The `nprofile1...` string (prefix it with `nostr:` for a URI)
+/// * `Err(BridgeError)` - If the key or a relay URL is invalid
+Future<String> encodeNprofile({
+  required String pubkey,
+  required List<String> relays,
+}) => RustLib.instance.api.crateApiNip19EncodeNprofile(
+  pubkey: pubkey,
+  relays: relays,
+);
+
+/// Encodes an event ID with relay, author and kind hints as an `nevent`.
+///
+/// # Parameters
+/// * `event_id` - The event ID (hex)
+/// * `relays` - Relays where the event can be found (`ws://` or `wss://` URLs)
+/// * `author` - Optional public key (hex) of the event's author
+/// * `kind` - Optional kind of the event
+///
+/// # Returns
+/// * `Ok(String)` - The `nevent1...` string (prefix it with `nostr:` for a URI)
+/// * `Err(BridgeError)` - If the event ID, author or a relay URL is invalid
+Future<String> encodeNevent({
+  required String eventId,
+  required List<String> relays,
+  String? author,
+  int? kind,
+}) => RustLib.instance.api.crateApiNip19EncodeNevent(
+  eventId: eventId,
+  relays: relays,
+  author: author,
+  kind: kind,
+);
+
+/// A decoded NIP-19 entity. Keys and event IDs are hex.
+@freezed
+sealed class NostrEntity with _$NostrEntity {
+  const NostrEntity._();
+
+  /// `npub`
+  const factory NostrEntity.pubkey({required String pubkey}) = NostrEntity_Pubkey;
+
+  /// `nprofile`: a public key with relays where the profile can be found
+  const factory NostrEntity.profile({
+    required String pubkey,
+    required List<String> relays,
+  }) = NostrEntity_Profile;
+
+  /// `note`
+  const factory NostrEntity.note({required String eventId}) = NostrEntity_Note;
+
+  /// `nevent`: an event ID with relay, author and kind hints
+  const factory NostrEntity.event({
+    required String eventId,
+    required List<String> relays,
+    String? author,
+    int? kind,
+  }) = NostrEntity_Event;
+
+  /// `naddr`: an addressable event
+  const factory NostrEntity.address({
+    required String identifier,
+    required String pubkey,
+    required int kind,
+    required List<String> relays,
+  }) = NostrEntity_Address;
+
+  /// `nsec`: a private key
+  const factory NostrEntity.secretKey({required String secretKey}) = NostrEntity_SecretKey;
+}
+
+/// Result of `parse_nostr_entity`.
+class ParsedNostrEntityData {
+  final NostrEntity entity;
+  final bool isSensitive;
+
+  const ParsedNostrEntityData({
+    required this.entity,
+    required this.isSensitive,
+  });
+
+  @override
+  int get hashCode => entity.hashCode ^ isSensitive.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ParsedNostrEntityData &&
+          runtimeType == other.runtimeType &&
+          entity == other.entity &&
+          isSensitive == other.isSensitive;
+}
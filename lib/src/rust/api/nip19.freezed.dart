@@ -0,0 +1,1272 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'nip19.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$NostrEntity {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String pubkey) pubkey,
+    required TResult Function(String pubkey, List<String> relays) profile,
+    required TResult Function(String eventId) note,
+    required TResult Function(String eventId, List<String> relays, String? author, int? kind) event,
+    required TResult Function(
+      String identifier,
+      String pubkey,
+      int kind,
+      List<String> relays,
+    )
+    address,
+    required TResult Function(String secretKey) secretKey,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String pubkey)? pubkey,
+    TResult? Function(String pubkey, List<String> relays)? profile,
+    TResult? Function(String eventId)? note,
+    TResult? Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult? Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult? Function(String secretKey)? secretKey,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String pubkey)? pubkey,
+    TResult Function(String pubkey, List<String> relays)? profile,
+    TResult Function(String eventId)? note,
+    TResult Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult Function(String secretKey)? secretKey,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(NostrEntity_Pubkey value) pubkey,
+    required TResult Function(NostrEntity_Profile value) profile,
+    required TResult Function(NostrEntity_Note value) note,
+    required TResult Function(NostrEntity_Event value) event,
+    required TResult Function(NostrEntity_Address value) address,
+    required TResult Function(NostrEntity_SecretKey value) secretKey,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(NostrEntity_Pubkey value)? pubkey,
+    TResult? Function(NostrEntity_Profile value)? profile,
+    TResult? Function(NostrEntity_Note value)? note,
+    TResult? Function(NostrEntity_Event value)? event,
+    TResult? Function(NostrEntity_Address value)? address,
+    TResult? Function(NostrEntity_SecretKey value)? secretKey,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(NostrEntity_Pubkey value)? pubkey,
+    TResult Function(NostrEntity_Profile value)? profile,
+    TResult Function(NostrEntity_Note value)? note,
+    TResult Function(NostrEntity_Event value)? event,
+    TResult Function(NostrEntity_Address value)? address,
+    TResult Function(NostrEntity_SecretKey value)? secretKey,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $NostrEntityCopyWith<$Res> {
+  factory $NostrEntityCopyWith(
+    NostrEntity value,
+    $Res Function(NostrEntity) then,
+  ) = _$NostrEntityCopyWithImpl<$Res, NostrEntity>;
+}
+
+/// @nodoc
+class _$NostrEntityCopyWithImpl<$Res, $Val extends NostrEntity>
+    implements $NostrEntityCopyWith<$Res> {
+  _$NostrEntityCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$NostrEntity_PubkeyImplCopyWith<$Res> {
+  factory _$$NostrEntity_PubkeyImplCopyWith(
+    _$NostrEntity_PubkeyImpl value,
+    $Res Function(_$NostrEntity_PubkeyImpl) then,
+  ) = __$$NostrEntity_PubkeyImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String pubkey});
+}
+
+/// @nodoc
+class __$$NostrEntity_PubkeyImplCopyWithImpl<$Res>
+    extends _$NostrEntityCopyWithImpl<$Res, _$NostrEntity_PubkeyImpl>
+    implements _$$NostrEntity_PubkeyImplCopyWith<$Res> {
+  __$$NostrEntity_PubkeyImplCopyWithImpl(
+    _$NostrEntity_PubkeyImpl _value,
+    $Res Function(_$NostrEntity_PubkeyImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? pubkey = null}) {
+    return _then(
+      _$NostrEntity_PubkeyImpl(
+        pubkey: null == pubkey
+            ? _value.pubkey
+            : pubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$NostrEntity_PubkeyImpl extends NostrEntity_Pubkey {
+  const _$NostrEntity_PubkeyImpl({required this.pubkey}) : super._();
+
+  @override
+  final String pubkey;
+
+  @override
+  String toString() {
+    return 'NostrEntity.pubkey(pubkey: $pubkey)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$NostrEntity_PubkeyImpl &&
+            (identical(other.pubkey, pubkey) || other.pubkey == pubkey));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, pubkey);
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$NostrEntity_PubkeyImplCopyWith<_$NostrEntity_PubkeyImpl> get copyWith =>
+      __$$NostrEntity_PubkeyImplCopyWithImpl<_$NostrEntity_PubkeyImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String pubkey) pubkey,
+    required TResult Function(String pubkey, List<String> relays) profile,
+    required TResult Function(String eventId) note,
+    required TResult Function(String eventId, List<String> relays, String? author, int? kind) event,
+    required TResult Function(
+      String identifier,
+      String pubkey,
+      int kind,
+      List<String> relays,
+    )
+    address,
+    required TResult Function(String secretKey) secretKey,
+  }) {
+    return pubkey(pubkey);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String pubkey)? pubkey,
+    TResult? Function(String pubkey, List<String> relays)? profile,
+    TResult? Function(String eventId)? note,
+    TResult? Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult? Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult? Function(String secretKey)? secretKey,
+  }) {
+    return pubkey?.call(pubkey);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String pubkey)? pubkey,
+    TResult Function(String pubkey, List<String> relays)? profile,
+    TResult Function(String eventId)? note,
+    TResult Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult Function(String secretKey)? secretKey,
+    required TResult orElse(),
+  }) {
+    if (pubkey != null) {
+      return pubkey(pubkey);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(NostrEntity_Pubkey value) pubkey,
+    required TResult Function(NostrEntity_Profile value) profile,
+    required TResult Function(NostrEntity_Note value) note,
+    required TResult Function(NostrEntity_Event value) event,
+    required TResult Function(NostrEntity_Address value) address,
+    required TResult Function(NostrEntity_SecretKey value) secretKey,
+  }) {
+    return pubkey(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(NostrEntity_Pubkey value)? pubkey,
+    TResult? Function(NostrEntity_Profile value)? profile,
+    TResult? Function(NostrEntity_Note value)? note,
+    TResult? Function(NostrEntity_Event value)? event,
+    TResult? Function(NostrEntity_Address value)? address,
+    TResult? Function(NostrEntity_SecretKey value)? secretKey,
+  }) {
+    return pubkey?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(NostrEntity_Pubkey value)? pubkey,
+    TResult Function(NostrEntity_Profile value)? profile,
+    TResult Function(NostrEntity_Note value)? note,
+    TResult Function(NostrEntity_Event value)? event,
+    TResult Function(NostrEntity_Address value)? address,
+    TResult Function(NostrEntity_SecretKey value)? secretKey,
+    required TResult orElse(),
+  }) {
+    if (pubkey != null) {
+      return pubkey(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class NostrEntity_Pubkey extends NostrEntity {
+  const factory NostrEntity_Pubkey({required final String pubkey}) = _$NostrEntity_PubkeyImpl;
+  const NostrEntity_Pubkey._() : super._();
+
+  String get pubkey;
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$NostrEntity_PubkeyImplCopyWith<_$NostrEntity_PubkeyImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$NostrEntity_ProfileImplCopyWith<$Res> {
+  factory _$$NostrEntity_ProfileImplCopyWith(
+    _$NostrEntity_ProfileImpl value,
+    $Res Function(_$NostrEntity_ProfileImpl) then,
+  ) = __$$NostrEntity_ProfileImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String pubkey, List<String> relays});
+}
+
+/// @nodoc
+class __$$NostrEntity_ProfileImplCopyWithImpl<$Res>
+    extends _$NostrEntityCopyWithImpl<$Res, _$NostrEntity_ProfileImpl>
+    implements _$$NostrEntity_ProfileImplCopyWith<$Res> {
+  __$$NostrEntity_ProfileImplCopyWithImpl(
+    _$NostrEntity_ProfileImpl _value,
+    $Res Function(_$NostrEntity_ProfileImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? pubkey = null, Object? relays = null}) {
+    return _then(
+      _$NostrEntity_ProfileImpl(
+        pubkey: null == pubkey
+            ? _value.pubkey
+            : pubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        relays: null == relays
+            ? _value._relays
+            : relays // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$NostrEntity_ProfileImpl extends NostrEntity_Profile {
+  const _$NostrEntity_ProfileImpl({required this.pubkey, required final List<String> relays})
+    : _relays = relays, super._();
+
+  @override
+  final String pubkey;
+
+  final List<String> _relays;
+  @override
+  List<String> get relays {
+    if (_relays is EqualUnmodifiableListView) return _relays;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_relays);
+  }
+
+  @override
+  String toString() {
+    return 'NostrEntity.profile(pubkey: $pubkey, relays: $relays)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$NostrEntity_ProfileImpl &&
+            (identical(other.pubkey, pubkey) || other.pubkey == pubkey) &&
+            const DeepCollectionEquality().equals(other._relays, _relays));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    pubkey,
+    const DeepCollectionEquality().hash(_relays),
+  );
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$NostrEntity_ProfileImplCopyWith<_$NostrEntity_ProfileImpl> get copyWith =>
+      __$$NostrEntity_ProfileImplCopyWithImpl<_$NostrEntity_ProfileImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String pubkey) pubkey,
+    required TResult Function(String pubkey, List<String> relays) profile,
+    required TResult Function(String eventId) note,
+    required TResult Function(String eventId, List<String> relays, String? author, int? kind) event,
+    required TResult Function(
+      String identifier,
+      String pubkey,
+      int kind,
+      List<String> relays,
+    )
+    address,
+    required TResult Function(String secretKey) secretKey,
+  }) {
+    return profile(pubkey, relays);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String pubkey)? pubkey,
+    TResult? Function(String pubkey, List<String> relays)? profile,
+    TResult? Function(String eventId)? note,
+    TResult? Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult? Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult? Function(String secretKey)? secretKey,
+  }) {
+    return profile?.call(pubkey, relays);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String pubkey)? pubkey,
+    TResult Function(String pubkey, List<String> relays)? profile,
+    TResult Function(String eventId)? note,
+    TResult Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult Function(String secretKey)? secretKey,
+    required TResult orElse(),
+  }) {
+    if (profile != null) {
+      return profile(pubkey, relays);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(NostrEntity_Pubkey value) pubkey,
+    required TResult Function(NostrEntity_Profile value) profile,
+    required TResult Function(NostrEntity_Note value) note,
+    required TResult Function(NostrEntity_Event value) event,
+    required TResult Function(NostrEntity_Address value) address,
+    required TResult Function(NostrEntity_SecretKey value) secretKey,
+  }) {
+    return profile(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(NostrEntity_Pubkey value)? pubkey,
+    TResult? Function(NostrEntity_Profile value)? profile,
+    TResult? Function(NostrEntity_Note value)? note,
+    TResult? Function(NostrEntity_Event value)? event,
+    TResult? Function(NostrEntity_Address value)? address,
+    TResult? Function(NostrEntity_SecretKey value)? secretKey,
+  }) {
+    return profile?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(NostrEntity_Pubkey value)? pubkey,
+    TResult Function(NostrEntity_Profile value)? profile,
+    TResult Function(NostrEntity_Note value)? note,
+    TResult Function(NostrEntity_Event value)? event,
+    TResult Function(NostrEntity_Address value)? address,
+    TResult Function(NostrEntity_SecretKey value)? secretKey,
+    required TResult orElse(),
+  }) {
+    if (profile != null) {
+      return profile(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class NostrEntity_Profile extends NostrEntity {
+  const factory NostrEntity_Profile({
+    required final String pubkey,
+    required final List<String> relays,
+  }) = _$NostrEntity_ProfileImpl;
+  const NostrEntity_Profile._() : super._();
+
+  String get pubkey;
+  List<String> get relays;
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$NostrEntity_ProfileImplCopyWith<_$NostrEntity_ProfileImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$NostrEntity_NoteImplCopyWith<$Res> {
+  factory _$$NostrEntity_NoteImplCopyWith(
+    _$NostrEntity_NoteImpl value,
+    $Res Function(_$NostrEntity_NoteImpl) then,
+  ) = __$$NostrEntity_NoteImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String eventId});
+}
+
+/// @nodoc
+class __$$NostrEntity_NoteImplCopyWithImpl<$Res>
+    extends _$NostrEntityCopyWithImpl<$Res, _$NostrEntity_NoteImpl>
+    implements _$$NostrEntity_NoteImplCopyWith<$Res> {
+  __$$NostrEntity_NoteImplCopyWithImpl(
+    _$NostrEntity_NoteImpl _value,
+    $Res Function(_$NostrEntity_NoteImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? eventId = null}) {
+    return _then(
+      _$NostrEntity_NoteImpl(
+        eventId: null == eventId
+            ? _value.eventId
+            : eventId // ignore: cast_nullable_to_non_nullable
+              as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$NostrEntity_NoteImpl extends NostrEntity_Note {
+  const _$NostrEntity_NoteImpl({required this.eventId}) : super._();
+
+  @override
+  final String eventId;
+
+  @override
+  String toString() {
+    return 'NostrEntity.note(eventId: $eventId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$NostrEntity_NoteImpl &&
+            (identical(other.eventId, eventId) || other.eventId == eventId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, eventId);
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$NostrEntity_NoteImplCopyWith<_$NostrEntity_NoteImpl> get copyWith =>
+      __$$NostrEntity_NoteImplCopyWithImpl<_$NostrEntity_NoteImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String pubkey) pubkey,
+    required TResult Function(String pubkey, List<String> relays) profile,
+    required TResult Function(String eventId) note,
+    required TResult Function(String eventId, List<String> relays, String? author, int? kind) event,
+    required TResult Function(
+      String identifier,
+      String pubkey,
+      int kind,
+      List<String> relays,
+    )
+    address,
+    required TResult Function(String secretKey) secretKey,
+  }) {
+    return note(eventId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String pubkey)? pubkey,
+    TResult? Function(String pubkey, List<String> relays)? profile,
+    TResult? Function(String eventId)? note,
+    TResult? Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult? Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult? Function(String secretKey)? secretKey,
+  }) {
+    return note?.call(eventId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String pubkey)? pubkey,
+    TResult Function(String pubkey, List<String> relays)? profile,
+    TResult Function(String eventId)? note,
+    TResult Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult Function(String secretKey)? secretKey,
+    required TResult orElse(),
+  }) {
+    if (note != null) {
+      return note(eventId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(NostrEntity_Pubkey value) pubkey,
+    required TResult Function(NostrEntity_Profile value) profile,
+    required TResult Function(NostrEntity_Note value) note,
+    required TResult Function(NostrEntity_Event value) event,
+    required TResult Function(NostrEntity_Address value) address,
+    required TResult Function(NostrEntity_SecretKey value) secretKey,
+  }) {
+    return note(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(NostrEntity_Pubkey value)? pubkey,
+    TResult? Function(NostrEntity_Profile value)? profile,
+    TResult? Function(NostrEntity_Note value)? note,
+    TResult? Function(NostrEntity_Event value)? event,
+    TResult? Function(NostrEntity_Address value)? address,
+    TResult? Function(NostrEntity_SecretKey value)? secretKey,
+  }) {
+    return note?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(NostrEntity_Pubkey value)? pubkey,
+    TResult Function(NostrEntity_Profile value)? profile,
+    TResult Function(NostrEntity_Note value)? note,
+    TResult Function(NostrEntity_Event value)? event,
+    TResult Function(NostrEntity_Address value)? address,
+    TResult Function(NostrEntity_SecretKey value)? secretKey,
+    required TResult orElse(),
+  }) {
+    if (note != null) {
+      return note(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class NostrEntity_Note extends NostrEntity {
+  const factory NostrEntity_Note({required final String eventId}) = _$NostrEntity_NoteImpl;
+  const NostrEntity_Note._() : super._();
+
+  String get eventId;
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$NostrEntity_NoteImplCopyWith<_$NostrEntity_NoteImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$NostrEntity_EventImplCopyWith<$Res> {
+  factory _$$NostrEntity_EventImplCopyWith(
+    _$NostrEntity_EventImpl value,
+    $Res Function(_$NostrEntity_EventImpl) then,
+  ) = __$$NostrEntity_EventImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String eventId, List<String> relays, String? author, int? kind});
+}
+
+/// @nodoc
+class __$$NostrEntity_EventImplCopyWithImpl<$Res>
+    extends _$NostrEntityCopyWithImpl<$Res, _$NostrEntity_EventImpl>
+    implements _$$NostrEntity_EventImplCopyWith<$Res> {
+  __$$NostrEntity_EventImplCopyWithImpl(
+    _$NostrEntity_EventImpl _value,
+    $Res Function(_$NostrEntity_EventImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? eventId = null,
+    Object? relays = null,
+    Object? author = freezed,
+    Object? kind = freezed,
+  }) {
+    return _then(
+      _$NostrEntity_EventImpl(
+        eventId: null == eventId
+            ? _value.eventId
+            : eventId // ignore: cast_nullable_to_non_nullable
+              as String,
+        relays: null == relays
+            ? _value._relays
+            : relays // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+        author: freezed == author
+            ? _value.author
+            : author // ignore: cast_nullable_to_non_nullable
+              as String?,
+        kind: freezed == kind
+            ? _value.kind
+            : kind // ignore: cast_nullable_to_non_nullable
+              as int?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$NostrEntity_EventImpl extends NostrEntity_Event {
+  const _$NostrEntity_EventImpl({
+    required this.eventId,
+    required final List<String> relays,
+    this.author,
+    this.kind,
+  }) : _relays = relays, super._();
+
+  @override
+  final String eventId;
+
+  final List<String> _relays;
+  @override
+  List<String> get relays {
+    if (_relays is EqualUnmodifiableListView) return _relays;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_relays);
+  }
+
+  @override
+  final String? author;
+
+  @override
+  final int? kind;
+
+  @override
+  String toString() {
+    return 'NostrEntity.event(eventId: $eventId, relays: $relays, author: $author, kind: $kind)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$NostrEntity_EventImpl &&
+            (identical(other.eventId, eventId) || other.eventId == eventId) &&
+            const DeepCollectionEquality().equals(other._relays, _relays) &&
+            (identical(other.author, author) || other.author == author) &&
+            (identical(other.kind, kind) || other.kind == kind));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    eventId,
+    const DeepCollectionEquality().hash(_relays),
+    author,
+    kind,
+  );
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$NostrEntity_EventImplCopyWith<_$NostrEntity_EventImpl> get copyWith =>
+      __$$NostrEntity_EventImplCopyWithImpl<_$NostrEntity_EventImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String pubkey) pubkey,
+    required TResult Function(String pubkey, List<String> relays) profile,
+    required TResult Function(String eventId) note,
+    required TResult Function(String eventId, List<String> relays, String? author, int? kind) event,
+    required TResult Function(
+      String identifier,
+      String pubkey,
+      int kind,
+      List<String> relays,
+    )
+    address,
+    required TResult Function(String secretKey) secretKey,
+  }) {
+    return event(eventId, relays, author, kind);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String pubkey)? pubkey,
+    TResult? Function(String pubkey, List<String> relays)? profile,
+    TResult? Function(String eventId)? note,
+    TResult? Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult? Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult? Function(String secretKey)? secretKey,
+  }) {
+    return event?.call(eventId, relays, author, kind);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String pubkey)? pubkey,
+    TResult Function(String pubkey, List<String> relays)? profile,
+    TResult Function(String eventId)? note,
+    TResult Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult Function(String secretKey)? secretKey,
+    required TResult orElse(),
+  }) {
+    if (event != null) {
+      return event(eventId, relays, author, kind);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(NostrEntity_Pubkey value) pubkey,
+    required TResult Function(NostrEntity_Profile value) profile,
+    required TResult Function(NostrEntity_Note value) note,
+    required TResult Function(NostrEntity_Event value) event,
+    required TResult Function(NostrEntity_Address value) address,
+    required TResult Function(NostrEntity_SecretKey value) secretKey,
+  }) {
+    return event(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(NostrEntity_Pubkey value)? pubkey,
+    TResult? Function(NostrEntity_Profile value)? profile,
+    TResult? Function(NostrEntity_Note value)? note,
+    TResult? Function(NostrEntity_Event value)? event,
+    TResult? Function(NostrEntity_Address value)? address,
+    TResult? Function(NostrEntity_SecretKey value)? secretKey,
+  }) {
+    return event?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(NostrEntity_Pubkey value)? pubkey,
+    TResult Function(NostrEntity_Profile value)? profile,
+    TResult Function(NostrEntity_Note value)? note,
+    TResult Function(NostrEntity_Event value)? event,
+    TResult Function(NostrEntity_Address value)? address,
+    TResult Function(NostrEntity_SecretKey value)? secretKey,
+    required TResult orElse(),
+  }) {
+    if (event != null) {
+      return event(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class NostrEntity_Event extends NostrEntity {
+  const factory NostrEntity_Event({
+    required final String eventId,
+    required final List<String> relays,
+    final String? author,
+    final int? kind,
+  }) = _$NostrEntity_EventImpl;
+  const NostrEntity_Event._() : super._();
+
+  String get eventId;
+  List<String> get relays;
+  String? get author;
+  int? get kind;
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$NostrEntity_EventImplCopyWith<_$NostrEntity_EventImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$NostrEntity_AddressImplCopyWith<$Res> {
+  factory _$$NostrEntity_AddressImplCopyWith(
+    _$NostrEntity_AddressImpl value,
+    $Res Function(_$NostrEntity_AddressImpl) then,
+  ) = __$$NostrEntity_AddressImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String identifier, String pubkey, int kind, List<String> relays});
+}
+
+/// @nodoc
+class __$$NostrEntity_AddressImplCopyWithImpl<$Res>
+    extends _$NostrEntityCopyWithImpl<$Res, _$NostrEntity_AddressImpl>
+    implements _$$NostrEntity_AddressImplCopyWith<$Res> {
+  __$$NostrEntity_AddressImplCopyWithImpl(
+    _$NostrEntity_AddressImpl _value,
+    $Res Function(_$NostrEntity_AddressImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? identifier = null,
+    Object? pubkey = null,
+    Object? kind = null,
+    Object? relays = null,
+  }) {
+    return _then(
+      _$NostrEntity_AddressImpl(
+        identifier: null == identifier
+            ? _value.identifier
+            : identifier // ignore: cast_nullable_to_non_nullable
+              as String,
+        pubkey: null == pubkey
+            ? _value.pubkey
+            : pubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        kind: null == kind
+            ? _value.kind
+            : kind // ignore: cast_nullable_to_non_nullable
+              as int,
+        relays: null == relays
+            ? _value._relays
+            : relays // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$NostrEntity_AddressImpl extends NostrEntity_Address {
+  const _$NostrEntity_AddressImpl({
+    required this.identifier,
+    required this.pubkey,
+    required this.kind,
+    required final List<String> relays,
+  }) : _relays = relays, super._();
+
+  @override
+  final String identifier;
+
+  @override
+  final String pubkey;
+
+  @override
+  final int kind;
+
+  final List<String> _relays;
+  @override
+  List<String> get relays {
+    if (_relays is EqualUnmodifiableListView) return _relays;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_relays);
+  }
+
+  @override
+  String toString() {
+    return 'NostrEntity.address(identifier: $identifier, pubkey: $pubkey, kind: $kind, relays: $relays)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$NostrEntity_AddressImpl &&
+            (identical(other.identifier, identifier) || other.identifier == identifier) &&
+            (identical(other.pubkey, pubkey) || other.pubkey == pubkey) &&
+            (identical(other.kind, kind) || other.kind == kind) &&
+            const DeepCollectionEquality().equals(other._relays, _relays));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    identifier,
+    pubkey,
+    kind,
+    const DeepCollectionEquality().hash(_relays),
+  );
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$NostrEntity_AddressImplCopyWith<_$NostrEntity_AddressImpl> get copyWith =>
+      __$$NostrEntity_AddressImplCopyWithImpl<_$NostrEntity_AddressImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String pubkey) pubkey,
+    required TResult Function(String pubkey, List<String> relays) profile,
+    required TResult Function(String eventId) note,
+    required TResult Function(String eventId, List<String> relays, String? author, int? kind) event,
+    required TResult Function(
+      String identifier,
+      String pubkey,
+      int kind,
+      List<String> relays,
+    )
+    address,
+    required TResult Function(String secretKey) secretKey,
+  }) {
+    return address(identifier, pubkey, kind, relays);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String pubkey)? pubkey,
+    TResult? Function(String pubkey, List<String> relays)? profile,
+    TResult? Function(String eventId)? note,
+    TResult? Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult? Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult? Function(String secretKey)? secretKey,
+  }) {
+    return address?.call(identifier, pubkey, kind, relays);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String pubkey)? pubkey,
+    TResult Function(String pubkey, List<String> relays)? profile,
+    TResult Function(String eventId)? note,
+    TResult Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult Function(String secretKey)? secretKey,
+    required TResult orElse(),
+  }) {
+    if (address != null) {
+      return address(identifier, pubkey, kind, relays);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(NostrEntity_Pubkey value) pubkey,
+    required TResult Function(NostrEntity_Profile value) profile,
+    required TResult Function(NostrEntity_Note value) note,
+    required TResult Function(NostrEntity_Event value) event,
+    required TResult Function(NostrEntity_Address value) address,
+    required TResult Function(NostrEntity_SecretKey value) secretKey,
+  }) {
+    return address(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(NostrEntity_Pubkey value)? pubkey,
+    TResult? Function(NostrEntity_Profile value)? profile,
+    TResult? Function(NostrEntity_Note value)? note,
+    TResult? Function(NostrEntity_Event value)? event,
+    TResult? Function(NostrEntity_Address value)? address,
+    TResult? Function(NostrEntity_SecretKey value)? secretKey,
+  }) {
+    return address?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(NostrEntity_Pubkey value)? pubkey,
+    TResult Function(NostrEntity_Profile value)? profile,
+    TResult Function(NostrEntity_Note value)? note,
+    TResult Function(NostrEntity_Event value)? event,
+    TResult Function(NostrEntity_Address value)? address,
+    TResult Function(NostrEntity_SecretKey value)? secretKey,
+    required TResult orElse(),
+  }) {
+    if (address != null) {
+      return address(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class NostrEntity_Address extends NostrEntity {
+  const factory NostrEntity_Address({
+    required final String identifier,
+    required final String pubkey,
+    required final int kind,
+    required final List<String> relays,
+  }) = _$NostrEntity_AddressImpl;
+  const NostrEntity_Address._() : super._();
+
+  String get identifier;
+  String get pubkey;
+  int get kind;
+  List<String> get relays;
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$NostrEntity_AddressImplCopyWith<_$NostrEntity_AddressImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$NostrEntity_SecretKeyImplCopyWith<$Res> {
+  factory _$$NostrEntity_SecretKeyImplCopyWith(
+    _$NostrEntity_SecretKeyImpl value,
+    $Res Function(_$NostrEntity_SecretKeyImpl) then,
+  ) = __$$NostrEntity_SecretKeyImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String secretKey});
+}
+
+/// @nodoc
+class __$$NostrEntity_SecretKeyImplCopyWithImpl<$Res>
+    extends _$NostrEntityCopyWithImpl<$Res, _$NostrEntity_SecretKeyImpl>
+    implements _$$NostrEntity_SecretKeyImplCopyWith<$Res> {
+  __$$NostrEntity_SecretKeyImplCopyWithImpl(
+    _$NostrEntity_SecretKeyImpl _value,
+    $Res Function(_$NostrEntity_SecretKeyImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? secretKey = null}) {
+    return _then(
+      _$NostrEntity_SecretKeyImpl(
+        secretKey: null == secretKey
+            ? _value.secretKey
+            : secretKey // ignore: cast_nullable_to_non_nullable
+              as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$NostrEntity_SecretKeyImpl extends NostrEntity_SecretKey {
+  const _$NostrEntity_SecretKeyImpl({required this.secretKey}) : super._();
+
+  @override
+  final String secretKey;
+
+  @override
+  String toString() {
+    return 'NostrEntity.secretKey(secretKey: $secretKey)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$NostrEntity_SecretKeyImpl &&
+            (identical(other.secretKey, secretKey) || other.secretKey == secretKey));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, secretKey);
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$NostrEntity_SecretKeyImplCopyWith<_$NostrEntity_SecretKeyImpl> get copyWith =>
+      __$$NostrEntity_SecretKeyImplCopyWithImpl<_$NostrEntity_SecretKeyImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String pubkey) pubkey,
+    required TResult Function(String pubkey, List<String> relays) profile,
+    required TResult Function(String eventId) note,
+    required TResult Function(String eventId, List<String> relays, String? author, int? kind) event,
+    required TResult Function(
+      String identifier,
+      String pubkey,
+      int kind,
+      List<String> relays,
+    )
+    address,
+    required TResult Function(String secretKey) secretKey,
+  }) {
+    return secretKey(secretKey);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String pubkey)? pubkey,
+    TResult? Function(String pubkey, List<String> relays)? profile,
+    TResult? Function(String eventId)? note,
+    TResult? Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult? Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult? Function(String secretKey)? secretKey,
+  }) {
+    return secretKey?.call(secretKey);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String pubkey)? pubkey,
+    TResult Function(String pubkey, List<String> relays)? profile,
+    TResult Function(String eventId)? note,
+    TResult Function(String eventId, List<String> relays, String? author, int? kind)? event,
+    TResult Function(String identifier, String pubkey, int kind, List<String> relays)? address,
+    TResult Function(String secretKey)? secretKey,
+    required TResult orElse(),
+  }) {
+    if (secretKey != null) {
+      return secretKey(secretKey);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(NostrEntity_Pubkey value) pubkey,
+    required TResult Function(NostrEntity_Profile value) profile,
+    required TResult Function(NostrEntity_Note value) note,
+    required TResult Function(NostrEntity_Event value) event,
+    required TResult Function(NostrEntity_Address value) address,
+    required TResult Function(NostrEntity_SecretKey value) secretKey,
+  }) {
+    return secretKey(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(NostrEntity_Pubkey value)? pubkey,
+    TResult? Function(NostrEntity_Profile value)? profile,
+    TResult? Function(NostrEntity_Note value)? note,
+    TResult? Function(NostrEntity_Event value)? event,
+    TResult? Function(NostrEntity_Address value)? address,
+    TResult? Function(NostrEntity_SecretKey value)? secretKey,
+  }) {
+    return secretKey?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(NostrEntity_Pubkey value)? pubkey,
+    TResult Function(NostrEntity_Profile value)? profile,
+    TResult Function(NostrEntity_Note value)? note,
+    TResult Function(NostrEntity_Event value)? event,
+    TResult Function(NostrEntity_Address value)? address,
+    TResult Function(NostrEntity_SecretKey value)? secretKey,
+    required TResult orElse(),
+  }) {
+    if (secretKey != null) {
+      return secretKey(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class NostrEntity_SecretKey extends NostrEntity {
+  const factory NostrEntity_SecretKey({
+    required final String secretKey,
+  }) = _$NostrEntity_SecretKeyImpl;
+  const NostrEntity_SecretKey._() : super._();
+
+  String get secretKey;
+
+  /// Create a copy of NostrEntity
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$NostrEntity_SecretKeyImplCopyWith<_$NostrEntity_SecretKeyImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,176 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+import 'accounts.dart';
+import 'chats.dart';
+import 'errors.dart';
+import 'messages.dart';
+
+// These functions are ignored because they are not marked as `pub`: `attempt_outbox_message`, `claim`, `load_outbox`, `load_outbox_message`, `new_outbox_id`, `new_outbox_message`, `outbox_wakeup`, `process_outbox`, `retry_delay`, `run_outbox`, `save_outbox_message`, `send_outbox_message`, `send_through_outbox`, `start_outbox_worker`, `update_outbox_message`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `drop`, `eq`, `fmt`, `fmt`
+
+/// Queues a message to be sent to a group, retrying until it is delivered.
+///
+/// Unlike `send_message_to_group`, which waits for the first attempt, the call returns as
+/// soon as the message is stored locally; a background worker sends it as soon as a relay
+/// is connected. Failed attempts are retried with exponential backoff (up to 5 minutes
+/// apart); the outbox survives app restarts.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account sending the message
+/// * `group_id` - The MLS group ID to send the message to
+/// * `message` - The message content
+/// * `kind` - The Nostr event kind
+/// * `tags` - Optional Nostr tags to include with the message
+///
+/// # Returns
+/// * `Ok(OutboxMessageData)` - The queued message; show it as pending until
+///   `WhitenoiseEvent::OutboxMessageSent` arrives with its ID
+/// * `Err(BridgeError)` - `InvalidInput` for typing indicators and read receipts, or a
+///   `Storage` error if the message could not be stored
+///
+/// # Notes
+/// * Messages to the same group are sent in the order they were queued
+/// * After 10 failed attempts the message is marked `OutboxState::Failed` and is only
+///   sent again through `retry_message`
+Future<OutboxMessageData> enqueueMessageToGroup({
+  required PublicKey pubkey,
+  required GroupId groupId,
+  required String message,
+  required int kind,
+  List<Tag>? tags,
+}) => RustLib.instance.api.crateApiOutboxEnqueueMessageToGroup(
+  pubkey: pubkey,
+  groupId: groupId,
+  message: message,
+  kind: kind,
+  tags: tags,
+);
+
+/// Fetches the messages waiting in the outbox of an account.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `group_id` - Only return messages for this group, or `None` for all groups
+///
+/// # Returns
+/// * `Ok(Vec<OutboxMessageData>)` - The queued messages, oldest first
+/// * `Err(BridgeError)` - If the outbox could not be read
+Future<List<OutboxMessageData>> fetchOutbox({
+  required PublicKey pubkey,
+  GroupId? groupId,
+}) => RustLib.instance.api.crateApiOutboxFetchOutbox(
+  pubkey: pubkey,
+  groupId: groupId,
+);
+
+/// Sends a queued message again right away.
+///
+/// Resets the retry counter, so a message marked `OutboxState::Failed` goes back to
+/// being retried automatically.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `outbox_id` - The outbox ID of the message
+///
+/// # Returns
+/// * `Ok(OutboxMessageData)` - The message, now pending
+/// * `Err(BridgeError)` - `MessageNotFound` if the message is no longer in the outbox
+///   (e.g. it has been sent in the meantime)
+Future<OutboxMessageData> retryMessage({
+  required PublicKey pubkey,
+  required String outboxId,
+}) => RustLib.instance.api.crateApiOutboxRetryMessage(
+  pubkey: pubkey,
+  outboxId: outboxId,
+);
+
+/// Removes a message from the outbox without sending it.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `outbox_id` - The outbox ID of the message
+///
+/// # Returns
+/// * `Ok(true)` - If the message was removed
+/// * `Ok(false)` - If the message was no longer in the outbox
+/// * `Err(BridgeError)` - If the outbox could not be updated
+///
+/// # Notes
+/// * A message that is being sent at the moment of the call may still be delivered
+Future<bool> cancelPendingMessage({
+  required PublicKey pubkey,
+  required String outboxId,
+}) => RustLib.instance.api.crateApiOutboxCancelPendingMessage(
+  pubkey: pubkey,
+  outboxId: outboxId,
+);
+
+/// A message waiting in the outbox to be sent.
+class OutboxMessageData {
+  final String id;
+  final String groupId;
+  final String content;
+  final int kind;
+  final List<List<String>> tags;
+  final BigInt createdAt;
+  final OutboxState state;
+  final int attempts;
+  final BigInt nextAttemptAt;
+  final String? lastError;
+
+  const OutboxMessageData({
+    required this.id,
+    required this.groupId,
+    required this.content,
+    required this.kind,
+    required this.tags,
+    required this.createdAt,
+    required this.state,
+    required this.attempts,
+    required this.nextAttemptAt,
+    this.lastError,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      groupId.hashCode ^
+      content.hashCode ^
+      kind.hashCode ^
+      tags.hashCode ^
+      createdAt.hashCode ^
+      state.hashCode ^
+      attempts.hashCode ^
+      nextAttemptAt.hashCode ^
+      lastError.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OutboxMessageData &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          groupId == other.groupId &&
+          content == other.content &&
+          kind == other.kind &&
+          tags == other.tags &&
+          createdAt == other.createdAt &&
+          state == other.state &&
+          attempts == other.attempts &&
+          nextAttemptAt == other.nextAttemptAt &&
+          lastError == other.lastError;
+}
+
+/// State of a message waiting in the outbox.
+enum OutboxState {
+  /// The message will be sent automatically
+  pending,
+  /// Automatic retries gave up; use `retry_message` or `cancel_pending_message`
+  failed,
+}
@@ -0,0 +1,180 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+
+import '../frb_generated.dart';
+import 'accounts.dart';
+import 'chats.dart';
+import 'errors.dart';
+
+part 'presence.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `load_presence_settings`, `presence_update`, `record_read_receipt`, `send_presence_event`, `unwrap_presence_event`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `default`, `eq`, `fmt`, `fmt`
+
+/// Fetches whether an account sends typing indicators and read receipts.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+///
+/// # Returns
+/// * `Ok(PresenceSettingsData)` - The settings; both are sent until disabled
+/// * `Err(BridgeError)` - If the settings could not be read
+Future<PresenceSettingsData> fetchPresenceSettings({
+  required PublicKey pubkey,
+}) => RustLib.instance.api.crateApiPresenceFetchPresenceSettings(
+  pubkey: pubkey,
+);
+
+/// Updates whether an account sends typing indicators and read receipts.
+///
+/// Only affects what the account sends; indicators and receipts from other members are
+/// still received.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `settings` - The new settings
+///
+/// # Returns
+/// * `Ok(())` - If the settings were saved
+/// * `Err(BridgeError)` - If the settings could not be saved
+Future<void> updatePresenceSettings({
+  required PublicKey pubkey,
+  required PresenceSettingsData settings,
+}) => RustLib.instance.api.crateApiPresenceUpdatePresenceSettings(
+  pubkey: pubkey,
+  settings: settings,
+);
+
+/// Tells the other members of a group that the account is typing.
+///
+/// Call it whenever the user types; indicators are throttled so at most one is sent every
+/// few seconds per group.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account that is typing
+/// * `group_id` - The MLS group ID
+///
+/// # Returns
+/// * `Ok(())` - If the indicator was sent, throttled, or typing indicators are disabled in
+///   the presence settings
+/// * `Err(BridgeError)` - If the indicator could not be sent
+///
+/// # Notes
+/// * Typing indicators and read receipts are ephemeral: they are not MLS messages, relays
+///   do not store them and members only get them while they are online (see
+///   `subscribe_to_group_presence`)
+Future<void> sendTypingIndicator({
+  required PublicKey pubkey,
+  required GroupId groupId,
+}) => RustLib.instance.api.crateApiPresenceSendTypingIndicator(
+  pubkey: pubkey,
+  groupId: groupId,
+);
+
+/// Tells the other members of a group that the account has read a message.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `group_id` - The MLS group ID
+/// * `message_id` - The event ID (hex) of the newest message the account has read
+///
+/// # Returns
+/// * `Ok(())` - If the receipt was sent or read receipts are disabled in the presence
+///   settings
+/// * `Err(BridgeError)` - `MessageNotFound` if the message is not in the group, or any
+///   error from sending the receipt
+///
+/// # Notes
+/// * Receipts are ephemeral like typing indicators: members who are offline miss them and
+///   catch up with the next one
+Future<void> sendReadReceipt({
+  required PublicKey pubkey,
+  required GroupId groupId,
+  required String messageId,
+}) => RustLib.instance.api.crateApiPresenceSendReadReceipt(
+  pubkey: pubkey,
+  groupId: groupId,
+  messageId: messageId,
+);
+
+/// Subscribes to typing indicators and read receipts of the other members of a group.
+///
+/// When the stream is opened, the latest read receipt received from every member is
+/// pushed so Flutter can show who has read what; after that, new typing indicators and
+/// receipts are pushed as they arrive.
+///
+/// # Arguments
+/// * `sink` - The stream sink the updates are pushed to (provided by flutter_rust_bridge)
+/// * `pubkey` - The public key of the account subscribing (must be a group member)
+/// * `group_id` - The MLS group ID to watch
+///
+/// # Returns
+/// * `Ok(())` - The subscription was started; updates arrive on the stream
+/// * `Err(BridgeError)` - If the group or its relays could not be loaded
+///
+/// # Notes
+/// * The first item on the stream is always `GroupPresenceUpdate::Subscribed`, carrying
+///   the handle to pass to `unsubscribe`
+/// * Presence events are ephemeral and only received while a stream is open; the latest
+///   read receipt of each member is kept on the device, everything else is dropped
+/// * Events from anyone who is not a member of the group are ignored
+Stream<GroupPresenceUpdate> subscribeToGroupPresence({
+  required PublicKey pubkey,
+  required GroupId groupId,
+}) => RustLib.instance.api.crateApiPresenceSubscribeToGroupPresence(
+  pubkey: pubkey,
+  groupId: groupId,
+);
+
+/// An update pushed on the stream created by `subscribe_to_group_presence`.
+@freezed
+sealed class GroupPresenceUpdate with _$GroupPresenceUpdate {
+  const GroupPresenceUpdate._();
+
+  /// First item on every stream; carries the handle to pass to `unsubscribe`
+  const factory GroupPresenceUpdate.subscribed({
+    required BigInt subscriptionId,
+  }) = GroupPresenceUpdate_Subscribed;
+
+  /// A member started typing; hide the indicator after `expires_at` (unix seconds)
+  const factory GroupPresenceUpdate.typing({
+    required String memberPubkey,
+    required BigInt expiresAt,
+  }) = GroupPresenceUpdate_Typing;
+
+  /// A member read the group up to a message
+  const factory GroupPresenceUpdate.readReceipt({
+    required String memberPubkey,
+    required String messageId,
+    required BigInt readAt,
+  }) = GroupPresenceUpdate_ReadReceipt;
+}
+
+/// Per-account opt-outs for typing indicators and read receipts.
+///
+/// Kept by the bridge next to the core's `AccountSettings`, which it cannot extend.
+class PresenceSettingsData {
+  final bool disableReadReceipts;
+  final bool disableTypingIndicators;
+
+  const PresenceSettingsData({
+    required this.disableReadReceipts,
+    required this.disableTypingIndicators,
+  });
+
+  @override
+  int get hashCode => disableReadReceipts.hashCode ^ disableTypingIndicators.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PresenceSettingsData &&
+          runtimeType == other.runtimeType &&
+          disableReadReceipts == other.disableReadReceipts &&
+          disableTypingIndicators == other.disableTypingIndicators;
+}
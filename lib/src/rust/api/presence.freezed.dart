@@ -0,0 +1,578 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'presence.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$GroupPresenceUpdate {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String memberPubkey, BigInt expiresAt) typing,
+    required TResult Function(String memberPubkey, String messageId, BigInt readAt) readReceipt,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String memberPubkey, BigInt expiresAt)? typing,
+    TResult? Function(String memberPubkey, String messageId, BigInt readAt)? readReceipt,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String memberPubkey, BigInt expiresAt)? typing,
+    TResult Function(String memberPubkey, String messageId, BigInt readAt)? readReceipt,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupPresenceUpdate_Subscribed value) subscribed,
+    required TResult Function(GroupPresenceUpdate_Typing value) typing,
+    required TResult Function(GroupPresenceUpdate_ReadReceipt value) readReceipt,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupPresenceUpdate_Subscribed value)? subscribed,
+    TResult? Function(GroupPresenceUpdate_Typing value)? typing,
+    TResult? Function(GroupPresenceUpdate_ReadReceipt value)? readReceipt,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupPresenceUpdate_Subscribed value)? subscribed,
+    TResult Function(GroupPresenceUpdate_Typing value)? typing,
+    TResult Function(GroupPresenceUpdate_ReadReceipt value)? readReceipt,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $GroupPresenceUpdateCopyWith<$Res> {
+  factory $GroupPresenceUpdateCopyWith(
+    GroupPresenceUpdate value,
+    $Res Function(GroupPresenceUpdate) then,
+  ) = _$GroupPresenceUpdateCopyWithImpl<$Res, GroupPresenceUpdate>;
+}
+
+/// @nodoc
+class _$GroupPresenceUpdateCopyWithImpl<$Res, $Val extends GroupPresenceUpdate>
+    implements $GroupPresenceUpdateCopyWith<$Res> {
+  _$GroupPresenceUpdateCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of GroupPresenceUpdate
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$GroupPresenceUpdate_SubscribedImplCopyWith<$Res> {
+  factory _$$GroupPresenceUpdate_SubscribedImplCopyWith(
+    _$GroupPresenceUpdate_SubscribedImpl value,
+    $Res Function(_$GroupPresenceUpdate_SubscribedImpl) then,
+  ) = __$$GroupPresenceUpdate_SubscribedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt subscriptionId});
+}
+
+/// @nodoc
+class __$$GroupPresenceUpdate_SubscribedImplCopyWithImpl<$Res>
+    extends _$GroupPresenceUpdateCopyWithImpl<$Res, _$GroupPresenceUpdate_SubscribedImpl>
+    implements _$$GroupPresenceUpdate_SubscribedImplCopyWith<$Res> {
+  __$$GroupPresenceUpdate_SubscribedImplCopyWithImpl(
+    _$GroupPresenceUpdate_SubscribedImpl _value,
+    $Res Function(_$GroupPresenceUpdate_SubscribedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GroupPresenceUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? subscriptionId = null}) {
+    return _then(
+      _$GroupPresenceUpdate_SubscribedImpl(
+        subscriptionId: null == subscriptionId
+            ? _value.subscriptionId
+            : subscriptionId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GroupPresenceUpdate_SubscribedImpl extends GroupPresenceUpdate_Subscribed {
+  const _$GroupPresenceUpdate_SubscribedImpl({required this.subscriptionId}) : super._();
+
+  @override
+  final BigInt subscriptionId;
+
+  @override
+  String toString() {
+    return 'GroupPresenceUpdate.subscribed(subscriptionId: $subscriptionId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupPresenceUpdate_SubscribedImpl &&
+            (identical(other.subscriptionId, subscriptionId) ||
+                other.subscriptionId == subscriptionId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, subscriptionId);
+
+  /// Create a copy of GroupPresenceUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupPresenceUpdate_SubscribedImplCopyWith<_$GroupPresenceUpdate_SubscribedImpl> get copyWith =>
+      __$$GroupPresenceUpdate_SubscribedImplCopyWithImpl<_$GroupPresenceUpdate_SubscribedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String memberPubkey, BigInt expiresAt) typing,
+    required TResult Function(String memberPubkey, String messageId, BigInt readAt) readReceipt,
+  }) {
+    return subscribed(subscriptionId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String memberPubkey, BigInt expiresAt)? typing,
+    TResult? Function(String memberPubkey, String messageId, BigInt readAt)? readReceipt,
+  }) {
+    return subscribed?.call(subscriptionId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String memberPubkey, BigInt expiresAt)? typing,
+    TResult Function(String memberPubkey, String messageId, BigInt readAt)? readReceipt,
+    required TResult orElse(),
+  }) {
+    if (subscribed != null) {
+      return subscribed(subscriptionId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupPresenceUpdate_Subscribed value) subscribed,
+    required TResult Function(GroupPresenceUpdate_Typing value) typing,
+    required TResult Function(GroupPresenceUpdate_ReadReceipt value) readReceipt,
+  }) {
+    return subscribed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupPresenceUpdate_Subscribed value)? subscribed,
+    TResult? Function(GroupPresenceUpdate_Typing value)? typing,
+    TResult? Function(GroupPresenceUpdate_ReadReceipt value)? readReceipt,
+  }) {
+    return subscribed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupPresenceUpdate_Subscribed value)? subscribed,
+    TResult Function(GroupPresenceUpdate_Typing value)? typing,
+    TResult Function(GroupPresenceUpdate_ReadReceipt value)? readReceipt,
+    required TResult orElse(),
+  }) {
+    if (subscribed != null) {
+      return subscribed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupPresenceUpdate_Subscribed extends GroupPresenceUpdate {
+  const factory GroupPresenceUpdate_Subscribed({
+    required final BigInt subscriptionId,
+  }) = _$GroupPresenceUpdate_SubscribedImpl;
+  const GroupPresenceUpdate_Subscribed._() : super._();
+
+  BigInt get subscriptionId;
+
+  /// Create a copy of GroupPresenceUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupPresenceUpdate_SubscribedImplCopyWith<_$GroupPresenceUpdate_SubscribedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupPresenceUpdate_TypingImplCopyWith<$Res> {
+  factory _$$GroupPresenceUpdate_TypingImplCopyWith(
+    _$GroupPresenceUpdate_TypingImpl value,
+    $Res Function(_$GroupPresenceUpdate_TypingImpl) then,
+  ) = __$$GroupPresenceUpdate_TypingImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String memberPubkey, BigInt expiresAt});
+}
+
+/// @nodoc
+class __$$GroupPresenceUpdate_TypingImplCopyWithImpl<$Res>
+    extends _$GroupPresenceUpdateCopyWithImpl<$Res, _$GroupPresenceUpdate_TypingImpl>
+    implements _$$GroupPresenceUpdate_TypingImplCopyWith<$Res> {
+  __$$GroupPresenceUpdate_TypingImplCopyWithImpl(
+    _$GroupPresenceUpdate_TypingImpl _value,
+    $Res Function(_$GroupPresenceUpdate_TypingImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GroupPresenceUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? memberPubkey = null, Object? expiresAt = null}) {
+    return _then(
+      _$GroupPresenceUpdate_TypingImpl(
+        memberPubkey: null == memberPubkey
+            ? _value.memberPubkey
+            : memberPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        expiresAt: null == expiresAt
+            ? _value.expiresAt
+            : expiresAt // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GroupPresenceUpdate_TypingImpl extends GroupPresenceUpdate_Typing {
+  const _$GroupPresenceUpdate_TypingImpl({required this.memberPubkey, required this.expiresAt})
+    : super._();
+
+  @override
+  final String memberPubkey;
+
+  @override
+  final BigInt expiresAt;
+
+  @override
+  String toString() {
+    return 'GroupPresenceUpdate.typing(memberPubkey: $memberPubkey, expiresAt: $expiresAt)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupPresenceUpdate_TypingImpl &&
+            (identical(other.memberPubkey, memberPubkey) || other.memberPubkey == memberPubkey) &&
+            (identical(other.expiresAt, expiresAt) || other.expiresAt == expiresAt));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, memberPubkey, expiresAt);
+
+  /// Create a copy of GroupPresenceUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupPresenceUpdate_TypingImplCopyWith<_$GroupPresenceUpdate_TypingImpl> get copyWith =>
+      __$$GroupPresenceUpdate_TypingImplCopyWithImpl<_$GroupPresenceUpdate_TypingImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String memberPubkey, BigInt expiresAt) typing,
+    required TResult Function(String memberPubkey, String messageId, BigInt readAt) readReceipt,
+  }) {
+    return typing(memberPubkey, expiresAt);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String memberPubkey, BigInt expiresAt)? typing,
+    TResult? Function(String memberPubkey, String messageId, BigInt readAt)? readReceipt,
+  }) {
+    return typing?.call(memberPubkey, expiresAt);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String memberPubkey, BigInt expiresAt)? typing,
+    TResult Function(String memberPubkey, String messageId, BigInt readAt)? readReceipt,
+    required TResult orElse(),
+  }) {
+    if (typing != null) {
+      return typing(memberPubkey, expiresAt);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupPresenceUpdate_Subscribed value) subscribed,
+    required TResult Function(GroupPresenceUpdate_Typing value) typing,
+    required TResult Function(GroupPresenceUpdate_ReadReceipt value) readReceipt,
+  }) {
+    return typing(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupPresenceUpdate_Subscribed value)? subscribed,
+    TResult? Function(GroupPresenceUpdate_Typing value)? typing,
+    TResult? Function(GroupPresenceUpdate_ReadReceipt value)? readReceipt,
+  }) {
+    return typing?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupPresenceUpdate_Subscribed value)? subscribed,
+    TResult Function(GroupPresenceUpdate_Typing value)? typing,
+    TResult Function(GroupPresenceUpdate_ReadReceipt value)? readReceipt,
+    required TResult orElse(),
+  }) {
+    if (typing != null) {
+      return typing(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupPresenceUpdate_Typing extends GroupPresenceUpdate {
+  const factory GroupPresenceUpdate_Typing({
+    required final String memberPubkey,
+    required final BigInt expiresAt,
+  }) = _$GroupPresenceUpdate_TypingImpl;
+  const GroupPresenceUpdate_Typing._() : super._();
+
+  String get memberPubkey;
+  BigInt get expiresAt;
+
+  /// Create a copy of GroupPresenceUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupPresenceUpdate_TypingImplCopyWith<_$GroupPresenceUpdate_TypingImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupPresenceUpdate_ReadReceiptImplCopyWith<$Res> {
+  factory _$$GroupPresenceUpdate_ReadReceiptImplCopyWith(
+    _$GroupPresenceUpdate_ReadReceiptImpl value,
+    $Res Function(_$GroupPresenceUpdate_ReadReceiptImpl) then,
+  ) = __$$GroupPresenceUpdate_ReadReceiptImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String memberPubkey, String messageId, BigInt readAt});
+}
+
+/// @nodoc
+class __$$GroupPresenceUpdate_ReadReceiptImplCopyWithImpl<$Res>
+    extends _$GroupPresenceUpdateCopyWithImpl<$Res, _$GroupPresenceUpdate_ReadReceiptImpl>
+    implements _$$GroupPresenceUpdate_ReadReceiptImplCopyWith<$Res> {
+  __$$GroupPresenceUpdate_ReadReceiptImplCopyWithImpl(
+    _$GroupPresenceUpdate_ReadReceiptImpl _value,
+    $Res Function(_$GroupPresenceUpdate_ReadReceiptImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GroupPresenceUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? memberPubkey = null, Object? messageId = null, Object? readAt = null}) {
+    return _then(
+      _$GroupPresenceUpdate_ReadReceiptImpl(
+        memberPubkey: null == memberPubkey
+            ? _value.memberPubkey
+            : memberPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+        messageId: null == messageId
+            ? _value.messageId
+            : messageId // ignore: cast_nullable_to_non_nullable
+              as String,
+        readAt: null == readAt
+            ? _value.readAt
+            : readAt // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GroupPresenceUpdate_ReadReceiptImpl extends GroupPresenceUpdate_ReadReceipt {
+  const _$GroupPresenceUpdate_ReadReceiptImpl({
+    required this.memberPubkey,
+    required this.messageId,
+    required this.readAt,
+  }) : super._();
+
+  @override
+  final String memberPubkey;
+
+  @override
+  final String messageId;
+
+  @override
+  final BigInt readAt;
+
+  @override
+  String toString() {
+    return 'GroupPresenceUpdate.readReceipt(memberPubkey: $memberPubkey, messageId: $messageId, readAt: $readAt)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupPresenceUpdate_ReadReceiptImpl &&
+            (identical(other.memberPubkey, memberPubkey) || other.memberPubkey == memberPubkey) &&
+            (identical(other.messageId, messageId) || other.messageId == messageId) &&
+            (identical(other.readAt, readAt) || other.readAt == readAt));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, memberPubkey, messageId, readAt);
+
+  /// Create a copy of GroupPresenceUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupPresenceUpdate_ReadReceiptImplCopyWith<_$GroupPresenceUpdate_ReadReceiptImpl> get copyWith =>
+      __$$GroupPresenceUpdate_ReadReceiptImplCopyWithImpl<_$GroupPresenceUpdate_ReadReceiptImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt subscriptionId) subscribed,
+    required TResult Function(String memberPubkey, BigInt expiresAt) typing,
+    required TResult Function(String memberPubkey, String messageId, BigInt readAt) readReceipt,
+  }) {
+    return readReceipt(memberPubkey, messageId, readAt);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt subscriptionId)? subscribed,
+    TResult? Function(String memberPubkey, BigInt expiresAt)? typing,
+    TResult? Function(String memberPubkey, String messageId, BigInt readAt)? readReceipt,
+  }) {
+    return readReceipt?.call(memberPubkey, messageId, readAt);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt subscriptionId)? subscribed,
+    TResult Function(String memberPubkey, BigInt expiresAt)? typing,
+    TResult Function(String memberPubkey, String messageId, BigInt readAt)? readReceipt,
+    required TResult orElse(),
+  }) {
+    if (readReceipt != null) {
+      return readReceipt(memberPubkey, messageId, readAt);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupPresenceUpdate_Subscribed value) subscribed,
+    required TResult Function(GroupPresenceUpdate_Typing value) typing,
+    required TResult Function(GroupPresenceUpdate_ReadReceipt value) readReceipt,
+  }) {
+    return readReceipt(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupPresenceUpdate_Subscribed value)? subscribed,
+    TResult? Function(GroupPresenceUpdate_Typing value)? typing,
+    TResult? Function(GroupPresenceUpdate_ReadReceipt value)? readReceipt,
+  }) {
+    return readReceipt?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupPresenceUpdate_Subscribed value)? subscribed,
+    TResult Function(GroupPresenceUpdate_Typing value)? typing,
+    TResult Function(GroupPresenceUpdate_ReadReceipt value)? readReceipt,
+    required TResult orElse(),
+  }) {
+    if (readReceipt != null) {
+      return readReceipt(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupPresenceUpdate_ReadReceipt extends GroupPresenceUpdate {
+  const factory GroupPresenceUpdate_ReadReceipt({
+    required final String memberPubkey,
+    required final String messageId,
+    required final BigInt readAt,
+  }) = _$GroupPresenceUpdate_ReadReceiptImpl;
+  const GroupPresenceUpdate_ReadReceipt._() : super._();
+
+  String get memberPubkey;
+  String get messageId;
+  BigInt get readAt;
+
+  /// Create a copy of GroupPresenceUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupPresenceUpdate_ReadReceiptImplCopyWith<_$GroupPresenceUpdate_ReadReceiptImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -5,9 +5,9 @@
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-import '../api.dart';
 import '../frb_generated.dart';
 import 'accounts.dart';
+import 'errors.dart';
 
 /// Creates a RelayType::Nostr variant.
 ///
@@ -48,7 +48,7 @@ Future<RelayType> relayTypeKeyPackage() => RustLib.instance.api.crateApiRelaysRe
 ///
 /// # Returns
 /// * `Ok(Vec<RelayUrl>)` - Vector of relay URLs for the specified type
-/// * `Err(WhitenoiseError)` - If there was an error fetching relays or account not found
+/// * `Err(BridgeError)` - If there was an error fetching relays or account not found
 Future<List<RelayUrl>> fetchRelays({
   required PublicKey pubkey,
   required RelayType relayType,
@@ -69,7 +69,7 @@ Future<List<RelayUrl>> fetchRelays({
 ///
 /// # Returns
 /// * `Ok(())` - If the relays were successfully updated
-/// * `Err(WhitenoiseError)` - If there was an error updating relays
+/// * `Err(BridgeError)` - If there was an error updating relays
 ///
 /// # Notes
 /// * This operation completely replaces the existing relay list for the specified type
@@ -96,7 +96,7 @@ Future<void> updateRelays({
 /// # Returns
 /// * `Ok(Some(Event))` - The key package event if found
 /// * `Ok(None)` - If no key package was found on the relays
-/// * `Err(WhitenoiseError)` - If there was an error fetching the key package
+/// * `Err(BridgeError)` - If there was an error fetching the key package
 ///
 /// # Notes
 /// * This function automatically uses the account's configured key package relays
@@ -117,7 +117,7 @@ Future<Event?> fetchKeyPackage({required PublicKey pubkey}) =>
 ///
 /// # Returns
 /// * `Ok(Vec<(String, String)>)` - Vector of tuples containing each relay URL as a string and its current status as a string
-/// * `Err(WhitenoiseError)` - If there was an error fetching relay statuses or account not found
+/// * `Err(BridgeError)` - If there was an error fetching relay statuses or account not found
 ///
 /// # Notes
 /// * The status reflects the current connection state at the time of the call
@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `spawn_subscription`, `subscriptions`
+
+/// Cancels a running subscription.
+///
+/// Stops the background task that feeds a stream created by one of the `subscribe_*`
+/// functions and releases its resources. The stream on the Flutter side will not
+/// receive any further items after this call.
+///
+/// # Parameters
+/// * `subscription_id` - The handle reported by the stream when it was created
+///
+/// # Returns
+/// * `true` - If a running subscription was found and cancelled
+/// * `false` - If no subscription with this handle exists (already cancelled or finished)
+///
+/// # Example
+/// ```dart
+/// // The first item pushed on the stream carries its handle
+/// final cancelled = await unsubscribe(subscriptionId: subscriptionId);
+/// ```
+Future<bool> unsubscribe({required BigInt subscriptionId}) =>
+    RustLib.instance.api.crateApiSubscriptionsUnsubscribe(
+      subscriptionId: subscriptionId,
+    );
@@ -8,10 +8,12 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import '../api.dart';
 import '../frb_generated.dart';
 import 'accounts.dart';
+import 'errors.dart';
 import 'messages.dart';
 import 'relays.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `image_type_from_bytes`, `same_image_format`, `unix_now`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
 
 /// Converts a core `Metadata` object to a Flutter-compatible `MetadataData` structure.
 ///
@@ -66,7 +68,7 @@ Future<Metadata> convertMetadataDataToMetadata({
 ///
 /// # Returns
 /// * `Ok(PublicKey)` - Successfully parsed public key
-/// * `Err(WhitenoiseError)` - If parsing fails due to invalid format
+/// * `Err(BridgeError)` - If parsing fails due to invalid format
 ///
 /// # Example
 /// ```rust
@@ -91,7 +93,7 @@ Future<PublicKey> publicKeyFromString({required String publicKeyString}) =>
 ///
 /// # Returns
 /// * `Ok(String)` - npub representation (e.g., "npub1...")
-/// * `Err(WhitenoiseError)` - If conversion fails
+/// * `Err(BridgeError)` - If conversion fails
 ///
 /// # Example
 /// ```rust
@@ -111,7 +113,7 @@ Future<String> npubFromPublicKey({required PublicKey publicKey}) =>
 ///
 /// # Returns
 /// * `Ok(String)` - npub representation
-/// * `Err(WhitenoiseError)` - If parsing or conversion fails
+/// * `Err(BridgeError)` - If parsing or conversion fails
 ///
 /// # Example
 /// ```rust
@@ -131,7 +133,7 @@ Future<String> npubFromHexPubkey({required String hexPubkey}) =>
 ///
 /// # Returns
 /// * `Ok(String)` - Hexadecimal representation
-/// * `Err(WhitenoiseError)` - If parsing or conversion fails
+/// * `Err(BridgeError)` - If parsing or conversion fails
 ///
 /// # Example
 /// ```rust
@@ -150,7 +152,7 @@ Future<String> hexPubkeyFromNpub({required String npub}) =>
 ///
 /// # Returns
 /// * `Ok(String)` - Hexadecimal representation
-/// * `Err(WhitenoiseError)` - If conversion fails
+/// * `Err(BridgeError)` - If conversion fails
 ///
 /// # Example
 /// ```rust
@@ -170,7 +172,7 @@ Future<String> hexPubkeyFromPublicKey({required PublicKey publicKey}) =>
 ///
 /// # Returns
 /// * `Ok(RelayUrl)` - Successfully parsed and validated relay URL
-/// * `Err(WhitenoiseError)` - If the URL is invalid or malformed
+/// * `Err(BridgeError)` - If the URL is invalid or malformed
 ///
 /// # Example
 /// ```rust
@@ -256,7 +258,7 @@ Future<WhitenoiseConfigData> convertConfigToData({
 ///
 /// # Returns
 /// * `Ok(Tag)` - Successfully created tag object
-/// * `Err(WhitenoiseError)` - If tag creation fails due to invalid format
+/// * `Err(BridgeError)` - If tag creation fails due to invalid format
 ///
 /// # Example
 /// ```rust
@@ -269,7 +271,9 @@ Future<Tag> tagFromVec({required List<String> vec}) =>
 /// Converts a `WhitenoiseError` to a human-readable string representation.
 ///
 /// This function provides error information that can be displayed in the Flutter UI,
-/// using debug formatting to include detailed error context.
+/// using debug formatting to include detailed error context. Bridge functions return
+/// `BridgeError`, which already carries a code and message; this is only needed for
+/// a raw `WhitenoiseError` value.
 ///
 /// # Parameters
 /// * `error` - Reference to a WhitenoiseError
@@ -372,7 +376,7 @@ Future<ImageType> imageTypeWebp() => RustLib.instance.api.crateApiUtilsImageType
 ///
 /// # Returns
 /// * `Ok(ImageType)` - Appropriate ImageType for the extension
-/// * `Err(WhitenoiseError)` - If the extension is not supported
+/// * `Err(BridgeError)` - If the extension is not supported
 ///
 /// # Example
 /// ```dart
@@ -384,6 +388,28 @@ Future<ImageType> imageTypeFromExtension({required String extension_}) =>
       extension_: extension_,
     );
 
+/// Determines the ImageType of a file from its contents.
+///
+/// Unlike `image_type_from_extension`, this looks at the file's magic bytes, so misnamed
+/// files (e.g. a PNG saved as `photo.jpg`) are detected correctly.
+///
+/// # Parameters
+/// * `file_path` - The local path to the image file
+///
+/// # Returns
+/// * `Ok(ImageType)` - The detected type (`Jpeg` for JPEG files)
+/// * `Err(BridgeError)` - `Unsupported` if the file is not a PNG, JPEG, GIF or WebP image;
+///   the message names the format when it is recognised (e.g. HEIC), or `Storage` if the
+///   file could not be read
+///
+/// # Example
+/// ```dart
+/// final imageType = await detectImageType(filePath: filePath);
+/// await uploadProfilePicture(pubkey, serverUrl, filePath, imageType);
+/// ```
+Future<ImageType> detectImageType({required String filePath}) =>
+    RustLib.instance.api.crateApiUtilsDetectImageType(filePath: filePath);
+
 /// Returns the default Blossom server URL based on build configuration.
 ///
 /// This function provides the appropriate Blossom server URL for image uploads:
@@ -476,6 +502,9 @@ abstract class MetadataData implements RustOpaqueInterface {
   Future<void> setCustom({required Map<String, String> customMap});
 }
 
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WhitenoiseError>>
+abstract class WhitenoiseError implements RustOpaqueInterface {}
+
 /// Flutter-compatible configuration structure that holds directory paths as strings.
 ///
 /// This struct is used to pass configuration data from Flutter to Rust, as flutter_rust_bridge
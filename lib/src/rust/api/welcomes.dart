@@ -5,9 +5,9 @@
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-import '../api.dart';
 import '../frb_generated.dart';
 import 'accounts.dart';
+import 'errors.dart';
 
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
 
@@ -27,7 +27,7 @@ Future<WelcomeData> convertWelcomeToData({required Welcome welcome}) =>
 ///
 /// Returns a `Result` containing:
 /// * `Ok(Vec<WelcomeData>)` - A vector of welcome invitation data if successful
-/// * `Err(WhitenoiseError)` - An error if the operation fails
+/// * `Err(BridgeError)` - An error if the operation fails
 ///
 /// # Errors
 ///
@@ -52,7 +52,7 @@ Future<List<WelcomeData>> fetchWelcomes({required PublicKey pubkey}) =>
 ///
 /// Returns a `Result` containing:
 /// * `Ok(WelcomeData)` - The welcome invitation data if found
-/// * `Err(WhitenoiseError)` - An error if the operation fails
+/// * `Err(BridgeError)` - An error if the operation fails
 ///
 /// # Errors
 ///
@@ -85,7 +85,7 @@ Future<WelcomeData> fetchWelcome({
 ///
 /// Returns a `Result` containing:
 /// * `Ok(())` - Success indicator if the welcome was accepted
-/// * `Err(WhitenoiseError)` - An error if the operation fails
+/// * `Err(BridgeError)` - An error if the operation fails
 ///
 /// # Errors
 ///
@@ -120,7 +120,7 @@ Future<void> acceptWelcome({
 ///
 /// Returns a `Result` containing:
 /// * `Ok(())` - Success indicator if the welcome was declined
-/// * `Err(WhitenoiseError)` - An error if the operation fails
+/// * `Err(BridgeError)` - An error if the operation fails
 ///
 /// # Errors
 ///
@@ -10,10 +10,24 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 import 'api.dart';
 import 'api/accounts.dart';
+import 'api/blossom.dart';
+import 'api/chats.dart';
 import 'api/contacts.dart';
+import 'api/delivery.dart';
+import 'api/errors.dart';
+import 'api/events.dart';
 import 'api/groups.dart';
+import 'api/images.dart';
+import 'api/media.dart';
+import 'api/media_cache.dart';
 import 'api/messages.dart';
+import 'api/metadata_cache.dart';
+import 'api/nip05.dart';
+import 'api/nip19.dart';
+import 'api/outbox.dart';
+import 'api/presence.dart';
 import 'api/relays.dart';
+import 'api/subscriptions.dart';
 import 'api/utils.dart';
 import 'api/welcomes.dart';
 import 'frb_generated.dart';
@@ -74,7 +88,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 1085588867;
+  int get rustContentHash => 1360144240;
 
   static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
     stem: 'rust_lib_whitenoise',
@@ -190,6 +204,17 @@ abstract class RustLibApi extends BaseApi {
     required List<PublicKey> memberPubkeys,
   });
 
+  Future<bool> crateApiOutboxCancelPendingMessage({
+    required PublicKey pubkey,
+    required String outboxId,
+  });
+
+  Future<void> crateApiMediaCacheClearGroupMediaCache({
+    required GroupId groupId,
+  });
+
+  Future<void> crateApiMediaCacheClearMediaCache();
+
   Future<AccountData> crateApiAccountsConvertAccountToData({
     required Account account,
   });
@@ -242,6 +267,52 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiDeleteAllData();
 
+  Future<void> crateApiMessagesDeleteMessage({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String messageId,
+  });
+
+  Future<ImageType> crateApiUtilsDetectImageType({required String filePath});
+
+  Future<void> crateApiImagesDiscardProcessedImage({
+    required ProcessedImageData image,
+  });
+
+  Future<String> crateApiMediaDownloadMedia({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String messageId,
+    required int attachmentIndex,
+  });
+
+  Future<MessageWithTokensData> crateApiMessagesEditMessage({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String messageId,
+    required String newContent,
+  });
+
+  Future<String> crateApiNip19EncodeNevent({
+    required String eventId,
+    required List<String> relays,
+    String? author,
+    int? kind,
+  });
+
+  Future<String> crateApiNip19EncodeNprofile({
+    required String pubkey,
+    required List<String> relays,
+  });
+
+  Future<OutboxMessageData> crateApiOutboxEnqueueMessageToGroup({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String message,
+    required int kind,
+    List<Tag>? tags,
+  });
+
   Future<String> crateApiAccountsExportAccountNpub({required PublicKey pubkey});
 
   Future<String> crateApiAccountsExportAccountNsec({required PublicKey pubkey});
@@ -255,6 +326,26 @@ abstract class RustLibApi extends BaseApi {
     required GroupId groupId,
   });
 
+  Future<MessagePageData> crateApiMessagesFetchAggregatedMessagesPage({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    MessageCursorData? before,
+    MessageCursorData? after,
+    required int limit,
+  });
+
+  Future<BlossomServerListData> crateApiBlossomFetchBlossomServers({
+    required PublicKey pubkey,
+  });
+
+  Future<Map<String, CachedMetadataData>> crateApiMetadataCacheFetchCachedMetadata({
+    required List<String> pubkeys,
+  });
+
+  Future<List<ChatSummaryData>> crateApiChatsFetchChatList({
+    required PublicKey pubkey,
+  });
+
   Future<Map<PublicKey, MetadataData?>> crateApiContactsFetchContacts({
     required PublicKey pubkey,
   });
@@ -275,6 +366,11 @@ abstract class RustLibApi extends BaseApi {
 
   Future<Event?> crateApiRelaysFetchKeyPackage({required PublicKey pubkey});
 
+  Future<DeliveryStatusData?> crateApiDeliveryFetchMessageDeliveryStatus({
+    required PublicKey pubkey,
+    required String messageId,
+  });
+
   Future<List<MessageWithTokensData>> crateApiMessagesFetchMessagesForGroup({
     required PublicKey pubkey,
     required GroupId groupId,
@@ -284,10 +380,24 @@ abstract class RustLibApi extends BaseApi {
     required PublicKey pubkey,
   });
 
+  Future<Map<String, CachedMetadataData>> crateApiMetadataCacheFetchMetadataBatch({
+    required List<String> pubkeys,
+    required bool forceRefresh,
+  });
+
   Future<OnboardingState> crateApiAccountsFetchOnboardingState({
     required PublicKey pubkey,
   });
 
+  Future<List<OutboxMessageData>> crateApiOutboxFetchOutbox({
+    required PublicKey pubkey,
+    GroupId? groupId,
+  });
+
+  Future<PresenceSettingsData> crateApiPresenceFetchPresenceSettings({
+    required PublicKey pubkey,
+  });
+
   Future<List<(String, String)>> crateApiRelaysFetchRelayStatus({
     required PublicKey pubkey,
   });
@@ -297,6 +407,16 @@ abstract class RustLibApi extends BaseApi {
     required RelayType relayType,
   });
 
+  Future<List<ChatMessageData>> crateApiMessagesFetchThread({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String rootMessageId,
+  });
+
+  Future<Map<String, int>> crateApiChatsFetchUnreadCounts({
+    required PublicKey pubkey,
+  });
+
   Future<WelcomeData> crateApiWelcomesFetchWelcome({
     required PublicKey pubkey,
     required String welcomeEventId,
@@ -308,6 +428,11 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiUtilsGetDefaultBlossomServerUrl();
 
+  Future<GroupData> crateApiGroupsGetOrCreateDmGroup({
+    required PublicKey pubkey,
+    required PublicKey otherPubkey,
+  });
+
   Future<GroupId> crateApiGroupsGroupIdFromString({required String hexString});
 
   Future<String> crateApiGroupsGroupIdToString({required GroupId groupId});
@@ -338,10 +463,27 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiAccountsLogout({required PublicKey pubkey});
 
+  Future<void> crateApiChatsMarkGroupRead({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String upToMessageId,
+  });
+
+  Future<MediaCacheStatsData> crateApiMediaCacheMediaCacheStats();
+
   Future<String> crateApiUtilsNpubFromHexPubkey({required String hexPubkey});
 
   Future<String> crateApiUtilsNpubFromPublicKey({required PublicKey publicKey});
 
+  Future<ParsedNostrEntityData> crateApiNip19ParseNostrEntity({
+    required String input,
+  });
+
+  Future<ProcessedImageData> crateApiImagesProcessImage({
+    required String filePath,
+    ImageProcessingOptions? options,
+  });
+
   Future<PublicKey> crateApiUtilsPublicKeyFromString({
     required String publicKeyString,
   });
@@ -350,6 +492,14 @@ abstract class RustLibApi extends BaseApi {
     required PublicKey pubkey,
   });
 
+  Future<MessageWithTokensData> crateApiMessagesReactToMessage({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String messageId,
+    required String emoji,
+    String? customEmojiUrl,
+  });
+
   Future<RelayType> crateApiRelaysRelayTypeInbox();
 
   Future<RelayType> crateApiRelaysRelayTypeKeyPackage();
@@ -369,6 +519,36 @@ abstract class RustLibApi extends BaseApi {
     required List<PublicKey> memberPubkeys,
   });
 
+  Future<void> crateApiMessagesRemoveReaction({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String messageId,
+    required String emoji,
+  });
+
+  Future<MessageWithTokensData> crateApiMessagesReplyToMessage({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String parentMessageId,
+    required String content,
+  });
+
+  Future<Nip05ProfileData?> crateApiNip05ResolveNip05({
+    required String identifier,
+    required bool forceRefresh,
+  });
+
+  Future<OutboxMessageData> crateApiOutboxRetryMessage({
+    required PublicKey pubkey,
+    required String outboxId,
+  });
+
+  Future<List<ContactSearchResultData>> crateApiContactsSearchContacts({
+    required PublicKey pubkey,
+    required String query,
+    required bool includeCachedProfiles,
+  });
+
   Future<void> crateApiMessagesSendDirectMessageNip04({
     required PublicKey sender,
     required PublicKey receiver,
@@ -384,10 +564,46 @@ abstract class RustLibApi extends BaseApi {
     List<Tag>? tags,
   });
 
+  Future<void> crateApiPresenceSendReadReceipt({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String messageId,
+  });
+
+  Future<void> crateApiPresenceSendTypingIndicator({
+    required PublicKey pubkey,
+    required GroupId groupId,
+  });
+
+  Future<MediaCacheStatsData> crateApiMediaCacheSetMediaCacheMaxSize({
+    required BigInt maxSizeBytes,
+  });
+
   Future<String> crateApiUtilsStringFromRelayUrl({required RelayUrl relayUrl});
 
+  Stream<GroupMessageUpdate> crateApiMessagesSubscribeToGroupMessages({
+    required PublicKey pubkey,
+    required GroupId groupId,
+  });
+
+  Stream<GroupPresenceUpdate> crateApiPresenceSubscribeToGroupPresence({
+    required PublicKey pubkey,
+    required GroupId groupId,
+  });
+
+  Stream<WhitenoiseEvent> crateApiSubscribeWhitenoiseEvents();
+
   Future<Tag> crateApiUtilsTagFromVec({required List<String> vec});
 
+  Future<bool> crateApiSubscriptionsUnsubscribe({
+    required BigInt subscriptionId,
+  });
+
+  Future<BlossomServerListData> crateApiBlossomUpdateBlossomServers({
+    required PublicKey pubkey,
+    required BlossomServerListData serverList,
+  });
+
   Future<void> crateApiContactsUpdateContacts({
     required PublicKey pubkey,
     required List<PublicKey> contactPubkeys,
@@ -398,17 +614,32 @@ abstract class RustLibApi extends BaseApi {
     required PublicKey pubkey,
   });
 
+  Future<void> crateApiPresenceUpdatePresenceSettings({
+    required PublicKey pubkey,
+    required PresenceSettingsData settings,
+  });
+
   Future<void> crateApiRelaysUpdateRelays({
     required PublicKey pubkey,
     required RelayType relayType,
     required List<RelayUrl> relays,
   });
 
+  Future<UploadedImageData> crateApiImagesUploadImage({
+    required PublicKey pubkey,
+    required ProcessedImageData image,
+  });
+
   Future<String> crateApiAccountsUploadProfilePicture({
     required PublicKey pubkey,
-    required String serverUrl,
     required String filePath,
     required ImageType imageType,
+    ImageProcessingOptions? options,
+  });
+
+  Future<bool> crateApiNip05VerifyNip05({
+    required PublicKey pubkey,
+    required String identifier,
   });
 
   Future<String> crateApiUtilsWhitenoiseErrorToString({
@@ -1179,8 +1410,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
         constMeta: kCrateApiWelcomesAcceptWelcomeConstMeta,
         argValues: [pubkey, welcomeEventId],
@@ -1220,8 +1450,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
         constMeta: kCrateApiContactsAddContactConstMeta,
         argValues: [pubkey, contactPubkey],
@@ -1266,8 +1495,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
         constMeta: kCrateApiGroupsAddMembersToGroupConstMeta,
         argValues: [pubkey, groupId, memberPubkeys],
@@ -1281,6 +1509,107 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['pubkey', 'groupId', 'memberPubkeys'],
   );
 
+  @override
+  Future<bool> crateApiOutboxCancelPendingMessage({
+    required PublicKey pubkey,
+    required String outboxId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_String(outboxId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiOutboxCancelPendingMessageConstMeta,
+        argValues: [pubkey, outboxId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiOutboxCancelPendingMessageConstMeta => const TaskConstMeta(
+    debugName: 'cancel_pending_message',
+    argNames: ['pubkey', 'outboxId'],
+  );
+
+  @override
+  Future<void> crateApiMediaCacheClearGroupMediaCache({
+    required GroupId groupId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiMediaCacheClearGroupMediaCacheConstMeta,
+        argValues: [groupId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaCacheClearGroupMediaCacheConstMeta => const TaskConstMeta(
+    debugName: 'clear_group_media_cache',
+    argNames: ['groupId'],
+  );
+
+  @override
+  Future<void> crateApiMediaCacheClearMediaCache() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiMediaCacheClearMediaCacheConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaCacheClearMediaCacheConstMeta => const TaskConstMeta(
+    debugName: 'clear_media_cache',
+    argNames: [],
+  );
+
   @override
   Future<AccountData> crateApiAccountsConvertAccountToData({
     required Account account,
@@ -1296,7 +1625,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 27,
             port: port_,
           );
         },
@@ -1331,7 +1660,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 28,
             port: port_,
           );
         },
@@ -1366,7 +1695,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 29,
             port: port_,
           );
         },
@@ -1399,7 +1728,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 30,
             port: port_,
           );
         },
@@ -1434,7 +1763,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 31,
             port: port_,
           );
         },
@@ -1469,7 +1798,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 29,
+            funcId: 32,
             port: port_,
           );
         },
@@ -1505,7 +1834,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 30,
+            funcId: 33,
             port: port_,
           );
         },
@@ -1541,7 +1870,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 31,
+            funcId: 34,
             port: port_,
           );
         },
@@ -1590,14 +1919,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 32,
+            funcId: 35,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_group_data,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
         constMeta: kCrateApiGroupsCreateGroupConstMeta,
         argValues: [
@@ -1632,15 +1960,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 33,
+            funcId: 36,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
         constMeta: kCrateApiAccountsCreateIdentityConstMeta,
         argValues: [],
@@ -1668,7 +1995,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 34,
+            funcId: 37,
             port: port_,
           );
         },
@@ -1706,14 +2033,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 35,
+            funcId: 38,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
         constMeta: kCrateApiWelcomesDeclineWelcomeConstMeta,
         argValues: [pubkey, welcomeEventId],
@@ -1736,14 +2062,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 36,
+            funcId: 39,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
         constMeta: kCrateApiDeleteAllDataConstMeta,
         argValues: [],
@@ -1758,8 +2083,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   );
 
   @override
-  Future<String> crateApiAccountsExportAccountNpub({
+  Future<void> crateApiMessagesDeleteMessage({
     required PublicKey pubkey,
+    required GroupId groupId,
+    required String messageId,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -1769,136 +2096,147 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pubkey,
             serializer,
           );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
+          sse_encode_String(messageId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 37,
+            funcId: 40,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiAccountsExportAccountNpubConstMeta,
-        argValues: [pubkey],
+        constMeta: kCrateApiMessagesDeleteMessageConstMeta,
+        argValues: [pubkey, groupId, messageId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountsExportAccountNpubConstMeta => const TaskConstMeta(
-    debugName: 'export_account_npub',
-    argNames: ['pubkey'],
+  TaskConstMeta get kCrateApiMessagesDeleteMessageConstMeta => const TaskConstMeta(
+    debugName: 'delete_message',
+    argNames: ['pubkey', 'groupId', 'messageId'],
   );
 
   @override
-  Future<String> crateApiAccountsExportAccountNsec({
-    required PublicKey pubkey,
-  }) {
+  Future<ImageType> crateApiUtilsDetectImageType({required String filePath}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            pubkey,
-            serializer,
-          );
+          sse_encode_String(filePath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 38,
+            funcId: 41,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiAccountsExportAccountNsecConstMeta,
-        argValues: [pubkey],
+        constMeta: kCrateApiUtilsDetectImageTypeConstMeta,
+        argValues: [filePath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountsExportAccountNsecConstMeta => const TaskConstMeta(
-    debugName: 'export_account_nsec',
-    argNames: ['pubkey'],
+  TaskConstMeta get kCrateApiUtilsDetectImageTypeConstMeta => const TaskConstMeta(
+    debugName: 'detect_image_type',
+    argNames: ['filePath'],
   );
 
   @override
-  Future<AccountData> crateApiAccountsFetchAccount({
-    required PublicKey pubkey,
+  Future<void> crateApiImagesDiscardProcessedImage({
+    required ProcessedImageData image,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            pubkey,
-            serializer,
-          );
+          sse_encode_processed_image_data(image, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 39,
+            funcId: 42,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_account_data,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiAccountsFetchAccountConstMeta,
-        argValues: [pubkey],
+        constMeta: kCrateApiImagesDiscardProcessedImageConstMeta,
+        argValues: [image],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountsFetchAccountConstMeta => const TaskConstMeta(
-    debugName: 'fetch_account',
-    argNames: ['pubkey'],
+  TaskConstMeta get kCrateApiImagesDiscardProcessedImageConstMeta => const TaskConstMeta(
+    debugName: 'discard_processed_image',
+    argNames: ['image'],
   );
 
   @override
-  Future<List<AccountData>> crateApiAccountsFetchAccounts() {
+  Future<String> crateApiMediaDownloadMedia({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String messageId,
+    required int attachmentIndex,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
+          sse_encode_String(messageId, serializer);
+          sse_encode_u_32(attachmentIndex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 40,
+            funcId: 43,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_account_data,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiAccountsFetchAccountsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMediaDownloadMediaConstMeta,
+        argValues: [pubkey, groupId, messageId, attachmentIndex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountsFetchAccountsConstMeta => const TaskConstMeta(
-    debugName: 'fetch_accounts',
-    argNames: [],
+  TaskConstMeta get kCrateApiMediaDownloadMediaConstMeta => const TaskConstMeta(
+    debugName: 'download_media',
+    argNames: ['pubkey', 'groupId', 'messageId', 'attachmentIndex'],
   );
 
   @override
-  Future<List<ChatMessageData>> crateApiMessagesFetchAggregatedMessagesForGroup({
+  Future<MessageWithTokensData> crateApiMessagesEditMessage({
     required PublicKey pubkey,
     required GroupId groupId,
+    required String messageId,
+    required String newContent,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -1912,114 +2250,110 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             groupId,
             serializer,
           );
+          sse_encode_String(messageId, serializer);
+          sse_encode_String(newContent, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 41,
+            funcId: 44,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_chat_message_data,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_message_with_tokens_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiMessagesFetchAggregatedMessagesForGroupConstMeta,
-        argValues: [pubkey, groupId],
+        constMeta: kCrateApiMessagesEditMessageConstMeta,
+        argValues: [pubkey, groupId, messageId, newContent],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessagesFetchAggregatedMessagesForGroupConstMeta =>
-      const TaskConstMeta(
-        debugName: 'fetch_aggregated_messages_for_group',
-        argNames: ['pubkey', 'groupId'],
-      );
+  TaskConstMeta get kCrateApiMessagesEditMessageConstMeta => const TaskConstMeta(
+    debugName: 'edit_message',
+    argNames: ['pubkey', 'groupId', 'messageId', 'newContent'],
+  );
 
   @override
-  Future<Map<PublicKey, MetadataData?>> crateApiContactsFetchContacts({
-    required PublicKey pubkey,
+  Future<String> crateApiNip19EncodeNevent({
+    required String eventId,
+    required List<String> relays,
+    String? author,
+    int? kind,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            pubkey,
-            serializer,
-          );
+          sse_encode_String(eventId, serializer);
+          sse_encode_list_String(relays, serializer);
+          sse_encode_opt_String(author, serializer);
+          sse_encode_opt_box_autoadd_u_32(kind, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 42,
+            funcId: 45,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Map_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData_None,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiContactsFetchContactsConstMeta,
-        argValues: [pubkey],
+        constMeta: kCrateApiNip19EncodeNeventConstMeta,
+        argValues: [eventId, relays, author, kind],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiContactsFetchContactsConstMeta => const TaskConstMeta(
-    debugName: 'fetch_contacts',
-    argNames: ['pubkey'],
+  TaskConstMeta get kCrateApiNip19EncodeNeventConstMeta => const TaskConstMeta(
+    debugName: 'encode_nevent',
+    argNames: ['eventId', 'relays', 'author', 'kind'],
   );
 
   @override
-  Future<List<PublicKey>> crateApiGroupsFetchGroupAdmins({
-    required PublicKey pubkey,
-    required GroupId groupId,
+  Future<String> crateApiNip19EncodeNprofile({
+    required String pubkey,
+    required List<String> relays,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            pubkey,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
-            groupId,
-            serializer,
-          );
+          sse_encode_String(pubkey, serializer);
+          sse_encode_list_String(relays, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 43,
+            funcId: 46,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiGroupsFetchGroupAdminsConstMeta,
-        argValues: [pubkey, groupId],
+        constMeta: kCrateApiNip19EncodeNprofileConstMeta,
+        argValues: [pubkey, relays],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupsFetchGroupAdminsConstMeta => const TaskConstMeta(
-    debugName: 'fetch_group_admins',
-    argNames: ['pubkey', 'groupId'],
+  TaskConstMeta get kCrateApiNip19EncodeNprofileConstMeta => const TaskConstMeta(
+    debugName: 'encode_nprofile',
+    argNames: ['pubkey', 'relays'],
   );
 
   @override
-  Future<List<PublicKey>> crateApiGroupsFetchGroupMembers({
+  Future<OutboxMessageData> crateApiOutboxEnqueueMessageToGroup({
     required PublicKey pubkey,
     required GroupId groupId,
+    required String message,
+    required int kind,
+    List<Tag>? tags,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -2033,33 +2367,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             groupId,
             serializer,
           );
+          sse_encode_String(message, serializer);
+          sse_encode_u_16(kind, serializer);
+          sse_encode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
+            tags,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 44,
+            funcId: 47,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_outbox_message_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiGroupsFetchGroupMembersConstMeta,
-        argValues: [pubkey, groupId],
+        constMeta: kCrateApiOutboxEnqueueMessageToGroupConstMeta,
+        argValues: [pubkey, groupId, message, kind, tags],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupsFetchGroupMembersConstMeta => const TaskConstMeta(
-    debugName: 'fetch_group_members',
-    argNames: ['pubkey', 'groupId'],
+  TaskConstMeta get kCrateApiOutboxEnqueueMessageToGroupConstMeta => const TaskConstMeta(
+    debugName: 'enqueue_message_to_group',
+    argNames: ['pubkey', 'groupId', 'message', 'kind', 'tags'],
   );
 
   @override
-  Future<List<GroupData>> crateApiGroupsFetchGroups({
+  Future<String> crateApiAccountsExportAccountNpub({
     required PublicKey pubkey,
   }) {
     return handler.executeNormal(
@@ -2073,66 +2411,64 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 45,
+            funcId: 48,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_group_data,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiGroupsFetchGroupsConstMeta,
+        constMeta: kCrateApiAccountsExportAccountNpubConstMeta,
         argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupsFetchGroupsConstMeta => const TaskConstMeta(
-    debugName: 'fetch_groups',
+  TaskConstMeta get kCrateApiAccountsExportAccountNpubConstMeta => const TaskConstMeta(
+    debugName: 'export_account_npub',
     argNames: ['pubkey'],
   );
 
   @override
-  Future<Event?> crateApiRelaysFetchKeyPackage({required PublicKey pubkey}) {
+  Future<String> crateApiAccountsExportAccountNsec({
+    required PublicKey pubkey,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
             pubkey,
             serializer,
           );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 46,
+            funcId: 49,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEvent,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiRelaysFetchKeyPackageConstMeta,
+        constMeta: kCrateApiAccountsExportAccountNsecConstMeta,
         argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelaysFetchKeyPackageConstMeta => const TaskConstMeta(
-    debugName: 'fetch_key_package',
+  TaskConstMeta get kCrateApiAccountsExportAccountNsecConstMeta => const TaskConstMeta(
+    debugName: 'export_account_nsec',
     argNames: ['pubkey'],
   );
 
   @override
-  Future<List<MessageWithTokensData>> crateApiMessagesFetchMessagesForGroup({
+  Future<AccountData> crateApiAccountsFetchAccount({
     required PublicKey pubkey,
-    required GroupId groupId,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -2142,225 +2478,214 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pubkey,
             serializer,
           );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
-            groupId,
-            serializer,
-          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 47,
+            funcId: 50,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_message_with_tokens_data,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_account_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiMessagesFetchMessagesForGroupConstMeta,
-        argValues: [pubkey, groupId],
+        constMeta: kCrateApiAccountsFetchAccountConstMeta,
+        argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessagesFetchMessagesForGroupConstMeta => const TaskConstMeta(
-    debugName: 'fetch_messages_for_group',
-    argNames: ['pubkey', 'groupId'],
+  TaskConstMeta get kCrateApiAccountsFetchAccountConstMeta => const TaskConstMeta(
+    debugName: 'fetch_account',
+    argNames: ['pubkey'],
   );
 
   @override
-  Future<MetadataData?> crateApiAccountsFetchMetadata({
-    required PublicKey pubkey,
-  }) {
+  Future<List<AccountData>> crateApiAccountsFetchAccounts() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            pubkey,
-            serializer,
-          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 48,
+            funcId: 51,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_list_account_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiAccountsFetchMetadataConstMeta,
-        argValues: [pubkey],
+        constMeta: kCrateApiAccountsFetchAccountsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountsFetchMetadataConstMeta => const TaskConstMeta(
-    debugName: 'fetch_metadata',
-    argNames: ['pubkey'],
+  TaskConstMeta get kCrateApiAccountsFetchAccountsConstMeta => const TaskConstMeta(
+    debugName: 'fetch_accounts',
+    argNames: [],
   );
 
   @override
-  Future<OnboardingState> crateApiAccountsFetchOnboardingState({
+  Future<List<ChatMessageData>> crateApiMessagesFetchAggregatedMessagesForGroup({
     required PublicKey pubkey,
+    required GroupId groupId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
             pubkey,
             serializer,
           );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 49,
+            funcId: 52,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_onboarding_state,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_list_chat_message_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiAccountsFetchOnboardingStateConstMeta,
-        argValues: [pubkey],
+        constMeta: kCrateApiMessagesFetchAggregatedMessagesForGroupConstMeta,
+        argValues: [pubkey, groupId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountsFetchOnboardingStateConstMeta => const TaskConstMeta(
-    debugName: 'fetch_onboarding_state',
-    argNames: ['pubkey'],
-  );
+  TaskConstMeta get kCrateApiMessagesFetchAggregatedMessagesForGroupConstMeta =>
+      const TaskConstMeta(
+        debugName: 'fetch_aggregated_messages_for_group',
+        argNames: ['pubkey', 'groupId'],
+      );
 
   @override
-  Future<List<(String, String)>> crateApiRelaysFetchRelayStatus({
+  Future<MessagePageData> crateApiMessagesFetchAggregatedMessagesPage({
     required PublicKey pubkey,
+    required GroupId groupId,
+    MessageCursorData? before,
+    MessageCursorData? after,
+    required int limit,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
             pubkey,
             serializer,
           );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_message_cursor_data(before, serializer);
+          sse_encode_opt_box_autoadd_message_cursor_data(after, serializer);
+          sse_encode_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 50,
+            funcId: 53,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_record_string_string,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_message_page_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiRelaysFetchRelayStatusConstMeta,
-        argValues: [pubkey],
+        constMeta: kCrateApiMessagesFetchAggregatedMessagesPageConstMeta,
+        argValues: [pubkey, groupId, before, after, limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelaysFetchRelayStatusConstMeta => const TaskConstMeta(
-    debugName: 'fetch_relay_status',
-    argNames: ['pubkey'],
+  TaskConstMeta get kCrateApiMessagesFetchAggregatedMessagesPageConstMeta => const TaskConstMeta(
+    debugName: 'fetch_aggregated_messages_page',
+    argNames: ['pubkey', 'groupId', 'before', 'after', 'limit'],
   );
 
   @override
-  Future<List<RelayUrl>> crateApiRelaysFetchRelays({
+  Future<BlossomServerListData> crateApiBlossomFetchBlossomServers({
     required PublicKey pubkey,
-    required RelayType relayType,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
             pubkey,
             serializer,
           );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType(
-            relayType,
-            serializer,
-          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 51,
+            funcId: 54,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayUrl,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_blossom_server_list_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiRelaysFetchRelaysConstMeta,
-        argValues: [pubkey, relayType],
+        constMeta: kCrateApiBlossomFetchBlossomServersConstMeta,
+        argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelaysFetchRelaysConstMeta => const TaskConstMeta(
-    debugName: 'fetch_relays',
-    argNames: ['pubkey', 'relayType'],
+  TaskConstMeta get kCrateApiBlossomFetchBlossomServersConstMeta => const TaskConstMeta(
+    debugName: 'fetch_blossom_servers',
+    argNames: ['pubkey'],
   );
 
   @override
-  Future<WelcomeData> crateApiWelcomesFetchWelcome({
-    required PublicKey pubkey,
-    required String welcomeEventId,
+  Future<Map<String, CachedMetadataData>> crateApiMetadataCacheFetchCachedMetadata({
+    required List<String> pubkeys,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            pubkey,
-            serializer,
-          );
-          sse_encode_String(welcomeEventId, serializer);
+          sse_encode_list_String(pubkeys, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 52,
+            funcId: 55,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_welcome_data,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_Map_String_cached_metadata_data_None,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiWelcomesFetchWelcomeConstMeta,
-        argValues: [pubkey, welcomeEventId],
+        constMeta: kCrateApiMetadataCacheFetchCachedMetadataConstMeta,
+        argValues: [pubkeys],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWelcomesFetchWelcomeConstMeta => const TaskConstMeta(
-    debugName: 'fetch_welcome',
-    argNames: ['pubkey', 'welcomeEventId'],
+  TaskConstMeta get kCrateApiMetadataCacheFetchCachedMetadataConstMeta => const TaskConstMeta(
+    debugName: 'fetch_cached_metadata',
+    argNames: ['pubkeys'],
   );
 
   @override
-  Future<List<WelcomeData>> crateApiWelcomesFetchWelcomes({
+  Future<List<ChatSummaryData>> crateApiChatsFetchChatList({
     required PublicKey pubkey,
   }) {
     return handler.executeNormal(
@@ -2374,614 +2699,697 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 53,
+            funcId: 56,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_welcome_data,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_list_chat_summary_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiWelcomesFetchWelcomesConstMeta,
+        constMeta: kCrateApiChatsFetchChatListConstMeta,
         argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWelcomesFetchWelcomesConstMeta => const TaskConstMeta(
-    debugName: 'fetch_welcomes',
+  TaskConstMeta get kCrateApiChatsFetchChatListConstMeta => const TaskConstMeta(
+    debugName: 'fetch_chat_list',
     argNames: ['pubkey'],
   );
 
   @override
-  Future<String> crateApiUtilsGetDefaultBlossomServerUrl() {
+  Future<Map<PublicKey, MetadataData?>> crateApiContactsFetchContacts({
+    required PublicKey pubkey,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 54,
+            funcId: 57,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Map_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData_None,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiUtilsGetDefaultBlossomServerUrlConstMeta,
-        argValues: [],
+        constMeta: kCrateApiContactsFetchContactsConstMeta,
+        argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsGetDefaultBlossomServerUrlConstMeta => const TaskConstMeta(
-    debugName: 'get_default_blossom_server_url',
-    argNames: [],
+  TaskConstMeta get kCrateApiContactsFetchContactsConstMeta => const TaskConstMeta(
+    debugName: 'fetch_contacts',
+    argNames: ['pubkey'],
   );
 
   @override
-  Future<GroupId> crateApiGroupsGroupIdFromString({required String hexString}) {
+  Future<List<PublicKey>> crateApiGroupsFetchGroupAdmins({
+    required PublicKey pubkey,
+    required GroupId groupId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(hexString, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 55,
+            funcId: 58,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiGroupsGroupIdFromStringConstMeta,
-        argValues: [hexString],
+        constMeta: kCrateApiGroupsFetchGroupAdminsConstMeta,
+        argValues: [pubkey, groupId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupsGroupIdFromStringConstMeta => const TaskConstMeta(
-    debugName: 'group_id_from_string',
-    argNames: ['hexString'],
+  TaskConstMeta get kCrateApiGroupsFetchGroupAdminsConstMeta => const TaskConstMeta(
+    debugName: 'fetch_group_admins',
+    argNames: ['pubkey', 'groupId'],
   );
 
   @override
-  Future<String> crateApiGroupsGroupIdToString({required GroupId groupId}) {
+  Future<List<PublicKey>> crateApiGroupsFetchGroupMembers({
+    required PublicKey pubkey,
+    required GroupId groupId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
             groupId,
             serializer,
           );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 56,
+            funcId: 59,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiGroupsGroupIdToStringConstMeta,
-        argValues: [groupId],
+        constMeta: kCrateApiGroupsFetchGroupMembersConstMeta,
+        argValues: [pubkey, groupId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupsGroupIdToStringConstMeta => const TaskConstMeta(
-    debugName: 'group_id_to_string',
-    argNames: ['groupId'],
+  TaskConstMeta get kCrateApiGroupsFetchGroupMembersConstMeta => const TaskConstMeta(
+    debugName: 'fetch_group_members',
+    argNames: ['pubkey', 'groupId'],
   );
 
   @override
-  Future<String> crateApiUtilsHexPubkeyFromNpub({required String npub}) {
+  Future<List<GroupData>> crateApiGroupsFetchGroups({
+    required PublicKey pubkey,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(npub, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 57,
+            funcId: 60,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_list_group_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiUtilsHexPubkeyFromNpubConstMeta,
-        argValues: [npub],
+        constMeta: kCrateApiGroupsFetchGroupsConstMeta,
+        argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsHexPubkeyFromNpubConstMeta => const TaskConstMeta(
-    debugName: 'hex_pubkey_from_npub',
-    argNames: ['npub'],
+  TaskConstMeta get kCrateApiGroupsFetchGroupsConstMeta => const TaskConstMeta(
+    debugName: 'fetch_groups',
+    argNames: ['pubkey'],
   );
 
   @override
-  Future<String> crateApiUtilsHexPubkeyFromPublicKey({
-    required PublicKey publicKey,
-  }) {
+  Future<Event?> crateApiRelaysFetchKeyPackage({required PublicKey pubkey}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            publicKey,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
             serializer,
           );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 58,
+            funcId: 61,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData:
+              sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEvent,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiUtilsHexPubkeyFromPublicKeyConstMeta,
-        argValues: [publicKey],
+        constMeta: kCrateApiRelaysFetchKeyPackageConstMeta,
+        argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsHexPubkeyFromPublicKeyConstMeta => const TaskConstMeta(
-    debugName: 'hex_pubkey_from_public_key',
-    argNames: ['publicKey'],
+  TaskConstMeta get kCrateApiRelaysFetchKeyPackageConstMeta => const TaskConstMeta(
+    debugName: 'fetch_key_package',
+    argNames: ['pubkey'],
   );
 
   @override
-  Future<ImageType> crateApiUtilsImageTypeFromExtension({
-    required String extension_,
+  Future<DeliveryStatusData?> crateApiDeliveryFetchMessageDeliveryStatus({
+    required PublicKey pubkey,
+    required String messageId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(extension_, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_String(messageId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 59,
+            funcId: 62,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_opt_box_autoadd_delivery_status_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiUtilsImageTypeFromExtensionConstMeta,
-        argValues: [extension_],
+        constMeta: kCrateApiDeliveryFetchMessageDeliveryStatusConstMeta,
+        argValues: [pubkey, messageId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsImageTypeFromExtensionConstMeta => const TaskConstMeta(
-    debugName: 'image_type_from_extension',
-    argNames: ['extension_'],
+  TaskConstMeta get kCrateApiDeliveryFetchMessageDeliveryStatusConstMeta => const TaskConstMeta(
+    debugName: 'fetch_message_delivery_status',
+    argNames: ['pubkey', 'messageId'],
   );
 
   @override
-  Future<ImageType> crateApiUtilsImageTypeGif() {
+  Future<List<MessageWithTokensData>> crateApiMessagesFetchMessagesForGroup({
+    required PublicKey pubkey,
+    required GroupId groupId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 60,
+            funcId: 63,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_message_with_tokens_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiUtilsImageTypeGifConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMessagesFetchMessagesForGroupConstMeta,
+        argValues: [pubkey, groupId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsImageTypeGifConstMeta => const TaskConstMeta(
-    debugName: 'image_type_gif',
-    argNames: [],
+  TaskConstMeta get kCrateApiMessagesFetchMessagesForGroupConstMeta => const TaskConstMeta(
+    debugName: 'fetch_messages_for_group',
+    argNames: ['pubkey', 'groupId'],
   );
 
   @override
-  Future<ImageType> crateApiUtilsImageTypeJpeg() {
+  Future<MetadataData?> crateApiAccountsFetchMetadata({
+    required PublicKey pubkey,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 61,
+            funcId: 64,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType,
-          decodeErrorData: null,
+              sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiUtilsImageTypeJpegConstMeta,
-        argValues: [],
+        constMeta: kCrateApiAccountsFetchMetadataConstMeta,
+        argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsImageTypeJpegConstMeta => const TaskConstMeta(
-    debugName: 'image_type_jpeg',
-    argNames: [],
+  TaskConstMeta get kCrateApiAccountsFetchMetadataConstMeta => const TaskConstMeta(
+    debugName: 'fetch_metadata',
+    argNames: ['pubkey'],
   );
 
   @override
-  Future<ImageType> crateApiUtilsImageTypeJpg() {
+  Future<Map<String, CachedMetadataData>> crateApiMetadataCacheFetchMetadataBatch({
+    required List<String> pubkeys,
+    required bool forceRefresh,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(pubkeys, serializer);
+          sse_encode_bool(forceRefresh, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 62,
+            funcId: 65,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_Map_String_cached_metadata_data_None,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiUtilsImageTypeJpgConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMetadataCacheFetchMetadataBatchConstMeta,
+        argValues: [pubkeys, forceRefresh],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsImageTypeJpgConstMeta => const TaskConstMeta(
-    debugName: 'image_type_jpg',
-    argNames: [],
+  TaskConstMeta get kCrateApiMetadataCacheFetchMetadataBatchConstMeta => const TaskConstMeta(
+    debugName: 'fetch_metadata_batch',
+    argNames: ['pubkeys', 'forceRefresh'],
   );
 
   @override
-  Future<ImageType> crateApiUtilsImageTypePng() {
+  Future<OnboardingState> crateApiAccountsFetchOnboardingState({
+    required PublicKey pubkey,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 63,
+            funcId: 66,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_onboarding_state,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiUtilsImageTypePngConstMeta,
-        argValues: [],
+        constMeta: kCrateApiAccountsFetchOnboardingStateConstMeta,
+        argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsImageTypePngConstMeta => const TaskConstMeta(
-    debugName: 'image_type_png',
-    argNames: [],
+  TaskConstMeta get kCrateApiAccountsFetchOnboardingStateConstMeta => const TaskConstMeta(
+    debugName: 'fetch_onboarding_state',
+    argNames: ['pubkey'],
   );
 
   @override
-  Future<ImageType> crateApiUtilsImageTypeWebp() {
+  Future<List<OutboxMessageData>> crateApiOutboxFetchOutbox({
+    required PublicKey pubkey,
+    GroupId? groupId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 64,
+            funcId: 67,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_outbox_message_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiUtilsImageTypeWebpConstMeta,
-        argValues: [],
+        constMeta: kCrateApiOutboxFetchOutboxConstMeta,
+        argValues: [pubkey, groupId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsImageTypeWebpConstMeta => const TaskConstMeta(
-    debugName: 'image_type_webp',
-    argNames: [],
+  TaskConstMeta get kCrateApiOutboxFetchOutboxConstMeta => const TaskConstMeta(
+    debugName: 'fetch_outbox',
+    argNames: ['pubkey', 'groupId'],
   );
 
   @override
-  Future<void> crateApiInitializeWhitenoise({
-    required WhitenoiseConfig config,
+  Future<PresenceSettingsData> crateApiPresenceFetchPresenceSettings({
+    required PublicKey pubkey,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseConfig(
-            config,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
             serializer,
           );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 65,
+            funcId: 68,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_presence_settings_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiInitializeWhitenoiseConstMeta,
-        argValues: [config],
+        constMeta: kCrateApiPresenceFetchPresenceSettingsConstMeta,
+        argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitializeWhitenoiseConstMeta => const TaskConstMeta(
-    debugName: 'initialize_whitenoise',
-    argNames: ['config'],
+  TaskConstMeta get kCrateApiPresenceFetchPresenceSettingsConstMeta => const TaskConstMeta(
+    debugName: 'fetch_presence_settings',
+    argNames: ['pubkey'],
   );
 
   @override
-  Future<Account> crateApiAccountsLogin({required String nsecOrHexPrivkey}) {
+  Future<List<(String, String)>> crateApiRelaysFetchRelayStatus({
+    required PublicKey pubkey,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(nsecOrHexPrivkey, serializer);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 66,
+            funcId: 69,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_list_record_string_string,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiAccountsLoginConstMeta,
-        argValues: [nsecOrHexPrivkey],
+        constMeta: kCrateApiRelaysFetchRelayStatusConstMeta,
+        argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountsLoginConstMeta => const TaskConstMeta(
-    debugName: 'login',
-    argNames: ['nsecOrHexPrivkey'],
+  TaskConstMeta get kCrateApiRelaysFetchRelayStatusConstMeta => const TaskConstMeta(
+    debugName: 'fetch_relay_status',
+    argNames: ['pubkey'],
   );
 
   @override
-  Future<void> crateApiAccountsLogout({required PublicKey pubkey}) {
+  Future<List<RelayUrl>> crateApiRelaysFetchRelays({
+    required PublicKey pubkey,
+    required RelayType relayType,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
             pubkey,
             serializer,
           );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType(
+            relayType,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 67,
+            funcId: 70,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData:
+              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayUrl,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiAccountsLogoutConstMeta,
-        argValues: [pubkey],
+        constMeta: kCrateApiRelaysFetchRelaysConstMeta,
+        argValues: [pubkey, relayType],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountsLogoutConstMeta => const TaskConstMeta(
-    debugName: 'logout',
-    argNames: ['pubkey'],
+  TaskConstMeta get kCrateApiRelaysFetchRelaysConstMeta => const TaskConstMeta(
+    debugName: 'fetch_relays',
+    argNames: ['pubkey', 'relayType'],
   );
 
   @override
-  Future<String> crateApiUtilsNpubFromHexPubkey({required String hexPubkey}) {
+  Future<List<ChatMessageData>> crateApiMessagesFetchThread({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String rootMessageId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(hexPubkey, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
+          sse_encode_String(rootMessageId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 68,
+            funcId: 71,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_list_chat_message_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiUtilsNpubFromHexPubkeyConstMeta,
-        argValues: [hexPubkey],
+        constMeta: kCrateApiMessagesFetchThreadConstMeta,
+        argValues: [pubkey, groupId, rootMessageId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsNpubFromHexPubkeyConstMeta => const TaskConstMeta(
-    debugName: 'npub_from_hex_pubkey',
-    argNames: ['hexPubkey'],
+  TaskConstMeta get kCrateApiMessagesFetchThreadConstMeta => const TaskConstMeta(
+    debugName: 'fetch_thread',
+    argNames: ['pubkey', 'groupId', 'rootMessageId'],
   );
 
   @override
-  Future<String> crateApiUtilsNpubFromPublicKey({
-    required PublicKey publicKey,
+  Future<Map<String, int>> crateApiChatsFetchUnreadCounts({
+    required PublicKey pubkey,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            publicKey,
+            pubkey,
             serializer,
           );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 69,
+            funcId: 72,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_Map_String_u_32_None,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiUtilsNpubFromPublicKeyConstMeta,
-        argValues: [publicKey],
+        constMeta: kCrateApiChatsFetchUnreadCountsConstMeta,
+        argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsNpubFromPublicKeyConstMeta => const TaskConstMeta(
-    debugName: 'npub_from_public_key',
-    argNames: ['publicKey'],
+  TaskConstMeta get kCrateApiChatsFetchUnreadCountsConstMeta => const TaskConstMeta(
+    debugName: 'fetch_unread_counts',
+    argNames: ['pubkey'],
   );
 
   @override
-  Future<PublicKey> crateApiUtilsPublicKeyFromString({
-    required String publicKeyString,
+  Future<WelcomeData> crateApiWelcomesFetchWelcome({
+    required PublicKey pubkey,
+    required String welcomeEventId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publicKeyString, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_String(welcomeEventId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 70,
+            funcId: 73,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_welcome_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiUtilsPublicKeyFromStringConstMeta,
-        argValues: [publicKeyString],
+        constMeta: kCrateApiWelcomesFetchWelcomeConstMeta,
+        argValues: [pubkey, welcomeEventId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsPublicKeyFromStringConstMeta => const TaskConstMeta(
-    debugName: 'public_key_from_string',
-    argNames: ['publicKeyString'],
+  TaskConstMeta get kCrateApiWelcomesFetchWelcomeConstMeta => const TaskConstMeta(
+    debugName: 'fetch_welcome',
+    argNames: ['pubkey', 'welcomeEventId'],
   );
 
   @override
-  Future<Map<PublicKey, MetadataData?>> crateApiContactsQueryContacts({
+  Future<List<WelcomeData>> crateApiWelcomesFetchWelcomes({
     required PublicKey pubkey,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
             pubkey,
             serializer,
           );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 71,
+            funcId: 74,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Map_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData_None,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_list_welcome_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiContactsQueryContactsConstMeta,
+        constMeta: kCrateApiWelcomesFetchWelcomesConstMeta,
         argValues: [pubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiContactsQueryContactsConstMeta => const TaskConstMeta(
-    debugName: 'query_contacts',
+  TaskConstMeta get kCrateApiWelcomesFetchWelcomesConstMeta => const TaskConstMeta(
+    debugName: 'fetch_welcomes',
     argNames: ['pubkey'],
   );
 
   @override
-  Future<RelayType> crateApiRelaysRelayTypeInbox() {
+  Future<String> crateApiUtilsGetDefaultBlossomServerUrl() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -2989,165 +3397,1009 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 72,
+            funcId: 75,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiRelaysRelayTypeInboxConstMeta,
+        constMeta: kCrateApiUtilsGetDefaultBlossomServerUrlConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelaysRelayTypeInboxConstMeta => const TaskConstMeta(
-    debugName: 'relay_type_inbox',
+  TaskConstMeta get kCrateApiUtilsGetDefaultBlossomServerUrlConstMeta => const TaskConstMeta(
+    debugName: 'get_default_blossom_server_url',
     argNames: [],
   );
 
   @override
-  Future<RelayType> crateApiRelaysRelayTypeKeyPackage() {
+  Future<GroupData> crateApiGroupsGetOrCreateDmGroup({
+    required PublicKey pubkey,
+    required PublicKey otherPubkey,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            otherPubkey,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 73,
+            funcId: 76,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_group_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiRelaysRelayTypeKeyPackageConstMeta,
-        argValues: [],
+        constMeta: kCrateApiGroupsGetOrCreateDmGroupConstMeta,
+        argValues: [pubkey, otherPubkey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelaysRelayTypeKeyPackageConstMeta => const TaskConstMeta(
-    debugName: 'relay_type_key_package',
-    argNames: [],
+  TaskConstMeta get kCrateApiGroupsGetOrCreateDmGroupConstMeta => const TaskConstMeta(
+    debugName: 'get_or_create_dm_group',
+    argNames: ['pubkey', 'otherPubkey'],
   );
 
   @override
-  Future<RelayType> crateApiRelaysRelayTypeNostr() {
+  Future<GroupId> crateApiGroupsGroupIdFromString({required String hexString}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(hexString, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 74,
+            funcId: 77,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType,
-          decodeErrorData: null,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiRelaysRelayTypeNostrConstMeta,
-        argValues: [],
+        constMeta: kCrateApiGroupsGroupIdFromStringConstMeta,
+        argValues: [hexString],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelaysRelayTypeNostrConstMeta => const TaskConstMeta(
-    debugName: 'relay_type_nostr',
-    argNames: [],
+  TaskConstMeta get kCrateApiGroupsGroupIdFromStringConstMeta => const TaskConstMeta(
+    debugName: 'group_id_from_string',
+    argNames: ['hexString'],
   );
 
   @override
-  Future<RelayUrl> crateApiUtilsRelayUrlFromString({required String url}) {
+  Future<String> crateApiGroupsGroupIdToString({required GroupId groupId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 75,
+            funcId: 78,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayUrl,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiUtilsRelayUrlFromStringConstMeta,
-        argValues: [url],
+        constMeta: kCrateApiGroupsGroupIdToStringConstMeta,
+        argValues: [groupId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsRelayUrlFromStringConstMeta => const TaskConstMeta(
-    debugName: 'relay_url_from_string',
-    argNames: ['url'],
+  TaskConstMeta get kCrateApiGroupsGroupIdToStringConstMeta => const TaskConstMeta(
+    debugName: 'group_id_to_string',
+    argNames: ['groupId'],
   );
 
   @override
-  Future<void> crateApiContactsRemoveContact({
-    required PublicKey pubkey,
-    required PublicKey contactPubkey,
-  }) {
+  Future<String> crateApiUtilsHexPubkeyFromNpub({required String npub}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            pubkey,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            contactPubkey,
-            serializer,
-          );
+          sse_encode_String(npub, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 76,
+            funcId: 79,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiUtilsHexPubkeyFromNpubConstMeta,
+        argValues: [npub],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsHexPubkeyFromNpubConstMeta => const TaskConstMeta(
+    debugName: 'hex_pubkey_from_npub',
+    argNames: ['npub'],
+  );
+
+  @override
+  Future<String> crateApiUtilsHexPubkeyFromPublicKey({
+    required PublicKey publicKey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            publicKey,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiUtilsHexPubkeyFromPublicKeyConstMeta,
+        argValues: [publicKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsHexPubkeyFromPublicKeyConstMeta => const TaskConstMeta(
+    debugName: 'hex_pubkey_from_public_key',
+    argNames: ['publicKey'],
+  );
+
+  @override
+  Future<ImageType> crateApiUtilsImageTypeFromExtension({
+    required String extension_,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(extension_, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiUtilsImageTypeFromExtensionConstMeta,
+        argValues: [extension_],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsImageTypeFromExtensionConstMeta => const TaskConstMeta(
+    debugName: 'image_type_from_extension',
+    argNames: ['extension_'],
+  );
+
+  @override
+  Future<ImageType> crateApiUtilsImageTypeGif() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUtilsImageTypeGifConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsImageTypeGifConstMeta => const TaskConstMeta(
+    debugName: 'image_type_gif',
+    argNames: [],
+  );
+
+  @override
+  Future<ImageType> crateApiUtilsImageTypeJpeg() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUtilsImageTypeJpegConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsImageTypeJpegConstMeta => const TaskConstMeta(
+    debugName: 'image_type_jpeg',
+    argNames: [],
+  );
+
+  @override
+  Future<ImageType> crateApiUtilsImageTypeJpg() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUtilsImageTypeJpgConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsImageTypeJpgConstMeta => const TaskConstMeta(
+    debugName: 'image_type_jpg',
+    argNames: [],
+  );
+
+  @override
+  Future<ImageType> crateApiUtilsImageTypePng() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUtilsImageTypePngConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsImageTypePngConstMeta => const TaskConstMeta(
+    debugName: 'image_type_png',
+    argNames: [],
+  );
+
+  @override
+  Future<ImageType> crateApiUtilsImageTypeWebp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUtilsImageTypeWebpConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsImageTypeWebpConstMeta => const TaskConstMeta(
+    debugName: 'image_type_webp',
+    argNames: [],
+  );
+
+  @override
+  Future<void> crateApiInitializeWhitenoise({
+    required WhitenoiseConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseConfig(
+            config,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiInitializeWhitenoiseConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitializeWhitenoiseConstMeta => const TaskConstMeta(
+    debugName: 'initialize_whitenoise',
+    argNames: ['config'],
+  );
+
+  @override
+  Future<Account> crateApiAccountsLogin({required String nsecOrHexPrivkey}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(nsecOrHexPrivkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiAccountsLoginConstMeta,
+        argValues: [nsecOrHexPrivkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAccountsLoginConstMeta => const TaskConstMeta(
+    debugName: 'login',
+    argNames: ['nsecOrHexPrivkey'],
+  );
+
+  @override
+  Future<void> crateApiAccountsLogout({required PublicKey pubkey}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 89,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiAccountsLogoutConstMeta,
+        argValues: [pubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAccountsLogoutConstMeta => const TaskConstMeta(
+    debugName: 'logout',
+    argNames: ['pubkey'],
+  );
+
+  @override
+  Future<void> crateApiChatsMarkGroupRead({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String upToMessageId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
+          sse_encode_String(upToMessageId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiChatsMarkGroupReadConstMeta,
+        argValues: [pubkey, groupId, upToMessageId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatsMarkGroupReadConstMeta => const TaskConstMeta(
+    debugName: 'mark_group_read',
+    argNames: ['pubkey', 'groupId', 'upToMessageId'],
+  );
+
+  @override
+  Future<MediaCacheStatsData> crateApiMediaCacheMediaCacheStats() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_media_cache_stats_data,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiMediaCacheMediaCacheStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaCacheMediaCacheStatsConstMeta => const TaskConstMeta(
+    debugName: 'media_cache_stats',
+    argNames: [],
+  );
+
+  @override
+  Future<String> crateApiUtilsNpubFromHexPubkey({required String hexPubkey}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(hexPubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiUtilsNpubFromHexPubkeyConstMeta,
+        argValues: [hexPubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsNpubFromHexPubkeyConstMeta => const TaskConstMeta(
+    debugName: 'npub_from_hex_pubkey',
+    argNames: ['hexPubkey'],
+  );
+
+  @override
+  Future<String> crateApiUtilsNpubFromPublicKey({
+    required PublicKey publicKey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            publicKey,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiUtilsNpubFromPublicKeyConstMeta,
+        argValues: [publicKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsNpubFromPublicKeyConstMeta => const TaskConstMeta(
+    debugName: 'npub_from_public_key',
+    argNames: ['publicKey'],
+  );
+
+  @override
+  Future<ParsedNostrEntityData> crateApiNip19ParseNostrEntity({
+    required String input,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(input, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_parsed_nostr_entity_data,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiNip19ParseNostrEntityConstMeta,
+        argValues: [input],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNip19ParseNostrEntityConstMeta => const TaskConstMeta(
+    debugName: 'parse_nostr_entity',
+    argNames: ['input'],
+  );
+
+  @override
+  Future<ProcessedImageData> crateApiImagesProcessImage({
+    required String filePath,
+    ImageProcessingOptions? options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          sse_encode_opt_box_autoadd_image_processing_options(
+            options,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_processed_image_data,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiImagesProcessImageConstMeta,
+        argValues: [filePath, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImagesProcessImageConstMeta => const TaskConstMeta(
+    debugName: 'process_image',
+    argNames: ['filePath', 'options'],
+  );
+
+  @override
+  Future<PublicKey> crateApiUtilsPublicKeyFromString({
+    required String publicKeyString,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(publicKeyString, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiUtilsPublicKeyFromStringConstMeta,
+        argValues: [publicKeyString],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsPublicKeyFromStringConstMeta => const TaskConstMeta(
+    debugName: 'public_key_from_string',
+    argNames: ['publicKeyString'],
+  );
+
+  @override
+  Future<Map<PublicKey, MetadataData?>> crateApiContactsQueryContacts({
+    required PublicKey pubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Map_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData_None,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiContactsQueryContactsConstMeta,
+        argValues: [pubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiContactsQueryContactsConstMeta => const TaskConstMeta(
+    debugName: 'query_contacts',
+    argNames: ['pubkey'],
+  );
+
+  @override
+  Future<MessageWithTokensData> crateApiMessagesReactToMessage({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String messageId,
+    required String emoji,
+    String? customEmojiUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
+          sse_encode_String(messageId, serializer);
+          sse_encode_String(emoji, serializer);
+          sse_encode_opt_String(customEmojiUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_message_with_tokens_data,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiMessagesReactToMessageConstMeta,
+        argValues: [pubkey, groupId, messageId, emoji, customEmojiUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessagesReactToMessageConstMeta => const TaskConstMeta(
+    debugName: 'react_to_message',
+    argNames: ['pubkey', 'groupId', 'messageId', 'emoji', 'customEmojiUrl'],
+  );
+
+  @override
+  Future<RelayType> crateApiRelaysRelayTypeInbox() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRelaysRelayTypeInboxConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelaysRelayTypeInboxConstMeta => const TaskConstMeta(
+    debugName: 'relay_type_inbox',
+    argNames: [],
+  );
+
+  @override
+  Future<RelayType> crateApiRelaysRelayTypeKeyPackage() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRelaysRelayTypeKeyPackageConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelaysRelayTypeKeyPackageConstMeta => const TaskConstMeta(
+    debugName: 'relay_type_key_package',
+    argNames: [],
+  );
+
+  @override
+  Future<RelayType> crateApiRelaysRelayTypeNostr() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRelaysRelayTypeNostrConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelaysRelayTypeNostrConstMeta => const TaskConstMeta(
+    debugName: 'relay_type_nostr',
+    argNames: [],
+  );
+
+  @override
+  Future<RelayUrl> crateApiUtilsRelayUrlFromString({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayUrl,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiUtilsRelayUrlFromStringConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsRelayUrlFromStringConstMeta => const TaskConstMeta(
+    debugName: 'relay_url_from_string',
+    argNames: ['url'],
+  );
+
+  @override
+  Future<void> crateApiContactsRemoveContact({
+    required PublicKey pubkey,
+    required PublicKey contactPubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            contactPubkey,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiContactsRemoveContactConstMeta,
+        argValues: [pubkey, contactPubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiContactsRemoveContactConstMeta => const TaskConstMeta(
+    debugName: 'remove_contact',
+    argNames: ['pubkey', 'contactPubkey'],
+  );
+
+  @override
+  Future<void> crateApiGroupsRemoveMembersFromGroup({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required List<PublicKey> memberPubkeys,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
+          sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            memberPubkeys,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiContactsRemoveContactConstMeta,
-        argValues: [pubkey, contactPubkey],
+        constMeta: kCrateApiGroupsRemoveMembersFromGroupConstMeta,
+        argValues: [pubkey, groupId, memberPubkeys],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiContactsRemoveContactConstMeta => const TaskConstMeta(
-    debugName: 'remove_contact',
-    argNames: ['pubkey', 'contactPubkey'],
+  TaskConstMeta get kCrateApiGroupsRemoveMembersFromGroupConstMeta => const TaskConstMeta(
+    debugName: 'remove_members_from_group',
+    argNames: ['pubkey', 'groupId', 'memberPubkeys'],
   );
 
   @override
-  Future<void> crateApiGroupsRemoveMembersFromGroup({
+  Future<void> crateApiMessagesRemoveReaction({
     required PublicKey pubkey,
     required GroupId groupId,
-    required List<PublicKey> memberPubkeys,
+    required String messageId,
+    required String emoji,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -3161,130 +4413,393 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             groupId,
             serializer,
           );
-          sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            memberPubkeys,
+          sse_encode_String(messageId, serializer);
+          sse_encode_String(emoji, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiMessagesRemoveReactionConstMeta,
+        argValues: [pubkey, groupId, messageId, emoji],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessagesRemoveReactionConstMeta => const TaskConstMeta(
+    debugName: 'remove_reaction',
+    argNames: ['pubkey', 'groupId', 'messageId', 'emoji'],
+  );
+
+  @override
+  Future<MessageWithTokensData> crateApiMessagesReplyToMessage({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String parentMessageId,
+    required String content,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
+          sse_encode_String(parentMessageId, serializer);
+          sse_encode_String(content, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_message_with_tokens_data,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiMessagesReplyToMessageConstMeta,
+        argValues: [pubkey, groupId, parentMessageId, content],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessagesReplyToMessageConstMeta => const TaskConstMeta(
+    debugName: 'reply_to_message',
+    argNames: ['pubkey', 'groupId', 'parentMessageId', 'content'],
+  );
+
+  @override
+  Future<Nip05ProfileData?> crateApiNip05ResolveNip05({
+    required String identifier,
+    required bool forceRefresh,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(identifier, serializer);
+          sse_encode_bool(forceRefresh, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_nip05_profile_data,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiNip05ResolveNip05ConstMeta,
+        argValues: [identifier, forceRefresh],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNip05ResolveNip05ConstMeta => const TaskConstMeta(
+    debugName: 'resolve_nip05',
+    argNames: ['identifier', 'forceRefresh'],
+  );
+
+  @override
+  Future<OutboxMessageData> crateApiOutboxRetryMessage({
+    required PublicKey pubkey,
+    required String outboxId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_String(outboxId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_outbox_message_data,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiOutboxRetryMessageConstMeta,
+        argValues: [pubkey, outboxId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiOutboxRetryMessageConstMeta => const TaskConstMeta(
+    debugName: 'retry_message',
+    argNames: ['pubkey', 'outboxId'],
+  );
+
+  @override
+  Future<List<ContactSearchResultData>> crateApiContactsSearchContacts({
+    required PublicKey pubkey,
+    required String query,
+    required bool includeCachedProfiles,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          sse_encode_bool(includeCachedProfiles, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_contact_search_result_data,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiContactsSearchContactsConstMeta,
+        argValues: [pubkey, query, includeCachedProfiles],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiContactsSearchContactsConstMeta => const TaskConstMeta(
+    debugName: 'search_contacts',
+    argNames: ['pubkey', 'query', 'includeCachedProfiles'],
+  );
+
+  @override
+  Future<void> crateApiMessagesSendDirectMessageNip04({
+    required PublicKey sender,
+    required PublicKey receiver,
+    required String content,
+    required List<Tag> tags,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            sender,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            receiver,
+            serializer,
+          );
+          sse_encode_String(content, serializer);
+          sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
+            tags,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiMessagesSendDirectMessageNip04ConstMeta,
+        argValues: [sender, receiver, content, tags],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessagesSendDirectMessageNip04ConstMeta => const TaskConstMeta(
+    debugName: 'send_direct_message_nip04',
+    argNames: ['sender', 'receiver', 'content', 'tags'],
+  );
+
+  @override
+  Future<MessageWithTokensData> crateApiMessagesSendMessageToGroup({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String message,
+    required int kind,
+    List<Tag>? tags,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
+            serializer,
+          );
+          sse_encode_String(message, serializer);
+          sse_encode_u_16(kind, serializer);
+          sse_encode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
+            tags,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_message_with_tokens_data,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiMessagesSendMessageToGroupConstMeta,
+        argValues: [pubkey, groupId, message, kind, tags],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessagesSendMessageToGroupConstMeta => const TaskConstMeta(
+    debugName: 'send_message_to_group',
+    argNames: ['pubkey', 'groupId', 'message', 'kind', 'tags'],
+  );
+
+  @override
+  Future<void> crateApiPresenceSendReadReceipt({
+    required PublicKey pubkey,
+    required GroupId groupId,
+    required String messageId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
             serializer,
           );
+          sse_encode_String(messageId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 77,
+            funcId: 112,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiGroupsRemoveMembersFromGroupConstMeta,
-        argValues: [pubkey, groupId, memberPubkeys],
+        constMeta: kCrateApiPresenceSendReadReceiptConstMeta,
+        argValues: [pubkey, groupId, messageId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupsRemoveMembersFromGroupConstMeta => const TaskConstMeta(
-    debugName: 'remove_members_from_group',
-    argNames: ['pubkey', 'groupId', 'memberPubkeys'],
+  TaskConstMeta get kCrateApiPresenceSendReadReceiptConstMeta => const TaskConstMeta(
+    debugName: 'send_read_receipt',
+    argNames: ['pubkey', 'groupId', 'messageId'],
   );
 
   @override
-  Future<void> crateApiMessagesSendDirectMessageNip04({
-    required PublicKey sender,
-    required PublicKey receiver,
-    required String content,
-    required List<Tag> tags,
+  Future<void> crateApiPresenceSendTypingIndicator({
+    required PublicKey pubkey,
+    required GroupId groupId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            sender,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            receiver,
+            pubkey,
             serializer,
           );
-          sse_encode_String(content, serializer);
-          sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
-            tags,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+            groupId,
             serializer,
           );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 78,
+            funcId: 113,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiMessagesSendDirectMessageNip04ConstMeta,
-        argValues: [sender, receiver, content, tags],
+        constMeta: kCrateApiPresenceSendTypingIndicatorConstMeta,
+        argValues: [pubkey, groupId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessagesSendDirectMessageNip04ConstMeta => const TaskConstMeta(
-    debugName: 'send_direct_message_nip04',
-    argNames: ['sender', 'receiver', 'content', 'tags'],
+  TaskConstMeta get kCrateApiPresenceSendTypingIndicatorConstMeta => const TaskConstMeta(
+    debugName: 'send_typing_indicator',
+    argNames: ['pubkey', 'groupId'],
   );
 
   @override
-  Future<MessageWithTokensData> crateApiMessagesSendMessageToGroup({
-    required PublicKey pubkey,
-    required GroupId groupId,
-    required String message,
-    required int kind,
-    List<Tag>? tags,
+  Future<MediaCacheStatsData> crateApiMediaCacheSetMediaCacheMaxSize({
+    required BigInt maxSizeBytes,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
-            pubkey,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
-            groupId,
-            serializer,
-          );
-          sse_encode_String(message, serializer);
-          sse_encode_u_16(kind, serializer);
-          sse_encode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
-            tags,
-            serializer,
-          );
+          sse_encode_u_64(maxSizeBytes, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 79,
+            funcId: 114,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_message_with_tokens_data,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeSuccessData: sse_decode_media_cache_stats_data,
+          decodeErrorData: sse_decode_bridge_error,
         ),
-        constMeta: kCrateApiMessagesSendMessageToGroupConstMeta,
-        argValues: [pubkey, groupId, message, kind, tags],
+        constMeta: kCrateApiMediaCacheSetMediaCacheMaxSizeConstMeta,
+        argValues: [maxSizeBytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessagesSendMessageToGroupConstMeta => const TaskConstMeta(
-    debugName: 'send_message_to_group',
-    argNames: ['pubkey', 'groupId', 'message', 'kind', 'tags'],
+  TaskConstMeta get kCrateApiMediaCacheSetMediaCacheMaxSizeConstMeta => const TaskConstMeta(
+    debugName: 'set_media_cache_max_size',
+    argNames: ['maxSizeBytes'],
   );
 
   @override
@@ -3300,7 +4815,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 80,
+            funcId: 115,
             port: port_,
           );
         },
@@ -3320,6 +4835,130 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['relayUrl'],
   );
 
+  @override
+  Stream<GroupMessageUpdate> crateApiMessagesSubscribeToGroupMessages({
+    required PublicKey pubkey,
+    required GroupId groupId,
+  }) {
+    final sink = RustStreamSink<GroupMessageUpdate>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_group_message_update_Sse(sink, serializer);
+            sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+              pubkey,
+              serializer,
+            );
+            sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+              groupId,
+              serializer,
+            );
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 116,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_bridge_error,
+          ),
+          constMeta: kCrateApiMessagesSubscribeToGroupMessagesConstMeta,
+          argValues: [sink, pubkey, groupId],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMessagesSubscribeToGroupMessagesConstMeta => const TaskConstMeta(
+    debugName: 'subscribe_to_group_messages',
+    argNames: ['sink', 'pubkey', 'groupId'],
+  );
+
+  @override
+  Stream<GroupPresenceUpdate> crateApiPresenceSubscribeToGroupPresence({
+    required PublicKey pubkey,
+    required GroupId groupId,
+  }) {
+    final sink = RustStreamSink<GroupPresenceUpdate>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_group_presence_update_Sse(sink, serializer);
+            sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+              pubkey,
+              serializer,
+            );
+            sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+              groupId,
+              serializer,
+            );
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 117,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_bridge_error,
+          ),
+          constMeta: kCrateApiPresenceSubscribeToGroupPresenceConstMeta,
+          argValues: [sink, pubkey, groupId],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiPresenceSubscribeToGroupPresenceConstMeta => const TaskConstMeta(
+    debugName: 'subscribe_to_group_presence',
+    argNames: ['sink', 'pubkey', 'groupId'],
+  );
+
+  @override
+  Stream<WhitenoiseEvent> crateApiSubscribeWhitenoiseEvents() {
+    final sink = RustStreamSink<WhitenoiseEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_whitenoise_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 118,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_bridge_error,
+          ),
+          constMeta: kCrateApiSubscribeWhitenoiseEventsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiSubscribeWhitenoiseEventsConstMeta => const TaskConstMeta(
+    debugName: 'subscribe_whitenoise_events',
+    argNames: ['sink'],
+  );
+
   @override
   Future<Tag> crateApiUtilsTagFromVec({required List<String> vec}) {
     return handler.executeNormal(
@@ -3330,15 +4969,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 81,
+            funcId: 119,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
         constMeta: kCrateApiUtilsTagFromVecConstMeta,
         argValues: [vec],
@@ -3352,6 +4990,75 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['vec'],
   );
 
+  @override
+  Future<bool> crateApiSubscriptionsUnsubscribe({
+    required BigInt subscriptionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(subscriptionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 120,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSubscriptionsUnsubscribeConstMeta,
+        argValues: [subscriptionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSubscriptionsUnsubscribeConstMeta => const TaskConstMeta(
+    debugName: 'unsubscribe',
+    argNames: ['subscriptionId'],
+  );
+
+  @override
+  Future<BlossomServerListData> crateApiBlossomUpdateBlossomServers({
+    required PublicKey pubkey,
+    required BlossomServerListData serverList,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_blossom_server_list_data(serverList, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_blossom_server_list_data,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiBlossomUpdateBlossomServersConstMeta,
+        argValues: [pubkey, serverList],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBlossomUpdateBlossomServersConstMeta => const TaskConstMeta(
+    debugName: 'update_blossom_servers',
+    argNames: ['pubkey', 'serverList'],
+  );
+
   @override
   Future<void> crateApiContactsUpdateContacts({
     required PublicKey pubkey,
@@ -3372,14 +5079,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 82,
+            funcId: 122,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
         constMeta: kCrateApiContactsUpdateContactsConstMeta,
         argValues: [pubkey, contactPubkeys],
@@ -3413,14 +5119,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 83,
+            funcId: 123,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
         constMeta: kCrateApiAccountsUpdateMetadataConstMeta,
         argValues: [metadata, pubkey],
@@ -3434,6 +5139,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['metadata', 'pubkey'],
   );
 
+  @override
+  Future<void> crateApiPresenceUpdatePresenceSettings({
+    required PublicKey pubkey,
+    required PresenceSettingsData settings,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_presence_settings_data(settings, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 124,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiPresenceUpdatePresenceSettingsConstMeta,
+        argValues: [pubkey, settings],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPresenceUpdatePresenceSettingsConstMeta => const TaskConstMeta(
+    debugName: 'update_presence_settings',
+    argNames: ['pubkey', 'settings'],
+  );
+
   @override
   Future<void> crateApiRelaysUpdateRelays({
     required PublicKey pubkey,
@@ -3459,14 +5201,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 84,
+            funcId: 125,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
         constMeta: kCrateApiRelaysUpdateRelaysConstMeta,
         argValues: [pubkey, relayType, relays],
@@ -3480,12 +5221,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['pubkey', 'relayType', 'relays'],
   );
 
+  @override
+  Future<UploadedImageData> crateApiImagesUploadImage({
+    required PublicKey pubkey,
+    required ProcessedImageData image,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_processed_image_data(image, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 126,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_uploaded_image_data,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiImagesUploadImageConstMeta,
+        argValues: [pubkey, image],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImagesUploadImageConstMeta => const TaskConstMeta(
+    debugName: 'upload_image',
+    argNames: ['pubkey', 'image'],
+  );
+
   @override
   Future<String> crateApiAccountsUploadProfilePicture({
     required PublicKey pubkey,
-    required String serverUrl,
     required String filePath,
     required ImageType imageType,
+    ImageProcessingOptions? options,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -3495,26 +5273,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pubkey,
             serializer,
           );
-          sse_encode_String(serverUrl, serializer);
           sse_encode_String(filePath, serializer);
           sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType(
             imageType,
             serializer,
           );
+          sse_encode_opt_box_autoadd_image_processing_options(
+            options,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 85,
+            funcId: 127,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError,
+          decodeErrorData: sse_decode_bridge_error,
         ),
         constMeta: kCrateApiAccountsUploadProfilePictureConstMeta,
-        argValues: [pubkey, serverUrl, filePath, imageType],
+        argValues: [pubkey, filePath, imageType, options],
         apiImpl: this,
       ),
     );
@@ -3522,7 +5302,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   TaskConstMeta get kCrateApiAccountsUploadProfilePictureConstMeta => const TaskConstMeta(
     debugName: 'upload_profile_picture',
-    argNames: ['pubkey', 'serverUrl', 'filePath', 'imageType'],
+    argNames: ['pubkey', 'filePath', 'imageType', 'options'],
+  );
+
+  @override
+  Future<bool> crateApiNip05VerifyNip05({
+    required PublicKey pubkey,
+    required String identifier,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
+            pubkey,
+            serializer,
+          );
+          sse_encode_String(identifier, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 128,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_bridge_error,
+        ),
+        constMeta: kCrateApiNip05VerifyNip05ConstMeta,
+        argValues: [pubkey, identifier],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNip05VerifyNip05ConstMeta => const TaskConstMeta(
+    debugName: 'verify_nip05',
+    argNames: ['pubkey', 'identifier'],
   );
 
   @override
@@ -3540,7 +5357,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 86,
+            funcId: 129,
             port: port_,
           );
         },
@@ -3656,6 +5473,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_WhitenoiseError =>
       wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError;
 
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
   @protected
   Account
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount(
@@ -3753,15 +5576,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WhitenoiseConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
-  @protected
-  WhitenoiseError
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return WhitenoiseErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
   @protected
   MetadataData
   dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -3892,7 +5706,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return Map.fromEntries(
-      dco_decode_list_record_string_string(
+      dco_decode_list_record_string_string(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  Map<String, CachedMetadataData> dco_decode_Map_String_cached_metadata_data_None(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_cached_metadata_data(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  Map<String, int> dco_decode_Map_String_u_32_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_u_32(
         raw,
       ).map((e) => MapEntry(e.$1, e.$2)),
     );
@@ -4030,6 +5866,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WhitenoiseErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  RustStreamSink<GroupMessageUpdate> dco_decode_StreamSink_group_message_update_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<GroupPresenceUpdate> dco_decode_StreamSink_group_presence_update_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<WhitenoiseEvent> dco_decode_StreamSink_whitenoise_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   String dco_decode_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4061,6 +5921,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  BlossomServerListData dco_decode_blossom_server_list_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BlossomServerListData(
+      servers: dco_decode_list_String(arr[0]),
+      mirrorUploads: dco_decode_bool(arr[1]),
+    );
+  }
+
   @protected
   bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4078,6 +5949,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  GroupId
+  dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+      raw,
+    );
+  }
+
   @protected
   MetadataData
   dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -4089,17 +5971,83 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  DeliveryStatusData dco_decode_box_autoadd_delivery_status_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_delivery_status_data(raw);
+  }
+
+  @protected
+  ImageProcessingOptions dco_decode_box_autoadd_image_processing_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_image_processing_options(raw);
+  }
+
+  @protected
+  MessageCursorData dco_decode_box_autoadd_message_cursor_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_message_cursor_data(raw);
+  }
+
+  @protected
+  Nip05ProfileData dco_decode_box_autoadd_nip05_profile_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_nip05_profile_data(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_32(raw);
+  }
+
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_u_64(raw);
   }
 
+  @protected
+  BridgeError dco_decode_bridge_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BridgeError(
+      code: dco_decode_bridge_error_code(arr[0]),
+      message: dco_decode_String(arr[1]),
+      details: dco_decode_opt_String(arr[2]),
+      outboxId: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  BridgeErrorCode dco_decode_bridge_error_code(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BridgeErrorCode.values[raw as int];
+  }
+
+  @protected
+  CachedMetadataData dco_decode_cached_metadata_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CachedMetadataData(
+      pubkey: dco_decode_String(arr[0]),
+      metadata: dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+        arr[1],
+      ),
+      fetchedAt: dco_decode_u_64(arr[2]),
+      isStale: dco_decode_bool(arr[3]),
+    );
+  }
+
   @protected
   ChatMessageData dco_decode_chat_message_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
     return ChatMessageData(
       id: dco_decode_String(arr[0]),
       pubkey: dco_decode_String(arr[1]),
@@ -4112,6 +6060,67 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       contentTokens: dco_decode_list_serializable_token_data(arr[8]),
       reactions: dco_decode_reaction_summary_data(arr[9]),
       kind: dco_decode_u_16(arr[10]),
+      isEdited: dco_decode_bool(arr[11]),
+      editHistory: dco_decode_list_message_version_data(arr[12]),
+      attachments: dco_decode_list_media_attachment_data(arr[13]),
+    );
+  }
+
+  @protected
+  ChatSummaryData dco_decode_chat_summary_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return ChatSummaryData(
+      group: dco_decode_group_data(arr[0]),
+      lastMessageId: dco_decode_opt_String(arr[1]),
+      lastMessagePreview: dco_decode_opt_String(arr[2]),
+      lastMessageAuthor: dco_decode_opt_String(arr[3]),
+      lastActivityAt: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      unreadCount: dco_decode_u_32(arr[5]),
+      memberCount: dco_decode_u_32(arr[6]),
+      dmCounterpartPubkey: dco_decode_opt_String(arr[7]),
+      dmCounterpartMetadata: dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+        arr[8],
+      ),
+    );
+  }
+
+  @protected
+  ContactSearchResultData dco_decode_contact_search_result_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ContactSearchResultData(
+      pubkey: dco_decode_String(arr[0]),
+      npub: dco_decode_String(arr[1]),
+      metadata: dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+        arr[2],
+      ),
+      isContact: dco_decode_bool(arr[3]),
+      score: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  DeliveryState dco_decode_delivery_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DeliveryState.values[raw as int];
+  }
+
+  @protected
+  DeliveryStatusData dco_decode_delivery_status_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return DeliveryStatusData(
+      outboxId: dco_decode_String(arr[0]),
+      messageId: dco_decode_opt_String(arr[1]),
+      groupId: dco_decode_String(arr[2]),
+      state: dco_decode_delivery_state(arr[3]),
+      attempts: dco_decode_u_32(arr[4]),
+      error: dco_decode_opt_String(arr[5]),
+      updatedAt: dco_decode_u_64(arr[6]),
     );
   }
 
@@ -4131,21 +6140,80 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   GroupData dco_decode_group_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
     return GroupData(
       mlsGroupId: dco_decode_String(arr[0]),
       nostrGroupId: dco_decode_String(arr[1]),
       name: dco_decode_String(arr[2]),
       description: dco_decode_String(arr[3]),
-      adminPubkeys: dco_decode_list_String(arr[4]),
-      lastMessageId: dco_decode_opt_String(arr[5]),
-      lastMessageAt: dco_decode_opt_box_autoadd_u_64(arr[6]),
-      groupType: dco_decode_group_type(arr[7]),
-      epoch: dco_decode_u_64(arr[8]),
-      state: dco_decode_group_state(arr[9]),
+      imageUrl: dco_decode_opt_String(arr[4]),
+      adminPubkeys: dco_decode_list_String(arr[5]),
+      lastMessageId: dco_decode_opt_String(arr[6]),
+      lastMessageAt: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      groupType: dco_decode_group_type(arr[8]),
+      epoch: dco_decode_u_64(arr[9]),
+      state: dco_decode_group_state(arr[10]),
     );
   }
 
+  @protected
+  GroupMessageUpdate dco_decode_group_message_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return GroupMessageUpdate_Subscribed(
+          subscriptionId: dco_decode_u_64(raw[1]),
+        );
+      case 1:
+        return GroupMessageUpdate_NewMessage(
+          message: dco_decode_chat_message_data(raw[1]),
+        );
+      case 2:
+        return GroupMessageUpdate_MessageEdited(
+          message: dco_decode_chat_message_data(raw[1]),
+        );
+      case 3:
+        return GroupMessageUpdate_MessageDeleted(
+          messageId: dco_decode_String(raw[1]),
+        );
+      case 4:
+        return GroupMessageUpdate_MessageRemoved(
+          messageId: dco_decode_String(raw[1]),
+        );
+      case 5:
+        return GroupMessageUpdate_ReactionsUpdated(
+          messageId: dco_decode_String(raw[1]),
+          reactions: dco_decode_reaction_summary_data(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  GroupPresenceUpdate dco_decode_group_presence_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return GroupPresenceUpdate_Subscribed(
+          subscriptionId: dco_decode_u_64(raw[1]),
+        );
+      case 1:
+        return GroupPresenceUpdate_Typing(
+          memberPubkey: dco_decode_String(raw[1]),
+          expiresAt: dco_decode_u_64(raw[2]),
+        );
+      case 2:
+        return GroupPresenceUpdate_ReadReceipt(
+          memberPubkey: dco_decode_String(raw[1]),
+          messageId: dco_decode_String(raw[2]),
+          readAt: dco_decode_u_64(raw[3]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   @protected
   GroupState dco_decode_group_state(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4164,6 +6232,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as int;
   }
 
+  @protected
+  ImageProcessingOptions dco_decode_image_processing_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ImageProcessingOptions(
+      maxDimension: dco_decode_u_32(arr[0]),
+      convertToWebp: dco_decode_bool(arr[1]),
+      quality: dco_decode_u_8(arr[2]),
+      thumbnailDimension: dco_decode_u_32(arr[3]),
+    );
+  }
+
   @protected
   List<PublicKey>
   dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
@@ -4221,6 +6302,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_chat_message_data).toList();
   }
 
+  @protected
+  List<ChatSummaryData> dco_decode_list_chat_summary_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chat_summary_data).toList();
+  }
+
+  @protected
+  List<ContactSearchResultData> dco_decode_list_contact_search_result_data(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_contact_search_result_data).toList();
+  }
+
   @protected
   List<EmojiReactionData> dco_decode_list_emoji_reaction_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4233,6 +6328,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_group_data).toList();
   }
 
+  @protected
+  List<List<String>> dco_decode_list_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_list_String).toList();
+  }
+
+  @protected
+  List<MediaAttachmentData> dco_decode_list_media_attachment_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_media_attachment_data).toList();
+  }
+
+  @protected
+  List<MessageVersionData> dco_decode_list_message_version_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_message_version_data).toList();
+  }
+
   @protected
   List<MessageWithTokensData> dco_decode_list_message_with_tokens_data(
     dynamic raw,
@@ -4241,6 +6354,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_message_with_tokens_data).toList();
   }
 
+  @protected
+  List<OutboxMessageData> dco_decode_list_outbox_message_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_outbox_message_data).toList();
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4260,12 +6379,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         .toList();
   }
 
+  @protected
+  List<(String, CachedMetadataData)> dco_decode_list_record_string_cached_metadata_data(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_cached_metadata_data).toList();
+  }
+
   @protected
   List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
   }
 
+  @protected
+  List<(String, int)> dco_decode_list_record_string_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_u_32).toList();
+  }
+
   @protected
   List<SerializableTokenData> dco_decode_list_serializable_token_data(
     dynamic raw,
@@ -4286,6 +6419,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_welcome_data).toList();
   }
 
+  @protected
+  MediaAttachmentData dco_decode_media_attachment_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return MediaAttachmentData(
+      url: dco_decode_String(arr[0]),
+      mimeType: dco_decode_String(arr[1]),
+      sha256: dco_decode_String(arr[2]),
+      size: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      dimensions: dco_decode_opt_String(arr[4]),
+      blurhash: dco_decode_opt_String(arr[5]),
+      isEncrypted: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  MediaCacheStatsData dco_decode_media_cache_stats_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MediaCacheStatsData(
+      fileCount: dco_decode_u_32(arr[0]),
+      totalSizeBytes: dco_decode_u_64(arr[1]),
+      maxSizeBytes: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  MessageCursorData dco_decode_message_cursor_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MessageCursorData(
+      createdAt: dco_decode_u_64(arr[0]),
+      messageId: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  MessagePageData dco_decode_message_page_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MessagePageData(
+      messages: dco_decode_list_chat_message_data(arr[0]),
+      nextCursor: dco_decode_opt_box_autoadd_message_cursor_data(arr[1]),
+      hasMore: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  MessageVersionData dco_decode_message_version_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MessageVersionData(
+      content: dco_decode_String(arr[0]),
+      createdAt: dco_decode_u_64(arr[1]),
+    );
+  }
+
   @protected
   MessageWithTokensData dco_decode_message_with_tokens_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4301,6 +6496,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  Nip05ProfileData dco_decode_nip05_profile_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Nip05ProfileData(
+      identifier: dco_decode_String(arr[0]),
+      pubkey: dco_decode_String(arr[1]),
+      relays: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  NostrEntity dco_decode_nostr_entity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return NostrEntity_Pubkey(
+          pubkey: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return NostrEntity_Profile(
+          pubkey: dco_decode_String(raw[1]),
+          relays: dco_decode_list_String(raw[2]),
+        );
+      case 2:
+        return NostrEntity_Note(
+          eventId: dco_decode_String(raw[1]),
+        );
+      case 3:
+        return NostrEntity_Event(
+          eventId: dco_decode_String(raw[1]),
+          relays: dco_decode_list_String(raw[2]),
+          author: dco_decode_opt_String(raw[3]),
+          kind: dco_decode_opt_box_autoadd_u_32(raw[4]),
+        );
+      case 4:
+        return NostrEntity_Address(
+          identifier: dco_decode_String(raw[1]),
+          pubkey: dco_decode_String(raw[2]),
+          kind: dco_decode_u_32(raw[3]),
+          relays: dco_decode_list_String(raw[4]),
+        );
+      case 5:
+        return NostrEntity_SecretKey(
+          secretKey: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   @protected
   OnboardingState dco_decode_onboarding_state(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4332,6 +6579,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         );
   }
 
+  @protected
+  GroupId?
+  dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+          raw,
+        );
+  }
+
   @protected
   MetadataData?
   dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -4345,6 +6605,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         );
   }
 
+  @protected
+  DeliveryStatusData? dco_decode_opt_box_autoadd_delivery_status_data(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_delivery_status_data(raw);
+  }
+
+  @protected
+  ImageProcessingOptions? dco_decode_opt_box_autoadd_image_processing_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_image_processing_options(raw);
+  }
+
+  @protected
+  MessageCursorData? dco_decode_opt_box_autoadd_message_cursor_data(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_message_cursor_data(raw);
+  }
+
+  @protected
+  Nip05ProfileData? dco_decode_opt_box_autoadd_nip05_profile_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_nip05_profile_data(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4364,6 +6660,73 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         );
   }
 
+  @protected
+  OutboxMessageData dco_decode_outbox_message_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return OutboxMessageData(
+      id: dco_decode_String(arr[0]),
+      groupId: dco_decode_String(arr[1]),
+      content: dco_decode_String(arr[2]),
+      kind: dco_decode_u_16(arr[3]),
+      tags: dco_decode_list_list_String(arr[4]),
+      createdAt: dco_decode_u_64(arr[5]),
+      state: dco_decode_outbox_state(arr[6]),
+      attempts: dco_decode_u_32(arr[7]),
+      nextAttemptAt: dco_decode_u_64(arr[8]),
+      lastError: dco_decode_opt_String(arr[9]),
+    );
+  }
+
+  @protected
+  OutboxState dco_decode_outbox_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return OutboxState.values[raw as int];
+  }
+
+  @protected
+  ParsedNostrEntityData dco_decode_parsed_nostr_entity_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ParsedNostrEntityData(
+      entity: dco_decode_nostr_entity(arr[0]),
+      isSensitive: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  PresenceSettingsData dco_decode_presence_settings_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PresenceSettingsData(
+      disableReadReceipts: dco_decode_bool(arr[0]),
+      disableTypingIndicators: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  ProcessedImageData dco_decode_processed_image_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return ProcessedImageData(
+      filePath: dco_decode_String(arr[0]),
+      imageType: dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType(
+        arr[1],
+      ),
+      mimeType: dco_decode_String(arr[2]),
+      width: dco_decode_u_32(arr[3]),
+      height: dco_decode_u_32(arr[4]),
+      size: dco_decode_u_64(arr[5]),
+      sha256: dco_decode_String(arr[6]),
+      blurhash: dco_decode_String(arr[7]),
+      thumbnailPath: dco_decode_opt_String(arr[8]),
+    );
+  }
+
   @protected
   ReactionSummaryData dco_decode_reaction_summary_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4395,6 +6758,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  (String, CachedMetadataData) dco_decode_record_string_cached_metadata_data(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_String(arr[0]),
+      dco_decode_cached_metadata_data(arr[1]),
+    );
+  }
+
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4408,6 +6786,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  (String, int) dco_decode_record_string_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_String(arr[0]),
+      dco_decode_u_32(arr[1]),
+    );
+  }
+
   @protected
   SerializableTokenData dco_decode_serializable_token_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4449,6 +6840,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return;
   }
 
+  @protected
+  UploadedImageData dco_decode_uploaded_image_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UploadedImageData(
+      url: dco_decode_String(arr[0]),
+      mirrorUrls: dco_decode_list_String(arr[1]),
+      thumbnailUrl: dco_decode_opt_String(arr[2]),
+    );
+  }
+
   @protected
   UserReactionData dco_decode_user_reaction_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4504,6 +6907,95 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  WhitenoiseEvent dco_decode_whitenoise_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return WhitenoiseEvent_Subscribed(
+          subscriptionId: dco_decode_u_64(raw[1]),
+        );
+      case 1:
+        return WhitenoiseEvent_WelcomeReceived(
+          accountPubkey: dco_decode_String(raw[1]),
+          welcome: dco_decode_welcome_data(raw[2]),
+        );
+      case 2:
+        return WhitenoiseEvent_GroupCreated(
+          accountPubkey: dco_decode_String(raw[1]),
+          group: dco_decode_group_data(raw[2]),
+        );
+      case 3:
+        return WhitenoiseEvent_GroupUpdated(
+          accountPubkey: dco_decode_String(raw[1]),
+          group: dco_decode_group_data(raw[2]),
+        );
+      case 4:
+        return WhitenoiseEvent_GroupStateChanged(
+          accountPubkey: dco_decode_String(raw[1]),
+          group: dco_decode_group_data(raw[2]),
+        );
+      case 5:
+        return WhitenoiseEvent_MemberAdded(
+          accountPubkey: dco_decode_String(raw[1]),
+          groupId: dco_decode_String(raw[2]),
+          memberPubkey: dco_decode_String(raw[3]),
+        );
+      case 6:
+        return WhitenoiseEvent_MemberRemoved(
+          accountPubkey: dco_decode_String(raw[1]),
+          groupId: dco_decode_String(raw[2]),
+          memberPubkey: dco_decode_String(raw[3]),
+        );
+      case 7:
+        return WhitenoiseEvent_RelayStatusChanged(
+          accountPubkey: dco_decode_String(raw[1]),
+          relayUrl: dco_decode_String(raw[2]),
+          status: dco_decode_String(raw[3]),
+        );
+      case 8:
+        return WhitenoiseEvent_MetadataUpdated(
+          pubkey: dco_decode_String(raw[1]),
+          metadata: dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+            raw[2],
+          ),
+        );
+      case 9:
+        return WhitenoiseEvent_AccountLoggedIn(
+          account: dco_decode_account_data(raw[1]),
+        );
+      case 10:
+        return WhitenoiseEvent_AccountLoggedOut(
+          pubkey: dco_decode_String(raw[1]),
+        );
+      case 11:
+        return WhitenoiseEvent_MessageDeliveryStatusChanged(
+          accountPubkey: dco_decode_String(raw[1]),
+          status: dco_decode_delivery_status_data(raw[2]),
+        );
+      case 12:
+        return WhitenoiseEvent_OutboxMessageUpdated(
+          accountPubkey: dco_decode_String(raw[1]),
+          message: dco_decode_outbox_message_data(raw[2]),
+        );
+      case 13:
+        return WhitenoiseEvent_OutboxMessageSent(
+          accountPubkey: dco_decode_String(raw[1]),
+          outboxId: dco_decode_String(raw[2]),
+          message: dco_decode_message_with_tokens_data(raw[3]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
   @protected
   Account
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount(
@@ -4634,18 +7126,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
-  @protected
-  WhitenoiseError
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return WhitenoiseErrorImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
   @protected
   MetadataData
   sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -4820,6 +7300,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
   }
 
+  @protected
+  Map<String, CachedMetadataData> sse_decode_Map_String_cached_metadata_data_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_list_record_string_cached_metadata_data(
+      deserializer,
+    );
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Map<String, int> sse_decode_Map_String_u_32_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_list_record_string_u_32(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
   @protected
   Account sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount(
     SseDeserializer deserializer,
@@ -5000,6 +7500,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RustStreamSink<GroupMessageUpdate> sse_decode_StreamSink_group_message_update_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<GroupPresenceUpdate> sse_decode_StreamSink_group_presence_update_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<WhitenoiseEvent> sse_decode_StreamSink_whitenoise_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   String sse_decode_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5035,6 +7559,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  BlossomServerListData sse_decode_blossom_server_list_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_servers = sse_decode_list_String(deserializer);
+    final var_mirrorUploads = sse_decode_bool(deserializer);
+    return BlossomServerListData(
+      servers: var_servers,
+      mirrorUploads: var_mirrorUploads,
+    );
+  }
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5052,6 +7589,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     ));
   }
 
+  @protected
+  GroupId
+  sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+      deserializer,
+    ));
+  }
+
   @protected
   MetadataData
   sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -5063,12 +7611,92 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     ));
   }
 
+  @protected
+  DeliveryStatusData sse_decode_box_autoadd_delivery_status_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_delivery_status_data(deserializer));
+  }
+
+  @protected
+  ImageProcessingOptions sse_decode_box_autoadd_image_processing_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_image_processing_options(deserializer));
+  }
+
+  @protected
+  MessageCursorData sse_decode_box_autoadd_message_cursor_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_message_cursor_data(deserializer));
+  }
+
+  @protected
+  Nip05ProfileData sse_decode_box_autoadd_nip05_profile_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_nip05_profile_data(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_u_64(deserializer));
   }
 
+  @protected
+  BridgeError sse_decode_bridge_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_code = sse_decode_bridge_error_code(deserializer);
+    final var_message = sse_decode_String(deserializer);
+    final var_details = sse_decode_opt_String(deserializer);
+    final var_outboxId = sse_decode_opt_String(deserializer);
+    return BridgeError(
+      code: var_code,
+      message: var_message,
+      details: var_details,
+      outboxId: var_outboxId,
+    );
+  }
+
+  @protected
+  BridgeErrorCode sse_decode_bridge_error_code(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return BridgeErrorCode.values[inner];
+  }
+
+  @protected
+  CachedMetadataData sse_decode_cached_metadata_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_pubkey = sse_decode_String(deserializer);
+    final var_metadata =
+        sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+          deserializer,
+        );
+    final var_fetchedAt = sse_decode_u_64(deserializer);
+    final var_isStale = sse_decode_bool(deserializer);
+    return CachedMetadataData(
+      pubkey: var_pubkey,
+      metadata: var_metadata,
+      fetchedAt: var_fetchedAt,
+      isStale: var_isStale,
+    );
+  }
+
   @protected
   ChatMessageData sse_decode_chat_message_data(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5085,6 +7713,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
     final var_reactions = sse_decode_reaction_summary_data(deserializer);
     final var_kind = sse_decode_u_16(deserializer);
+    final var_isEdited = sse_decode_bool(deserializer);
+    final var_editHistory = sse_decode_list_message_version_data(deserializer);
+    final var_attachments = sse_decode_list_media_attachment_data(deserializer);
     return ChatMessageData(
       id: var_id,
       pubkey: var_pubkey,
@@ -5097,6 +7728,89 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       contentTokens: var_contentTokens,
       reactions: var_reactions,
       kind: var_kind,
+      isEdited: var_isEdited,
+      editHistory: var_editHistory,
+      attachments: var_attachments,
+    );
+  }
+
+  @protected
+  ChatSummaryData sse_decode_chat_summary_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_group = sse_decode_group_data(deserializer);
+    final var_lastMessageId = sse_decode_opt_String(deserializer);
+    final var_lastMessagePreview = sse_decode_opt_String(deserializer);
+    final var_lastMessageAuthor = sse_decode_opt_String(deserializer);
+    final var_lastActivityAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+    final var_unreadCount = sse_decode_u_32(deserializer);
+    final var_memberCount = sse_decode_u_32(deserializer);
+    final var_dmCounterpartPubkey = sse_decode_opt_String(deserializer);
+    final var_dmCounterpartMetadata =
+        sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+          deserializer,
+        );
+    return ChatSummaryData(
+      group: var_group,
+      lastMessageId: var_lastMessageId,
+      lastMessagePreview: var_lastMessagePreview,
+      lastMessageAuthor: var_lastMessageAuthor,
+      lastActivityAt: var_lastActivityAt,
+      unreadCount: var_unreadCount,
+      memberCount: var_memberCount,
+      dmCounterpartPubkey: var_dmCounterpartPubkey,
+      dmCounterpartMetadata: var_dmCounterpartMetadata,
+    );
+  }
+
+  @protected
+  ContactSearchResultData sse_decode_contact_search_result_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_pubkey = sse_decode_String(deserializer);
+    final var_npub = sse_decode_String(deserializer);
+    final var_metadata =
+        sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+          deserializer,
+        );
+    final var_isContact = sse_decode_bool(deserializer);
+    final var_score = sse_decode_u_32(deserializer);
+    return ContactSearchResultData(
+      pubkey: var_pubkey,
+      npub: var_npub,
+      metadata: var_metadata,
+      isContact: var_isContact,
+      score: var_score,
+    );
+  }
+
+  @protected
+  DeliveryState sse_decode_delivery_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return DeliveryState.values[inner];
+  }
+
+  @protected
+  DeliveryStatusData sse_decode_delivery_status_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_outboxId = sse_decode_String(deserializer);
+    final var_messageId = sse_decode_opt_String(deserializer);
+    final var_groupId = sse_decode_String(deserializer);
+    final var_state = sse_decode_delivery_state(deserializer);
+    final var_attempts = sse_decode_u_32(deserializer);
+    final var_error = sse_decode_opt_String(deserializer);
+    final var_updatedAt = sse_decode_u_64(deserializer);
+    return DeliveryStatusData(
+      outboxId: var_outboxId,
+      messageId: var_messageId,
+      groupId: var_groupId,
+      state: var_state,
+      attempts: var_attempts,
+      error: var_error,
+      updatedAt: var_updatedAt,
     );
   }
 
@@ -5122,6 +7836,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     final var_nostrGroupId = sse_decode_String(deserializer);
     final var_name = sse_decode_String(deserializer);
     final var_description = sse_decode_String(deserializer);
+    final var_imageUrl = sse_decode_opt_String(deserializer);
     final var_adminPubkeys = sse_decode_list_String(deserializer);
     final var_lastMessageId = sse_decode_opt_String(deserializer);
     final var_lastMessageAt = sse_decode_opt_box_autoadd_u_64(deserializer);
@@ -5133,6 +7848,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       nostrGroupId: var_nostrGroupId,
       name: var_name,
       description: var_description,
+      imageUrl: var_imageUrl,
       adminPubkeys: var_adminPubkeys,
       lastMessageId: var_lastMessageId,
       lastMessageAt: var_lastMessageAt,
@@ -5142,6 +7858,77 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  GroupMessageUpdate sse_decode_group_message_update(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_subscriptionId = sse_decode_u_64(deserializer);
+        return GroupMessageUpdate_Subscribed(
+          subscriptionId: var_subscriptionId,
+        );
+      case 1:
+        var var_message = sse_decode_chat_message_data(deserializer);
+        return GroupMessageUpdate_NewMessage(message: var_message);
+      case 2:
+        var var_message = sse_decode_chat_message_data(deserializer);
+        return GroupMessageUpdate_MessageEdited(message: var_message);
+      case 3:
+        var var_messageId = sse_decode_String(deserializer);
+        return GroupMessageUpdate_MessageDeleted(messageId: var_messageId);
+      case 4:
+        var var_messageId = sse_decode_String(deserializer);
+        return GroupMessageUpdate_MessageRemoved(messageId: var_messageId);
+      case 5:
+        var var_messageId = sse_decode_String(deserializer);
+        var var_reactions = sse_decode_reaction_summary_data(deserializer);
+        return GroupMessageUpdate_ReactionsUpdated(
+          messageId: var_messageId,
+          reactions: var_reactions,
+        );
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  GroupPresenceUpdate sse_decode_group_presence_update(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_subscriptionId = sse_decode_u_64(deserializer);
+        return GroupPresenceUpdate_Subscribed(
+          subscriptionId: var_subscriptionId,
+        );
+      case 1:
+        var var_memberPubkey = sse_decode_String(deserializer);
+        var var_expiresAt = sse_decode_u_64(deserializer);
+        return GroupPresenceUpdate_Typing(
+          memberPubkey: var_memberPubkey,
+          expiresAt: var_expiresAt,
+        );
+      case 2:
+        var var_memberPubkey = sse_decode_String(deserializer);
+        var var_messageId = sse_decode_String(deserializer);
+        var var_readAt = sse_decode_u_64(deserializer);
+        return GroupPresenceUpdate_ReadReceipt(
+          memberPubkey: var_memberPubkey,
+          messageId: var_messageId,
+          readAt: var_readAt,
+        );
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   GroupState sse_decode_group_state(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5162,6 +7949,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getInt32();
   }
 
+  @protected
+  ImageProcessingOptions sse_decode_image_processing_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_maxDimension = sse_decode_u_32(deserializer);
+    final var_convertToWebp = sse_decode_bool(deserializer);
+    final var_quality = sse_decode_u_8(deserializer);
+    final var_thumbnailDimension = sse_decode_u_32(deserializer);
+    return ImageProcessingOptions(
+      maxDimension: var_maxDimension,
+      convertToWebp: var_convertToWebp,
+      quality: var_quality,
+      thumbnailDimension: var_thumbnailDimension,
+    );
+  }
+
   @protected
   List<PublicKey>
   sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
@@ -5257,6 +8061,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ChatSummaryData> sse_decode_list_chat_summary_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <ChatSummaryData>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chat_summary_data(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ContactSearchResultData> sse_decode_list_contact_search_result_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <ContactSearchResultData>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_contact_search_result_data(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<EmojiReactionData> sse_decode_list_emoji_reaction_data(
     SseDeserializer deserializer,
@@ -5283,6 +8115,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <List<String>>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MediaAttachmentData> sse_decode_list_media_attachment_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <MediaAttachmentData>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_media_attachment_data(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MessageVersionData> sse_decode_list_message_version_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <MessageVersionData>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_message_version_data(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<MessageWithTokensData> sse_decode_list_message_with_tokens_data(
     SseDeserializer deserializer,
@@ -5297,6 +8169,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<OutboxMessageData> sse_decode_list_outbox_message_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <OutboxMessageData>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_outbox_message_data(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5323,6 +8209,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<(String, CachedMetadataData)> sse_decode_list_record_string_cached_metadata_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <(String, CachedMetadataData)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_cached_metadata_data(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<(String, String)> sse_decode_list_record_string_string(
     SseDeserializer deserializer,
@@ -5337,6 +8237,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<(String, int)> sse_decode_list_record_string_u_32(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <(String, int)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_u_32(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<SerializableTokenData> sse_decode_list_serializable_token_data(
     SseDeserializer deserializer,
@@ -5377,6 +8291,82 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  MediaAttachmentData sse_decode_media_attachment_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_url = sse_decode_String(deserializer);
+    final var_mimeType = sse_decode_String(deserializer);
+    final var_sha256 = sse_decode_String(deserializer);
+    final var_size = sse_decode_opt_box_autoadd_u_64(deserializer);
+    final var_dimensions = sse_decode_opt_String(deserializer);
+    final var_blurhash = sse_decode_opt_String(deserializer);
+    final var_isEncrypted = sse_decode_bool(deserializer);
+    return MediaAttachmentData(
+      url: var_url,
+      mimeType: var_mimeType,
+      sha256: var_sha256,
+      size: var_size,
+      dimensions: var_dimensions,
+      blurhash: var_blurhash,
+      isEncrypted: var_isEncrypted,
+    );
+  }
+
+  @protected
+  MediaCacheStatsData sse_decode_media_cache_stats_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_fileCount = sse_decode_u_32(deserializer);
+    final var_totalSizeBytes = sse_decode_u_64(deserializer);
+    final var_maxSizeBytes = sse_decode_u_64(deserializer);
+    return MediaCacheStatsData(
+      fileCount: var_fileCount,
+      totalSizeBytes: var_totalSizeBytes,
+      maxSizeBytes: var_maxSizeBytes,
+    );
+  }
+
+  @protected
+  MessageCursorData sse_decode_message_cursor_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_createdAt = sse_decode_u_64(deserializer);
+    final var_messageId = sse_decode_opt_String(deserializer);
+    return MessageCursorData(
+      createdAt: var_createdAt,
+      messageId: var_messageId,
+    );
+  }
+
+  @protected
+  MessagePageData sse_decode_message_page_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_messages = sse_decode_list_chat_message_data(deserializer);
+    final var_nextCursor = sse_decode_opt_box_autoadd_message_cursor_data(
+      deserializer,
+    );
+    final var_hasMore = sse_decode_bool(deserializer);
+    return MessagePageData(
+      messages: var_messages,
+      nextCursor: var_nextCursor,
+      hasMore: var_hasMore,
+    );
+  }
+
+  @protected
+  MessageVersionData sse_decode_message_version_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_content = sse_decode_String(deserializer);
+    final var_createdAt = sse_decode_u_64(deserializer);
+    return MessageVersionData(content: var_content, createdAt: var_createdAt);
+  }
+
   @protected
   MessageWithTokensData sse_decode_message_with_tokens_data(
     SseDeserializer deserializer,
@@ -5398,6 +8388,65 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  Nip05ProfileData sse_decode_nip05_profile_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_identifier = sse_decode_String(deserializer);
+    final var_pubkey = sse_decode_String(deserializer);
+    final var_relays = sse_decode_list_String(deserializer);
+    return Nip05ProfileData(
+      identifier: var_identifier,
+      pubkey: var_pubkey,
+      relays: var_relays,
+    );
+  }
+
+  @protected
+  NostrEntity sse_decode_nostr_entity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_pubkey = sse_decode_String(deserializer);
+        return NostrEntity_Pubkey(pubkey: var_pubkey);
+      case 1:
+        var var_pubkey = sse_decode_String(deserializer);
+        var var_relays = sse_decode_list_String(deserializer);
+        return NostrEntity_Profile(pubkey: var_pubkey, relays: var_relays);
+      case 2:
+        var var_eventId = sse_decode_String(deserializer);
+        return NostrEntity_Note(eventId: var_eventId);
+      case 3:
+        var var_eventId = sse_decode_String(deserializer);
+        var var_relays = sse_decode_list_String(deserializer);
+        var var_author = sse_decode_opt_String(deserializer);
+        var var_kind = sse_decode_opt_box_autoadd_u_32(deserializer);
+        return NostrEntity_Event(
+          eventId: var_eventId,
+          relays: var_relays,
+          author: var_author,
+          kind: var_kind,
+        );
+      case 4:
+        var var_identifier = sse_decode_String(deserializer);
+        var var_pubkey = sse_decode_String(deserializer);
+        var var_kind = sse_decode_u_32(deserializer);
+        var var_relays = sse_decode_list_String(deserializer);
+        return NostrEntity_Address(
+          identifier: var_identifier,
+          pubkey: var_pubkey,
+          kind: var_kind,
+          relays: var_relays,
+        );
+      case 5:
+        var var_secretKey = sse_decode_String(deserializer);
+        return NostrEntity_SecretKey(secretKey: var_secretKey);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   OnboardingState sse_decode_onboarding_state(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5438,6 +8487,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  GroupId?
+  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+        deserializer,
+      ));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   MetadataData?
   sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -5455,30 +8520,185 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+  DeliveryStatusData? sse_decode_opt_box_autoadd_delivery_status_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_delivery_status_data(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ImageProcessingOptions? sse_decode_opt_box_autoadd_image_processing_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_image_processing_options(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MessageCursorData? sse_decode_opt_box_autoadd_message_cursor_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_message_cursor_data(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Nip05ProfileData? sse_decode_opt_box_autoadd_nip05_profile_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_nip05_profile_data(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<Tag>?
+  sse_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
+        deserializer,
+      ));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OutboxMessageData sse_decode_outbox_message_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_String(deserializer);
+    final var_groupId = sse_decode_String(deserializer);
+    final var_content = sse_decode_String(deserializer);
+    final var_kind = sse_decode_u_16(deserializer);
+    final var_tags = sse_decode_list_list_String(deserializer);
+    final var_createdAt = sse_decode_u_64(deserializer);
+    final var_state = sse_decode_outbox_state(deserializer);
+    final var_attempts = sse_decode_u_32(deserializer);
+    final var_nextAttemptAt = sse_decode_u_64(deserializer);
+    final var_lastError = sse_decode_opt_String(deserializer);
+    return OutboxMessageData(
+      id: var_id,
+      groupId: var_groupId,
+      content: var_content,
+      kind: var_kind,
+      tags: var_tags,
+      createdAt: var_createdAt,
+      state: var_state,
+      attempts: var_attempts,
+      nextAttemptAt: var_nextAttemptAt,
+      lastError: var_lastError,
+    );
+  }
+
+  @protected
+  OutboxState sse_decode_outbox_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return OutboxState.values[inner];
+  }
+
+  @protected
+  ParsedNostrEntityData sse_decode_parsed_nostr_entity_data(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
+    final var_entity = sse_decode_nostr_entity(deserializer);
+    final var_isSensitive = sse_decode_bool(deserializer);
+    return ParsedNostrEntityData(
+      entity: var_entity,
+      isSensitive: var_isSensitive,
+    );
   }
 
   @protected
-  List<Tag>?
-  sse_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
+  PresenceSettingsData sse_decode_presence_settings_data(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_disableReadReceipts = sse_decode_bool(deserializer);
+    final var_disableTypingIndicators = sse_decode_bool(deserializer);
+    return PresenceSettingsData(
+      disableReadReceipts: var_disableReadReceipts,
+      disableTypingIndicators: var_disableTypingIndicators,
+    );
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
-        deserializer,
-      ));
-    } else {
-      return null;
-    }
+  @protected
+  ProcessedImageData sse_decode_processed_image_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_filePath = sse_decode_String(deserializer);
+    final var_imageType =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType(
+          deserializer,
+        );
+    final var_mimeType = sse_decode_String(deserializer);
+    final var_width = sse_decode_u_32(deserializer);
+    final var_height = sse_decode_u_32(deserializer);
+    final var_size = sse_decode_u_64(deserializer);
+    final var_sha256 = sse_decode_String(deserializer);
+    final var_blurhash = sse_decode_String(deserializer);
+    final var_thumbnailPath = sse_decode_opt_String(deserializer);
+    return ProcessedImageData(
+      filePath: var_filePath,
+      imageType: var_imageType,
+      mimeType: var_mimeType,
+      width: var_width,
+      height: var_height,
+      size: var_size,
+      sha256: var_sha256,
+      blurhash: var_blurhash,
+      thumbnailPath: var_thumbnailPath,
+    );
   }
 
   @protected
@@ -5511,6 +8731,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  (String, CachedMetadataData) sse_decode_record_string_cached_metadata_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_field0 = sse_decode_String(deserializer);
+    final var_field1 = sse_decode_cached_metadata_data(deserializer);
+    return (var_field0, var_field1);
+  }
+
   @protected
   (String, String) sse_decode_record_string_string(
     SseDeserializer deserializer,
@@ -5521,6 +8751,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  (String, int) sse_decode_record_string_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_field0 = sse_decode_String(deserializer);
+    final var_field1 = sse_decode_u_32(deserializer);
+    return (var_field0, var_field1);
+  }
+
   @protected
   SerializableTokenData sse_decode_serializable_token_data(
     SseDeserializer deserializer,
@@ -5563,6 +8801,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  UploadedImageData sse_decode_uploaded_image_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_url = sse_decode_String(deserializer);
+    final var_mirrorUrls = sse_decode_list_String(deserializer);
+    final var_thumbnailUrl = sse_decode_opt_String(deserializer);
+    return UploadedImageData(
+      url: var_url,
+      mirrorUrls: var_mirrorUrls,
+      thumbnailUrl: var_thumbnailUrl,
+    );
+  }
+
   @protected
   UserReactionData sse_decode_user_reaction_data(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5628,6 +8881,123 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WhitenoiseConfigData(dataDir: var_dataDir, logsDir: var_logsDir);
   }
 
+  @protected
+  WhitenoiseEvent sse_decode_whitenoise_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_subscriptionId = sse_decode_u_64(deserializer);
+        return WhitenoiseEvent_Subscribed(subscriptionId: var_subscriptionId);
+      case 1:
+        var var_accountPubkey = sse_decode_String(deserializer);
+        var var_welcome = sse_decode_welcome_data(deserializer);
+        return WhitenoiseEvent_WelcomeReceived(
+          accountPubkey: var_accountPubkey,
+          welcome: var_welcome,
+        );
+      case 2:
+        var var_accountPubkey = sse_decode_String(deserializer);
+        var var_group = sse_decode_group_data(deserializer);
+        return WhitenoiseEvent_GroupCreated(
+          accountPubkey: var_accountPubkey,
+          group: var_group,
+        );
+      case 3:
+        var var_accountPubkey = sse_decode_String(deserializer);
+        var var_group = sse_decode_group_data(deserializer);
+        return WhitenoiseEvent_GroupUpdated(
+          accountPubkey: var_accountPubkey,
+          group: var_group,
+        );
+      case 4:
+        var var_accountPubkey = sse_decode_String(deserializer);
+        var var_group = sse_decode_group_data(deserializer);
+        return WhitenoiseEvent_GroupStateChanged(
+          accountPubkey: var_accountPubkey,
+          group: var_group,
+        );
+      case 5:
+        var var_accountPubkey = sse_decode_String(deserializer);
+        var var_groupId = sse_decode_String(deserializer);
+        var var_memberPubkey = sse_decode_String(deserializer);
+        return WhitenoiseEvent_MemberAdded(
+          accountPubkey: var_accountPubkey,
+          groupId: var_groupId,
+          memberPubkey: var_memberPubkey,
+        );
+      case 6:
+        var var_accountPubkey = sse_decode_String(deserializer);
+        var var_groupId = sse_decode_String(deserializer);
+        var var_memberPubkey = sse_decode_String(deserializer);
+        return WhitenoiseEvent_MemberRemoved(
+          accountPubkey: var_accountPubkey,
+          groupId: var_groupId,
+          memberPubkey: var_memberPubkey,
+        );
+      case 7:
+        var var_accountPubkey = sse_decode_String(deserializer);
+        var var_relayUrl = sse_decode_String(deserializer);
+        var var_status = sse_decode_String(deserializer);
+        return WhitenoiseEvent_RelayStatusChanged(
+          accountPubkey: var_accountPubkey,
+          relayUrl: var_relayUrl,
+          status: var_status,
+        );
+      case 8:
+        var var_pubkey = sse_decode_String(deserializer);
+        var var_metadata =
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+              deserializer,
+            );
+        return WhitenoiseEvent_MetadataUpdated(
+          pubkey: var_pubkey,
+          metadata: var_metadata,
+        );
+      case 9:
+        var var_account = sse_decode_account_data(deserializer);
+        return WhitenoiseEvent_AccountLoggedIn(account: var_account);
+      case 10:
+        var var_pubkey = sse_decode_String(deserializer);
+        return WhitenoiseEvent_AccountLoggedOut(pubkey: var_pubkey);
+      case 11:
+        var var_accountPubkey = sse_decode_String(deserializer);
+        var var_status = sse_decode_delivery_status_data(deserializer);
+        return WhitenoiseEvent_MessageDeliveryStatusChanged(
+          accountPubkey: var_accountPubkey,
+          status: var_status,
+        );
+      case 12:
+        var var_accountPubkey = sse_decode_String(deserializer);
+        var var_message = sse_decode_outbox_message_data(deserializer);
+        return WhitenoiseEvent_OutboxMessageUpdated(
+          accountPubkey: var_accountPubkey,
+          message: var_message,
+        );
+      case 13:
+        var var_accountPubkey = sse_decode_String(deserializer);
+        var var_outboxId = sse_decode_String(deserializer);
+        var var_message = sse_decode_message_with_tokens_data(deserializer);
+        return WhitenoiseEvent_OutboxMessageSent(
+          accountPubkey: var_accountPubkey,
+          outboxId: var_outboxId,
+          message: var_message,
+        );
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
   @protected
   void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount(
     Account self,
@@ -5765,19 +9135,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError(
-    WhitenoiseError self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as WhitenoiseErrorImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
   @protected
   void
   sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -5965,6 +9322,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_Map_String_cached_metadata_data_None(
+    Map<String, CachedMetadataData> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_cached_metadata_data(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Map_String_u_32_None(
+    Map<String, int> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_u_32(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount(
     Account self,
@@ -6133,6 +9514,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_group_message_update_Sse(
+    RustStreamSink<GroupMessageUpdate> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group_message_update,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_group_presence_update_Sse(
+    RustStreamSink<GroupPresenceUpdate> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group_presence_update,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_whitenoise_event_Sse(
+    RustStreamSink<WhitenoiseEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_whitenoise_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6159,6 +9591,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.lockdownMode, serializer);
   }
 
+  @protected
+  void sse_encode_blossom_server_list_data(
+    BlossomServerListData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.servers, serializer);
+    sse_encode_bool(self.mirrorUploads, serializer);
+  }
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6178,6 +9620,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+    GroupId self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+      self,
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -6191,12 +9646,87 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_box_autoadd_delivery_status_data(
+    DeliveryStatusData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_delivery_status_data(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_image_processing_options(
+    ImageProcessingOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_image_processing_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_message_cursor_data(
+    MessageCursorData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_message_cursor_data(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_nip05_profile_data(
+    Nip05ProfileData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_nip05_profile_data(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_u_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_bridge_error(BridgeError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bridge_error_code(self.code, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.details, serializer);
+    sse_encode_opt_String(self.outboxId, serializer);
+  }
+
+  @protected
+  void sse_encode_bridge_error_code(
+    BridgeErrorCode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_cached_metadata_data(
+    CachedMetadataData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.pubkey, serializer);
+    sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+      self.metadata,
+      serializer,
+    );
+    sse_encode_u_64(self.fetchedAt, serializer);
+    sse_encode_bool(self.isStale, serializer);
+  }
+
   @protected
   void sse_encode_chat_message_data(
     ChatMessageData self,
@@ -6214,6 +9744,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_serializable_token_data(self.contentTokens, serializer);
     sse_encode_reaction_summary_data(self.reactions, serializer);
     sse_encode_u_16(self.kind, serializer);
+    sse_encode_bool(self.isEdited, serializer);
+    sse_encode_list_message_version_data(self.editHistory, serializer);
+    sse_encode_list_media_attachment_data(self.attachments, serializer);
+  }
+
+  @protected
+  void sse_encode_chat_summary_data(
+    ChatSummaryData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_group_data(self.group, serializer);
+    sse_encode_opt_String(self.lastMessageId, serializer);
+    sse_encode_opt_String(self.lastMessagePreview, serializer);
+    sse_encode_opt_String(self.lastMessageAuthor, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.lastActivityAt, serializer);
+    sse_encode_u_32(self.unreadCount, serializer);
+    sse_encode_u_32(self.memberCount, serializer);
+    sse_encode_opt_String(self.dmCounterpartPubkey, serializer);
+    sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+      self.dmCounterpartMetadata,
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_contact_search_result_data(
+    ContactSearchResultData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.pubkey, serializer);
+    sse_encode_String(self.npub, serializer);
+    sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+      self.metadata,
+      serializer,
+    );
+    sse_encode_bool(self.isContact, serializer);
+    sse_encode_u_32(self.score, serializer);
+  }
+
+  @protected
+  void sse_encode_delivery_state(DeliveryState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_delivery_status_data(
+    DeliveryStatusData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.outboxId, serializer);
+    sse_encode_opt_String(self.messageId, serializer);
+    sse_encode_String(self.groupId, serializer);
+    sse_encode_delivery_state(self.state, serializer);
+    sse_encode_u_32(self.attempts, serializer);
+    sse_encode_opt_String(self.error, serializer);
+    sse_encode_u_64(self.updatedAt, serializer);
   }
 
   @protected
@@ -6230,16 +9820,78 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   void sse_encode_group_data(GroupData self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.mlsGroupId, serializer);
-    sse_encode_String(self.nostrGroupId, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.description, serializer);
-    sse_encode_list_String(self.adminPubkeys, serializer);
-    sse_encode_opt_String(self.lastMessageId, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.lastMessageAt, serializer);
-    sse_encode_group_type(self.groupType, serializer);
-    sse_encode_u_64(self.epoch, serializer);
-    sse_encode_group_state(self.state, serializer);
+    sse_encode_String(self.mlsGroupId, serializer);
+    sse_encode_String(self.nostrGroupId, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_opt_String(self.imageUrl, serializer);
+    sse_encode_list_String(self.adminPubkeys, serializer);
+    sse_encode_opt_String(self.lastMessageId, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.lastMessageAt, serializer);
+    sse_encode_group_type(self.groupType, serializer);
+    sse_encode_u_64(self.epoch, serializer);
+    sse_encode_group_state(self.state, serializer);
+  }
+
+  @protected
+  void sse_encode_group_message_update(
+    GroupMessageUpdate self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case GroupMessageUpdate_Subscribed(subscriptionId: final subscriptionId):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_64(subscriptionId, serializer);
+      case GroupMessageUpdate_NewMessage(message: final message):
+        sse_encode_i_32(1, serializer);
+        sse_encode_chat_message_data(message, serializer);
+      case GroupMessageUpdate_MessageEdited(message: final message):
+        sse_encode_i_32(2, serializer);
+        sse_encode_chat_message_data(message, serializer);
+      case GroupMessageUpdate_MessageDeleted(messageId: final messageId):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(messageId, serializer);
+      case GroupMessageUpdate_MessageRemoved(messageId: final messageId):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(messageId, serializer);
+      case GroupMessageUpdate_ReactionsUpdated(
+        messageId: final messageId,
+        reactions: final reactions,
+      ):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(messageId, serializer);
+        sse_encode_reaction_summary_data(reactions, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_group_presence_update(
+    GroupPresenceUpdate self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case GroupPresenceUpdate_Subscribed(subscriptionId: final subscriptionId):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_64(subscriptionId, serializer);
+      case GroupPresenceUpdate_Typing(
+        memberPubkey: final memberPubkey,
+        expiresAt: final expiresAt,
+      ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(memberPubkey, serializer);
+        sse_encode_u_64(expiresAt, serializer);
+      case GroupPresenceUpdate_ReadReceipt(
+        memberPubkey: final memberPubkey,
+        messageId: final messageId,
+        readAt: final readAt,
+      ):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(memberPubkey, serializer);
+        sse_encode_String(messageId, serializer);
+        sse_encode_u_64(readAt, serializer);
+    }
   }
 
   @protected
@@ -6260,6 +9912,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putInt32(self);
   }
 
+  @protected
+  void sse_encode_image_processing_options(
+    ImageProcessingOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxDimension, serializer);
+    sse_encode_bool(self.convertToWebp, serializer);
+    sse_encode_u_8(self.quality, serializer);
+    sse_encode_u_32(self.thumbnailDimension, serializer);
+  }
+
   @protected
   void
   sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
@@ -6340,6 +10004,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_chat_summary_data(
+    List<ChatSummaryData> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chat_summary_data(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_contact_search_result_data(
+    List<ContactSearchResultData> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_contact_search_result_data(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_emoji_reaction_data(
     List<EmojiReactionData> self,
@@ -6364,6 +10052,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_list_String(
+    List<List<String>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_media_attachment_data(
+    List<MediaAttachmentData> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_media_attachment_data(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_message_version_data(
+    List<MessageVersionData> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message_version_data(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_message_with_tokens_data(
     List<MessageWithTokensData> self,
@@ -6376,6 +10100,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_outbox_message_data(
+    List<OutboxMessageData> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_outbox_message_data(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -6402,6 +10138,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_record_string_cached_metadata_data(
+    List<(String, CachedMetadataData)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_cached_metadata_data(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_record_string_string(
     List<(String, String)> self,
@@ -6414,6 +10162,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_record_string_u_32(
+    List<(String, int)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_u_32(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_serializable_token_data(
     List<SerializableTokenData> self,
@@ -6450,6 +10210,63 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_media_attachment_data(
+    MediaAttachmentData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_String(self.sha256, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.size, serializer);
+    sse_encode_opt_String(self.dimensions, serializer);
+    sse_encode_opt_String(self.blurhash, serializer);
+    sse_encode_bool(self.isEncrypted, serializer);
+  }
+
+  @protected
+  void sse_encode_media_cache_stats_data(
+    MediaCacheStatsData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.fileCount, serializer);
+    sse_encode_u_64(self.totalSizeBytes, serializer);
+    sse_encode_u_64(self.maxSizeBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_message_cursor_data(
+    MessageCursorData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.createdAt, serializer);
+    sse_encode_opt_String(self.messageId, serializer);
+  }
+
+  @protected
+  void sse_encode_message_page_data(
+    MessagePageData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_chat_message_data(self.messages, serializer);
+    sse_encode_opt_box_autoadd_message_cursor_data(self.nextCursor, serializer);
+    sse_encode_bool(self.hasMore, serializer);
+  }
+
+  @protected
+  void sse_encode_message_version_data(
+    MessageVersionData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.content, serializer);
+    sse_encode_u_64(self.createdAt, serializer);
+  }
+
   @protected
   void sse_encode_message_with_tokens_data(
     MessageWithTokensData self,
@@ -6464,6 +10281,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_String(self.tokens, serializer);
   }
 
+  @protected
+  void sse_encode_nip05_profile_data(
+    Nip05ProfileData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.identifier, serializer);
+    sse_encode_String(self.pubkey, serializer);
+    sse_encode_list_String(self.relays, serializer);
+  }
+
+  @protected
+  void sse_encode_nostr_entity(NostrEntity self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case NostrEntity_Pubkey(pubkey: final pubkey):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(pubkey, serializer);
+      case NostrEntity_Profile(pubkey: final pubkey, relays: final relays):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(pubkey, serializer);
+        sse_encode_list_String(relays, serializer);
+      case NostrEntity_Note(eventId: final eventId):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(eventId, serializer);
+      case NostrEntity_Event(
+        eventId: final eventId,
+        relays: final relays,
+        author: final author,
+        kind: final kind,
+      ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(eventId, serializer);
+        sse_encode_list_String(relays, serializer);
+        sse_encode_opt_String(author, serializer);
+        sse_encode_opt_box_autoadd_u_32(kind, serializer);
+      case NostrEntity_Address(
+        identifier: final identifier,
+        pubkey: final pubkey,
+        kind: final kind,
+        relays: final relays,
+      ):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(identifier, serializer);
+        sse_encode_String(pubkey, serializer);
+        sse_encode_u_32(kind, serializer);
+        sse_encode_list_String(relays, serializer);
+      case NostrEntity_SecretKey(secretKey: final secretKey):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(secretKey, serializer);
+    }
+  }
+
   @protected
   void sse_encode_onboarding_state(
     OnboardingState self,
@@ -6502,6 +10372,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void
+  sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+    GroupId? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+        self,
+        serializer,
+      );
+    }
+  }
+
   @protected
   void
   sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -6519,6 +10406,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_delivery_status_data(
+    DeliveryStatusData? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_delivery_status_data(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_image_processing_options(
+    ImageProcessingOptions? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_image_processing_options(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_message_cursor_data(
+    MessageCursorData? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_message_cursor_data(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_nip05_profile_data(
+    Nip05ProfileData? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_nip05_profile_data(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6546,6 +10495,70 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_outbox_message_data(
+    OutboxMessageData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.groupId, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_u_16(self.kind, serializer);
+    sse_encode_list_list_String(self.tags, serializer);
+    sse_encode_u_64(self.createdAt, serializer);
+    sse_encode_outbox_state(self.state, serializer);
+    sse_encode_u_32(self.attempts, serializer);
+    sse_encode_u_64(self.nextAttemptAt, serializer);
+    sse_encode_opt_String(self.lastError, serializer);
+  }
+
+  @protected
+  void sse_encode_outbox_state(OutboxState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_parsed_nostr_entity_data(
+    ParsedNostrEntityData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_nostr_entity(self.entity, serializer);
+    sse_encode_bool(self.isSensitive, serializer);
+  }
+
+  @protected
+  void sse_encode_presence_settings_data(
+    PresenceSettingsData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.disableReadReceipts, serializer);
+    sse_encode_bool(self.disableTypingIndicators, serializer);
+  }
+
+  @protected
+  void sse_encode_processed_image_data(
+    ProcessedImageData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.filePath, serializer);
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageType(
+      self.imageType,
+      serializer,
+    );
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_String(self.sha256, serializer);
+    sse_encode_String(self.blurhash, serializer);
+    sse_encode_opt_String(self.thumbnailPath, serializer);
+  }
+
   @protected
   void sse_encode_reaction_summary_data(
     ReactionSummaryData self,
@@ -6573,6 +10586,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_record_string_cached_metadata_data(
+    (String, CachedMetadataData) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_cached_metadata_data(self.$2, serializer);
+  }
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
@@ -6583,6 +10606,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_record_string_u_32(
+    (String, int) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_u_32(self.$2, serializer);
+  }
+
   @protected
   void sse_encode_serializable_token_data(
     SerializableTokenData self,
@@ -6622,6 +10655,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  void sse_encode_uploaded_image_data(
+    UploadedImageData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_list_String(self.mirrorUrls, serializer);
+    sse_encode_opt_String(self.thumbnailUrl, serializer);
+  }
+
   @protected
   void sse_encode_user_reaction_data(
     UserReactionData self,
@@ -6670,6 +10714,113 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.dataDir, serializer);
     sse_encode_String(self.logsDir, serializer);
   }
+
+  @protected
+  void sse_encode_whitenoise_event(
+    WhitenoiseEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case WhitenoiseEvent_Subscribed(subscriptionId: final subscriptionId):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_64(subscriptionId, serializer);
+      case WhitenoiseEvent_WelcomeReceived(
+        accountPubkey: final accountPubkey,
+        welcome: final welcome,
+      ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(accountPubkey, serializer);
+        sse_encode_welcome_data(welcome, serializer);
+      case WhitenoiseEvent_GroupCreated(
+        accountPubkey: final accountPubkey,
+        group: final group,
+      ):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(accountPubkey, serializer);
+        sse_encode_group_data(group, serializer);
+      case WhitenoiseEvent_GroupUpdated(
+        accountPubkey: final accountPubkey,
+        group: final group,
+      ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(accountPubkey, serializer);
+        sse_encode_group_data(group, serializer);
+      case WhitenoiseEvent_GroupStateChanged(
+        accountPubkey: final accountPubkey,
+        group: final group,
+      ):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(accountPubkey, serializer);
+        sse_encode_group_data(group, serializer);
+      case WhitenoiseEvent_MemberAdded(
+        accountPubkey: final accountPubkey,
+        groupId: final groupId,
+        memberPubkey: final memberPubkey,
+      ):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(accountPubkey, serializer);
+        sse_encode_String(groupId, serializer);
+        sse_encode_String(memberPubkey, serializer);
+      case WhitenoiseEvent_MemberRemoved(
+        accountPubkey: final accountPubkey,
+        groupId: final groupId,
+        memberPubkey: final memberPubkey,
+      ):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(accountPubkey, serializer);
+        sse_encode_String(groupId, serializer);
+        sse_encode_String(memberPubkey, serializer);
+      case WhitenoiseEvent_RelayStatusChanged(
+        accountPubkey: final accountPubkey,
+        relayUrl: final relayUrl,
+        status: final status,
+      ):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(accountPubkey, serializer);
+        sse_encode_String(relayUrl, serializer);
+        sse_encode_String(status, serializer);
+      case WhitenoiseEvent_MetadataUpdated(
+        pubkey: final pubkey,
+        metadata: final metadata,
+      ):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(pubkey, serializer);
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+          metadata,
+          serializer,
+        );
+      case WhitenoiseEvent_AccountLoggedIn(account: final account):
+        sse_encode_i_32(9, serializer);
+        sse_encode_account_data(account, serializer);
+      case WhitenoiseEvent_AccountLoggedOut(pubkey: final pubkey):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(pubkey, serializer);
+      case WhitenoiseEvent_MessageDeliveryStatusChanged(
+        accountPubkey: final accountPubkey,
+        status: final status,
+      ):
+        sse_encode_i_32(11, serializer);
+        sse_encode_String(accountPubkey, serializer);
+        sse_encode_delivery_status_data(status, serializer);
+      case WhitenoiseEvent_OutboxMessageUpdated(
+        accountPubkey: final accountPubkey,
+        message: final message,
+      ):
+        sse_encode_i_32(12, serializer);
+        sse_encode_String(accountPubkey, serializer);
+        sse_encode_outbox_message_data(message, serializer);
+      case WhitenoiseEvent_OutboxMessageSent(
+        accountPubkey: final accountPubkey,
+        outboxId: final outboxId,
+        message: final message,
+      ):
+        sse_encode_i_32(13, serializer);
+        sse_encode_String(accountPubkey, serializer);
+        sse_encode_String(outboxId, serializer);
+        sse_encode_message_with_tokens_data(message, serializer);
+    }
+  }
 }
 
 @sealed
@@ -11,10 +11,24 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
 import 'api.dart';
 import 'api/accounts.dart';
+import 'api/blossom.dart';
+import 'api/chats.dart';
 import 'api/contacts.dart';
+import 'api/delivery.dart';
+import 'api/errors.dart';
+import 'api/events.dart';
 import 'api/groups.dart';
+import 'api/images.dart';
+import 'api/media.dart';
+import 'api/media_cache.dart';
 import 'api/messages.dart';
+import 'api/metadata_cache.dart';
+import 'api/nip05.dart';
+import 'api/nip19.dart';
+import 'api/outbox.dart';
+import 'api/presence.dart';
 import 'api/relays.dart';
+import 'api/subscriptions.dart';
 import 'api/utils.dart';
 import 'api/welcomes.dart';
 import 'frb_generated.dart';
@@ -75,6 +89,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_WhitenoiseErrorPtr =>
       wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseErrorPtr;
 
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
   @protected
   Account
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount(
@@ -139,12 +156,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
-  @protected
-  WhitenoiseError
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError(
-    dynamic raw,
-  );
-
   @protected
   MetadataData
   dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -228,6 +239,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
 
+  @protected
+  Map<String, CachedMetadataData> dco_decode_Map_String_cached_metadata_data_None(
+    dynamic raw,
+  );
+
+  @protected
+  Map<String, int> dco_decode_Map_String_u_32_None(dynamic raw);
+
   @protected
   Account dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount(
     dynamic raw,
@@ -312,6 +331,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<GroupMessageUpdate> dco_decode_StreamSink_group_message_update_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<GroupPresenceUpdate> dco_decode_StreamSink_group_presence_update_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<WhitenoiseEvent> dco_decode_StreamSink_whitenoise_event_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
@@ -321,6 +355,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AccountSettings dco_decode_account_settings(dynamic raw);
 
+  @protected
+  BlossomServerListData dco_decode_blossom_server_list_data(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
@@ -330,24 +367,74 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  GroupId
+  dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+    dynamic raw,
+  );
+
   @protected
   MetadataData
   dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
     dynamic raw,
   );
 
+  @protected
+  DeliveryStatusData dco_decode_box_autoadd_delivery_status_data(dynamic raw);
+
+  @protected
+  ImageProcessingOptions dco_decode_box_autoadd_image_processing_options(
+    dynamic raw,
+  );
+
+  @protected
+  MessageCursorData dco_decode_box_autoadd_message_cursor_data(dynamic raw);
+
+  @protected
+  Nip05ProfileData dco_decode_box_autoadd_nip05_profile_data(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  BridgeError dco_decode_bridge_error(dynamic raw);
+
+  @protected
+  BridgeErrorCode dco_decode_bridge_error_code(dynamic raw);
+
+  @protected
+  CachedMetadataData dco_decode_cached_metadata_data(dynamic raw);
+
   @protected
   ChatMessageData dco_decode_chat_message_data(dynamic raw);
 
+  @protected
+  ChatSummaryData dco_decode_chat_summary_data(dynamic raw);
+
+  @protected
+  ContactSearchResultData dco_decode_contact_search_result_data(dynamic raw);
+
+  @protected
+  DeliveryState dco_decode_delivery_state(dynamic raw);
+
+  @protected
+  DeliveryStatusData dco_decode_delivery_status_data(dynamic raw);
+
   @protected
   EmojiReactionData dco_decode_emoji_reaction_data(dynamic raw);
 
   @protected
   GroupData dco_decode_group_data(dynamic raw);
 
+  @protected
+  GroupMessageUpdate dco_decode_group_message_update(dynamic raw);
+
+  @protected
+  GroupPresenceUpdate dco_decode_group_presence_update(dynamic raw);
+
   @protected
   GroupState dco_decode_group_state(dynamic raw);
 
@@ -357,6 +444,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int dco_decode_i_32(dynamic raw);
 
+  @protected
+  ImageProcessingOptions dco_decode_image_processing_options(dynamic raw);
+
   @protected
   List<PublicKey>
   dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
@@ -384,17 +474,37 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<ChatMessageData> dco_decode_list_chat_message_data(dynamic raw);
 
+  @protected
+  List<ChatSummaryData> dco_decode_list_chat_summary_data(dynamic raw);
+
+  @protected
+  List<ContactSearchResultData> dco_decode_list_contact_search_result_data(
+    dynamic raw,
+  );
+
   @protected
   List<EmojiReactionData> dco_decode_list_emoji_reaction_data(dynamic raw);
 
   @protected
   List<GroupData> dco_decode_list_group_data(dynamic raw);
 
+  @protected
+  List<List<String>> dco_decode_list_list_String(dynamic raw);
+
+  @protected
+  List<MediaAttachmentData> dco_decode_list_media_attachment_data(dynamic raw);
+
+  @protected
+  List<MessageVersionData> dco_decode_list_message_version_data(dynamic raw);
+
   @protected
   List<MessageWithTokensData> dco_decode_list_message_with_tokens_data(
     dynamic raw,
   );
 
+  @protected
+  List<OutboxMessageData> dco_decode_list_outbox_message_data(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
@@ -404,9 +514,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  List<(String, CachedMetadataData)> dco_decode_list_record_string_cached_metadata_data(
+    dynamic raw,
+  );
+
   @protected
   List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
 
+  @protected
+  List<(String, int)> dco_decode_list_record_string_u_32(dynamic raw);
+
   @protected
   List<SerializableTokenData> dco_decode_list_serializable_token_data(
     dynamic raw,
@@ -418,9 +536,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<WelcomeData> dco_decode_list_welcome_data(dynamic raw);
 
+  @protected
+  MediaAttachmentData dco_decode_media_attachment_data(dynamic raw);
+
+  @protected
+  MediaCacheStatsData dco_decode_media_cache_stats_data(dynamic raw);
+
+  @protected
+  MessageCursorData dco_decode_message_cursor_data(dynamic raw);
+
+  @protected
+  MessagePageData dco_decode_message_page_data(dynamic raw);
+
+  @protected
+  MessageVersionData dco_decode_message_version_data(dynamic raw);
+
   @protected
   MessageWithTokensData dco_decode_message_with_tokens_data(dynamic raw);
 
+  @protected
+  Nip05ProfileData dco_decode_nip05_profile_data(dynamic raw);
+
+  @protected
+  NostrEntity dco_decode_nostr_entity(dynamic raw);
+
   @protected
   OnboardingState dco_decode_onboarding_state(dynamic raw);
 
@@ -433,12 +572,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  GroupId?
+  dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+    dynamic raw,
+  );
+
   @protected
   MetadataData?
   dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
     dynamic raw,
   );
 
+  @protected
+  DeliveryStatusData? dco_decode_opt_box_autoadd_delivery_status_data(
+    dynamic raw,
+  );
+
+  @protected
+  ImageProcessingOptions? dco_decode_opt_box_autoadd_image_processing_options(
+    dynamic raw,
+  );
+
+  @protected
+  MessageCursorData? dco_decode_opt_box_autoadd_message_cursor_data(
+    dynamic raw,
+  );
+
+  @protected
+  Nip05ProfileData? dco_decode_opt_box_autoadd_nip05_profile_data(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
@@ -448,6 +614,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  OutboxMessageData dco_decode_outbox_message_data(dynamic raw);
+
+  @protected
+  OutboxState dco_decode_outbox_state(dynamic raw);
+
+  @protected
+  ParsedNostrEntityData dco_decode_parsed_nostr_entity_data(dynamic raw);
+
+  @protected
+  PresenceSettingsData dco_decode_presence_settings_data(dynamic raw);
+
+  @protected
+  ProcessedImageData dco_decode_processed_image_data(dynamic raw);
+
   @protected
   ReactionSummaryData dco_decode_reaction_summary_data(dynamic raw);
 
@@ -457,9 +638,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  (String, CachedMetadataData) dco_decode_record_string_cached_metadata_data(
+    dynamic raw,
+  );
+
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw);
 
+  @protected
+  (String, int) dco_decode_record_string_u_32(dynamic raw);
+
   @protected
   SerializableTokenData dco_decode_serializable_token_data(dynamic raw);
 
@@ -478,6 +667,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  UploadedImageData dco_decode_uploaded_image_data(dynamic raw);
+
   @protected
   UserReactionData dco_decode_user_reaction_data(dynamic raw);
 
@@ -493,6 +685,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   WhitenoiseConfigData dco_decode_whitenoise_config_data(dynamic raw);
 
+  @protected
+  WhitenoiseEvent dco_decode_whitenoise_event(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
   @protected
   Account
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount(
@@ -557,12 +755,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
-  @protected
-  WhitenoiseError
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError(
-    SseDeserializer deserializer,
-  );
-
   @protected
   MetadataData
   sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -648,6 +840,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  Map<String, CachedMetadataData> sse_decode_Map_String_cached_metadata_data_None(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Map<String, int> sse_decode_Map_String_u_32_None(
+    SseDeserializer deserializer,
+  );
+
   @protected
   Account sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount(
     SseDeserializer deserializer,
@@ -732,6 +934,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<GroupMessageUpdate> sse_decode_StreamSink_group_message_update_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<GroupPresenceUpdate> sse_decode_StreamSink_group_presence_update_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<WhitenoiseEvent> sse_decode_StreamSink_whitenoise_event_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
@@ -741,6 +958,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AccountSettings sse_decode_account_settings(SseDeserializer deserializer);
 
+  @protected
+  BlossomServerListData sse_decode_blossom_server_list_data(
+    SseDeserializer deserializer,
+  );
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
@@ -750,18 +972,74 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  GroupId
+  sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+    SseDeserializer deserializer,
+  );
+
   @protected
   MetadataData
   sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
     SseDeserializer deserializer,
   );
 
+  @protected
+  DeliveryStatusData sse_decode_box_autoadd_delivery_status_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImageProcessingOptions sse_decode_box_autoadd_image_processing_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MessageCursorData sse_decode_box_autoadd_message_cursor_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Nip05ProfileData sse_decode_box_autoadd_nip05_profile_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  BridgeError sse_decode_bridge_error(SseDeserializer deserializer);
+
+  @protected
+  BridgeErrorCode sse_decode_bridge_error_code(SseDeserializer deserializer);
+
+  @protected
+  CachedMetadataData sse_decode_cached_metadata_data(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ChatMessageData sse_decode_chat_message_data(SseDeserializer deserializer);
 
+  @protected
+  ChatSummaryData sse_decode_chat_summary_data(SseDeserializer deserializer);
+
+  @protected
+  ContactSearchResultData sse_decode_contact_search_result_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DeliveryState sse_decode_delivery_state(SseDeserializer deserializer);
+
+  @protected
+  DeliveryStatusData sse_decode_delivery_status_data(
+    SseDeserializer deserializer,
+  );
+
   @protected
   EmojiReactionData sse_decode_emoji_reaction_data(
     SseDeserializer deserializer,
@@ -770,6 +1048,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   GroupData sse_decode_group_data(SseDeserializer deserializer);
 
+  @protected
+  GroupMessageUpdate sse_decode_group_message_update(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GroupPresenceUpdate sse_decode_group_presence_update(
+    SseDeserializer deserializer,
+  );
+
   @protected
   GroupState sse_decode_group_state(SseDeserializer deserializer);
 
@@ -779,6 +1067,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
+  @protected
+  ImageProcessingOptions sse_decode_image_processing_options(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<PublicKey>
   sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
@@ -809,68 +1102,164 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  List<EmojiReactionData> sse_decode_list_emoji_reaction_data(
+  List<ChatSummaryData> sse_decode_list_chat_summary_data(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<GroupData> sse_decode_list_group_data(SseDeserializer deserializer);
+  List<ContactSearchResultData> sse_decode_list_contact_search_result_data(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  List<MessageWithTokensData> sse_decode_list_message_with_tokens_data(
+  List<EmojiReactionData> sse_decode_list_emoji_reaction_data(
     SseDeserializer deserializer,
   );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  List<GroupData> sse_decode_list_group_data(SseDeserializer deserializer);
 
   @protected
-  List<(PublicKey, MetadataData?)>
-  sse_decode_list_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_public_key_opt_box_autoadd_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_metadata_data(
+  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<MediaAttachmentData> sse_decode_list_media_attachment_data(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
+  List<MessageVersionData> sse_decode_list_message_version_data(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<SerializableTokenData> sse_decode_list_serializable_token_data(
+  List<MessageWithTokensData> sse_decode_list_message_with_tokens_data(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<UserReactionData> sse_decode_list_user_reaction_data(
+  List<OutboxMessageData> sse_decode_list_outbox_message_data(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<WelcomeData> sse_decode_list_welcome_data(SseDeserializer deserializer);
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
   @protected
-  MessageWithTokensData sse_decode_message_with_tokens_data(
+  List<(PublicKey, MetadataData?)>
+  sse_decode_list_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_public_key_opt_box_autoadd_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_metadata_data(
     SseDeserializer deserializer,
   );
 
   @protected
-  OnboardingState sse_decode_onboarding_state(SseDeserializer deserializer);
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  List<(String, CachedMetadataData)> sse_decode_list_record_string_cached_metadata_data(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  Event?
-  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEvent(
+  List<(String, String)> sse_decode_list_record_string_string(
     SseDeserializer deserializer,
   );
 
   @protected
-  MetadataData?
-  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+  List<(String, int)> sse_decode_list_record_string_u_32(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<SerializableTokenData> sse_decode_list_serializable_token_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<UserReactionData> sse_decode_list_user_reaction_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WelcomeData> sse_decode_list_welcome_data(SseDeserializer deserializer);
+
+  @protected
+  MediaAttachmentData sse_decode_media_attachment_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MediaCacheStatsData sse_decode_media_cache_stats_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MessageCursorData sse_decode_message_cursor_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MessagePageData sse_decode_message_page_data(SseDeserializer deserializer);
+
+  @protected
+  MessageVersionData sse_decode_message_version_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MessageWithTokensData sse_decode_message_with_tokens_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Nip05ProfileData sse_decode_nip05_profile_data(SseDeserializer deserializer);
+
+  @protected
+  NostrEntity sse_decode_nostr_entity(SseDeserializer deserializer);
+
+  @protected
+  OnboardingState sse_decode_onboarding_state(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  Event?
+  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEvent(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GroupId?
+  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MetadataData?
+  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DeliveryStatusData? sse_decode_opt_box_autoadd_delivery_status_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImageProcessingOptions? sse_decode_opt_box_autoadd_image_processing_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MessageCursorData? sse_decode_opt_box_autoadd_message_cursor_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Nip05ProfileData? sse_decode_opt_box_autoadd_nip05_profile_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
@@ -880,6 +1269,29 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  OutboxMessageData sse_decode_outbox_message_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutboxState sse_decode_outbox_state(SseDeserializer deserializer);
+
+  @protected
+  ParsedNostrEntityData sse_decode_parsed_nostr_entity_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PresenceSettingsData sse_decode_presence_settings_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessedImageData sse_decode_processed_image_data(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ReactionSummaryData sse_decode_reaction_summary_data(
     SseDeserializer deserializer,
@@ -891,11 +1303,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  (String, CachedMetadataData) sse_decode_record_string_cached_metadata_data(
+    SseDeserializer deserializer,
+  );
+
   @protected
   (String, String) sse_decode_record_string_string(
     SseDeserializer deserializer,
   );
 
+  @protected
+  (String, int) sse_decode_record_string_u_32(SseDeserializer deserializer);
+
   @protected
   SerializableTokenData sse_decode_serializable_token_data(
     SseDeserializer deserializer,
@@ -916,6 +1336,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
+  @protected
+  UploadedImageData sse_decode_uploaded_image_data(
+    SseDeserializer deserializer,
+  );
+
   @protected
   UserReactionData sse_decode_user_reaction_data(SseDeserializer deserializer);
 
@@ -933,6 +1358,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  WhitenoiseEvent sse_decode_whitenoise_event(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount(
     Account self,
@@ -1004,13 +1438,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhitenoiseError(
-    WhitenoiseError self,
-    SseSerializer serializer,
-  );
-
   @protected
   void
   sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -1108,6 +1535,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_Map_String_cached_metadata_data_None(
+    Map<String, CachedMetadataData> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Map_String_u_32_None(
+    Map<String, int> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccount(
     Account self,
@@ -1204,6 +1643,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_group_message_update_Sse(
+    RustStreamSink<GroupMessageUpdate> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_group_presence_update_Sse(
+    RustStreamSink<GroupPresenceUpdate> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_whitenoise_event_Sse(
+    RustStreamSink<WhitenoiseEvent> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
@@ -1216,6 +1673,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_blossom_server_list_data(
+    BlossomServerListData self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
@@ -1226,6 +1689,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+    GroupId self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -1233,15 +1703,78 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_delivery_status_data(
+    DeliveryStatusData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_image_processing_options(
+    ImageProcessingOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_message_cursor_data(
+    MessageCursorData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_nip05_profile_data(
+    Nip05ProfileData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_bridge_error(BridgeError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bridge_error_code(
+    BridgeErrorCode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cached_metadata_data(
+    CachedMetadataData self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_chat_message_data(
     ChatMessageData self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_chat_summary_data(
+    ChatSummaryData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_contact_search_result_data(
+    ContactSearchResultData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_delivery_state(DeliveryState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_delivery_status_data(
+    DeliveryStatusData self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_emoji_reaction_data(
     EmojiReactionData self,
@@ -1251,6 +1784,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_group_data(GroupData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_group_message_update(
+    GroupMessageUpdate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_group_presence_update(
+    GroupPresenceUpdate self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_group_state(GroupState self, SseSerializer serializer);
 
@@ -1260,6 +1805,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_image_processing_options(
+    ImageProcessingOptions self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPublicKey(
@@ -1295,6 +1846,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_chat_summary_data(
+    List<ChatSummaryData> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_contact_search_result_data(
+    List<ContactSearchResultData> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_emoji_reaction_data(
     List<EmojiReactionData> self,
@@ -1307,12 +1870,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_list_String(
+    List<List<String>> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_media_attachment_data(
+    List<MediaAttachmentData> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_message_version_data(
+    List<MessageVersionData> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_message_with_tokens_data(
     List<MessageWithTokensData> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_outbox_message_data(
+    List<OutboxMessageData> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -1326,12 +1913,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_record_string_cached_metadata_data(
+    List<(String, CachedMetadataData)> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_record_string_string(
     List<(String, String)> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_record_string_u_32(
+    List<(String, int)> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_serializable_token_data(
     List<SerializableTokenData> self,
@@ -1350,12 +1949,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_media_attachment_data(
+    MediaAttachmentData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_media_cache_stats_data(
+    MediaCacheStatsData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_message_cursor_data(
+    MessageCursorData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_message_page_data(
+    MessagePageData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_message_version_data(
+    MessageVersionData self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_message_with_tokens_data(
     MessageWithTokensData self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_nip05_profile_data(
+    Nip05ProfileData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_nostr_entity(NostrEntity self, SseSerializer serializer);
+
   @protected
   void sse_encode_onboarding_state(
     OnboardingState self,
@@ -1372,6 +2010,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGroupId(
+    GroupId? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMetadataData(
@@ -1379,6 +2024,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_delivery_status_data(
+    DeliveryStatusData? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_image_processing_options(
+    ImageProcessingOptions? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_message_cursor_data(
+    MessageCursorData? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_nip05_profile_data(
+    Nip05ProfileData? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
@@ -1389,6 +2061,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_outbox_message_data(
+    OutboxMessageData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_outbox_state(OutboxState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_parsed_nostr_entity_data(
+    ParsedNostrEntityData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_presence_settings_data(
+    PresenceSettingsData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_processed_image_data(
+    ProcessedImageData self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_reaction_summary_data(
     ReactionSummaryData self,
@@ -1402,12 +2101,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_record_string_cached_metadata_data(
+    (String, CachedMetadataData) self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_record_string_u_32(
+    (String, int) self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_serializable_token_data(
     SerializableTokenData self,
@@ -1429,6 +2140,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_uploaded_image_data(
+    UploadedImageData self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_user_reaction_data(
     UserReactionData self,
@@ -1449,6 +2166,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     WhitenoiseConfigData self,
     SseSerializer serializer,
   );
+
+  @protected
+  void sse_encode_whitenoise_event(
+    WhitenoiseEvent self,
+    SseSerializer serializer,
+  );
 }
 
 // Section: wire_class
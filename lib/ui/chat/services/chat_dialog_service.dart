@@ -135,8 +135,6 @@ class ChatDialogService {
                 chatNotifier.deleteMessage(
                   groupId: message.groupId ?? '',
                   messageId: message.id,
-                  messageKind: message.kind,
-                  messagePubkey: message.sender.publicKey,
                 );
               }
             },
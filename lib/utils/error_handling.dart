@@ -1,15 +1,14 @@
 import 'package:logging/logging.dart';
-import 'package:whitenoise/src/rust/api.dart';
-import 'package:whitenoise/src/rust/api/utils.dart';
+import 'package:whitenoise/src/rust/api/errors.dart';
 
-/// Utility class for handling WhitenoiseError conversion and providing user-friendly error messages
+/// Utility class for handling BridgeError conversion and providing user-friendly error messages
 class ErrorHandlingUtils {
   static final _logger = Logger('ErrorHandlingUtils');
 
   /// Attempts to convert any error (including WhitenoiseErrorImpl exceptions) to a user-friendly string
   ///
   /// This method handles:
-  /// - Direct WhitenoiseError objects
+  /// - Direct BridgeError objects
   /// - WhitenoiseErrorImpl wrapped in generic exceptions (flutter_rust_bridge issue)
   /// - Generic exceptions with custom error messages
   ///
@@ -26,18 +25,11 @@ class ErrorHandlingUtils {
     final logPrefix = context.isNotEmpty ? '$context: ' : '';
 
     try {
-      if (error is WhitenoiseError) {
-        try {
-          _logger.severe('${logPrefix}Converting WhitenoiseError to string...');
-          final rawErrorMessage = await whitenoiseErrorToString(error: error);
-          _logger.severe('${logPrefix}WhitenoiseError converted to: $rawErrorMessage');
-          return _parseSpecificErrorPatterns(rawErrorMessage);
-        } catch (conversionError) {
-          _logger.severe(
-            '${logPrefix}Failed to convert WhitenoiseError to string: $conversionError',
-          );
-          return fallbackMessage;
-        }
+      if (error is BridgeError) {
+        _logger.severe(
+          '${logPrefix}BridgeError ${error.code.name}: ${error.message} (details: ${error.details})',
+        );
+        return _messageForBridgeError(error);
       } else if (error is Exception) {
         return _handleWrappedException(
           error: error,
@@ -130,27 +122,21 @@ class ErrorHandlingUtils {
     }
   }
 
-  /// Parses specific error patterns from converted WhitenoiseError strings
-  static String _parseSpecificErrorPatterns(String rawErrorMessage) {
-    try {
-      if (rawErrorMessage.contains('KeyPackage') && rawErrorMessage.contains('Does not exist')) {
-        return '$rawErrorMessage\n\n${_getKeyPackageHelpText()}';
-      } else if (rawErrorMessage.contains('Network') || rawErrorMessage.contains('Connection')) {
-        return '$rawErrorMessage\n\nThis appears to be a network connectivity issue. Please check your internet connection and try again.';
-      } else if (rawErrorMessage.contains('Permission') ||
-          rawErrorMessage.contains('Unauthorized')) {
-        return '$rawErrorMessage\n\nThis appears to be a permission issue. You may not have permission to perform this operation.';
-      } else if (rawErrorMessage.contains('Relay')) {
-        return '$rawErrorMessage\n\nUnable to connect to Nostr relays. Please check your network connection.';
-      } else if (rawErrorMessage.contains('Database') || rawErrorMessage.contains('Storage')) {
-        return '$rawErrorMessage\n\nThere was an issue with local data storage. Please restart the app.';
-      } else {
-        // Return the raw error message as-is for unknown error types
-        return rawErrorMessage;
-      }
-    } catch (_) {
-      // If anything goes wrong in pattern parsing, just return the raw message
-      return rawErrorMessage;
+  /// Builds a user-facing message for a [BridgeError], adding help text based on its code
+  static String _messageForBridgeError(BridgeError error) {
+    switch (error.code) {
+      case BridgeErrorCode.noKeyPackage:
+        return '${error.message}\n\n${_getKeyPackageHelpText()}';
+      case BridgeErrorCode.network:
+        return '${error.message}\n\nThis appears to be a network connectivity issue. Please check your internet connection and try again.';
+      case BridgeErrorCode.relayUnreachable:
+        return '${error.message}\n\nUnable to connect to Nostr relays. Please check your network connection.';
+      case BridgeErrorCode.unauthorized:
+        return '${error.message}\n\nThis appears to be a permission issue. You may not have permission to perform this operation.';
+      case BridgeErrorCode.storage:
+        return '${error.message}\n\nThere was an issue with local data storage. Please restart the app.';
+      default:
+        return error.message;
     }
   }
 
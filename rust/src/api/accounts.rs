@@ -1,12 +1,23 @@
+use crate::api::blossom::upload_to_blossom_servers;
+use crate::api::chats::clear_chat_list_caches;
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use crate::api::events::refresh_events;
+use crate::api::groups::clear_epoch_members;
+use crate::api::images::{process_image_blocking, remove_processed_files, ImageProcessingOptions};
+use crate::api::media::image_mime_type;
+use crate::api::metadata_cache::{cache_metadata, persist_memory_cache};
 use crate::api::utils::{
-    convert_metadata_data_to_metadata, convert_metadata_to_data, MetadataData,
+    convert_metadata_data_to_metadata, convert_metadata_to_data, detect_image_type,
+    same_image_format, MetadataData,
 };
 use flutter_rust_bridge::frb;
-use url::Url;
 pub use whitenoise::{
     Account, AccountSettings, ImageType, OnboardingState, PublicKey, Whitenoise, WhitenoiseError,
 };
 
+/// Longest side, in pixels, profile pictures are scaled down to by default.
+const PROFILE_PICTURE_MAX_DIMENSION: u32 = 1024;
+
 #[derive(Debug, Clone)]
 pub struct AccountData {
     pub pubkey: String,
@@ -60,14 +71,14 @@ pub fn convert_account_to_data(account: &Account) -> AccountData {
 /// Flutter-compatible AccountData structure for use in the UI.
 ///
 /// # Returns
-/// * `Result<Vec<AccountData>, WhitenoiseError>` - A vector of all available accounts,
+/// * `Result<Vec<AccountData>, BridgeError>` - A vector of all available accounts,
 ///   or an error if the operation fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the Whitenoise instance cannot be accessed or if
+/// * Returns `BridgeError` if the Whitenoise instance cannot be accessed or if
 ///   there's an issue fetching the accounts
 #[frb]
-pub async fn fetch_accounts() -> Result<Vec<AccountData>, WhitenoiseError> {
+pub async fn fetch_accounts() -> Result<Vec<AccountData>, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let accounts = whitenoise.fetch_accounts().await?;
     Ok(accounts.values().map(convert_account_to_data).collect())
@@ -82,14 +93,14 @@ pub async fn fetch_accounts() -> Result<Vec<AccountData>, WhitenoiseError> {
 /// * `pubkey` - The public key of the account to fetch
 ///
 /// # Returns
-/// * `Result<AccountData, WhitenoiseError>` - The account data for the specified
+/// * `Result<AccountData, BridgeError>` - The account data for the specified
 ///   public key, or an error if the operation fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the account doesn't exist, the Whitenoise instance
+/// * Returns `BridgeError` if the account doesn't exist, the Whitenoise instance
 ///   cannot be accessed, or if there's an issue fetching the account
 #[frb]
-pub async fn fetch_account(pubkey: &PublicKey) -> Result<AccountData, WhitenoiseError> {
+pub async fn fetch_account(pubkey: &PublicKey) -> Result<AccountData, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let account = whitenoise.fetch_account(pubkey).await?;
     Ok(convert_account_to_data(&account))
@@ -102,16 +113,18 @@ pub async fn fetch_account(pubkey: &PublicKey) -> Result<AccountData, Whitenoise
 /// be ready for participation in secure group conversations.
 ///
 /// # Returns
-/// * `Result<Account, WhitenoiseError>` - The newly created account with full MLS capabilities,
+/// * `Result<Account, BridgeError>` - The newly created account with full MLS capabilities,
 ///   or an error if the creation process fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if there's an issue with key generation, MLS setup,
+/// * Returns `BridgeError` if there's an issue with key generation, MLS setup,
 ///   or if the Whitenoise instance cannot be accessed
 #[frb]
-pub async fn create_identity() -> Result<Account, WhitenoiseError> {
+pub async fn create_identity() -> Result<Account, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
-    whitenoise.create_identity().await
+    let account = whitenoise.create_identity().await?;
+    refresh_events();
+    Ok(account)
 }
 
 /// Authenticates and logs in a user account using their private key.
@@ -124,16 +137,28 @@ pub async fn create_identity() -> Result<Account, WhitenoiseError> {
 /// * `nsec_or_hex_privkey` - The private key in either nsec (bech32) format or hexadecimal format
 ///
 /// # Returns
-/// * `Result<Account, WhitenoiseError>` - The successfully logged-in account,
+/// * `Result<Account, BridgeError>` - The successfully logged-in account,
 ///   or an error if authentication fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the private key is invalid, malformed, or if there's
-///   an issue with the login process
+/// * Returns `InvalidInput` if the private key is invalid or malformed, or another
+///   `BridgeError` if there's an issue with the login process
 #[frb]
-pub async fn login(nsec_or_hex_privkey: String) -> Result<Account, WhitenoiseError> {
+pub async fn login(nsec_or_hex_privkey: String) -> Result<Account, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
-    whitenoise.login(nsec_or_hex_privkey).await
+    let account = whitenoise
+        .login(nsec_or_hex_privkey)
+        .await
+        .map_err(|error| match error {
+            // The only key parsed here is the private key
+            WhitenoiseError::NostrKey(_) => {
+                BridgeError::new(BridgeErrorCode::InvalidInput, "Invalid private key")
+                    .with_details(error)
+            }
+            error => error.into(),
+        })?;
+    refresh_events();
+    Ok(account)
 }
 
 /// Logs out an account identified by its public key.
@@ -146,15 +171,19 @@ pub async fn login(nsec_or_hex_privkey: String) -> Result<Account, WhitenoiseErr
 /// * `pubkey` - The public key of the account to log out
 ///
 /// # Returns
-/// * `Result<(), WhitenoiseError>` - Success (empty result) or an error if logout fails
+/// * `Result<(), BridgeError>` - Success (empty result) or an error if logout fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the account doesn't exist, is not currently logged in,
+/// * Returns `BridgeError` if the account doesn't exist, is not currently logged in,
 ///   or if there's an issue with the logout process
 #[frb]
-pub async fn logout(pubkey: &PublicKey) -> Result<(), WhitenoiseError> {
+pub async fn logout(pubkey: &PublicKey) -> Result<(), BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
-    whitenoise.logout(pubkey).await
+    whitenoise.logout(pubkey).await?;
+    clear_chat_list_caches(Some(pubkey));
+    clear_epoch_members(Some(pubkey));
+    refresh_events();
+    Ok(())
 }
 
 /// Exports an account's private key in nsec (Nostr secret key) format.
@@ -167,20 +196,20 @@ pub async fn logout(pubkey: &PublicKey) -> Result<(), WhitenoiseError> {
 /// * `pubkey` - The public key of the account whose private key should be exported
 ///
 /// # Returns
-/// * `Result<String, WhitenoiseError>` - The private key in nsec format,
+/// * `Result<String, BridgeError>` - The private key in nsec format,
 ///   or an error if the export fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the account doesn't exist, cannot be accessed,
+/// * Returns `BridgeError` if the account doesn't exist, cannot be accessed,
 ///   or if there's an issue with the key export process
 ///
 /// # Security Note
 /// The exported private key should be handled securely and never exposed in logs or UI
 #[frb]
-pub async fn export_account_nsec(pubkey: &PublicKey) -> Result<String, WhitenoiseError> {
+pub async fn export_account_nsec(pubkey: &PublicKey) -> Result<String, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let account = whitenoise.fetch_account(pubkey).await?;
-    whitenoise.export_account_nsec(&account).await
+    Ok(whitenoise.export_account_nsec(&account).await?)
 }
 
 /// Exports an account's public key in npub (Nostr public key) format.
@@ -193,17 +222,17 @@ pub async fn export_account_nsec(pubkey: &PublicKey) -> Result<String, Whitenois
 /// * `pubkey` - The public key of the account whose npub should be exported
 ///
 /// # Returns
-/// * `Result<String, WhitenoiseError>` - The public key in npub format,
+/// * `Result<String, BridgeError>` - The public key in npub format,
 ///   or an error if the export fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the account doesn't exist, cannot be accessed,
+/// * Returns `BridgeError` if the account doesn't exist, cannot be accessed,
 ///   or if there's an issue with the key export process
 #[frb]
-pub async fn export_account_npub(pubkey: &PublicKey) -> Result<String, WhitenoiseError> {
+pub async fn export_account_npub(pubkey: &PublicKey) -> Result<String, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let account = whitenoise.fetch_account(pubkey).await?;
-    whitenoise.export_account_npub(&account).await
+    Ok(whitenoise.export_account_npub(&account).await?)
 }
 
 /// Retrieves metadata information for a specific account.
@@ -216,14 +245,14 @@ pub async fn export_account_npub(pubkey: &PublicKey) -> Result<String, Whitenois
 /// * `pubkey` - The public key of the account whose metadata should be fetched
 ///
 /// # Returns
-/// * `Result<Option<MetadataData>, WhitenoiseError>` - The account's metadata if it exists,
+/// * `Result<Option<MetadataData>, BridgeError>` - The account's metadata if it exists,
 ///   None if no metadata is found, or an error if the operation fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if there's an issue accessing the Whitenoise instance
+/// * Returns `BridgeError` if there's an issue accessing the Whitenoise instance
 ///   or fetching the metadata from the network
 #[frb]
-pub async fn fetch_metadata(pubkey: PublicKey) -> Result<Option<MetadataData>, WhitenoiseError> {
+pub async fn fetch_metadata(pubkey: PublicKey) -> Result<Option<MetadataData>, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let metadata = whitenoise.fetch_metadata(pubkey).await?;
     Ok(metadata.map(|m| convert_metadata_to_data(&m)))
@@ -240,20 +269,28 @@ pub async fn fetch_metadata(pubkey: PublicKey) -> Result<Option<MetadataData>, W
 /// * `pubkey` - The public key of the account whose metadata should be updated
 ///
 /// # Returns
-/// * `Result<(), WhitenoiseError>` - Success (empty result) or an error if the update fails
+/// * `Result<(), BridgeError>` - Success (empty result) or an error if the update fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the account doesn't exist, cannot be accessed,
-///   or if there's an issue publishing the metadata to the network
+/// * Returns `BridgeError` if the account doesn't exist, cannot be accessed,
+///   or if there's an issue publishing the metadata to the network. Failing to update
+///   the local metadata cache afterwards is not an error
 #[frb]
 pub async fn update_metadata(
     metadata: &MetadataData,
     pubkey: &PublicKey,
-) -> Result<(), WhitenoiseError> {
+) -> Result<(), BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     // Convert MetadataData back to Metadata for the whitenoise API
     let metadata_to_save = convert_metadata_data_to_metadata(metadata);
-    whitenoise.update_metadata(&metadata_to_save, pubkey).await
+    whitenoise
+        .update_metadata(&metadata_to_save, pubkey)
+        .await?;
+    // The metadata is published at this point, so a cache that cannot be written must not
+    // turn the update into an error; `cache_metadata` announces the change either way
+    let _ = cache_metadata(&pubkey.to_hex(), Some(metadata.clone())).await;
+    let _ = persist_memory_cache().await;
+    Ok(())
 }
 
 /// Retrieves the onboarding state for a specific account.
@@ -266,51 +303,90 @@ pub async fn update_metadata(
 /// * `pubkey` - The public key of the account whose onboarding state should be fetched
 ///
 /// # Returns
-/// * `Result<OnboardingState, WhitenoiseError>` - The current onboarding state,
+/// * `Result<OnboardingState, BridgeError>` - The current onboarding state,
 ///   or an error if the operation fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the account doesn't exist or there's an issue
+/// * Returns `BridgeError` if the account doesn't exist or there's an issue
 ///   accessing the account's onboarding information
 #[frb]
-pub async fn fetch_onboarding_state(pubkey: PublicKey) -> Result<OnboardingState, WhitenoiseError> {
+pub async fn fetch_onboarding_state(pubkey: PublicKey) -> Result<OnboardingState, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
-    whitenoise.fetch_onboarding_state(pubkey).await
+    Ok(whitenoise.fetch_onboarding_state(pubkey).await?)
 }
 
-/// Uploads a profile picture for a specific account to a media server.
+/// Uploads a profile picture for a specific account to its Blossom servers.
+///
+/// This function takes a local image file and uploads it to the account's Blossom servers
+/// (see `fetch_blossom_servers`), making it available as a profile picture for the
+/// account. Servers are tried in order, and the upload is mirrored when the account has
+/// mirroring enabled. The function supports different image types and returns the URL
+/// where the uploaded image can be accessed.
 ///
-/// This function takes a local image file and uploads it to the specified media server,
-/// making it available as a profile picture for the account. The function supports
-/// different image types and returns the URL where the uploaded image can be accessed.
+/// The image is run through `process_image` before upload, so metadata such as the GPS
+/// location is stripped and large photos are scaled down. Only the processed copy is
+/// uploaded; the original file is left untouched.
 ///
 /// # Parameters
 /// * `pubkey` - The public key of the account whose profile picture should be updated
-/// * `server_url` - The URL string of the media server where the image will be uploaded
 /// * `file_path` - The local path to the image file that should be uploaded
 /// * `image_type` - The type of image being uploaded (e.g., avatar, banner)
+/// * `options` - Processing options, or `None` to scale the image down to 1024 px and
+///   keep its format
 ///
 /// # Returns
-/// * `Result<String, WhitenoiseError>` - The URL of the uploaded image if successful,
+/// * `Result<String, BridgeError>` - The URL of the uploaded image if successful,
 ///   or an error if the upload fails
 ///
 /// # Errors
-/// * Returns `WhitenoiseError` if the file cannot be read, the server is unreachable,
-///   the upload fails, the URL is invalid, or if there's an issue with the account access
+/// * Returns `BridgeError` if the file cannot be read, no server accepts the upload, or
+///   if there's an issue with the account access
+/// * Returns `InvalidInput` if `image_type` does not match the file contents (see
+///   `detect_image_type`), or `Unsupported` if the file is not a supported image
 #[frb]
 pub async fn upload_profile_picture(
     pubkey: PublicKey,
-    server_url: String,
     file_path: &str,
     image_type: ImageType,
-) -> Result<String, WhitenoiseError> {
-    let whitenoise = Whitenoise::get_instance()?;
+    options: Option<ImageProcessingOptions>,
+) -> Result<String, BridgeError> {
+    let detected = detect_image_type(file_path.to_string())?;
+    if !same_image_format(detected, image_type) {
+        return Err(BridgeError::new(
+            BridgeErrorCode::InvalidInput,
+            format!(
+                "The image is declared as {} but the file contains {}",
+                image_mime_type(image_type),
+                image_mime_type(detected)
+            ),
+        ));
+    }
 
-    // Parse the server URL string into a Url
-    let server =
-        Url::parse(&server_url).map_err(|e| WhitenoiseError::from(std::io::Error::other(e)))?;
+    let options = options.unwrap_or(ImageProcessingOptions {
+        max_dimension: PROFILE_PICTURE_MAX_DIMENSION,
+        thumbnail_dimension: 0,
+        ..Default::default()
+    });
+    let converted = options.convert_to_webp;
+    let file_path = file_path.to_string();
+    let processed =
+        tokio::task::spawn_blocking(move || process_image_blocking(&file_path, &options)).await??;
+    // Converting changes the format the image is uploaded as
+    let image_type = if converted {
+        processed.image_type
+    } else {
+        image_type
+    };
 
-    whitenoise
-        .upload_profile_picture(pubkey, server, file_path, image_type)
-        .await
+    let blob = std::fs::read(&processed.file_path);
+    remove_processed_files(&processed);
+    let blob = blob.map_err(|e| {
+        BridgeError::new(
+            BridgeErrorCode::Storage,
+            "Could not read the processed image",
+        )
+        .with_details(e)
+    })?;
+    let upload = upload_to_blossom_servers(pubkey, &blob, image_type).await?;
+    Ok(upload.url)
 }
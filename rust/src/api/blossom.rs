@@ -0,0 +1,278 @@
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use crate::api::media::upload_blob;
+use crate::api::messages::tag_value;
+use crate::api::utils::{get_default_blossom_server_url, tag_from_vec};
+use crate::relay_client::{fetch_events, publish_event};
+use crate::signer::AccountSigner;
+use crate::store;
+use flutter_rust_bridge::frb;
+use nostr_sdk::{EventBuilder, Filter, Kind};
+use serde::{Deserialize, Serialize};
+use url::Url;
+pub use whitenoise::{Event, ImageType, PublicKey, RelayType, Whitenoise};
+
+/// The Blossom servers an account uploads media to (BUD-03).
+///
+/// Servers are tried in order: the first one that accepts an upload hosts the file. With
+/// `mirror_uploads` set, the file is also uploaded to every other server in the list, and
+/// those copies are used as fallbacks when downloading.
+#[derive(Debug, Clone, Default)]
+pub struct BlossomServerListData {
+    pub servers: Vec<String>,
+    pub mirror_uploads: bool,
+}
+
+/// A blob uploaded by `upload_to_blossom_servers`.
+#[derive(Debug, Clone)]
+pub(crate) struct BlossomUpload {
+    pub(crate) url: String,
+    pub(crate) mirror_urls: Vec<String>,
+}
+
+/// Name of the per-account store document holding the Blossom server list.
+const BLOSSOM_SERVERS_DOCUMENT: &str = "blossom_servers";
+
+/// Event kind of a user's Blossom server list (BUD-03).
+const KIND_BLOSSOM_SERVER_LIST: u16 = 10063;
+
+/// The Blossom server list as kept on this device.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct StoredServerList {
+    servers: Vec<String>,
+    mirror_uploads: bool, // Local preference, not part of the published list
+    #[serde(default)]
+    published_at: u64, // created_at of the kind-10063 event the servers come from
+}
+
+async fn load_stored_list(pubkey: &PublicKey) -> Result<StoredServerList, BridgeError> {
+    store::load(BLOSSOM_SERVERS_DOCUMENT, Some(&pubkey.to_hex())).await
+}
+
+async fn save_stored_list(pubkey: &PublicKey, list: StoredServerList) -> Result<(), BridgeError> {
+    store::update(
+        BLOSSOM_SERVERS_DOCUMENT,
+        Some(&pubkey.to_hex()),
+        |current: &mut StoredServerList| *current = list,
+    )
+    .await
+}
+
+/// Returns the account's Blossom server list, falling back to the default server when
+/// the account has not configured any.
+async fn load_server_list(pubkey: &PublicKey) -> Result<BlossomServerListData, BridgeError> {
+    let stored = load_stored_list(pubkey).await?;
+    let mut list = BlossomServerListData {
+        servers: stored.servers,
+        mirror_uploads: stored.mirror_uploads,
+    };
+    if list.servers.is_empty() {
+        list.servers.push(get_default_blossom_server_url());
+    }
+    Ok(list)
+}
+
+/// Validates server URLs, dropping duplicates and keeping the first occurrence.
+fn normalize_server_urls<'a>(
+    server_urls: impl IntoIterator<Item = &'a str>,
+) -> Result<Vec<String>, BridgeError> {
+    let mut servers: Vec<String> = Vec::new();
+    for server_url in server_urls {
+        let server_url = normalize_server_url(server_url)?;
+        if !servers.contains(&server_url) {
+            servers.push(server_url);
+        }
+    }
+    Ok(servers)
+}
+
+/// Reads the servers of a kind-10063 event, in order, skipping invalid URLs.
+fn servers_from_event(event: &Event) -> Vec<String> {
+    let valid = event
+        .tags
+        .iter()
+        .filter_map(|tag| tag_value(tag, "server"))
+        .filter(|server_url| normalize_server_url(server_url).is_ok());
+    normalize_server_urls(valid).unwrap_or_default()
+}
+
+/// Returns the relays the account's server list is published to and read from.
+async fn server_list_relays(pubkey: &PublicKey) -> Result<Vec<whitenoise::RelayUrl>, BridgeError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    Ok(whitenoise.fetch_relays(*pubkey, RelayType::Nostr).await?)
+}
+
+/// Parses a Blossom server URL and returns it without a trailing slash.
+fn normalize_server_url(server_url: &str) -> Result<String, BridgeError> {
+    let url = Url::parse(server_url.trim()).map_err(|e| {
+        BridgeError::new(BridgeErrorCode::InvalidInput, "Invalid media server URL").with_details(e)
+    })?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(BridgeError::new(
+            BridgeErrorCode::InvalidInput,
+            format!("Media server URL must use http or https: {server_url}"),
+        ));
+    }
+    Ok(url.as_str().trim_end_matches('/').to_string())
+}
+
+/// Uploads a blob to the account's Blossom servers.
+///
+/// Servers are tried in order until one accepts the blob. If mirroring is enabled, the
+/// blob is then uploaded to the remaining servers as well; mirrors that fail are skipped.
+pub(crate) async fn upload_to_blossom_servers(
+    pubkey: PublicKey,
+    blob: &[u8],
+    image_type: ImageType,
+) -> Result<BlossomUpload, BridgeError> {
+    let list = load_server_list(&pubkey).await?;
+    let mut servers = list.servers.iter();
+
+    let mut last_error = None;
+    let mut url = None;
+    for server_url in servers.by_ref() {
+        match upload_blob(pubkey, server_url, blob, image_type).await {
+            Ok(uploaded) => {
+                url = Some(uploaded);
+                break;
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+    let Some(url) = url else {
+        return Err(last_error.unwrap_or_else(|| {
+            BridgeError::new(BridgeErrorCode::Configuration, "No media server configured")
+        }));
+    };
+
+    let mut mirror_urls = Vec::new();
+    if list.mirror_uploads {
+        for server_url in servers {
+            if let Ok(mirror_url) = upload_blob(pubkey, server_url, blob, image_type).await {
+                mirror_urls.push(mirror_url);
+            }
+        }
+    }
+    Ok(BlossomUpload { url, mirror_urls })
+}
+
+/// Fetches the Blossom servers an account uploads media to.
+///
+/// The list is read from the account's latest kind-10063 event (BUD-03) on its relays,
+/// so changes made by other clients are picked up, and kept on this device for uploads.
+/// When the relays cannot be reached, the list last seen on this device is returned.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+///
+/// # Returns
+/// * `Ok(BlossomServerListData)` - The servers in order of preference; if the account has
+///   not configured any, the list contains only `get_default_blossom_server_url()`
+/// * `Err(BridgeError)` - If the list could not be read
+#[frb]
+pub async fn fetch_blossom_servers(
+    pubkey: &PublicKey,
+) -> Result<BlossomServerListData, BridgeError> {
+    let filter = Filter::new()
+        .kind(Kind::from_u16(KIND_BLOSSOM_SERVER_LIST))
+        .author(*pubkey)
+        .limit(1);
+    let events = match server_list_relays(pubkey).await {
+        Ok(relays) => fetch_events(&relays, filter).await.unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+    let latest = events
+        .iter()
+        .filter(|event| event.pubkey == *pubkey)
+        .max_by_key(|event| event.created_at);
+
+    let stored = load_stored_list(pubkey).await?;
+    if let Some(event) = latest {
+        if event.created_at.as_u64() > stored.published_at {
+            save_stored_list(
+                pubkey,
+                StoredServerList {
+                    servers: servers_from_event(event),
+                    mirror_uploads: stored.mirror_uploads,
+                    published_at: event.created_at.as_u64(),
+                },
+            )
+            .await?;
+        }
+    }
+    load_server_list(pubkey).await
+}
+
+/// Updates the Blossom servers an account uploads media to.
+///
+/// The list is published as a kind-10063 event (BUD-03) to the account's relays, then
+/// kept on this device. URLs are validated and normalized (trailing slashes removed);
+/// duplicates are dropped, keeping the first occurrence. An empty list resets the account
+/// to the default server.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `server_list` - The servers in order of preference, and whether to mirror uploads
+///
+/// # Returns
+/// * `Ok(BlossomServerListData)` - The list as saved
+/// * `Err(BridgeError)` - `InvalidInput` if a URL is not a valid http(s) URL, a network
+///   error if no relay accepted the list (nothing is changed then), or a `Storage` error
+///
+/// # Notes
+/// * `mirror_uploads` is a preference of this device and is not published
+#[frb]
+pub async fn update_blossom_servers(
+    pubkey: &PublicKey,
+    server_list: BlossomServerListData,
+) -> Result<BlossomServerListData, BridgeError> {
+    let servers = normalize_server_urls(server_list.servers.iter().map(String::as_str))?;
+
+    let tags = servers
+        .iter()
+        .map(|server_url| tag_from_vec(vec!["server".to_string(), server_url.clone()]))
+        .collect::<Result<Vec<_>, _>>()?;
+    let event = AccountSigner::load(pubkey)
+        .await?
+        .sign(EventBuilder::new(Kind::from_u16(KIND_BLOSSOM_SERVER_LIST), "").tags(tags))?;
+    publish_event(&server_list_relays(pubkey).await?, &event).await?;
+
+    save_stored_list(
+        pubkey,
+        StoredServerList {
+            servers,
+            mirror_uploads: server_list.mirror_uploads,
+            published_at: event.created_at.as_u64(),
+        },
+    )
+    .await?;
+    load_server_list(pubkey).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn server_urls_are_normalized_and_deduplicated() {
+        let servers = normalize_server_urls([
+            "https://blossom.example.com/",
+            " https://cdn.example.org ",
+            "https://blossom.example.com",
+        ])
+        .unwrap();
+        assert_eq!(
+            servers,
+            vec![
+                "https://blossom.example.com".to_string(),
+                "https://cdn.example.org".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn non_http_server_urls_are_rejected() {
+        let error = normalize_server_urls(["wss://relay.example.com"]).unwrap_err();
+        assert_eq!(error.code, BridgeErrorCode::InvalidInput);
+        assert!(normalize_server_urls(["not a url"]).is_err());
+    }
+}
@@ -0,0 +1,427 @@
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use crate::api::groups::{convert_group_to_data, epoch_members, group_id_to_string, GroupData};
+use crate::api::messages::{load_chat_messages, ChatMessageData, KIND_CHAT_MESSAGE};
+use crate::api::metadata_cache::{lookup_metadata, persist_memory_cache};
+use crate::api::utils::MetadataData;
+use crate::store;
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+pub use whitenoise::{Account, Group, GroupId, GroupType, PublicKey, Whitenoise};
+
+/// Maximum number of characters of the last message shown in the chat list.
+const PREVIEW_MAX_CHARS: usize = 100;
+
+/// Name of the per-account store document holding read markers.
+const READ_MARKERS_DOCUMENT: &str = "read_markers";
+
+/// The last message an account has read in a group.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ReadMarker {
+    message_id: String,
+    created_at: u64,
+}
+
+/// Read markers of an account, keyed by MLS group ID (hex).
+type ReadMarkers = HashMap<String, ReadMarker>;
+
+/// Everything the chat list needs to render one group.
+#[derive(Debug, Clone)]
+pub struct ChatSummaryData {
+    pub group: GroupData,
+    pub last_message_id: Option<String>,
+    pub last_message_preview: Option<String>, // Truncated to 100 characters
+    pub last_message_author: Option<String>,
+    pub last_activity_at: Option<u64>,
+    pub unread_count: u32,
+    pub member_count: u32,
+    pub dm_counterpart_pubkey: Option<String>, // Only set for direct messages
+    pub dm_counterpart_metadata: Option<MetadataData>,
+}
+
+/// Returns a short single-line preview of a message.
+fn message_preview(message: &ChatMessageData) -> String {
+    let line = message
+        .content
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    match line.char_indices().nth(PREVIEW_MAX_CHARS) {
+        Some((end, _)) => format!("{}…", &line[..end]),
+        None => line,
+    }
+}
+
+/// A visible chat message, reduced to what unread counts need.
+#[derive(Debug, Clone)]
+struct MessageStamp {
+    id: String,
+    pubkey: String,
+    created_at: u64,
+}
+
+/// What the chat list keeps of a group's messages between calls.
+#[derive(Debug, Clone, Default)]
+struct GroupActivity {
+    /// The group's last message ID and time when the messages were loaded
+    version: (Option<String>, Option<u64>),
+    last_message: Option<ChatMessageData>,
+    /// Visible messages in chronological order
+    stamps: Vec<MessageStamp>,
+}
+
+/// Identifies a group of an account: (account public key, MLS group ID), both hex.
+type GroupKey = (String, String);
+
+static GROUP_ACTIVITY: OnceLock<Mutex<HashMap<GroupKey, GroupActivity>>> = OnceLock::new();
+
+/// Forgets the cached messages of a group for every account, so the chat list loads them
+/// again. Edits and deletions do not change the group's last message, which is all the
+/// cache is checked against, so whoever learns of new events in a group calls this.
+pub(crate) fn forget_group_activity(group_id: &GroupId) {
+    let group_id = group_id_to_string(group_id);
+    if let Some(cache) = GROUP_ACTIVITY.get() {
+        cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|(_, cached_group_id), _| *cached_group_id != group_id);
+    }
+}
+
+/// Clears the cached chat list activity of an account (on logout), or of all accounts.
+pub(crate) fn clear_chat_list_caches(pubkey: Option<&PublicKey>) {
+    let pubkey = pubkey.map(PublicKey::to_hex);
+    if let Some(cache) = GROUP_ACTIVITY.get() {
+        cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|(account, _), _| pubkey.as_ref().is_some_and(|pubkey| account != pubkey));
+    }
+}
+
+/// Returns the visible chat messages of a group in chronological order.
+async fn load_visible_messages(
+    pubkey: &PublicKey,
+    group_id: &GroupId,
+) -> Result<Vec<ChatMessageData>, BridgeError> {
+    let mut messages: Vec<ChatMessageData> = load_chat_messages(pubkey, group_id)
+        .await?
+        .into_iter()
+        .filter(|message| message.kind == KIND_CHAT_MESSAGE && !message.is_deleted)
+        .collect();
+    messages.sort_by(|a, b| (a.created_at, &a.id).cmp(&(b.created_at, &b.id)));
+    Ok(messages)
+}
+
+/// Returns the message activity of a group.
+///
+/// The core records the last message of every group, so the group's messages are only
+/// loaded again once that changes; listing many quiet groups costs no message queries.
+async fn group_activity(pubkey: &PublicKey, group: &Group) -> Result<GroupActivity, BridgeError> {
+    let key = (pubkey.to_hex(), group_id_to_string(&group.mls_group_id));
+    let version = (
+        group.last_message_id.map(|id| id.to_hex()),
+        group.last_message_at.map(|at| at.as_u64()),
+    );
+    let cache = GROUP_ACTIVITY.get_or_init(Default::default);
+    if let Some(activity) = cache.lock().unwrap_or_else(|e| e.into_inner()).get(&key) {
+        if activity.version == version {
+            return Ok(activity.clone());
+        }
+    }
+
+    let messages = load_visible_messages(pubkey, &group.mls_group_id).await?;
+    let activity = GroupActivity {
+        version,
+        stamps: messages
+            .iter()
+            .map(|message| MessageStamp {
+                id: message.id.clone(),
+                pubkey: message.pubkey.clone(),
+                created_at: message.created_at,
+            })
+            .collect(),
+        last_message: messages.into_iter().next_back(),
+    };
+    cache
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(key, activity.clone());
+    Ok(activity)
+}
+
+/// Counts messages from other members after the read marker.
+///
+/// Messages are the visible chat messages in chronological order. Without a read marker
+/// (groups that have never been marked read), messages since the account's own latest
+/// message count as unread.
+fn count_unread(pubkey: &str, marker: Option<&ReadMarker>, messages: &[MessageStamp]) -> u32 {
+    let newest_first = messages.iter().rev();
+    match marker {
+        Some(marker) => newest_first
+            .take_while(|message| {
+                (message.created_at, message.id.as_str())
+                    > (marker.created_at, marker.message_id.as_str())
+            })
+            .filter(|message| message.pubkey != pubkey)
+            .count() as u32,
+        None => newest_first
+            .take_while(|message| message.pubkey != pubkey)
+            .count() as u32,
+    }
+}
+
+/// Fetches the chat list of an account.
+///
+/// Returns one summary per active group, with the last message preview, unread count,
+/// member count and, for direct messages, the other participant's public key and
+/// metadata. Everything is computed in a single call so Flutter does not have to query
+/// each group separately.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account whose chat list to fetch
+///
+/// # Returns
+/// * `Ok(Vec<ChatSummaryData>)` - One summary per group, most recent activity first
+/// * `Err(BridgeError)` - If the groups or their messages could not be loaded
+///
+/// # Notes
+/// * Unread counts are based on the read markers set with `mark_group_read`
+/// * Groups without messages are ordered last
+/// * Messages and members are only loaded again for groups whose last message or epoch
+///   changed since the previous call, or in which the bridge sent or saw (in an open
+///   message stream or event stream) an event that may edit or delete a message
+/// * Direct message metadata comes from the metadata cache; it is `None` if it was never
+///   fetched and cannot be fetched now
+#[frb]
+pub async fn fetch_chat_list(pubkey: &PublicKey) -> Result<Vec<ChatSummaryData>, BridgeError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let account = whitenoise.fetch_account(pubkey).await?;
+    let account_pubkey = pubkey.to_hex();
+    let read_markers: ReadMarkers =
+        store::load(READ_MARKERS_DOCUMENT, Some(&account_pubkey)).await?;
+
+    let mut summaries = Vec::new();
+    let mut looked_up_metadata = false;
+    for group in whitenoise.fetch_groups(&account, true).await? {
+        let members = epoch_members(whitenoise, &account, &group).await?;
+        let activity = group_activity(pubkey, &group).await?;
+        let last_message = activity.last_message.as_ref();
+        let marker = read_markers.get(&group_id_to_string(&group.mls_group_id));
+
+        let dm_counterpart = match group.group_type {
+            GroupType::DirectMessage => members.iter().find(|member| *member != pubkey).copied(),
+            GroupType::Group => None,
+        };
+        // Missing metadata only costs the chat list a display name, so failures are ignored
+        let dm_counterpart_metadata = match dm_counterpart {
+            Some(counterpart) => {
+                looked_up_metadata = true;
+                lookup_metadata(whitenoise, counterpart, false)
+                    .await
+                    .ok()
+                    .and_then(|cached| cached.metadata)
+            }
+            None => None,
+        };
+
+        summaries.push(ChatSummaryData {
+            last_message_id: last_message.map(|message| message.id.clone()),
+            last_message_preview: last_message.map(message_preview),
+            last_message_author: last_message.map(|message| message.pubkey.clone()),
+            last_activity_at: last_message
+                .map(|message| message.created_at)
+                .or(group.last_message_at.map(|at| at.as_u64())),
+            unread_count: count_unread(&account_pubkey, marker, &activity.stamps),
+            member_count: members.len() as u32,
+            dm_counterpart_pubkey: dm_counterpart.map(|counterpart| counterpart.to_hex()),
+            dm_counterpart_metadata,
+            group: convert_group_to_data(&group),
+        });
+    }
+    if looked_up_metadata {
+        let _ = persist_memory_cache().await;
+    }
+
+    summaries.sort_by(|a, b| b.last_activity_at.cmp(&a.last_activity_at));
+    Ok(summaries)
+}
+
+/// Marks a group as read up to a message.
+///
+/// The read marker is persisted per account, so unread counts survive restarts and are
+/// the same in `fetch_chat_list` and `fetch_unread_counts`. The marker never moves
+/// backwards: marking an older message as read has no effect.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `group_id` - The MLS group ID
+/// * `up_to_message_id` - The event ID (hex) of the newest message that has been read
+///
+/// # Returns
+/// * `Ok(())` - If the marker was saved
+/// * `Err(BridgeError)` - `MessageNotFound` if the message is not in the group, or a
+///   `Storage` error if the marker could not be saved
+///
+/// # Example
+/// ```dart
+/// // When the group screen shows its newest message
+/// await markGroupRead(pubkey: pubkey, groupId: groupId, upToMessageId: messages.last.id);
+/// ```
+#[frb]
+pub async fn mark_group_read(
+    pubkey: &PublicKey,
+    group_id: whitenoise::GroupId,
+    up_to_message_id: String,
+) -> Result<(), BridgeError> {
+    let messages = load_chat_messages(pubkey, &group_id).await?;
+    let message = messages
+        .iter()
+        .find(|message| message.id == up_to_message_id)
+        .ok_or_else(|| BridgeError::new(BridgeErrorCode::MessageNotFound, "Message not found"))?;
+    let marker = ReadMarker {
+        message_id: message.id.clone(),
+        created_at: message.created_at,
+    };
+
+    store::update(
+        READ_MARKERS_DOCUMENT,
+        Some(&pubkey.to_hex()),
+        |markers: &mut ReadMarkers| {
+            let key = group_id_to_string(&group_id);
+            let is_newer = markers.get(&key).is_none_or(|current| {
+                (marker.created_at, &marker.message_id) > (current.created_at, &current.message_id)
+            });
+            if is_newer {
+                markers.insert(key, marker);
+            }
+        },
+    )
+    .await
+}
+
+/// Fetches the number of unread messages in each of the account's active groups.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+///
+/// # Returns
+/// * `Ok(HashMap<String, u32>)` - Unread counts keyed by MLS group ID (hex)
+/// * `Err(BridgeError)` - If the groups, messages or read markers could not be loaded
+#[frb]
+pub async fn fetch_unread_counts(pubkey: &PublicKey) -> Result<HashMap<String, u32>, BridgeError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let account = whitenoise.fetch_account(pubkey).await?;
+    let account_pubkey = pubkey.to_hex();
+    let read_markers: ReadMarkers =
+        store::load(READ_MARKERS_DOCUMENT, Some(&account_pubkey)).await?;
+
+    let mut counts = HashMap::new();
+    for group in whitenoise.fetch_groups(&account, true).await? {
+        let group_id = group_id_to_string(&group.mls_group_id);
+        let activity = group_activity(pubkey, &group).await?;
+        let count = count_unread(
+            &account_pubkey,
+            read_markers.get(&group_id),
+            &activity.stamps,
+        );
+        counts.insert(group_id, count);
+    }
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(id: &str, author: &str, created_at: u64) -> MessageStamp {
+        MessageStamp {
+            id: id.to_string(),
+            pubkey: author.to_string(),
+            created_at,
+        }
+    }
+
+    #[test]
+    fn counts_messages_after_the_read_marker() {
+        let messages = vec![
+            message("a", "bob", 1),
+            message("b", "bob", 2),
+            message("c", "me", 3),
+            message("d", "bob", 4),
+        ];
+        let marker = ReadMarker {
+            message_id: "a".to_string(),
+            created_at: 1,
+        };
+        // The account's own messages after the marker are not unread
+        assert_eq!(count_unread("me", Some(&marker), &messages), 2);
+    }
+
+    #[test]
+    fn counts_messages_since_own_last_message_without_marker() {
+        let messages = vec![
+            message("a", "bob", 1),
+            message("b", "me", 2),
+            message("c", "bob", 3),
+            message("d", "carol", 4),
+        ];
+        assert_eq!(count_unread("me", None, &messages), 2);
+    }
+
+    #[test]
+    fn orders_messages_of_the_same_second_by_id() {
+        let messages = vec![
+            message("a", "bob", 5),
+            message("b", "bob", 5),
+            message("c", "bob", 5),
+        ];
+        let marker = ReadMarker {
+            message_id: "b".to_string(),
+            created_at: 5,
+        };
+        assert_eq!(count_unread("me", Some(&marker), &messages), 1);
+    }
+
+    #[test]
+    fn counts_from_a_marker_on_a_deleted_message() {
+        // "b" was read and then deleted, so it is no longer among the visible messages
+        let messages = vec![message("a", "bob", 1), message("c", "bob", 3)];
+        let marker = ReadMarker {
+            message_id: "b".to_string(),
+            created_at: 2,
+        };
+        assert_eq!(count_unread("me", Some(&marker), &messages), 1);
+    }
+
+    #[test]
+    fn forgets_and_clears_cached_groups() {
+        let cache = GROUP_ACTIVITY.get_or_init(Default::default);
+        let alice =
+            PublicKey::from_hex("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                .unwrap();
+        let keys = [
+            (alice.to_hex(), "0a01".to_string()),
+            (alice.to_hex(), "0a02".to_string()),
+            ("bob".to_string(), "0a01".to_string()),
+            ("bob".to_string(), "0a03".to_string()),
+        ];
+        {
+            let mut cache = cache.lock().unwrap();
+            for key in &keys {
+                cache.insert(key.clone(), GroupActivity::default());
+            }
+        }
+        let cached = |key: &GroupKey| cache.lock().unwrap().contains_key(key);
+
+        forget_group_activity(&GroupId::from_slice(&[0x0a, 0x01]));
+        assert!(!cached(&keys[0]) && !cached(&keys[2]));
+        assert!(cached(&keys[1]) && cached(&keys[3]));
+
+        clear_chat_list_caches(Some(&alice));
+        assert!(!cached(&keys[1]) && cached(&keys[3]));
+        clear_chat_list_caches(None);
+        assert!(!cached(&keys[3]));
+    }
+}
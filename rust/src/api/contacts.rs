@@ -1,6 +1,10 @@
+use crate::api::errors::BridgeError;
+use crate::api::metadata_cache::cached_profiles;
 use crate::api::utils::{convert_metadata_to_data, MetadataData};
 use flutter_rust_bridge::frb;
 use std::collections::HashMap;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
 pub use whitenoise::{PublicKey, Whitenoise, WhitenoiseError};
 
 /// Fetches all contacts associated with an account.
@@ -16,7 +20,7 @@ pub use whitenoise::{PublicKey, Whitenoise, WhitenoiseError};
 ///
 /// # Returns
 /// * `Ok(HashMap<PublicKey, Option<MetadataData>>)` - Map of contact public keys to their metadata
-/// * `Err(WhitenoiseError)` - If there was an error fetching contacts or account not found
+/// * `Err(BridgeError)` - If there was an error fetching contacts or account not found
 ///
 /// # Example
 /// ```rust
@@ -26,7 +30,7 @@ pub use whitenoise::{PublicKey, Whitenoise, WhitenoiseError};
 #[frb]
 pub async fn fetch_contacts(
     pubkey: PublicKey,
-) -> Result<HashMap<PublicKey, Option<MetadataData>>, WhitenoiseError> {
+) -> Result<HashMap<PublicKey, Option<MetadataData>>, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let contacts = whitenoise.fetch_contacts(pubkey).await?;
     let converted_contacts = contacts
@@ -52,7 +56,7 @@ pub async fn fetch_contacts(
 ///
 /// # Returns
 /// * `Ok(HashMap<PublicKey, Option<MetadataData>>)` - Map of contact public keys to their metadata
-/// * `Err(WhitenoiseError)` - If there was an error fetching contacts or account not found
+/// * `Err(BridgeError)` - If there was an error fetching contacts or account not found
 ///
 /// # Example
 /// ```rust
@@ -62,7 +66,7 @@ pub async fn fetch_contacts(
 #[frb]
 pub async fn query_contacts(
     pubkey: PublicKey,
-) -> Result<HashMap<PublicKey, Option<MetadataData>>, WhitenoiseError> {
+) -> Result<HashMap<PublicKey, Option<MetadataData>>, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let contacts = whitenoise.query_contacts(pubkey).await?;
     let converted_contacts = contacts
@@ -85,7 +89,7 @@ pub async fn query_contacts(
 ///
 /// # Returns
 /// * `Ok(())` - If the contact was successfully added
-/// * `Err(WhitenoiseError)` - If there was an error adding the contact (e.g., account not found, network error)
+/// * `Err(BridgeError)` - If there was an error adding the contact (e.g., account not found, network error)
 ///
 /// # Example
 /// ```rust
@@ -93,13 +97,10 @@ pub async fn query_contacts(
 /// println!("Contact added successfully");
 /// ```
 #[frb]
-pub async fn add_contact(
-    pubkey: &PublicKey,
-    contact_pubkey: PublicKey,
-) -> Result<(), WhitenoiseError> {
+pub async fn add_contact(pubkey: &PublicKey, contact_pubkey: PublicKey) -> Result<(), BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let account = whitenoise.fetch_account(pubkey).await?;
-    whitenoise.add_contact(&account, contact_pubkey).await
+    Ok(whitenoise.add_contact(&account, contact_pubkey).await?)
 }
 
 /// Removes a contact from an account's contact list.
@@ -113,7 +114,7 @@ pub async fn add_contact(
 ///
 /// # Returns
 /// * `Ok(())` - If the contact was successfully removed
-/// * `Err(WhitenoiseError)` - If there was an error removing the contact (e.g., account not found, contact not in list)
+/// * `Err(BridgeError)` - If there was an error removing the contact (e.g., account not found, contact not in list)
 ///
 /// # Example
 /// ```rust
@@ -124,10 +125,10 @@ pub async fn add_contact(
 pub async fn remove_contact(
     pubkey: &PublicKey,
     contact_pubkey: PublicKey,
-) -> Result<(), WhitenoiseError> {
+) -> Result<(), BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let account = whitenoise.fetch_account(pubkey).await?;
-    whitenoise.remove_contact(&account, contact_pubkey).await
+    Ok(whitenoise.remove_contact(&account, contact_pubkey).await?)
 }
 
 /// Completely replaces an account's contact list with a new set of contacts.
@@ -142,7 +143,7 @@ pub async fn remove_contact(
 ///
 /// # Returns
 /// * `Ok(())` - If the contact list was successfully updated
-/// * `Err(WhitenoiseError)` - If there was an error updating the contacts
+/// * `Err(BridgeError)` - If there was an error updating the contacts
 ///
 /// # Warning
 /// This operation completely replaces the existing contact list. Use with caution.
@@ -157,8 +158,249 @@ pub async fn remove_contact(
 pub async fn update_contacts(
     pubkey: &PublicKey,
     contact_pubkeys: Vec<PublicKey>,
-) -> Result<(), WhitenoiseError> {
+) -> Result<(), BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let account = whitenoise.fetch_account(pubkey).await?;
-    whitenoise.update_contacts(&account, contact_pubkeys).await
+    Ok(whitenoise
+        .update_contacts(&account, contact_pubkeys)
+        .await?)
+}
+
+/// A profile matching a `search_contacts` query.
+#[derive(Debug, Clone)]
+pub struct ContactSearchResultData {
+    pub pubkey: String, // Hex
+    pub npub: String,
+    pub metadata: Option<MetadataData>,
+    pub is_contact: bool,
+    pub score: u32, // Higher is a better match
+}
+
+/// Lowercases and strips accents from a string for matching, so "José" matches "jose".
+///
+/// Text is decomposed (NFD) and the combining marks are dropped, which leaves the base
+/// letters whether the input was precomposed or not.
+fn normalize_for_search(text: &str) -> String {
+    text.trim()
+        .nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// Shortest query that is matched against hex public keys.
+const MIN_KEY_QUERY_LENGTH: usize = 4;
+
+/// Prefix shared by every npub.
+const NPUB_PREFIX: &str = "npub1";
+
+/// Scores how well a name matches a normalized query; `None` if it does not match.
+fn score_name(name: &str, query: &str) -> Option<u32> {
+    let name = normalize_for_search(name);
+    if name == query {
+        Some(100)
+    } else if name.starts_with(query) {
+        Some(80)
+    } else if name
+        .split(|c: char| !c.is_alphanumeric())
+        .any(|word| word.starts_with(query))
+    {
+        Some(60)
+    } else if name.contains(query) {
+        Some(40)
+    } else {
+        None
+    }
+}
+
+/// Scores how well a profile matches a normalized query; `None` if it does not match.
+fn score_profile(
+    hex: &str,
+    npub: &str,
+    metadata: Option<&MetadataData>,
+    query: &str,
+) -> Option<u32> {
+    let mut best = None;
+    // Keys only match by prefix, and only once the query is long enough to be deliberate.
+    // Every npub starts with "npub1", so that part alone matches nobody in particular
+    let hex_match = query.len() >= MIN_KEY_QUERY_LENGTH && hex.starts_with(query);
+    let npub_match = query.len() > NPUB_PREFIX.len() && npub.starts_with(query);
+    if hex_match || npub_match {
+        best = Some(if hex == query || npub == query {
+            100
+        } else {
+            70
+        });
+    }
+    if let Some(metadata) = metadata {
+        let names = [&metadata.name, &metadata.display_name];
+        for name in names.into_iter().flatten() {
+            best = best.max(score_name(name, query));
+        }
+        if let Some(nip05) = &metadata.nip05 {
+            // A NIP-05 match ranks just below the same kind of match on a name
+            best = best.max(score_name(nip05, query).map(|score| score - 5));
+        }
+    }
+    best
+}
+
+/// Searches an account's contacts by name, display name, NIP-05 identifier or key.
+///
+/// Matching is case- and accent-insensitive ("jose" finds "José"). Names match anywhere,
+/// but exact matches rank first, followed by matches at the start of the name, at the
+/// start of a word, and anywhere else. Public keys match by hex prefix once the query is
+/// at least 4 characters long, and by npub prefix once it goes past "npub1". Contacts
+/// rank above other profiles with an equally good match.
+///
+/// # Parameters
+/// * `pubkey` - The public key of the account whose contacts to search
+/// * `query` - The search text
+/// * `include_cached_profiles` - Also search profiles that are not contacts but are in
+///   the metadata cache (e.g. members of the account's groups)
+///
+/// # Returns
+/// * `Ok(Vec<ContactSearchResultData>)` - Matching profiles, best match first; empty if
+///   the query is blank
+/// * `Err(BridgeError)` - If the contacts or the metadata cache could not be read
+///
+/// # Example
+/// ```rust
+/// let results = search_contacts(&my_pubkey, "alice".to_string(), true).await?;
+/// println!("Found {} matches", results.len());
+/// ```
+#[frb]
+pub async fn search_contacts(
+    pubkey: &PublicKey,
+    query: String,
+    include_cached_profiles: bool,
+) -> Result<Vec<ContactSearchResultData>, BridgeError> {
+    let query = normalize_for_search(&query);
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let whitenoise = Whitenoise::get_instance()?;
+
+    let mut candidates: HashMap<String, (PublicKey, Option<MetadataData>, bool)> = whitenoise
+        .query_contacts(*pubkey)
+        .await?
+        .into_iter()
+        .map(|(contact, metadata)| {
+            let metadata = metadata.map(|m| convert_metadata_to_data(&m));
+            (contact.to_hex(), (contact, metadata, true))
+        })
+        .collect();
+    if include_cached_profiles {
+        for (hex, metadata) in cached_profiles().await? {
+            if hex == pubkey.to_hex() || candidates.contains_key(&hex) {
+                continue;
+            }
+            if let Ok(profile) = PublicKey::from_hex(&hex) {
+                candidates.insert(hex, (profile, metadata, false));
+            }
+        }
+    }
+
+    let mut results = Vec::new();
+    for (hex, (profile, metadata, is_contact)) in candidates {
+        let npub = Whitenoise::npub_from_public_key(&profile)?;
+        if let Some(score) = score_profile(&hex, &npub, metadata.as_ref(), &query) {
+            results.push(ContactSearchResultData {
+                pubkey: hex,
+                npub,
+                metadata,
+                is_contact,
+                score,
+            });
+        }
+    }
+    let display_name = |result: &ContactSearchResultData| {
+        result
+            .metadata
+            .as_ref()
+            .and_then(|m| m.display_name.clone().or_else(|| m.name.clone()))
+            .map(|name| normalize_for_search(&name))
+            .unwrap_or_default()
+    };
+    results.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then(b.is_contact.cmp(&a.is_contact))
+            .then_with(|| display_name(a).cmp(&display_name(b)))
+            .then_with(|| a.pubkey.cmp(&b.pubkey))
+    });
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Metadata;
+
+    const HEX: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+    const NPUB: &str = "npub10xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqpkge6d";
+
+    fn metadata(name: &str, display_name: Option<&str>, nip05: Option<&str>) -> MetadataData {
+        convert_metadata_to_data(&Metadata {
+            name: Some(name.to_string()),
+            display_name: display_name.map(str::to_string),
+            nip05: nip05.map(str::to_string),
+            ..Default::default()
+        })
+    }
+
+    fn score(metadata: Option<&MetadataData>, query: &str) -> Option<u32> {
+        score_profile(HEX, NPUB, metadata, &normalize_for_search(query))
+    }
+
+    #[test]
+    fn strips_accents_from_precomposed_and_decomposed_text() {
+        assert_eq!(normalize_for_search(" José "), "jose");
+        assert_eq!(normalize_for_search("Jose\u{301}"), "jose");
+        assert_eq!(normalize_for_search("ÅNGSTRÖM"), "angstrom");
+        assert_eq!(normalize_for_search("Dvořák"), "dvorak");
+    }
+
+    #[test]
+    fn ranks_names_by_match_position() {
+        let alice = metadata("Alice Liddell", None, None);
+        assert_eq!(score(Some(&alice), "alice liddell"), Some(100));
+        assert_eq!(score(Some(&alice), "ali"), Some(80));
+        assert_eq!(score(Some(&alice), "lid"), Some(60));
+        assert_eq!(score(Some(&alice), "dell"), Some(40));
+        assert_eq!(score(Some(&alice), "bob"), None);
+    }
+
+    #[test]
+    fn matches_names_without_accents() {
+        let jose = metadata("josé", Some("José Álvarez"), None);
+        assert_eq!(score(Some(&jose), "jose"), Some(100));
+        assert_eq!(score(Some(&jose), "alvarez"), Some(60));
+    }
+
+    #[test]
+    fn takes_the_best_of_name_display_name_and_nip05() {
+        let profile = metadata("zed", Some("Carol"), Some("carol@example.com"));
+        assert_eq!(score(Some(&profile), "carol"), Some(100));
+        let profile = metadata("zed", None, Some("carol@example.com"));
+        assert_eq!(score(Some(&profile), "carol"), Some(75));
+        assert_eq!(score(Some(&profile), "example"), Some(55));
+    }
+
+    #[test]
+    fn matches_hex_keys_by_prefix_from_four_characters() {
+        assert_eq!(score(None, "79b"), None);
+        assert_eq!(score(None, "79be"), Some(70));
+        assert_eq!(score(None, HEX), Some(100));
+        assert_eq!(score(None, "be66"), None);
+    }
+
+    #[test]
+    fn matches_npubs_only_past_the_common_prefix() {
+        assert_eq!(score(None, "npub"), None);
+        assert_eq!(score(None, "npub1"), None);
+        assert_eq!(score(None, "npub10"), Some(70));
+        assert_eq!(score(None, "npub11"), None);
+        assert_eq!(score(None, NPUB), Some(100));
+    }
 }
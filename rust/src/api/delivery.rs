@@ -0,0 +1,184 @@
+use crate::api::errors::BridgeError;
+use crate::api::events::{emit_event, WhitenoiseEvent};
+use crate::api::outbox::{load_outbox, OutboxMessageData, OutboxState};
+use crate::api::utils::unix_now;
+use crate::store;
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+pub use whitenoise::PublicKey;
+
+/// Delivery state of a message sent through the outbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeliveryState {
+    /// The message is queued and will be sent (again) automatically
+    Pending,
+    /// The core published the message
+    Sent,
+    /// Automatic retries gave up; use `retry_message` or `cancel_pending_message`
+    Failed,
+}
+
+/// Delivery status of a message sent by the account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeliveryStatusData {
+    pub outbox_id: String,
+    pub message_id: Option<String>, // Event ID (hex), once sent
+    pub group_id: String,
+    pub state: DeliveryState,
+    pub attempts: u32,         // Failed attempts so far
+    pub error: Option<String>, // Why the last attempt failed
+    pub updated_at: u64,
+}
+
+/// Name of the per-account store document holding the statuses of sent messages.
+/// Pending and failed messages are read from the outbox itself.
+const SENT_MESSAGES_DOCUMENT: &str = "sent_messages";
+
+/// Number of most recently sent messages whose status is kept per account.
+const MAX_SENT_MESSAGES: usize = 1000;
+
+/// Returns the delivery status of a message waiting in the outbox.
+pub(crate) fn outbox_delivery_status(message: &OutboxMessageData) -> DeliveryStatusData {
+    DeliveryStatusData {
+        outbox_id: message.id.clone(),
+        message_id: None,
+        group_id: message.group_id.clone(),
+        state: match message.state {
+            OutboxState::Pending => DeliveryState::Pending,
+            OutboxState::Failed => DeliveryState::Failed,
+        },
+        attempts: message.attempts,
+        error: message.last_error.clone(),
+        updated_at: unix_now(),
+    }
+}
+
+/// Announces a delivery status change on the event bus.
+pub(crate) fn announce_delivery_status(account_pubkey: &str, status: DeliveryStatusData) {
+    emit_event(WhitenoiseEvent::MessageDeliveryStatusChanged {
+        account_pubkey: account_pubkey.to_string(),
+        status,
+    });
+}
+
+/// Adds the status of a sent message, dropping the oldest ones past `MAX_SENT_MESSAGES`.
+fn insert_sent(sent: &mut Vec<DeliveryStatusData>, status: DeliveryStatusData) {
+    sent.retain(|known| known.outbox_id != status.outbox_id);
+    sent.push(status);
+    if sent.len() > MAX_SENT_MESSAGES {
+        sent.drain(..sent.len() - MAX_SENT_MESSAGES);
+    }
+}
+
+/// Records that an outbox message was sent as the event `message_id` and announces it.
+///
+/// The message is already sent, so failing to record it only loses its status.
+pub(crate) async fn record_sent_message(
+    account_pubkey: &str,
+    message: &OutboxMessageData,
+    message_id: String,
+) {
+    let status = DeliveryStatusData {
+        message_id: Some(message_id),
+        state: DeliveryState::Sent,
+        error: None,
+        ..outbox_delivery_status(message)
+    };
+    let _ = store::update(
+        SENT_MESSAGES_DOCUMENT,
+        Some(account_pubkey),
+        |sent: &mut Vec<DeliveryStatusData>| insert_sent(sent, status.clone()),
+    )
+    .await;
+    announce_delivery_status(account_pubkey, status);
+}
+
+/// Fetches the delivery status of a message sent by the account.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account that sent the message
+/// * `message_id` - The outbox ID of the message (see `BridgeError::outbox_id` and
+///   `OutboxMessageData::id`) or, once sent, its event ID (hex)
+///
+/// # Returns
+/// * `Ok(Some(DeliveryStatusData))` - The status: pending or failed while the message is
+///   in the outbox, sent once the core has published it
+/// * `Ok(None)` - If the message is unknown: sent by someone else, cancelled, sent
+///   without the outbox (reactions and deletions) or older than the last 1000 sent
+///   messages
+/// * `Err(BridgeError)` - If the status could not be read
+///
+/// # Notes
+/// * Status changes are pushed on the global event stream as
+///   `WhitenoiseEvent::MessageDeliveryStatusChanged`
+/// * The core publishes to all of the group's relays at once and reports neither which
+///   relays accepted the message nor why single relays refused it, so there is no
+///   per-relay status: `Sent` means the core reported the publish as successful
+#[frb]
+pub async fn fetch_message_delivery_status(
+    pubkey: &PublicKey,
+    message_id: String,
+) -> Result<Option<DeliveryStatusData>, BridgeError> {
+    let account_pubkey = pubkey.to_hex();
+    if let Some(queued) = load_outbox(&account_pubkey)
+        .await?
+        .iter()
+        .find(|queued| queued.id == message_id)
+    {
+        return Ok(Some(outbox_delivery_status(queued)));
+    }
+    let sent: Vec<DeliveryStatusData> =
+        store::load(SENT_MESSAGES_DOCUMENT, Some(&account_pubkey)).await?;
+    Ok(sent.into_iter().rev().find(|status| {
+        status.outbox_id == message_id || status.message_id.as_deref() == Some(&message_id)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queued(id: &str, state: OutboxState) -> OutboxMessageData {
+        OutboxMessageData {
+            id: id.to_string(),
+            group_id: "0102".to_string(),
+            content: "hello".to_string(),
+            kind: 9,
+            tags: Vec::new(),
+            created_at: 1,
+            state,
+            attempts: 3,
+            next_attempt_at: 10,
+            last_error: Some("No relay connected".to_string()),
+        }
+    }
+
+    #[test]
+    fn outbox_messages_are_pending_or_failed() {
+        let status = outbox_delivery_status(&queued("a", OutboxState::Pending));
+        assert_eq!(status.state, DeliveryState::Pending);
+        assert_eq!(status.attempts, 3);
+        assert_eq!(status.error.as_deref(), Some("No relay connected"));
+        assert_eq!(status.message_id, None);
+        let status = outbox_delivery_status(&queued("a", OutboxState::Failed));
+        assert_eq!(status.state, DeliveryState::Failed);
+    }
+
+    #[test]
+    fn keeps_the_most_recent_sent_messages() {
+        let mut sent = Vec::new();
+        for i in 0..MAX_SENT_MESSAGES + 5 {
+            insert_sent(
+                &mut sent,
+                outbox_delivery_status(&queued(&i.to_string(), OutboxState::Pending)),
+            );
+        }
+        insert_sent(
+            &mut sent,
+            outbox_delivery_status(&queued("10", OutboxState::Pending)),
+        );
+        assert_eq!(sent.len(), MAX_SENT_MESSAGES);
+        assert_eq!(sent[0].outbox_id, "5");
+        assert_eq!(sent.last().unwrap().outbox_id, "10");
+    }
+}
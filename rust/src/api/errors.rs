@@ -0,0 +1,171 @@
+//! Structured error type returned by every bridge function.
+//!
+//! `WhitenoiseError` is opaque on the Flutter side and can only be shown as a debug string.
+//! `BridgeError` instead carries a stable machine-readable code that Flutter can branch on
+//! and map to localized messages, a default user-facing message in English, and optional
+//! technical details for logging.
+
+use std::fmt;
+pub use whitenoise::WhitenoiseError;
+
+/// Stable, machine-readable error codes.
+///
+/// These codes are part of the bridge contract: existing variants must not be renamed or
+/// repurposed, new ones may be added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BridgeErrorCode {
+    /// `initialize_whitenoise` has not been called (or failed)
+    NotInitialized,
+    /// The account is not logged in on this device
+    AccountNotFound,
+    /// The account is not allowed to perform this action (e.g. not a group admin)
+    Unauthorized,
+    /// The group does not exist or the account is not a member
+    GroupNotFound,
+    /// The welcome does not exist
+    WelcomeNotFound,
+    /// The message does not exist in the group
+    MessageNotFound,
+    /// One or more of the given members are not in the group
+    MemberNotInGroup,
+    /// A public key could not be parsed (hex or npub)
+    InvalidPublicKey,
+    /// A hex string could not be decoded
+    InvalidHex,
+    /// A relay URL could not be parsed
+    InvalidRelayUrl,
+    /// Any other invalid argument
+    InvalidInput,
+    /// The operation is not supported (e.g. an unsupported file format)
+    Unsupported,
+    /// None of the relays needed for the operation could be reached
+    RelayUnreachable,
+    /// A user has not published a key package, so they cannot be added to a group
+    NoKeyPackage,
+    /// An error in the MLS layer
+    MlsError,
+    /// A network or Nostr client error
+    Network,
+    /// A local database, keychain or filesystem error
+    Storage,
+    /// Invalid configuration or logging setup
+    Configuration,
+    /// An unexpected internal error
+    Internal,
+}
+
+/// Error returned by bridge functions.
+///
+/// # Fields
+/// * `code` - Stable code to branch on in Flutter
+/// * `message` - Default user-facing message (English); Flutter should prefer a localized
+///   message chosen by `code`
+/// * `details` - Optional technical details (e.g. the underlying error) for logs
+/// * `outbox_id` - Set when a message could not be sent but stays in the outbox and is
+///   retried automatically; track it with `fetch_outbox` and the outbox events
+#[derive(Debug, Clone)]
+pub struct BridgeError {
+    pub code: BridgeErrorCode,
+    pub message: String,
+    pub details: Option<String>,
+    pub outbox_id: Option<String>,
+}
+
+impl BridgeError {
+    /// Creates a new error with the given code and user-facing message.
+    pub(crate) fn new(code: BridgeErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            details: None,
+            outbox_id: None,
+        }
+    }
+
+    /// Attaches technical details to the error.
+    pub(crate) fn with_details(mut self, details: impl fmt::Display) -> Self {
+        self.details = Some(details.to_string());
+        self
+    }
+
+    /// Marks the error as a failed attempt to send a message that is still queued.
+    pub(crate) fn with_outbox_id(mut self, outbox_id: impl Into<String>) -> Self {
+        self.outbox_id = Some(outbox_id.into());
+        self
+    }
+}
+
+impl fmt::Display for BridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.details {
+            Some(details) => write!(f, "{:?}: {} ({details})", self.code, self.message),
+            None => write!(f, "{:?}: {}", self.code, self.message),
+        }
+    }
+}
+
+impl std::error::Error for BridgeError {}
+
+impl From<WhitenoiseError> for BridgeError {
+    fn from(error: WhitenoiseError) -> Self {
+        let (code, message) = match &error {
+            WhitenoiseError::Initialization => (
+                BridgeErrorCode::NotInitialized,
+                "Whitenoise is not initialized",
+            ),
+            WhitenoiseError::Filesystem(_) => {
+                (BridgeErrorCode::Storage, "Could not access local files")
+            }
+            WhitenoiseError::LoggingSetup(_) | WhitenoiseError::Configuration(_) => (
+                BridgeErrorCode::Configuration,
+                "Whitenoise is misconfigured",
+            ),
+            WhitenoiseError::SqlxError(_)
+            | WhitenoiseError::Database(_)
+            | WhitenoiseError::NostrMlsSqliteStorage(_)
+            | WhitenoiseError::SecretsStore(_) => {
+                (BridgeErrorCode::Storage, "Could not access local storage")
+            }
+            WhitenoiseError::AccountNotFound => {
+                (BridgeErrorCode::AccountNotFound, "Account not found")
+            }
+            WhitenoiseError::AccountNotAuthorized => (
+                BridgeErrorCode::Unauthorized,
+                "This account is not allowed to do that",
+            ),
+            WhitenoiseError::GroupNotFound => (BridgeErrorCode::GroupNotFound, "Group not found"),
+            WhitenoiseError::WelcomeNotFound => {
+                (BridgeErrorCode::WelcomeNotFound, "Invitation not found")
+            }
+            WhitenoiseError::MembersNotInGroup => (
+                BridgeErrorCode::MemberNotInGroup,
+                "One or more members are not in the group",
+            ),
+            WhitenoiseError::InvalidPublicKey | WhitenoiseError::NostrKey(_) => {
+                (BridgeErrorCode::InvalidPublicKey, "Invalid public key")
+            }
+            WhitenoiseError::NostrUrl(_) => (BridgeErrorCode::InvalidRelayUrl, "Invalid relay URL"),
+            WhitenoiseError::InvalidEvent(_)
+            | WhitenoiseError::NostrTag(_)
+            | WhitenoiseError::JsonError(_) => (BridgeErrorCode::InvalidInput, "Invalid data"),
+            WhitenoiseError::NostrMlsError(_) | WhitenoiseError::NostrMlsNotInitialized => {
+                (BridgeErrorCode::MlsError, "Secure messaging (MLS) error")
+            }
+            WhitenoiseError::NostrClient(_) | WhitenoiseError::NostrManager(_) => {
+                (BridgeErrorCode::Network, "Network error")
+            }
+            WhitenoiseError::ContactList(_) => (
+                BridgeErrorCode::Internal,
+                "Could not update the contact list",
+            ),
+            WhitenoiseError::Other(_) => (BridgeErrorCode::Internal, "Something went wrong"),
+        };
+        BridgeError::new(code, message).with_details(error)
+    }
+}
+
+impl From<tokio::task::JoinError> for BridgeError {
+    fn from(error: tokio::task::JoinError) -> Self {
+        BridgeError::new(BridgeErrorCode::Internal, "Background task failed").with_details(error)
+    }
+}
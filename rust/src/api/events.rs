@@ -0,0 +1,607 @@
+use crate::api::accounts::{convert_account_to_data, AccountData};
+use crate::api::chats::forget_group_activity;
+use crate::api::delivery::DeliveryStatusData;
+use crate::api::groups::{convert_group_to_data, epoch_members, group_id_from_string, GroupData};
+use crate::api::messages::{tag_value, MessageWithTokensData};
+use crate::api::outbox::OutboxMessageData;
+use crate::api::utils::MetadataData;
+use crate::api::welcomes::{convert_welcome_to_data, WelcomeData};
+use crate::group_watch::SETTLE_DELAYS;
+use crate::relay_client::{subscribe, RelaySubscription};
+use futures::future::select_all;
+use nostr_sdk::{Alphabet, Event, Filter, Kind, SingleLetterTag, Timestamp};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::sync::{broadcast, Notify};
+use whitenoise::{PublicKey, RelayType, RelayUrl};
+pub use whitenoise::{WelcomeState, Whitenoise, WhitenoiseError};
+
+/// An event pushed on the stream created by `subscribe_whitenoise_events`.
+///
+/// Every event that concerns a single account carries that account's public key
+/// (hex) so multi-account setups can route it to the right providers.
+#[derive(Debug, Clone)]
+pub enum WhitenoiseEvent {
+    /// First item on every stream; carries the handle to pass to `unsubscribe`
+    Subscribed { subscription_id: u64 },
+    /// A new pending welcome (group invitation) was received
+    WelcomeReceived {
+        account_pubkey: String,
+        welcome: WelcomeData,
+    },
+    /// The account joined or created a group
+    GroupCreated {
+        account_pubkey: String,
+        group: GroupData,
+    },
+    /// Group details (name, description, image, admins, epoch) changed
+    GroupUpdated {
+        account_pubkey: String,
+        group: GroupData,
+    },
+    /// A group moved to another `GroupState`
+    GroupStateChanged {
+        account_pubkey: String,
+        group: GroupData,
+    },
+    /// A member was added to a group
+    MemberAdded {
+        account_pubkey: String,
+        group_id: String,
+        member_pubkey: String,
+    },
+    /// A member was removed from a group
+    MemberRemoved {
+        account_pubkey: String,
+        group_id: String,
+        member_pubkey: String,
+    },
+    /// The connection status of one of the account's relays changed. Noticed whenever the
+    /// state is checked (after bridge actions and incoming events), not on a timer
+    RelayStatusChanged {
+        account_pubkey: String,
+        relay_url: String,
+        status: String,
+    },
+    /// Profile metadata for a public key was updated
+    MetadataUpdated {
+        pubkey: String,
+        metadata: MetadataData,
+    },
+    /// An account was logged in
+    AccountLoggedIn { account: AccountData },
+    /// An account was logged out
+    AccountLoggedOut { pubkey: String },
+    /// The delivery status of a message sent by the account changed
+    MessageDeliveryStatusChanged {
+        account_pubkey: String,
+        status: DeliveryStatusData,
+    },
+    /// A queued message failed to send and will be retried (or has been given up on)
+    OutboxMessageUpdated {
+        account_pubkey: String,
+        message: OutboxMessageData,
+    },
+    /// A queued message was sent and removed from the outbox
+    OutboxMessageSent {
+        account_pubkey: String,
+        outbox_id: String,
+        message: MessageWithTokensData,
+    },
+}
+
+/// Number of events buffered for slow subscribers before they start skipping events.
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+struct EventBus {
+    sender: broadcast::Sender<WhitenoiseEvent>,
+    refresh: Notify,
+}
+
+static EVENT_BUS: OnceLock<EventBus> = OnceLock::new();
+
+fn event_bus() -> &'static EventBus {
+    EVENT_BUS.get_or_init(|| EventBus {
+        sender: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
+        refresh: Notify::new(),
+    })
+}
+
+/// Starts the background task that turns state changes into events. Called from
+/// `initialize_whitenoise`, so it runs on the bridge's async runtime.
+pub(crate) fn start_event_watcher() {
+    static STARTED: OnceLock<()> = OnceLock::new();
+    if STARTED.set(()).is_ok() {
+        tokio::spawn(watch_state());
+    }
+}
+
+/// Returns a new receiver on the event bus and has the watcher take a baseline of the
+/// state, from which it reports changes.
+pub(crate) fn subscribe_events() -> broadcast::Receiver<WhitenoiseEvent> {
+    let bus = event_bus();
+    let receiver = bus.sender.subscribe();
+    bus.refresh.notify_one();
+    receiver
+}
+
+/// Publishes an event directly to all current subscribers.
+///
+/// Used for changes the state watcher cannot observe on its own, such as metadata
+/// updates. Events are dropped silently when nobody is subscribed.
+pub(crate) fn emit_event(event: WhitenoiseEvent) {
+    if let Some(bus) = EVENT_BUS.get() {
+        let _ = bus.sender.send(event);
+    }
+}
+
+/// Asks the state watcher to check for changes.
+///
+/// Called after bridge actions that change local state (creating groups, accepting
+/// welcomes, logging in, ...) so the resulting events reach Flutter immediately.
+pub(crate) fn refresh_events() {
+    if let Some(bus) = EVENT_BUS.get() {
+        bus.refresh.notify_one();
+    }
+}
+
+/// What the watcher subscribes to on relays to learn about changes the core receives.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct WatchTargets {
+    relays: BTreeSet<RelayUrl>,
+    accounts: BTreeSet<PublicKey>, // Recipients of welcomes (gift wraps, kind 1059)
+    groups: BTreeSet<String>,      // Nostr group IDs (hex) of group messages (kind 445)
+}
+
+impl WatchTargets {
+    fn extend(&mut self, other: &WatchTargets) {
+        self.relays.extend(other.relays.iter().cloned());
+        self.accounts.extend(other.accounts.iter().copied());
+        self.groups.extend(other.groups.iter().cloned());
+    }
+}
+
+/// A point-in-time view of everything the state watcher diffs, keyed by account.
+#[derive(Default)]
+struct StateSnapshot {
+    accounts: HashMap<String, AccountData>,
+    welcomes: HashMap<(String, String), WelcomeData>,
+    groups: HashMap<(String, String), GroupData>,
+    members: HashMap<(String, String), HashSet<String>>,
+    relay_statuses: HashMap<(String, String), String>,
+    watch: WatchTargets,
+}
+
+/// Loads the current state, falling back to `previous` for whatever fails to load.
+///
+/// Only failing to list the accounts fails the whole snapshot. When the welcomes, groups,
+/// members or relay statuses of one account cannot be loaded, the entries from the previous
+/// snapshot are carried over so the failure does not show up as spurious removals, and the
+/// other accounts are still checked. Relay subscription targets are carried over the same
+/// way, so a failed query does not close subscriptions.
+async fn load_snapshot(
+    whitenoise: &Whitenoise,
+    previous: Option<&StateSnapshot>,
+) -> Result<StateSnapshot, WhitenoiseError> {
+    let empty = StateSnapshot::default();
+    let previous = previous.unwrap_or(&empty);
+    let mut snapshot = StateSnapshot::default();
+    let mut failed = false;
+    let accounts = whitenoise.fetch_accounts().await?;
+    for account in accounts.values() {
+        let account_pubkey = account.pubkey.to_hex();
+        snapshot.watch.accounts.insert(account.pubkey);
+        for relay_type in [RelayType::Nostr, RelayType::Inbox] {
+            match whitenoise.fetch_relays(account.pubkey, relay_type).await {
+                Ok(relays) => snapshot.watch.relays.extend(relays),
+                Err(_) => failed = true,
+            }
+        }
+
+        match whitenoise.fetch_welcomes(&account.pubkey).await {
+            Ok(welcomes) => {
+                for welcome in welcomes {
+                    // Messages of groups joined through a welcome are sent to its relays
+                    snapshot
+                        .watch
+                        .relays
+                        .extend(welcome.group_relays.iter().cloned());
+                    if welcome.state == WelcomeState::Pending {
+                        let welcome = convert_welcome_to_data(&welcome);
+                        snapshot
+                            .welcomes
+                            .insert((account_pubkey.clone(), welcome.id.clone()), welcome);
+                    }
+                }
+            }
+            Err(_) => {
+                failed = true;
+                carry_over(&previous.welcomes, &mut snapshot.welcomes, &account_pubkey);
+            }
+        }
+
+        match whitenoise.fetch_groups(account, false).await {
+            Ok(groups) => {
+                for group in groups {
+                    let data = convert_group_to_data(&group);
+                    let key = (account_pubkey.clone(), data.mls_group_id.clone());
+                    snapshot.watch.groups.insert(data.nostr_group_id.clone());
+                    // Members are only fetched again when the epoch moved
+                    let members = match epoch_members(whitenoise, account, &group).await {
+                        Ok(members) => Some(members.iter().map(|pk| pk.to_hex()).collect()),
+                        Err(_) => previous.members.get(&key).cloned(),
+                    };
+                    if let Some(members) = members {
+                        snapshot.members.insert(key.clone(), members);
+                    }
+                    snapshot.groups.insert(key, data);
+                }
+            }
+            Err(_) => {
+                failed = true;
+                carry_over(&previous.groups, &mut snapshot.groups, &account_pubkey);
+                carry_over(&previous.members, &mut snapshot.members, &account_pubkey);
+            }
+        }
+
+        match whitenoise.fetch_relay_status(account.pubkey).await {
+            Ok(statuses) => {
+                for (url, status) in statuses {
+                    snapshot.relay_statuses.insert(
+                        (account_pubkey.clone(), url.to_string()),
+                        status.to_string(),
+                    );
+                }
+            }
+            Err(_) => carry_over(
+                &previous.relay_statuses,
+                &mut snapshot.relay_statuses,
+                &account_pubkey,
+            ),
+        }
+
+        snapshot
+            .accounts
+            .insert(account_pubkey, convert_account_to_data(account));
+    }
+    if failed {
+        snapshot.watch.extend(&previous.watch);
+    }
+    Ok(snapshot)
+}
+
+/// Copies an account's entries from a previous snapshot map into the current one.
+fn carry_over<V: Clone>(
+    previous: &HashMap<(String, String), V>,
+    current: &mut HashMap<(String, String), V>,
+    account_pubkey: &str,
+) {
+    current.extend(
+        previous
+            .iter()
+            .filter(|((pubkey, _), _)| pubkey == account_pubkey)
+            .map(|(key, value)| (key.clone(), value.clone())),
+    );
+}
+
+fn diff_snapshots(previous: &StateSnapshot, current: &StateSnapshot) -> Vec<WhitenoiseEvent> {
+    let mut events = Vec::new();
+
+    for (pubkey, account) in &current.accounts {
+        if !previous.accounts.contains_key(pubkey) {
+            events.push(WhitenoiseEvent::AccountLoggedIn {
+                account: account.clone(),
+            });
+        }
+    }
+    for pubkey in previous.accounts.keys() {
+        if !current.accounts.contains_key(pubkey) {
+            events.push(WhitenoiseEvent::AccountLoggedOut {
+                pubkey: pubkey.clone(),
+            });
+        }
+    }
+
+    for ((account_pubkey, welcome_id), welcome) in &current.welcomes {
+        if !previous
+            .welcomes
+            .contains_key(&(account_pubkey.clone(), welcome_id.clone()))
+        {
+            events.push(WhitenoiseEvent::WelcomeReceived {
+                account_pubkey: account_pubkey.clone(),
+                welcome: welcome.clone(),
+            });
+        }
+    }
+
+    for (key, group) in &current.groups {
+        let account_pubkey = key.0.clone();
+        let Some(known) = previous.groups.get(key) else {
+            events.push(WhitenoiseEvent::GroupCreated {
+                account_pubkey,
+                group: group.clone(),
+            });
+            continue;
+        };
+        if known.state != group.state {
+            events.push(WhitenoiseEvent::GroupStateChanged {
+                account_pubkey: account_pubkey.clone(),
+                group: group.clone(),
+            });
+        }
+        if known.name != group.name
+            || known.description != group.description
+            || known.image_url != group.image_url
+            || known.admin_pubkeys != group.admin_pubkeys
+            || known.epoch != group.epoch
+        {
+            events.push(WhitenoiseEvent::GroupUpdated {
+                account_pubkey,
+                group: group.clone(),
+            });
+        }
+    }
+
+    for (key, members) in &current.members {
+        // Members of a group we have only just seen are reported through GroupCreated
+        let Some(known) = previous.members.get(key) else {
+            continue;
+        };
+        let (account_pubkey, group_id) = key;
+        for member in members.difference(known) {
+            events.push(WhitenoiseEvent::MemberAdded {
+                account_pubkey: account_pubkey.clone(),
+                group_id: group_id.clone(),
+                member_pubkey: member.clone(),
+            });
+        }
+        for member in known.difference(members) {
+            events.push(WhitenoiseEvent::MemberRemoved {
+                account_pubkey: account_pubkey.clone(),
+                group_id: group_id.clone(),
+                member_pubkey: member.clone(),
+            });
+        }
+    }
+
+    for (key, status) in &current.relay_statuses {
+        if previous.relay_statuses.get(key) != Some(status) {
+            events.push(WhitenoiseEvent::RelayStatusChanged {
+                account_pubkey: key.0.clone(),
+                relay_url: key.1.clone(),
+                status: status.clone(),
+            });
+        }
+    }
+
+    events
+}
+
+/// Relay subscriptions that tell the watcher when the core may have received a welcome
+/// or a group change. The core reports neither, so the bridge subscribes to the same
+/// events on its own relay connections.
+#[derive(Default)]
+struct RelayWatch {
+    targets: WatchTargets,
+    subscriptions: Vec<RelaySubscription>,
+}
+
+impl RelayWatch {
+    /// Subscribes to the events of new targets, replacing the previous subscriptions.
+    /// Filters that cannot be subscribed to are retried when the targets change again.
+    async fn update(&mut self, targets: &WatchTargets) {
+        if self.targets == *targets {
+            return;
+        }
+        let relays: Vec<RelayUrl> = targets.relays.iter().cloned().collect();
+        let mut filters = Vec::new();
+        if !targets.accounts.is_empty() {
+            // Gift wraps are backdated by up to two days, so a `since` filter would drop
+            // new ones; a zero limit skips the stored ones instead
+            filters.push(
+                Filter::new()
+                    .kind(Kind::GiftWrap)
+                    .pubkeys(targets.accounts.iter().copied())
+                    .limit(0),
+            );
+        }
+        if !targets.groups.is_empty() {
+            filters.push(
+                Filter::new()
+                    .kind(Kind::MlsGroupMessage)
+                    .custom_tags(
+                        SingleLetterTag::lowercase(Alphabet::H),
+                        targets.groups.iter().cloned(),
+                    )
+                    .since(Timestamp::now()),
+            );
+        }
+        let mut subscriptions = Vec::new();
+        for filter in filters {
+            if let Ok(subscription) = subscribe(&relays, filter).await {
+                subscriptions.push(subscription);
+            }
+        }
+        // The previous subscriptions close only now, so no event falls in between
+        self.subscriptions = subscriptions;
+        self.targets = targets.clone();
+    }
+
+    /// Waits for the next event on any subscription. Returns `None` if the relay client
+    /// shut down; the subscriptions are then opened again on the next update. Never
+    /// returns without subscriptions.
+    async fn next_event(&mut self) -> Option<Event> {
+        if self.subscriptions.is_empty() {
+            return std::future::pending().await;
+        }
+        let (event, _, _) = select_all(
+            self.subscriptions
+                .iter_mut()
+                .map(|subscription| Box::pin(subscription.next_event())),
+        )
+        .await;
+        if event.is_none() {
+            *self = Self::default();
+        }
+        event
+    }
+}
+
+/// Has the chat list load the messages of the group a group message (kind 445) belongs to
+/// again, since it may edit or delete one of them.
+fn forget_messages_of(snapshot: Option<&StateSnapshot>, event: &Event) {
+    let (Some(snapshot), Some(nostr_group_id)) = (
+        snapshot,
+        event.tags.iter().find_map(|tag| tag_value(tag, "h")),
+    ) else {
+        return;
+    };
+    for group in snapshot.groups.values() {
+        if group.nostr_group_id == nostr_group_id {
+            if let Ok(group_id) = group_id_from_string(group.mls_group_id.clone()) {
+                forget_group_activity(&group_id);
+            }
+        }
+    }
+}
+
+/// Background task that turns changes in the local Whitenoise state into events.
+///
+/// The state is checked when the bridge changed it (`refresh_events`) and when one of the
+/// relay subscriptions receives an event; in the latter case again after a few delays
+/// (`SETTLE_DELAYS`), until a change shows up, since the core processes the event a moment
+/// later. There is no polling.
+///
+/// The watcher only does work while at least one stream is subscribed. When the last
+/// subscriber goes away its snapshot and relay subscriptions are dropped, so a later
+/// subscriber starts from a fresh baseline instead of receiving a burst of stale changes.
+async fn watch_state() {
+    let mut previous: Option<StateSnapshot> = None;
+    let mut relay_watch = RelayWatch::default();
+
+    loop {
+        let bus = event_bus();
+        let from_relay = tokio::select! {
+            _ = bus.refresh.notified() => false,
+            received = relay_watch.next_event() => match received {
+                Some(event) => {
+                    forget_messages_of(previous.as_ref(), &event);
+                    true
+                }
+                None => continue,
+            }
+        };
+
+        if bus.sender.receiver_count() == 0 {
+            previous = None;
+            relay_watch = RelayWatch::default();
+            continue;
+        }
+        let Ok(whitenoise) = Whitenoise::get_instance() else {
+            continue;
+        };
+        let delays: &[Duration] = if from_relay {
+            &SETTLE_DELAYS
+        } else {
+            &[Duration::ZERO]
+        };
+        for delay in delays {
+            tokio::time::sleep(*delay).await;
+            // Without the account list there is nothing to compare; try again next time
+            let Ok(current) = load_snapshot(whitenoise, previous.as_ref()).await else {
+                continue;
+            };
+
+            let mut changed = false;
+            if let Some(previous) = &previous {
+                for event in diff_snapshots(previous, &current) {
+                    changed = true;
+                    let _ = bus.sender.send(event);
+                }
+            }
+            relay_watch.update(&current.watch).await;
+            previous = Some(current);
+            if changed {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(account: &str, item: &str) -> (String, String) {
+        (account.to_string(), item.to_string())
+    }
+
+    #[test]
+    fn carry_over_copies_only_the_failed_account() {
+        let previous: HashMap<(String, String), String> = [
+            (key("alice", "wss://a"), "Connected".to_string()),
+            (key("bob", "wss://b"), "Connected".to_string()),
+        ]
+        .into_iter()
+        .collect();
+        let mut current = HashMap::new();
+        carry_over(&previous, &mut current, "alice");
+        assert_eq!(current.len(), 1);
+        assert!(current.contains_key(&key("alice", "wss://a")));
+    }
+
+    #[test]
+    fn watch_targets_extend_to_the_union() {
+        let relay = |url: &str| RelayUrl::parse(url).unwrap();
+        let mut targets = WatchTargets {
+            relays: [relay("wss://a.example")].into_iter().collect(),
+            accounts: BTreeSet::new(),
+            groups: ["0a".to_string()].into_iter().collect(),
+        };
+        let previous = WatchTargets {
+            relays: [relay("wss://a.example"), relay("wss://b.example")]
+                .into_iter()
+                .collect(),
+            accounts: BTreeSet::new(),
+            groups: ["0b".to_string()].into_iter().collect(),
+        };
+        targets.extend(&previous);
+        assert_eq!(targets.relays.len(), 2);
+        assert_eq!(
+            targets.groups.into_iter().collect::<Vec<_>>(),
+            vec!["0a".to_string(), "0b".to_string()]
+        );
+        assert_ne!(WatchTargets::default(), previous);
+    }
+
+    #[test]
+    fn diff_reports_member_changes_of_known_groups() {
+        let mut previous = StateSnapshot::default();
+        previous.members.insert(
+            key("alice", "group"),
+            ["bob", "carol"].iter().map(|pk| pk.to_string()).collect(),
+        );
+        let mut current = StateSnapshot::default();
+        current.members.insert(
+            key("alice", "group"),
+            ["bob", "dave"].iter().map(|pk| pk.to_string()).collect(),
+        );
+        // Members of groups seen for the first time are reported through GroupCreated
+        current.members.insert(
+            key("alice", "new-group"),
+            ["erin"].iter().map(|pk| pk.to_string()).collect(),
+        );
+
+        let events = diff_snapshots(&previous, &current);
+        assert_eq!(events.len(), 2);
+        assert!(events.iter().any(|event| matches!(
+            event,
+            WhitenoiseEvent::MemberAdded { member_pubkey, .. } if member_pubkey == "dave"
+        )));
+        assert!(events.iter().any(|event| matches!(
+            event,
+            WhitenoiseEvent::MemberRemoved { member_pubkey, .. } if member_pubkey == "carol"
+        )));
+    }
+}
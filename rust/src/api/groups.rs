@@ -1,8 +1,12 @@
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use crate::api::events::refresh_events;
 use flutter_rust_bridge::frb;
 use hex;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Mutex, OnceLock};
 pub use whitenoise::{
-    Group, GroupId, GroupState, GroupType, NostrGroupConfigData, PublicKey, Whitenoise,
-    WhitenoiseError,
+    Account, Group, GroupId, GroupState, GroupType, NostrGroupConfigData, PublicKey, WelcomeState,
+    Whitenoise, WhitenoiseError,
 };
 
 #[derive(Debug, Clone)]
@@ -11,6 +15,7 @@ pub struct GroupData {
     pub nostr_group_id: String,
     pub name: String,
     pub description: String,
+    pub image_url: Option<String>, // Set by other clients; the bridge cannot set or decrypt it
     pub admin_pubkeys: Vec<String>,
     pub last_message_id: Option<String>,
     pub last_message_at: Option<u64>,
@@ -60,16 +65,12 @@ pub fn group_id_to_string(group_id: &GroupId) -> String {
 ///
 /// # Returns
 /// * `Ok(GroupId)` - Successfully parsed group ID
-/// * `Err(WhitenoiseError)` - If the hex string is invalid or malformed
+/// * `Err(BridgeError)` - If the hex string is invalid or malformed
 #[frb]
-pub fn group_id_from_string(hex_string: String) -> Result<GroupId, WhitenoiseError> {
+pub fn group_id_from_string(hex_string: String) -> Result<GroupId, BridgeError> {
     // Convert hex string back to GroupId using from_slice() method and hex crate
     let bytes = hex::decode(hex_string).map_err(|e| {
-        let json_error = serde_json::Error::io(std::io::Error::new(
-            std::io::ErrorKind::InvalidData,
-            format!("Hex decode error: {e}"),
-        ));
-        WhitenoiseError::from(json_error)
+        BridgeError::new(BridgeErrorCode::InvalidHex, "Invalid group ID").with_details(e)
     })?;
     Ok(GroupId::from_slice(&bytes))
 }
@@ -91,6 +92,7 @@ pub fn convert_group_to_data(group: &Group) -> GroupData {
         nostr_group_id: hex::encode(group.nostr_group_id),
         name: group.name.clone(),
         description: group.description.clone(),
+        image_url: group.image_url.clone(),
         admin_pubkeys: group.admin_pubkeys.iter().map(|pk| pk.to_hex()).collect(),
         last_message_id: group.last_message_id.map(|id| id.to_hex()),
         last_message_at: group.last_message_at.map(|at| at.as_u64()),
@@ -110,15 +112,124 @@ pub fn convert_group_to_data(group: &Group) -> GroupData {
 ///
 /// # Returns
 /// * `Ok(Vec<GroupData>)` - Vector of group data for all active groups
-/// * `Err(WhitenoiseError)` - If there was an error fetching groups or account not found
+/// * `Err(BridgeError)` - If there was an error fetching groups or account not found
 #[frb]
-pub async fn fetch_groups(pubkey: &PublicKey) -> Result<Vec<GroupData>, WhitenoiseError> {
+pub async fn fetch_groups(pubkey: &PublicKey) -> Result<Vec<GroupData>, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let account = whitenoise.fetch_account(pubkey).await?;
     let groups = whitenoise.fetch_groups(&account, true).await?;
     Ok(groups.iter().map(convert_group_to_data).collect())
 }
 
+/// Serializes `get_or_create_dm_group` so concurrent calls cannot create duplicate DMs.
+static DM_GROUP_LOCK: OnceLock<tokio::sync::Mutex<()>> = OnceLock::new();
+
+/// Looks up a group the account belongs to, whatever its state.
+pub(crate) async fn find_group(
+    whitenoise: &Whitenoise,
+    account: &Account,
+    group_id: &GroupId,
+) -> Result<Group, BridgeError> {
+    whitenoise
+        .fetch_groups(account, false)
+        .await?
+        .into_iter()
+        .find(|group| group.mls_group_id == *group_id)
+        .ok_or_else(|| BridgeError::new(BridgeErrorCode::GroupNotFound, "Group not found"))
+}
+
+/// Members of a group as of an epoch.
+#[derive(Debug, Clone)]
+struct EpochMembers {
+    epoch: u64,
+    members: Vec<PublicKey>,
+}
+
+/// Members of the groups of each account, keyed by (account public key, MLS group ID),
+/// both hex. Shared by the chat list and the event watcher.
+static GROUP_MEMBERS: OnceLock<Mutex<HashMap<(String, String), EpochMembers>>> = OnceLock::new();
+
+/// Returns the members of a group, fetching them again only when the epoch changes.
+///
+/// Membership only changes with a new MLS epoch, so members fetched at the group's
+/// current epoch are still correct.
+pub(crate) async fn epoch_members(
+    whitenoise: &Whitenoise,
+    account: &Account,
+    group: &Group,
+) -> Result<Vec<PublicKey>, BridgeError> {
+    let key = (
+        account.pubkey.to_hex(),
+        group_id_to_string(&group.mls_group_id),
+    );
+    let cache = GROUP_MEMBERS.get_or_init(Default::default);
+    if let Some(cached) = cache.lock().unwrap_or_else(|e| e.into_inner()).get(&key) {
+        if cached.epoch == group.epoch {
+            return Ok(cached.members.clone());
+        }
+    }
+
+    let members = whitenoise
+        .fetch_group_members(account, &group.mls_group_id)
+        .await?;
+    cache.lock().unwrap_or_else(|e| e.into_inner()).insert(
+        key,
+        EpochMembers {
+            epoch: group.epoch,
+            members: members.clone(),
+        },
+    );
+    Ok(members)
+}
+
+/// Clears the cached group members of an account (on logout), or of all accounts.
+pub(crate) fn clear_epoch_members(pubkey: Option<&PublicKey>) {
+    let pubkey = pubkey.map(PublicKey::to_hex);
+    if let Some(cache) = GROUP_MEMBERS.get() {
+        cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|(account, _), _| pubkey.as_ref().is_some_and(|pubkey| account != pubkey));
+    }
+}
+
+/// Explains why adding members to a group (or creating one) failed.
+///
+/// The core reports a member without a key package as a generic error, so when the change
+/// failed, the members' key packages are looked up; if some member has none, the error
+/// becomes `NoKeyPackage`, listing them. Lookups that fail leave the original error.
+async fn explain_member_error(
+    whitenoise: &Whitenoise,
+    member_pubkeys: &[PublicKey],
+    error: BridgeError,
+) -> BridgeError {
+    let mut missing = Vec::new();
+    for member in member_pubkeys {
+        let Ok(relays) = whitenoise
+            .fetch_relays(*member, whitenoise::RelayType::KeyPackage)
+            .await
+        else {
+            continue;
+        };
+        // Without key package relays, no key package can be found
+        if relays.is_empty() {
+            missing.push(member.to_hex());
+            continue;
+        }
+        if let Ok(None) = whitenoise.fetch_key_package_event(*member, relays).await {
+            missing.push(member.to_hex());
+        }
+    }
+    if missing.is_empty() {
+        return error;
+    }
+    BridgeError::new(
+        BridgeErrorCode::NoKeyPackage,
+        "Some members have not published a key package",
+    )
+    .with_details(missing.join(", "))
+}
+
 /// Fetches all members of a specific group.
 ///
 /// This function retrieves the public keys of all current members in the specified group.
@@ -130,15 +241,15 @@ pub async fn fetch_groups(pubkey: &PublicKey) -> Result<Vec<GroupData>, Whitenoi
 ///
 /// # Returns
 /// * `Ok(Vec<PublicKey>)` - Vector of public keys for all group members
-/// * `Err(WhitenoiseError)` - If there was an error fetching members or insufficient permissions
+/// * `Err(BridgeError)` - If there was an error fetching members or insufficient permissions
 #[frb]
 pub async fn fetch_group_members(
     pubkey: &PublicKey,
     group_id: whitenoise::GroupId,
-) -> Result<Vec<PublicKey>, WhitenoiseError> {
+) -> Result<Vec<PublicKey>, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let account = whitenoise.fetch_account(pubkey).await?;
-    whitenoise.fetch_group_members(&account, &group_id).await
+    Ok(whitenoise.fetch_group_members(&account, &group_id).await?)
 }
 
 /// Fetches all administrators of a specific group.
@@ -152,15 +263,15 @@ pub async fn fetch_group_members(
 ///
 /// # Returns
 /// * `Ok(Vec<PublicKey>)` - Vector of public keys for all group administrators
-/// * `Err(WhitenoiseError)` - If there was an error fetching admins or insufficient permissions
+/// * `Err(BridgeError)` - If there was an error fetching admins or insufficient permissions
 #[frb]
 pub async fn fetch_group_admins(
     pubkey: &PublicKey,
     group_id: whitenoise::GroupId,
-) -> Result<Vec<PublicKey>, WhitenoiseError> {
+) -> Result<Vec<PublicKey>, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let account = whitenoise.fetch_account(pubkey).await?;
-    whitenoise.fetch_group_admins(&account, &group_id).await
+    Ok(whitenoise.fetch_group_admins(&account, &group_id).await?)
 }
 
 /// Creates a new MLS group with specified members and administrators.
@@ -178,7 +289,8 @@ pub async fn fetch_group_admins(
 ///
 /// # Returns
 /// * `Ok(GroupData)` - The created group data if successful
-/// * `Err(WhitenoiseError)` - If there was an error creating the group
+/// * `Err(BridgeError)` - `NoKeyPackage` if a member has not published a key package, or
+///   another error if the group could not be created
 ///
 /// # Notes
 /// * All members must have published key packages to relays
@@ -191,7 +303,7 @@ pub async fn create_group(
     admin_pubkeys: Vec<PublicKey>,
     group_name: String,
     group_description: String,
-) -> Result<GroupData, WhitenoiseError> {
+) -> Result<GroupData, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let creator_account = whitenoise.fetch_account(creator_pubkey).await?;
 
@@ -208,7 +320,8 @@ pub async fn create_group(
         relays: nostr_relays,
     };
 
-    let group = tokio::task::spawn_blocking(move || {
+    let members = member_pubkeys.clone();
+    let created = tokio::task::spawn_blocking(move || {
         tokio::runtime::Handle::current().block_on(whitenoise.create_group(
             &creator_account,
             member_pubkeys,
@@ -216,8 +329,12 @@ pub async fn create_group(
             nostr_group_config,
         ))
     })
-    .await
-    .map_err(|e| WhitenoiseError::from(std::io::Error::other(e)))??;
+    .await?;
+    let group = match created {
+        Ok(group) => group,
+        Err(error) => return Err(explain_member_error(whitenoise, &members, error.into()).await),
+    };
+    refresh_events();
     Ok(convert_group_to_data(&group))
 }
 
@@ -232,7 +349,8 @@ pub async fn create_group(
 ///
 /// # Returns
 /// * `Ok(())` - If the members were successfully added to the group
-/// * `Err(WhitenoiseError)` - If there was an error adding members (e.g., insufficient permissions, invalid group ID, or MLS protocol errors)
+/// * `Err(BridgeError)` - `NoKeyPackage` if a new member has not published a key package,
+///   or another error (e.g., insufficient permissions, invalid group ID, or MLS protocol errors)
 ///
 /// # Notes
 /// * Only group administrators can add new members
@@ -244,18 +362,23 @@ pub async fn add_members_to_group(
     pubkey: &PublicKey,
     group_id: whitenoise::GroupId,
     member_pubkeys: Vec<PublicKey>,
-) -> Result<(), WhitenoiseError> {
+) -> Result<(), BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let account = whitenoise.fetch_account(pubkey).await?;
-    tokio::task::spawn_blocking(move || {
+    let members = member_pubkeys.clone();
+    let added = tokio::task::spawn_blocking(move || {
         tokio::runtime::Handle::current().block_on(whitenoise.add_members_to_group(
             &account,
             &group_id,
             member_pubkeys,
         ))
     })
-    .await
-    .map_err(|e| WhitenoiseError::from(std::io::Error::other(e)))?
+    .await?;
+    if let Err(error) = added {
+        return Err(explain_member_error(whitenoise, &members, error.into()).await);
+    }
+    refresh_events();
+    Ok(())
 }
 
 /// This method removes existing members from an MLS group. The calling account must have
@@ -269,7 +392,7 @@ pub async fn add_members_to_group(
 ///
 /// # Returns
 /// * `Ok(())` - If the members were successfully removed from the group
-/// * `Err(WhitenoiseError)` - If there was an error removing members (e.g., insufficient permissions, invalid group ID, member not found, or MLS protocol errors)
+/// * `Err(BridgeError)` - If there was an error removing members (e.g., insufficient permissions, invalid group ID, member not found, or MLS protocol errors)
 ///
 /// # Notes
 /// * Only group administrators can remove members
@@ -283,7 +406,7 @@ pub async fn remove_members_from_group(
     pubkey: &PublicKey,
     group_id: whitenoise::GroupId,
     member_pubkeys: Vec<PublicKey>,
-) -> Result<(), WhitenoiseError> {
+) -> Result<(), BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let account = whitenoise.fetch_account(pubkey).await?;
     tokio::task::spawn_blocking(move || {
@@ -293,6 +416,154 @@ pub async fn remove_members_from_group(
             member_pubkeys,
         ))
     })
+    .await??;
+    refresh_events();
+    Ok(())
+}
+
+/// Returns whether a group (or the group a welcome invites to) has the shape of a direct
+/// message between `pubkey` and `other_pubkey`, as `get_or_create_dm_group` creates them:
+/// the two as its only members and admins, and no name or description.
+///
+/// Welcomes carry no group type, and the core's type alone does not tell a DM apart
+/// from a named group that happens to have two members, so the shape is checked too.
+fn is_dm_shape(
+    pubkey: &PublicKey,
+    other_pubkey: &PublicKey,
+    member_count: usize,
+    name: &str,
+    description: &str,
+    admins: &BTreeSet<PublicKey>,
+) -> bool {
+    member_count == 2
+        && name.is_empty()
+        && description.is_empty()
+        && admins.len() == 2
+        && admins.contains(pubkey)
+        && admins.contains(other_pubkey)
+}
+
+/// Returns the direct message group with a contact, creating it if needed.
+///
+/// Looks for an active `GroupType::DirectMessage` group of the two accounts with the
+/// shape of a DM (see below). If there is none but the contact has already invited the
+/// account to such a group, that pending welcome is accepted and its group returned, if
+/// the core types it as a DM. Otherwise a new DM group is created with both participants
+/// as members and admins and no name or description.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account starting the conversation
+/// * `other_pubkey` - The public key of the contact
+///
+/// # Returns
+/// * `Ok(GroupData)` - The existing or newly created DM group
+/// * `Err(BridgeError)` - `InvalidInput` if both keys are the same, or any error from
+///   accepting the welcome or creating the group
+///
+/// # Notes
+/// * The contact must have published a key package for a new group to be created
+/// * Concurrent calls are serialized, so they return the same group
+#[frb]
+pub async fn get_or_create_dm_group(
+    pubkey: &PublicKey,
+    other_pubkey: PublicKey,
+) -> Result<GroupData, BridgeError> {
+    if *pubkey == other_pubkey {
+        return Err(BridgeError::new(
+            BridgeErrorCode::InvalidInput,
+            "Cannot start a direct message with yourself",
+        ));
+    }
+    let _guard = DM_GROUP_LOCK
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await;
+
+    let whitenoise = Whitenoise::get_instance()?;
+    let account = whitenoise.fetch_account(pubkey).await?;
+
+    for group in whitenoise.fetch_groups(&account, true).await? {
+        if group.group_type != GroupType::DirectMessage || group.state != GroupState::Active {
+            continue;
+        }
+        let members = whitenoise
+            .fetch_group_members(&account, &group.mls_group_id)
+            .await?;
+        if members.contains(pubkey)
+            && members.contains(&other_pubkey)
+            && is_dm_shape(
+                pubkey,
+                &other_pubkey,
+                members.len(),
+                &group.name,
+                &group.description,
+                &group.admin_pubkeys,
+            )
+        {
+            return Ok(convert_group_to_data(&group));
+        }
+    }
+
+    let pending_welcome = whitenoise
+        .fetch_welcomes(pubkey)
+        .await?
+        .into_iter()
+        .find(|welcome| {
+            welcome.state == WelcomeState::Pending
+                && welcome.welcomer == other_pubkey
+                && is_dm_shape(
+                    pubkey,
+                    &other_pubkey,
+                    welcome.member_count as usize,
+                    &welcome.group_name,
+                    &welcome.group_description,
+                    &welcome.group_admin_pubkeys,
+                )
+        });
+    if let Some(welcome) = pending_welcome {
+        whitenoise
+            .accept_welcome(pubkey, welcome.id.to_string())
+            .await?;
+        refresh_events();
+        let group = find_group(whitenoise, &account, &welcome.mls_group_id).await?;
+        // The invitation stays accepted, but only a group the core types as a DM is one
+        if group.group_type == GroupType::DirectMessage {
+            return Ok(convert_group_to_data(&group));
+        }
+    }
+
+    create_group(
+        pubkey,
+        vec![other_pubkey],
+        vec![*pubkey, other_pubkey],
+        String::new(),
+        String::new(),
+    )
     .await
-    .map_err(|e| WhitenoiseError::from(std::io::Error::other(e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(hex: &str) -> PublicKey {
+        PublicKey::from_hex(hex).unwrap()
+    }
+
+    #[test]
+    fn dm_shape_needs_both_accounts_as_only_members_and_admins_and_no_name() {
+        let alice = key("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+        let bob = key("c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5");
+        let carol = key("f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9");
+        let both: BTreeSet<PublicKey> = [alice, bob].into_iter().collect();
+        assert!(is_dm_shape(&alice, &bob, 2, "", "", &both));
+        // Two-member groups with a name or description are regular groups
+        assert!(!is_dm_shape(&alice, &bob, 2, "Project", "", &both));
+        assert!(!is_dm_shape(&alice, &bob, 2, "", "Planning", &both));
+        assert!(!is_dm_shape(&alice, &bob, 3, "", "", &both));
+        let only_bob: BTreeSet<PublicKey> = [bob].into_iter().collect();
+        assert!(!is_dm_shape(&alice, &bob, 2, "", "", &only_bob));
+        let with_carol: BTreeSet<PublicKey> = [bob, carol].into_iter().collect();
+        assert!(!is_dm_shape(&alice, &bob, 2, "", "", &with_carol));
+    }
 }
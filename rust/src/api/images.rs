@@ -0,0 +1,507 @@
+use crate::api::blossom::upload_to_blossom_servers;
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use crate::api::media::{image_mime_type, sha256_hex};
+use crate::api::utils::image_type_from_bytes;
+use flutter_rust_bridge::frb;
+use image::codecs::gif::{GifDecoder, GifEncoder, Repeat};
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::imageops::FilterType;
+use image::{
+    AnimationDecoder, DynamicImage, Frame, ImageDecoder, ImageFormat, ImageReader, RgbaImage,
+};
+use std::io::Cursor;
+use std::path::PathBuf;
+use std::time::Duration;
+pub use whitenoise::{ImageType, PublicKey};
+
+/// Options for `process_image`.
+#[derive(Debug, Clone)]
+pub struct ImageProcessingOptions {
+    pub max_dimension: u32, // Longest side of the output, in pixels
+    pub convert_to_webp: bool,
+    pub quality: u8,              // 1-100, used for JPEG and WebP output
+    pub thumbnail_dimension: u32, // Longest side of the thumbnail; 0 for no thumbnail
+}
+
+impl Default for ImageProcessingOptions {
+    fn default() -> Self {
+        Self {
+            max_dimension: 2048,
+            convert_to_webp: false,
+            quality: 85,
+            thumbnail_dimension: 256,
+        }
+    }
+}
+
+/// An image prepared for upload by `process_image`.
+#[derive(Debug, Clone)]
+pub struct ProcessedImageData {
+    pub file_path: String,
+    pub image_type: ImageType,
+    pub mime_type: String,
+    pub width: u32,
+    pub height: u32,
+    pub size: u64,
+    pub sha256: String,
+    pub blurhash: String,
+    pub thumbnail_path: Option<String>,
+}
+
+/// Where `upload_image` stored an image and its thumbnail.
+#[derive(Debug, Clone)]
+pub struct UploadedImageData {
+    pub url: String,
+    pub mirror_urls: Vec<String>, // Copies on the account's other Blossom servers
+    pub thumbnail_url: Option<String>,
+}
+
+/// Size the image is scaled down to before computing its blurhash; the hash only
+/// encodes a handful of colour components, so more pixels only cost time.
+const BLURHASH_SAMPLE_DIMENSION: u32 = 32;
+
+/// Number of blurhash components along the longest side of the image.
+const BLURHASH_COMPONENTS: u32 = 4;
+
+fn image_error(error: impl std::fmt::Display) -> BridgeError {
+    BridgeError::new(BridgeErrorCode::InvalidInput, "Could not process the image")
+        .with_details(error)
+}
+
+fn encode_image(
+    image: &DynamicImage,
+    image_type: ImageType,
+    quality: u8,
+) -> Result<Vec<u8>, BridgeError> {
+    let quality = quality.clamp(1, 100);
+    let mut bytes = Vec::new();
+    match image_type {
+        ImageType::Jpeg | ImageType::Jpg => image
+            .to_rgb8()
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut bytes, quality)),
+        // The `image` crate can only write lossless WebP, which is often larger than the
+        // JPEG it replaces, so WebP goes through libwebp
+        ImageType::Webp => {
+            let rgba = image.to_rgba8();
+            let encoded = webp::Encoder::from_rgba(rgba.as_raw(), rgba.width(), rgba.height())
+                .encode_simple(false, f32::from(quality))
+                .map_err(|e| image_error(format!("{e:?}")))?;
+            return Ok(encoded.to_vec());
+        }
+        ImageType::Png => image.write_with_encoder(PngEncoder::new(&mut bytes)),
+        // A single-frame GIF; use `resize_gif` to keep the frames of an animation
+        ImageType::Gif => image
+            .to_rgba8()
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Gif),
+    }
+    .map_err(image_error)?;
+    Ok(bytes)
+}
+
+/// Re-encodes every frame of an animated GIF at `width` x `height`, keeping the frame
+/// delays and looping forever. Comments and application data (e.g. XMP) are dropped.
+fn resize_gif(original: &[u8], width: u32, height: u32) -> Result<Vec<u8>, BridgeError> {
+    let decoder = GifDecoder::new(Cursor::new(original)).map_err(image_error)?;
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = GifEncoder::new(&mut bytes);
+        encoder.set_repeat(Repeat::Infinite).map_err(image_error)?;
+        for frame in decoder.into_frames() {
+            let frame = frame.map_err(image_error)?;
+            let delay = frame.delay();
+            // The decoder composites each frame onto the full canvas
+            let buffer: RgbaImage =
+                image::imageops::resize(frame.buffer(), width, height, FilterType::Triangle);
+            encoder
+                .encode_frame(Frame::from_parts(buffer, 0, 0, delay))
+                .map_err(image_error)?;
+        }
+    }
+    Ok(bytes)
+}
+
+fn compute_blurhash(image: &DynamicImage) -> Result<String, BridgeError> {
+    let sample = image
+        .thumbnail(BLURHASH_SAMPLE_DIMENSION, BLURHASH_SAMPLE_DIMENSION)
+        .to_rgba8();
+    let (width, height) = sample.dimensions();
+    let (components_x, components_y) = if width >= height {
+        (
+            BLURHASH_COMPONENTS,
+            (BLURHASH_COMPONENTS * height / width).max(1),
+        )
+    } else {
+        (
+            (BLURHASH_COMPONENTS * width / height).max(1),
+            BLURHASH_COMPONENTS,
+        )
+    };
+    blurhash::encode(components_x, components_y, width, height, sample.as_raw()).map_err(|e| {
+        BridgeError::new(BridgeErrorCode::Internal, "Could not compute blurhash")
+            .with_details(format!("{e:?}"))
+    })
+}
+
+/// How long a processed image may stay in the temporary directory (1 hour); it is only
+/// kept until it is uploaded.
+const PROCESSED_IMAGE_MAX_AGE: Duration = Duration::from_secs(60 * 60);
+
+pub(crate) fn processed_images_dir() -> PathBuf {
+    std::env::temp_dir().join("whitenoise-images")
+}
+
+/// Deletes processed images left behind for longer than `PROCESSED_IMAGE_MAX_AGE`, e.g.
+/// by an app that was closed before uploading them.
+fn remove_stale_processed_images() {
+    let Ok(entries) = std::fs::read_dir(processed_images_dir()) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let stale = entry
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > PROCESSED_IMAGE_MAX_AGE);
+        if stale {
+            let _ = std::fs::remove_file(entry.path());
+        }
+    }
+}
+
+/// Deletes the files `process_image` wrote for an image.
+pub(crate) fn remove_processed_files(image: &ProcessedImageData) {
+    let _ = std::fs::remove_file(&image.file_path);
+    if let Some(thumbnail_path) = &image.thumbnail_path {
+        let _ = std::fs::remove_file(thumbnail_path);
+    }
+}
+
+fn write_processed(bytes: &[u8], name: &str) -> Result<PathBuf, BridgeError> {
+    let dir = processed_images_dir();
+    let path = dir.join(name);
+    std::fs::create_dir_all(&dir)
+        .and_then(|_| std::fs::write(&path, bytes))
+        .map_err(|e| {
+            BridgeError::new(
+                BridgeErrorCode::Storage,
+                "Could not save the processed image",
+            )
+            .with_details(e)
+        })?;
+    Ok(path)
+}
+
+pub(crate) fn process_image_blocking(
+    file_path: &str,
+    options: &ImageProcessingOptions,
+) -> Result<ProcessedImageData, BridgeError> {
+    remove_stale_processed_images();
+    let original = std::fs::read(file_path).map_err(|e| {
+        BridgeError::new(BridgeErrorCode::Storage, "Could not read the image file").with_details(e)
+    })?;
+    let input_type = image_type_from_bytes(&original)?;
+    let format = match input_type {
+        ImageType::Png => ImageFormat::Png,
+        ImageType::Jpeg | ImageType::Jpg => ImageFormat::Jpeg,
+        ImageType::Gif => ImageFormat::Gif,
+        ImageType::Webp => ImageFormat::WebP,
+    };
+    let reader = ImageReader::with_format(Cursor::new(original.as_slice()), format);
+    let mut decoder = reader.into_decoder().map_err(image_error)?;
+    let orientation = decoder.orientation().map_err(image_error)?;
+    let mut image = DynamicImage::from_decoder(decoder).map_err(image_error)?;
+    // Metadata is dropped when the image is re-encoded, so bake the EXIF rotation into
+    // the pixels first
+    image.apply_orientation(orientation);
+
+    let max_dimension = options.max_dimension.max(1);
+    let oversized = image.width() > max_dimension || image.height() > max_dimension;
+    if oversized {
+        image = image.resize(max_dimension, max_dimension, FilterType::Lanczos3);
+    }
+    let blurhash = compute_blurhash(&image)?;
+
+    // Re-encoding a GIF as a still image would lose its animation, so GIFs stay GIFs,
+    // with every frame re-encoded (and scaled down if needed) to drop their metadata
+    let (bytes, image_type) = match input_type {
+        ImageType::Gif => (
+            resize_gif(&original, image.width(), image.height())?,
+            ImageType::Gif,
+        ),
+        _ => {
+            let image_type = if options.convert_to_webp {
+                ImageType::Webp
+            } else {
+                input_type
+            };
+            (
+                encode_image(&image, image_type, options.quality)?,
+                image_type,
+            )
+        }
+    };
+    let sha256 = sha256_hex(&bytes);
+    let extension = match image_type {
+        ImageType::Png => "png",
+        ImageType::Jpeg | ImageType::Jpg => "jpg",
+        ImageType::Gif => "gif",
+        ImageType::Webp => "webp",
+    };
+    let path = write_processed(&bytes, &format!("{sha256}.{extension}"))?;
+
+    let thumbnail_path = if options.thumbnail_dimension > 0 {
+        let thumbnail = image.thumbnail(options.thumbnail_dimension, options.thumbnail_dimension);
+        let thumbnail_type = match image_type {
+            ImageType::Gif => ImageType::Png,
+            other => other,
+        };
+        let thumbnail_bytes = encode_image(&thumbnail, thumbnail_type, options.quality)?;
+        let thumbnail_extension = if matches!(image_type, ImageType::Gif) {
+            "png"
+        } else {
+            extension
+        };
+        let path = write_processed(
+            &thumbnail_bytes,
+            &format!("{sha256}-thumb.{thumbnail_extension}"),
+        )?;
+        Some(path.to_string_lossy().to_string())
+    } else {
+        None
+    };
+
+    Ok(ProcessedImageData {
+        file_path: path.to_string_lossy().to_string(),
+        image_type,
+        mime_type: image_mime_type(image_type).to_string(),
+        width: image.width(),
+        height: image.height(),
+        size: bytes.len() as u64,
+        sha256,
+        blurhash,
+        thumbnail_path,
+    })
+}
+
+/// Prepares an image for upload.
+///
+/// * Strips all metadata (EXIF, including GPS location), after applying the EXIF
+///   orientation so the image is still shown the right way up
+/// * Scales the image down so its longest side is at most `max_dimension`
+/// * Optionally converts it to (lossy) WebP
+/// * Computes a blurhash and writes a thumbnail for use as a placeholder
+///
+/// The processed image and thumbnail are written to a temporary directory; the original
+/// file is not modified. `upload_image` deletes them once uploaded; call
+/// `discard_processed_image` for an image that is not uploaded. Leftovers are deleted
+/// after an hour.
+///
+/// # Arguments
+/// * `file_path` - The local path to the image
+/// * `options` - Processing options, or `None` for the defaults (2048 px, no WebP
+///   conversion, quality 85, 256 px thumbnail)
+///
+/// # Returns
+/// * `Ok(ProcessedImageData)` - The processed image, its dimensions, hash and blurhash
+/// * `Err(BridgeError)` - `Unsupported` if the file is not a PNG, JPEG, GIF or WebP
+///   image, or `InvalidInput` if it could not be decoded
+///
+/// # Notes
+/// * GIFs stay GIFs so they keep their animation; every frame is re-encoded, and scaled
+///   down when larger than `max_dimension`
+#[frb]
+pub async fn process_image(
+    file_path: String,
+    options: Option<ImageProcessingOptions>,
+) -> Result<ProcessedImageData, BridgeError> {
+    let options = options.unwrap_or_default();
+    tokio::task::spawn_blocking(move || process_image_blocking(&file_path, &options)).await?
+}
+
+fn read_processed(path: &str) -> Result<Vec<u8>, BridgeError> {
+    std::fs::read(path).map_err(|e| {
+        BridgeError::new(
+            BridgeErrorCode::Storage,
+            "Could not read the processed image",
+        )
+        .with_details(e)
+    })
+}
+
+/// Uploads an image processed by `process_image`, and its thumbnail, to the account's
+/// Blossom servers, then deletes the processed files.
+///
+/// The upload is public: anyone with the URL can fetch the image. Use it for images that
+/// are public anyway, such as profile pictures and banners, never for chat media, which
+/// must be encrypted for the group (see `docs/bridge_core_limitations.md`).
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account uploading the image
+/// * `image` - The processed image
+///
+/// # Returns
+/// * `Ok(UploadedImageData)` - The URLs of the image, its mirrors and its thumbnail
+/// * `Err(BridgeError)` - If the image could not be read or no server accepted it
+///
+/// # Notes
+/// * A thumbnail that fails to upload is left out rather than failing the upload
+#[frb]
+pub async fn upload_image(
+    pubkey: PublicKey,
+    image: ProcessedImageData,
+) -> Result<UploadedImageData, BridgeError> {
+    let blob = read_processed(&image.file_path)?;
+    let upload = upload_to_blossom_servers(pubkey, &blob, image.image_type).await?;
+
+    let mut thumbnail_url = None;
+    if let Some(thumbnail_path) = &image.thumbnail_path {
+        let thumbnail = read_processed(thumbnail_path)?;
+        // GIF thumbnails are stills, so the thumbnail's format is read from its bytes
+        let thumbnail_type = image_type_from_bytes(&thumbnail)?;
+        thumbnail_url = upload_to_blossom_servers(pubkey, &thumbnail, thumbnail_type)
+            .await
+            .ok()
+            .map(|upload| upload.url);
+    }
+
+    remove_processed_files(&image);
+    Ok(UploadedImageData {
+        url: upload.url,
+        mirror_urls: upload.mirror_urls,
+        thumbnail_url,
+    })
+}
+
+/// Deletes the files `process_image` wrote for an image that will not be uploaded.
+///
+/// # Arguments
+/// * `image` - The processed image
+#[frb]
+pub fn discard_processed_image(image: ProcessedImageData) {
+    remove_processed_files(&image);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    fn write_input(name: &str, bytes: &[u8]) -> String {
+        let dir = std::env::temp_dir().join("whitenoise-image-tests");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, bytes).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    fn gif(width: u32, height: u32, frames: u8) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        {
+            let mut encoder = GifEncoder::new(&mut bytes);
+            for i in 0..frames {
+                let buffer = RgbaImage::from_pixel(width, height, Rgba([i * 60, 0, 0, 255]));
+                encoder.encode_frame(Frame::new(buffer)).unwrap();
+            }
+        }
+        bytes
+    }
+
+    fn options(max_dimension: u32) -> ImageProcessingOptions {
+        ImageProcessingOptions {
+            max_dimension,
+            thumbnail_dimension: 0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn strips_metadata_from_small_gifs() {
+        // A comment extension block, inserted before the trailer
+        let mut original = gif(40, 20, 2);
+        original.pop();
+        original.extend_from_slice(&[0x21, 0xfe, 9]);
+        original.extend_from_slice(b"GPS 52,13");
+        original.extend_from_slice(&[0x00, 0x3b]);
+        let path = write_input("small.gif", &original);
+        let processed = process_image_blocking(&path, &options(64)).unwrap();
+        assert_eq!((processed.width, processed.height), (40, 20));
+        let bytes = std::fs::read(&processed.file_path).unwrap();
+        assert!(!bytes.windows(3).any(|window| window == b"GPS"));
+        let frames = GifDecoder::new(Cursor::new(bytes))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+        assert_eq!(frames.len(), 2);
+    }
+
+    #[test]
+    fn encodes_gifs_as_gifs() {
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(8, 8, Rgba([1, 2, 3, 255])));
+        let bytes = encode_image(&image, ImageType::Gif, 85).unwrap();
+        assert!(matches!(image_type_from_bytes(&bytes), Ok(ImageType::Gif)));
+    }
+
+    #[test]
+    fn uploads_and_discards_remove_processed_files() {
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(64, 64, Rgba([5, 5, 5, 255])));
+        let path = write_input(
+            "discard.png",
+            &encode_image(&image, ImageType::Png, 85).unwrap(),
+        );
+        let processed = process_image_blocking(
+            &path,
+            &ImageProcessingOptions {
+                thumbnail_dimension: 16,
+                ..options(2048)
+            },
+        )
+        .unwrap();
+        let thumbnail_path = processed.thumbnail_path.clone().unwrap();
+        assert!(std::path::Path::new(&thumbnail_path).exists());
+        discard_processed_image(processed.clone());
+        assert!(!std::path::Path::new(&processed.file_path).exists());
+        assert!(!std::path::Path::new(&thumbnail_path).exists());
+    }
+
+    #[test]
+    fn scales_every_frame_of_large_gifs() {
+        let path = write_input("large.gif", &gif(200, 100, 3));
+        let processed = process_image_blocking(&path, &options(50)).unwrap();
+        assert_eq!((processed.width, processed.height), (50, 25));
+        let bytes = std::fs::read(&processed.file_path).unwrap();
+        let frames = GifDecoder::new(Cursor::new(bytes))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+        assert_eq!(frames.len(), 3);
+        assert!(frames
+            .iter()
+            .all(|frame| frame.buffer().dimensions() == (50, 25)));
+    }
+
+    #[test]
+    fn converts_to_webp() {
+        let image =
+            DynamicImage::ImageRgba8(RgbaImage::from_pixel(64, 64, Rgba([9, 99, 199, 255])));
+        let png = encode_image(&image, ImageType::Png, 85).unwrap();
+        let path = write_input("photo.png", &png);
+        let processed = process_image_blocking(
+            &path,
+            &ImageProcessingOptions {
+                convert_to_webp: true,
+                ..options(2048)
+            },
+        )
+        .unwrap();
+        assert!(matches!(processed.image_type, ImageType::Webp));
+        assert_eq!(processed.mime_type, "image/webp");
+        let bytes = std::fs::read(&processed.file_path).unwrap();
+        assert!(matches!(image_type_from_bytes(&bytes), Ok(ImageType::Webp)));
+        // Lossy WebP uses the `VP8 ` chunk; lossless would be `VP8L`
+        assert_eq!(&bytes[12..16], b"VP8 ");
+    }
+}
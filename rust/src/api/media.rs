@@ -0,0 +1,509 @@
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use crate::api::media_cache::{cache_insert, cache_lookup};
+use crate::api::messages::find_group_message;
+use crate::signer::AccountSigner;
+use crate::store;
+use flutter_rust_bridge::frb;
+use nostr_blossom::client::BlossomClient;
+use sha2::{Digest, Sha256};
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+use std::time::Duration;
+use url::{Host, Url};
+pub use whitenoise::{ImageType, PublicKey, Tag, Whitenoise};
+
+/// A media file attached to a chat message with an `imeta` tag.
+///
+/// Use `download_media` to get the file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaAttachmentData {
+    pub url: String,
+    pub mime_type: String,
+    pub sha256: String, // Hash of the original file; empty if the tag has no valid hash
+    pub size: Option<u64>,
+    pub dimensions: Option<String>, // "<width>x<height>"
+    pub blurhash: Option<String>,
+    pub is_encrypted: bool, // Encrypted with a key derived from the group's MLS state
+}
+
+/// Everything an `imeta` tag says about an attachment.
+#[derive(Debug, Clone)]
+pub(crate) struct Imeta {
+    pub(crate) attachment: MediaAttachmentData,
+    pub(crate) encrypted_sha256: Option<String>, // `x`: hash of the file as uploaded
+    pub(crate) fallback_urls: Vec<String>,       // Mirrors of `attachment.url`
+}
+
+/// Returns the lowercase hex SHA-256 hash of a blob, as used by Blossom to address it.
+pub(crate) fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+/// Uploads a blob to a Blossom server on behalf of an account and returns its URL.
+///
+/// The upload is authorized (BUD-02) with an event signed by the account's keys, so the
+/// server can attribute it to the account. `image_type` is the type of the uploaded media.
+pub(crate) async fn upload_blob(
+    pubkey: PublicKey,
+    server_url: &str,
+    blob: &[u8],
+    image_type: ImageType,
+) -> Result<String, BridgeError> {
+    let server = Url::parse(server_url).map_err(|e| {
+        BridgeError::new(BridgeErrorCode::InvalidInput, "Invalid media server URL").with_details(e)
+    })?;
+    let signer = AccountSigner::load(&pubkey).await?;
+
+    let descriptor = BlossomClient::new(server)
+        .upload_blob(
+            blob.to_vec(),
+            Some(image_mime_type(image_type).to_string()),
+            None,
+            Some(signer.nostr_signer()),
+        )
+        .await
+        .map_err(|e| {
+            BridgeError::new(BridgeErrorCode::Network, "Could not upload media").with_details(e)
+        })?;
+    Ok(descriptor.url.to_string())
+}
+
+/// Timeout for downloading one media file.
+const MEDIA_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Largest media file downloaded (100 MiB), also when the `imeta` tag claims a larger size.
+const MEDIA_MAX_DOWNLOAD_BYTES: u64 = 100 * 1024 * 1024;
+
+/// Whether an address is local to the device or its network (loopback, private,
+/// link-local, ...) and must not be contacted on behalf of a message from someone else.
+fn is_non_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || ip.is_documentation()
+                || ip.octets()[0] == 0
+                // Shared address space (RFC 6598), used by carrier-grade NAT
+                || (ip.octets()[0] == 100 && (ip.octets()[1] & 0xc0) == 64)
+        }
+        IpAddr::V6(ip) => {
+            if let Some(mapped) = ip.to_ipv4_mapped() {
+                return is_non_public_address(IpAddr::V4(mapped));
+            }
+            ip.is_loopback()
+                || ip.is_unspecified()
+                || ip.is_multicast()
+                // Unique local (fc00::/7) and link-local (fe80::/10)
+                || (ip.segments()[0] & 0xfe00) == 0xfc00
+                || (ip.segments()[0] & 0xffc0) == 0xfe80
+        }
+    }
+}
+
+/// Checks that a media URL may be fetched: it must use HTTPS and name a public host.
+/// Returns the host name to pin when the host is a domain.
+fn check_media_url(url: &Url) -> Result<Option<String>, BridgeError> {
+    let rejected = |message: &str| BridgeError::new(BridgeErrorCode::InvalidInput, message);
+    if url.scheme() != "https" {
+        return Err(rejected("Media URLs must use HTTPS"));
+    }
+    match url.host() {
+        Some(Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            if domain == "localhost" || domain.ends_with(".localhost") {
+                return Err(rejected("Media URL points to a local host"));
+            }
+            Ok(Some(domain))
+        }
+        Some(Host::Ipv4(ip)) if !is_non_public_address(IpAddr::V4(ip)) => Ok(None),
+        Some(Host::Ipv6(ip)) if !is_non_public_address(IpAddr::V6(ip)) => Ok(None),
+        Some(_) => Err(rejected("Media URL points to a local host")),
+        None => Err(rejected("Invalid media URL")),
+    }
+}
+
+/// Reads a response body, failing once it grows past `max_bytes`.
+async fn read_blob(
+    mut response: reqwest::Response,
+    max_bytes: u64,
+) -> Result<Vec<u8>, BridgeError> {
+    let too_large = || BridgeError::new(BridgeErrorCode::InvalidInput, "Media file is too large");
+    if response
+        .content_length()
+        .is_some_and(|length| length > max_bytes)
+    {
+        return Err(too_large());
+    }
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|e| {
+        BridgeError::new(BridgeErrorCode::Network, "Could not download media").with_details(e)
+    })? {
+        if (body.len() + chunk.len()) as u64 > max_bytes {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+/// Downloads a blob of at most `max_bytes` from a Blossom server and checks it against
+/// its SHA-256 hash.
+///
+/// Media URLs come from other people's messages, so only HTTPS URLs of public hosts are
+/// fetched: the host is resolved first and the connection is pinned to the checked
+/// addresses, and redirects are not followed.
+pub(crate) async fn download_blob(
+    url: &str,
+    sha256: &str,
+    max_bytes: u64,
+) -> Result<Vec<u8>, BridgeError> {
+    let parsed = Url::parse(url).map_err(|e| {
+        BridgeError::new(BridgeErrorCode::InvalidInput, "Invalid media URL").with_details(e)
+    })?;
+    let network_error = |e: reqwest::Error| {
+        BridgeError::new(BridgeErrorCode::Network, "Could not download media").with_details(e)
+    };
+    let mut client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .timeout(MEDIA_DOWNLOAD_TIMEOUT);
+    if let Some(domain) = check_media_url(&parsed)? {
+        let port = parsed.port_or_known_default().unwrap_or(443);
+        let addresses: Vec<SocketAddr> = tokio::net::lookup_host((domain.as_str(), port))
+            .await
+            .map_err(|e| {
+                BridgeError::new(BridgeErrorCode::Network, "Could not resolve the media host")
+                    .with_details(e)
+            })?
+            .collect();
+        if addresses.is_empty()
+            || addresses
+                .iter()
+                .any(|address| is_non_public_address(address.ip()))
+        {
+            return Err(BridgeError::new(
+                BridgeErrorCode::InvalidInput,
+                "Media URL points to a local host",
+            ));
+        }
+        client = client.resolve_to_addrs(&domain, &addresses);
+    }
+    let response = client
+        .build()
+        .map_err(network_error)?
+        .get(parsed)
+        .send()
+        .await
+        .map_err(network_error)?;
+    // Redirects are returned as is, so anything but a success is a failed download
+    if !response.status().is_success() {
+        return Err(
+            BridgeError::new(BridgeErrorCode::Network, "Could not download media")
+                .with_details(response.status()),
+        );
+    }
+    let data = read_blob(response, max_bytes).await?;
+    if sha256_hex(&data) != sha256 {
+        return Err(BridgeError::new(
+            BridgeErrorCode::InvalidInput,
+            "Downloaded media does not match its hash",
+        ));
+    }
+    Ok(data)
+}
+
+/// Returns the SHA-256 hash a Blossom URL addresses its blob by, if it has one.
+fn url_sha256(url: &str) -> Option<String> {
+    let parsed = Url::parse(url).ok()?;
+    let name = parsed.path_segments()?.next_back()?;
+    sha256_from_hex(name.split('.').next().unwrap_or(name))
+}
+
+/// Returns the hash a downloaded attachment must match.
+///
+/// That is the hash from the `imeta` tag or, failing that, the one in the Blossom URL;
+/// an attachment with neither cannot be verified and is not downloaded.
+fn expected_sha256(imeta: &Imeta) -> Result<String, BridgeError> {
+    let tag_hashes = [
+        imeta.encrypted_sha256.as_deref(),
+        Some(imeta.attachment.sha256.as_str()).filter(|hash| !hash.is_empty()),
+    ];
+    let mut tag_hashes = tag_hashes.into_iter().flatten();
+    match tag_hashes.next() {
+        // An unencrypted file is uploaded as is, so `x` and `ox` must agree
+        Some(hash) if tag_hashes.all(|other| other == hash) => Ok(hash.to_string()),
+        Some(_) => Err(BridgeError::new(
+            BridgeErrorCode::InvalidInput,
+            "Attachment hashes do not match",
+        )),
+        None => url_sha256(&imeta.attachment.url).ok_or_else(|| {
+            BridgeError::new(
+                BridgeErrorCode::InvalidInput,
+                "Attachment has no hash to verify it against",
+            )
+        }),
+    }
+}
+
+/// Returns the MIME type of an image type.
+pub(crate) fn image_mime_type(image_type: ImageType) -> &'static str {
+    match image_type {
+        ImageType::Png => "image/png",
+        ImageType::Jpeg | ImageType::Jpg => "image/jpeg",
+        ImageType::Gif => "image/gif",
+        ImageType::Webp => "image/webp",
+    }
+}
+
+/// Returns the usual file extension for a MIME type.
+fn extension_for_mime_type(mime_type: &str) -> &'static str {
+    match mime_type {
+        "image/png" => "png",
+        "image/jpeg" => "jpg",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        _ => "bin",
+    }
+}
+
+/// Returns `value` as a lowercase SHA-256 hash, or `None` unless it is exactly 64 hex
+/// characters.
+fn sha256_from_hex(value: &str) -> Option<String> {
+    (value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit()))
+        .then(|| value.to_ascii_lowercase())
+}
+
+/// Parses a NIP-92 `imeta` tag. Returns `None` for other tags and for `imeta` tags
+/// without a URL.
+///
+/// Hashes (`x` and `ox`) that are not 64 hex characters are ignored, so a malformed tag
+/// cannot make `download_media` skip verification or address an arbitrary cache file.
+pub(crate) fn parse_imeta(tag: &Tag) -> Option<Imeta> {
+    let values = tag.as_slice();
+    if values.first().map(String::as_str) != Some("imeta") {
+        return None;
+    }
+    let fields = |name: &'static str| {
+        values[1..].iter().filter_map(move |entry| {
+            let (key, value) = entry.split_once(' ')?;
+            (key == name).then(|| value.to_string())
+        })
+    };
+    let field = |name: &'static str| fields(name).next();
+    let encrypted_sha256 = field("x").and_then(|hash| sha256_from_hex(&hash));
+    Some(Imeta {
+        attachment: MediaAttachmentData {
+            url: field("url")?,
+            mime_type: field("m").unwrap_or_else(|| "application/octet-stream".to_string()),
+            sha256: field("ox")
+                .and_then(|hash| sha256_from_hex(&hash))
+                .or_else(|| encrypted_sha256.clone())
+                .unwrap_or_default(),
+            size: field("size").and_then(|size| size.parse().ok()),
+            dimensions: field("dim"),
+            blurhash: field("blurhash"),
+            is_encrypted: field("encryption-algorithm").is_some(),
+        },
+        encrypted_sha256,
+        fallback_urls: fields("fallback").collect(),
+    })
+}
+
+/// Directory of the media cache.
+pub(crate) fn media_dir() -> Result<PathBuf, BridgeError> {
+    Ok(store::store_dir()?.join("media"))
+}
+
+/// Downloads a media attachment of a message.
+///
+/// The file is fetched from its Blossom server (falling back to its mirrors when the server
+/// is unavailable), checked against the hashes in the `imeta` tag and stored in the local
+/// media cache. Files that are still cached are not fetched again.
+///
+/// Only HTTPS URLs of public hosts are fetched, redirects are not followed and the download
+/// stops at the size given in the `imeta` tag (at most 100 MiB).
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account (must be a group member)
+/// * `group_id` - The MLS group ID the message belongs to
+/// * `message_id` - The event ID (hex) of the message
+/// * `attachment_index` - The index of the attachment in the message's `attachments`
+///
+/// # Returns
+/// * `Ok(String)` - The local path of the file
+/// * `Err(BridgeError)` - `MessageNotFound` if the message or attachment does not exist,
+///   `Unsupported` for encrypted attachments, `InvalidInput` if the attachment has no hash,
+///   its URL is not allowed, it is too large or a hash does not match, or a `Network` error
+///
+/// # Notes
+/// * Only unencrypted attachments are supported, and the bridge cannot send media. Encrypted
+///   attachments need a key derived from the group's MLS exporter secret, which the core
+///   does not expose (see `docs/bridge_core_limitations.md`)
+#[frb]
+pub async fn download_media(
+    pubkey: &PublicKey,
+    group_id: whitenoise::GroupId,
+    message_id: String,
+    attachment_index: u32,
+) -> Result<String, BridgeError> {
+    let message = find_group_message(pubkey, &group_id, &message_id).await?;
+    let imeta = message
+        .tags
+        .iter()
+        .filter_map(parse_imeta)
+        .nth(attachment_index as usize)
+        .ok_or_else(|| {
+            BridgeError::new(BridgeErrorCode::MessageNotFound, "Attachment not found")
+        })?;
+
+    let attachment = &imeta.attachment;
+    if attachment.is_encrypted {
+        return Err(BridgeError::new(
+            BridgeErrorCode::Unsupported,
+            "Encrypted attachments are not supported",
+        ));
+    }
+    let sha256 = expected_sha256(&imeta)?;
+    if let Some(path) = cache_lookup(&sha256, &group_id).await? {
+        return Ok(path.to_string_lossy().to_string());
+    }
+
+    let max_bytes = attachment.size.map_or(MEDIA_MAX_DOWNLOAD_BYTES, |size| {
+        size.min(MEDIA_MAX_DOWNLOAD_BYTES)
+    });
+    let mut data = download_blob(&attachment.url, &sha256, max_bytes).await;
+    for fallback_url in &imeta.fallback_urls {
+        if data.is_ok() {
+            break;
+        }
+        data = download_blob(fallback_url, &sha256, max_bytes).await;
+    }
+    let data = data?;
+
+    let path = cache_insert(
+        &sha256,
+        extension_for_mime_type(&attachment.mime_type),
+        &data,
+        &group_id,
+    )
+    .await?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    fn imeta(fields: &[&str]) -> Tag {
+        let mut values = vec!["imeta".to_string()];
+        values.extend(fields.iter().map(|field| field.to_string()));
+        Tag::parse(values).unwrap()
+    }
+
+    #[test]
+    fn parses_imeta_fields() {
+        let tag = imeta(&[
+            "url https://blossom.example/a.png",
+            "m image/png",
+            &format!("x {}", HASH.to_uppercase()),
+            "size 1234",
+            "dim 640x480",
+            "blurhash LEHV6nWB2yk8",
+            "fallback https://mirror.example/a.png",
+        ]);
+        let parsed = parse_imeta(&tag).unwrap();
+        assert_eq!(parsed.attachment.url, "https://blossom.example/a.png");
+        assert_eq!(parsed.attachment.mime_type, "image/png");
+        assert_eq!(parsed.attachment.sha256, HASH);
+        assert_eq!(parsed.encrypted_sha256.as_deref(), Some(HASH));
+        assert_eq!(parsed.attachment.size, Some(1234));
+        assert_eq!(parsed.attachment.dimensions.as_deref(), Some("640x480"));
+        assert!(!parsed.attachment.is_encrypted);
+        assert_eq!(parsed.fallback_urls, vec!["https://mirror.example/a.png"]);
+    }
+
+    #[test]
+    fn ignores_malformed_hashes() {
+        let tag = imeta(&[
+            "url https://blossom.example/a.png",
+            "x ../../etc/passwd",
+            &format!("ox {}", &HASH[..63]),
+        ]);
+        let parsed = parse_imeta(&tag).unwrap();
+        assert_eq!(parsed.encrypted_sha256, None);
+        assert_eq!(parsed.attachment.sha256, "");
+    }
+
+    #[test]
+    fn skips_other_tags_and_imeta_without_url() {
+        assert!(parse_imeta(&Tag::parse(["e", HASH]).unwrap()).is_none());
+        assert!(parse_imeta(&imeta(&["m image/png"])).is_none());
+    }
+
+    #[test]
+    fn flags_encrypted_attachments() {
+        let tag = imeta(&[
+            "url https://blossom.example/a",
+            "encryption-algorithm chacha20-poly1305",
+        ]);
+        assert!(parse_imeta(&tag).unwrap().attachment.is_encrypted);
+    }
+
+    #[test]
+    fn only_allows_https_urls_of_public_hosts() {
+        let check = |url: &str| check_media_url(&Url::parse(url).unwrap());
+        assert_eq!(
+            check("https://Blossom.example./a").unwrap().as_deref(),
+            Some("blossom.example")
+        );
+        assert_eq!(check("https://93.184.216.34/a").unwrap(), None);
+        for url in [
+            "http://blossom.example/a",
+            "file:///etc/passwd",
+            "https://localhost/a",
+            "https://media.localhost/a",
+            "https://127.0.0.1/a",
+            "https://10.0.0.1/a",
+            "https://192.168.1.1/a",
+            "https://169.254.169.254/a",
+            "https://100.64.0.1/a",
+            "https://[::1]/a",
+            "https://[fd00::1]/a",
+            "https://[fe80::1]/a",
+            "https://[::ffff:127.0.0.1]/a",
+        ] {
+            assert!(check(url).is_err(), "{url} should be rejected");
+        }
+    }
+
+    #[test]
+    fn requires_a_hash_to_verify_downloads() {
+        let expected = |fields: &[&str]| expected_sha256(&parse_imeta(&imeta(fields)).unwrap());
+        assert_eq!(
+            expected(&["url https://blossom.example/a.png", &format!("ox {HASH}")]).unwrap(),
+            HASH
+        );
+        assert_eq!(
+            expected(&[&format!("url https://blossom.example/{HASH}.png")]).unwrap(),
+            HASH
+        );
+        assert!(expected(&["url https://blossom.example/a.png"]).is_err());
+        let other = "0".repeat(64);
+        assert!(expected(&[
+            "url https://blossom.example/a.png",
+            &format!("x {HASH}"),
+            &format!("ox {other}"),
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn hashes_blobs_as_lowercase_hex() {
+        assert_eq!(sha256_hex(b"test"), HASH);
+    }
+}
@@ -0,0 +1,365 @@
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use crate::api::groups::group_id_to_string;
+use crate::api::media::media_dir;
+use crate::api::utils::unix_now;
+use crate::store;
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+
+/// Size and usage of the local media cache.
+#[derive(Debug, Clone)]
+pub struct MediaCacheStatsData {
+    pub file_count: u32,
+    pub total_size_bytes: u64,
+    pub max_size_bytes: u64,
+}
+
+/// Name of the store document indexing the media cache.
+const MEDIA_CACHE_DOCUMENT: &str = "media_cache";
+
+/// Default size limit of the media cache (500 MB).
+const DEFAULT_MAX_SIZE_BYTES: u64 = 500 * 1024 * 1024;
+
+/// How old the recorded last access of a file may get before a cache hit records it
+/// again. Eviction only needs a rough order, and it spares rewriting the index on every
+/// hit.
+const ACCESS_TIME_RESOLUTION_SECS: u64 = 60 * 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    file_name: String,
+    size: u64,
+    last_accessed: u64,
+    group_ids: BTreeSet<String>, // Groups the file was downloaded for
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheIndex {
+    max_size_bytes: u64,
+    entries: HashMap<String, CacheEntry>, // Keyed by SHA-256 of the file
+}
+
+impl Default for CacheIndex {
+    fn default() -> Self {
+        Self {
+            max_size_bytes: DEFAULT_MAX_SIZE_BYTES,
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl CacheIndex {
+    fn total_size(&self) -> u64 {
+        self.entries.values().map(|entry| entry.size).sum()
+    }
+
+    /// Removes least recently used entries until the cache fits its size limit, sparing
+    /// `keep` (the file that is being added). Returns the removed entries.
+    ///
+    /// Files larger than the whole limit can never fit: they are removed by the next
+    /// eviction, and `keep` being one does not push other files out.
+    fn evict(&mut self, keep: Option<&str>) -> Vec<CacheEntry> {
+        let max_size = self.max_size_bytes;
+        let mut evicted = Vec::new();
+        let oversized: Vec<String> = self
+            .entries
+            .iter()
+            .filter(|(sha256, entry)| entry.size > max_size && Some(sha256.as_str()) != keep)
+            .map(|(sha256, _)| sha256.clone())
+            .collect();
+        for sha256 in oversized {
+            evicted.extend(self.entries.remove(&sha256));
+        }
+
+        let mut by_age: Vec<(u64, String)> = self
+            .entries
+            .iter()
+            .filter(|(sha256, _)| Some(sha256.as_str()) != keep)
+            .map(|(sha256, entry)| (entry.last_accessed, sha256.clone()))
+            .collect();
+        by_age.sort();
+
+        let mut total: u64 = self
+            .entries
+            .values()
+            .map(|entry| entry.size)
+            .filter(|size| *size <= max_size)
+            .sum();
+        for (_, sha256) in by_age {
+            if total <= max_size {
+                break;
+            }
+            if let Some(entry) = self.entries.remove(&sha256) {
+                total -= entry.size;
+                evicted.push(entry);
+            }
+        }
+        evicted
+    }
+
+    /// Records a stored file and evicts older ones as needed. Returns the entries whose
+    /// files must be removed, including the previous file of `sha256` if it was stored
+    /// under another name (another extension).
+    fn insert(
+        &mut self,
+        sha256: &str,
+        file_name: String,
+        size: u64,
+        group_id: String,
+    ) -> Vec<CacheEntry> {
+        let entry = self
+            .entries
+            .entry(sha256.to_string())
+            .or_insert_with(|| CacheEntry {
+                file_name: file_name.clone(),
+                size,
+                last_accessed: 0,
+                group_ids: BTreeSet::new(),
+            });
+        let mut replaced = Vec::new();
+        if entry.file_name != file_name {
+            replaced.push(CacheEntry {
+                file_name: std::mem::replace(&mut entry.file_name, file_name),
+                ..entry.clone()
+            });
+        }
+        entry.size = size;
+        entry.last_accessed = unix_now();
+        entry.group_ids.insert(group_id);
+        replaced.extend(self.evict(Some(sha256)));
+        replaced
+    }
+}
+
+fn remove_files(entries: &[CacheEntry]) -> Result<(), BridgeError> {
+    let dir = media_dir()?;
+    for entry in entries {
+        // Files already removed by the OS or the user are fine
+        let _ = std::fs::remove_file(dir.join(&entry.file_name));
+    }
+    Ok(())
+}
+
+/// Returns the path of a cached file and marks it as recently used.
+///
+/// Returns `None` if the file is not cached or its file has disappeared. The index is
+/// only rewritten when the hit changes it by more than `ACCESS_TIME_RESOLUTION_SECS`.
+pub(crate) async fn cache_lookup(
+    sha256: &str,
+    group_id: &whitenoise::GroupId,
+) -> Result<Option<PathBuf>, BridgeError> {
+    let dir = media_dir()?;
+    let group_id = group_id_to_string(group_id);
+    let index: CacheIndex = store::load(MEDIA_CACHE_DOCUMENT, None).await?;
+    let Some(entry) = index.entries.get(sha256) else {
+        return Ok(None);
+    };
+    let path = dir.join(&entry.file_name);
+    let up_to_date = entry.group_ids.contains(&group_id)
+        && unix_now().saturating_sub(entry.last_accessed) < ACCESS_TIME_RESOLUTION_SECS;
+    if up_to_date && path.exists() {
+        return Ok(Some(path));
+    }
+
+    store::update(MEDIA_CACHE_DOCUMENT, None, |index: &mut CacheIndex| {
+        let entry = index.entries.get_mut(sha256)?;
+        let path = dir.join(&entry.file_name);
+        if !path.exists() {
+            index.entries.remove(sha256);
+            return None;
+        }
+        entry.last_accessed = unix_now();
+        entry.group_ids.insert(group_id);
+        Some(path)
+    })
+    .await
+}
+
+/// Stores a file in the cache and returns its path, evicting older files if the cache
+/// grows beyond its size limit.
+pub(crate) async fn cache_insert(
+    sha256: &str,
+    extension: &str,
+    data: &[u8],
+    group_id: &whitenoise::GroupId,
+) -> Result<PathBuf, BridgeError> {
+    let dir = media_dir()?;
+    let file_name = format!("{sha256}.{extension}");
+    let path = dir.join(&file_name);
+    std::fs::create_dir_all(&dir)
+        .and_then(|_| std::fs::write(&path, data))
+        .map_err(|e| {
+            BridgeError::new(BridgeErrorCode::Storage, "Could not save media").with_details(e)
+        })?;
+
+    let group_id = group_id_to_string(group_id);
+    let evicted = store::update(MEDIA_CACHE_DOCUMENT, None, |index: &mut CacheIndex| {
+        index.insert(sha256, file_name, data.len() as u64, group_id)
+    })
+    .await?;
+    remove_files(&evicted)?;
+    Ok(path)
+}
+
+/// Removes the cached media of a group.
+///
+/// Files that were also downloaded for another group are kept for that group.
+pub(crate) async fn purge_group_media(group_id: &whitenoise::GroupId) -> Result<(), BridgeError> {
+    let group_id = group_id_to_string(group_id);
+    let removed = store::update(MEDIA_CACHE_DOCUMENT, None, |index: &mut CacheIndex| {
+        let mut removed = Vec::new();
+        index.entries.retain(|_, entry| {
+            entry.group_ids.remove(&group_id);
+            if entry.group_ids.is_empty() {
+                removed.push(entry.clone());
+                return false;
+            }
+            true
+        });
+        removed
+    })
+    .await?;
+    remove_files(&removed)
+}
+
+/// Returns the size and usage of the local media cache.
+///
+/// # Returns
+/// * `Ok(MediaCacheStatsData)` - Number of cached files, their total size and the limit
+/// * `Err(BridgeError)` - If the cache index could not be read
+#[frb]
+pub async fn media_cache_stats() -> Result<MediaCacheStatsData, BridgeError> {
+    let index: CacheIndex = store::load(MEDIA_CACHE_DOCUMENT, None).await?;
+    Ok(MediaCacheStatsData {
+        file_count: index.entries.len() as u32,
+        total_size_bytes: index.total_size(),
+        max_size_bytes: index.max_size_bytes,
+    })
+}
+
+/// Sets the size limit of the local media cache.
+///
+/// Least recently used files are removed right away if the cache is larger than the new
+/// limit.
+///
+/// # Arguments
+/// * `max_size_bytes` - The new limit in bytes
+///
+/// # Returns
+/// * `Ok(MediaCacheStatsData)` - The cache usage after applying the limit
+/// * `Err(BridgeError)` - If the cache could not be updated
+#[frb]
+pub async fn set_media_cache_max_size(
+    max_size_bytes: u64,
+) -> Result<MediaCacheStatsData, BridgeError> {
+    let evicted = store::update(MEDIA_CACHE_DOCUMENT, None, |index: &mut CacheIndex| {
+        index.max_size_bytes = max_size_bytes;
+        index.evict(None)
+    })
+    .await?;
+    remove_files(&evicted)?;
+    media_cache_stats().await
+}
+
+/// Removes the cached media of a single group.
+///
+/// # Arguments
+/// * `group_id` - The MLS group ID whose media to remove
+///
+/// # Returns
+/// * `Ok(())` - If the media was removed
+/// * `Err(BridgeError)` - If the cache could not be updated
+#[frb]
+pub async fn clear_group_media_cache(group_id: whitenoise::GroupId) -> Result<(), BridgeError> {
+    purge_group_media(&group_id).await
+}
+
+/// Removes all cached media. The size limit is kept.
+///
+/// Downloaded media is fetched again from its Blossom server the next time it is shown.
+///
+/// # Returns
+/// * `Ok(())` - If the cache was cleared
+/// * `Err(BridgeError)` - If the cache could not be cleared
+#[frb]
+pub async fn clear_media_cache() -> Result<(), BridgeError> {
+    let removed = store::update(MEDIA_CACHE_DOCUMENT, None, |index: &mut CacheIndex| {
+        index
+            .entries
+            .drain()
+            .map(|(_, entry)| entry)
+            .collect::<Vec<_>>()
+    })
+    .await?;
+    remove_files(&removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(max_size_bytes: u64, entries: &[(&str, u64, u64)]) -> CacheIndex {
+        CacheIndex {
+            max_size_bytes,
+            entries: entries
+                .iter()
+                .map(|(sha256, size, last_accessed)| {
+                    (
+                        sha256.to_string(),
+                        CacheEntry {
+                            file_name: format!("{sha256}.jpg"),
+                            size: *size,
+                            last_accessed: *last_accessed,
+                            group_ids: BTreeSet::new(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn evicted_names(evicted: Vec<CacheEntry>) -> Vec<String> {
+        let mut names: Vec<String> = evicted.into_iter().map(|entry| entry.file_name).collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn evicts_least_recently_used_files_until_the_cache_fits() {
+        let mut cache = index(100, &[("a", 40, 3), ("b", 40, 1), ("c", 40, 2)]);
+        assert_eq!(evicted_names(cache.evict(None)), vec!["b.jpg"]);
+        assert_eq!(cache.total_size(), 80);
+        assert!(cache.evict(None).is_empty());
+    }
+
+    #[test]
+    fn spares_the_file_being_added() {
+        let mut cache = index(100, &[("a", 60, 1), ("b", 60, 2)]);
+        assert_eq!(evicted_names(cache.evict(Some("a"))), vec!["b.jpg"]);
+        assert!(cache.entries.contains_key("a"));
+    }
+
+    #[test]
+    fn files_larger_than_the_limit_do_not_stay() {
+        let mut cache = index(100, &[("a", 40, 1), ("big", 150, 2)]);
+        // Adding it leaves the other files alone
+        assert!(cache.evict(Some("big")).is_empty());
+        // The next eviction removes it, although it is the most recently used file
+        assert_eq!(evicted_names(cache.evict(None)), vec!["big.jpg"]);
+        assert_eq!(cache.total_size(), 40);
+    }
+
+    #[test]
+    fn reinserting_under_another_extension_removes_the_old_file() {
+        let mut cache = index(100, &[("a", 40, 1)]);
+        let removed = cache.insert("a", "a.png".to_string(), 30, "0a01".to_string());
+        assert_eq!(evicted_names(removed), vec!["a.jpg"]);
+        assert_eq!(cache.entries["a"].file_name, "a.png");
+        assert_eq!(cache.entries["a"].size, 30);
+        assert!(cache
+            .insert("a", "a.png".to_string(), 30, "0a01".to_string())
+            .is_empty());
+    }
+}
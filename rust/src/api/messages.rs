@@ -1,4 +1,13 @@
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use crate::api::groups::find_group;
+use crate::api::media::{parse_imeta, MediaAttachmentData};
+use crate::api::outbox::{send_through_outbox, CLIENT_ID_TAG};
+use crate::api::subscriptions::spawn_subscription;
+use crate::api::utils::tag_from_vec;
+use crate::frb_generated::StreamSink;
+use crate::group_watch::{notify_group_changed, GroupWatch, SETTLE_DELAYS};
 use flutter_rust_bridge::frb;
+use std::collections::{HashMap, HashSet};
 pub use whitenoise::{
     ChatMessage, MessageWithTokens, PublicKey, ReactionSummary, SerializableToken, Tag, Whitenoise,
     WhitenoiseError,
@@ -14,7 +23,7 @@ pub struct MessageWithTokensData {
     pub tokens: Vec<String>, // Simplified tokens representation
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ChatMessageData {
     pub id: String,
     pub pubkey: String,
@@ -27,17 +36,27 @@ pub struct ChatMessageData {
     pub content_tokens: Vec<SerializableTokenData>,
     pub reactions: ReactionSummaryData,
     pub kind: u16,
+    pub is_edited: bool,
+    pub edit_history: Vec<MessageVersionData>, // Previous versions, oldest first
+    pub attachments: Vec<MediaAttachmentData>, // From imeta tags; see download_media
+}
+
+/// A previous version of an edited message
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageVersionData {
+    pub content: String,
+    pub created_at: u64, // When this version was written
 }
 
 /// Flutter-compatible reaction summary
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ReactionSummaryData {
     pub by_emoji: Vec<EmojiReactionData>,
     pub user_reactions: Vec<UserReactionData>,
 }
 
 /// Flutter-compatible emoji reaction details
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct EmojiReactionData {
     pub emoji: String,
     pub count: u64,         // Using u64 for Flutter compatibility
@@ -45,7 +64,7 @@ pub struct EmojiReactionData {
 }
 
 /// Flutter-compatible user reaction
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UserReactionData {
     pub user: String, // PublicKey converted to hex string
     pub emoji: String,
@@ -53,12 +72,228 @@ pub struct UserReactionData {
 }
 
 /// Flutter-compatible serializable token
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SerializableTokenData {
     pub token_type: String, // "Nostr", "Url", "Hashtag", "Text", "LineBreak", "Whitespace"
     pub content: Option<String>, // None for LineBreak and Whitespace
 }
 
+/// Position of a message in a conversation, used to page through group history.
+///
+/// Messages are ordered by `created_at` and then by `message_id`, so a cursor taken from
+/// a message identifies a unique position even when several messages share a timestamp.
+/// When `message_id` is `None` the cursor is a plain timestamp boundary.
+#[derive(Debug, Clone)]
+pub struct MessageCursorData {
+    pub created_at: u64,
+    pub message_id: Option<String>,
+}
+
+/// A page of aggregated chat messages returned by `fetch_aggregated_messages_page`.
+#[derive(Debug, Clone)]
+pub struct MessagePageData {
+    /// Messages in this page, in chronological order (oldest first)
+    pub messages: Vec<ChatMessageData>,
+    /// Cursor to pass to the next call to keep paging in the same direction
+    pub next_cursor: Option<MessageCursorData>,
+    /// Whether more messages exist beyond `next_cursor`
+    pub has_more: bool,
+}
+
+/// An update pushed on a group message stream created by `subscribe_to_group_messages`.
+#[derive(Debug, Clone)]
+pub enum GroupMessageUpdate {
+    /// First item on every stream; carries the handle to pass to `unsubscribe`
+    Subscribed { subscription_id: u64 },
+    /// A message that was not part of the conversation before
+    NewMessage { message: ChatMessageData },
+    /// An existing message whose content was edited
+    MessageEdited { message: ChatMessageData },
+    /// An existing message that has been deleted by its author
+    MessageDeleted { message_id: String },
+    /// A message that is no longer part of the conversation at all (e.g. the duplicate of
+    /// a message that was sent twice)
+    MessageRemoved { message_id: String },
+    /// The reactions on an existing message changed
+    ReactionsUpdated {
+        message_id: String,
+        reactions: ReactionSummaryData,
+    },
+}
+
+/// Nostr kind of NIP-25 reactions.
+const KIND_REACTION: u16 = 7;
+
+/// Nostr kind of chat messages (NIP-C7), used for regular messages and replies.
+pub(crate) const KIND_CHAT_MESSAGE: u16 = 9;
+
+/// Nostr kind of NIP-09 deletion requests.
+const KIND_DELETION: u16 = 5;
+
+/// Marker of the `e` tag that makes a chat message (kind 9) an edit of the message it
+/// references. The edit carries the complete new content, so clients that do not know
+/// about edits show it as a reply with the corrected text.
+const EDIT_MARKER: &str = "edit";
+
+/// Nostr kind of typing indicators. Only meaningful for a few seconds.
+pub(crate) const KIND_TYPING_INDICATOR: u16 = 20_010;
+
+/// Nostr kind of read receipts. References the read message with an `e` tag.
+pub(crate) const KIND_READ_RECEIPT: u16 = 20_011;
+
+/// Returns whether a kind is a presence event (typing indicator or read receipt).
+///
+/// Presence events are sent outside the group's MLS messages (see `presence`), so they
+/// cannot be sent as messages; ones that earlier versions sent as MLS messages are never
+/// shown as a message or counted as unread.
+pub(crate) fn is_presence_kind(kind: u16) -> bool {
+    kind == KIND_TYPING_INDICATOR || kind == KIND_READ_RECEIPT
+}
+
+/// Upper bound on the number of code points in a single emoji reaction.
+const MAX_EMOJI_CODE_POINTS: usize = 16;
+
+/// Code points that are emoji by themselves (Unicode's `Extended_Pictographic`, without
+/// the regional indicators and skin tone modifiers, which only occur in sequences).
+const PICTOGRAPHIC_RANGES: &[(char, char)] = &[
+    ('\u{00A9}', '\u{00A9}'),
+    ('\u{00AE}', '\u{00AE}'),
+    ('\u{203C}', '\u{203C}'),
+    ('\u{2049}', '\u{2049}'),
+    ('\u{2122}', '\u{2122}'),
+    ('\u{2139}', '\u{2139}'),
+    ('\u{2194}', '\u{2199}'),
+    ('\u{21A9}', '\u{21AA}'),
+    ('\u{231A}', '\u{231B}'),
+    ('\u{2328}', '\u{2328}'),
+    ('\u{2388}', '\u{2388}'),
+    ('\u{23CF}', '\u{23CF}'),
+    ('\u{23E9}', '\u{23F3}'),
+    ('\u{23F8}', '\u{23FA}'),
+    ('\u{24C2}', '\u{24C2}'),
+    ('\u{25AA}', '\u{25AB}'),
+    ('\u{25B6}', '\u{25B6}'),
+    ('\u{25C0}', '\u{25C0}'),
+    ('\u{25FB}', '\u{25FE}'),
+    ('\u{2600}', '\u{2605}'),
+    ('\u{2607}', '\u{2612}'),
+    ('\u{2614}', '\u{2685}'),
+    ('\u{2690}', '\u{2705}'),
+    ('\u{2708}', '\u{2712}'),
+    ('\u{2714}', '\u{2714}'),
+    ('\u{2716}', '\u{2716}'),
+    ('\u{271D}', '\u{271D}'),
+    ('\u{2721}', '\u{2721}'),
+    ('\u{2728}', '\u{2728}'),
+    ('\u{2733}', '\u{2734}'),
+    ('\u{2744}', '\u{2744}'),
+    ('\u{2747}', '\u{2747}'),
+    ('\u{274C}', '\u{274C}'),
+    ('\u{274E}', '\u{274E}'),
+    ('\u{2753}', '\u{2755}'),
+    ('\u{2757}', '\u{2757}'),
+    ('\u{2763}', '\u{2767}'),
+    ('\u{2795}', '\u{2797}'),
+    ('\u{27A1}', '\u{27A1}'),
+    ('\u{27B0}', '\u{27B0}'),
+    ('\u{27BF}', '\u{27BF}'),
+    ('\u{2934}', '\u{2935}'),
+    ('\u{2B05}', '\u{2B07}'),
+    ('\u{2B1B}', '\u{2B1C}'),
+    ('\u{2B50}', '\u{2B50}'),
+    ('\u{2B55}', '\u{2B55}'),
+    ('\u{3030}', '\u{3030}'),
+    ('\u{303D}', '\u{303D}'),
+    ('\u{3297}', '\u{3297}'),
+    ('\u{3299}', '\u{3299}'),
+    ('\u{1F000}', '\u{1F0FF}'),
+    ('\u{1F10D}', '\u{1F10F}'),
+    ('\u{1F12F}', '\u{1F12F}'),
+    ('\u{1F16C}', '\u{1F171}'),
+    ('\u{1F17E}', '\u{1F17F}'),
+    ('\u{1F18E}', '\u{1F18E}'),
+    ('\u{1F191}', '\u{1F19A}'),
+    ('\u{1F1AD}', '\u{1F1E5}'),
+    ('\u{1F201}', '\u{1F20F}'),
+    ('\u{1F21A}', '\u{1F21A}'),
+    ('\u{1F22F}', '\u{1F22F}'),
+    ('\u{1F232}', '\u{1F23A}'),
+    ('\u{1F23C}', '\u{1F23F}'),
+    ('\u{1F249}', '\u{1F3FA}'),
+    ('\u{1F400}', '\u{1F53D}'),
+    ('\u{1F546}', '\u{1F64F}'),
+    ('\u{1F680}', '\u{1F6FF}'),
+    ('\u{1F774}', '\u{1F77F}'),
+    ('\u{1F7D5}', '\u{1F7FF}'),
+    ('\u{1F80C}', '\u{1F80F}'),
+    ('\u{1F848}', '\u{1F84F}'),
+    ('\u{1F85A}', '\u{1F85F}'),
+    ('\u{1F888}', '\u{1F88F}'),
+    ('\u{1F8AE}', '\u{1F8FF}'),
+    ('\u{1F90C}', '\u{1F93A}'),
+    ('\u{1F93C}', '\u{1F945}'),
+    ('\u{1F947}', '\u{1FAFF}'),
+    ('\u{1FC00}', '\u{1FFFD}'),
+];
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
+const COMBINING_KEYCAP: char = '\u{20E3}';
+const BLACK_FLAG: char = '\u{1F3F4}';
+const CANCEL_TAG: char = '\u{E007F}';
+
+fn is_pictographic(c: char) -> bool {
+    PICTOGRAPHIC_RANGES
+        .iter()
+        .any(|(first, last)| (*first..=*last).contains(&c))
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+fn is_skin_tone(c: char) -> bool {
+    ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)
+}
+
+/// Returns whether code points form one emoji of a ZWJ sequence: a pictographic code
+/// point, optionally with the emoji presentation selector or a skin tone, or a black flag
+/// with tag characters (subdivision flags such as 🏴󠁧󠁢󠁳󠁣󠁴󠁿).
+fn is_emoji_element(element: &[char]) -> bool {
+    match element {
+        [base, rest @ ..] if is_pictographic(*base) => match rest {
+            [] | [EMOJI_PRESENTATION_SELECTOR] => true,
+            [modifier] => is_skin_tone(*modifier),
+            [tags @ .., CANCEL_TAG] if *base == BLACK_FLAG => {
+                !tags.is_empty() && tags.iter().all(|c| ('\u{E0020}'..='\u{E007E}').contains(c))
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns whether a string is exactly one emoji: a flag (two regional indicators), a
+/// keycap such as 1️⃣ or pictographic emoji joined by zero-width joiners.
+fn is_single_emoji(emoji: &str) -> bool {
+    let chars: Vec<char> = emoji.chars().collect();
+    if chars.is_empty() || chars.len() > MAX_EMOJI_CODE_POINTS {
+        return false;
+    }
+    match chars.as_slice() {
+        [first, second] if is_regional_indicator(*first) => is_regional_indicator(*second),
+        ['0'..='9' | '#' | '*', rest @ ..] => {
+            matches!(
+                rest,
+                [COMBINING_KEYCAP] | [EMOJI_PRESENTATION_SELECTOR, COMBINING_KEYCAP]
+            )
+        }
+        _ => chars
+            .split(|c| *c == ZERO_WIDTH_JOINER)
+            .all(is_emoji_element),
+    }
+}
+
 /// Converts a core `MessageWithTokens` object to a Flutter-compatible `MessageWithTokensData` structure.
 ///
 /// This function handles the conversion of complex message and token data to Flutter-compatible
@@ -199,6 +434,14 @@ pub fn convert_chat_message_to_data(chat_message: &ChatMessage) -> ChatMessageDa
         content_tokens,
         reactions,
         kind: chat_message.kind,
+        is_edited: false,
+        edit_history: Vec::new(),
+        attachments: chat_message
+            .tags
+            .iter()
+            .filter_map(parse_imeta)
+            .map(|imeta| imeta.attachment)
+            .collect(),
     }
 }
 
@@ -216,7 +459,16 @@ pub fn convert_chat_message_to_data(chat_message: &ChatMessage) -> ChatMessageDa
 ///
 /// # Returns
 /// * `Ok(MessageWithTokensData)` - The sent message and parsed tokens if successful
-/// * `Err(WhitenoiseError)` - If there was an error sending the message
+/// * `Err(BridgeError)` - If there was an error sending the message
+///
+/// # Notes
+/// * The core publishes the message without reporting which relays accepted it, so there
+///   is no per-relay status; `fetch_message_delivery_status` reports whether the message
+///   is pending, sent or failed
+/// * The message goes through the outbox: when sending fails, the error is returned with
+///   its `outbox_id` set, and the message stays queued and is retried automatically (see
+///   `fetch_outbox`); use `enqueue_message_to_group` to return without waiting for the
+///   first attempt
 #[frb]
 pub async fn send_message_to_group(
     pubkey: &PublicKey,
@@ -224,21 +476,478 @@ pub async fn send_message_to_group(
     message: String,
     kind: u16,
     tags: Option<Vec<Tag>>,
-) -> Result<MessageWithTokensData, WhitenoiseError> {
+) -> Result<MessageWithTokensData, BridgeError> {
+    send_group_message(*pubkey, group_id, message, kind, tags).await
+}
+
+/// Sends a user-visible message through the outbox and converts the result for Flutter.
+pub(crate) async fn send_group_message(
+    pubkey: PublicKey,
+    group_id: whitenoise::GroupId,
+    content: String,
+    kind: u16,
+    tags: Option<Vec<Tag>>,
+) -> Result<MessageWithTokensData, BridgeError> {
+    send_through_outbox(pubkey, group_id, content, kind, tags).await
+}
+
+/// Sends a reaction or deletion once, bypassing the outbox, and converts the result for
+/// Flutter.
+///
+/// These are not queued: they are cheap to repeat, a late retry could undo a change the
+/// user made in the meantime, and they carry no outbox ID.
+async fn send_group_event(
+    pubkey: PublicKey,
+    group_id: whitenoise::GroupId,
+    content: String,
+    kind: u16,
+    tags: Vec<Tag>,
+) -> Result<MessageWithTokensData, BridgeError> {
+    let sent = send_inner_event(pubkey, group_id, content, kind, Some(tags)).await?;
+    Ok(convert_message_with_tokens_to_data(&sent))
+}
+
+/// Encrypts and sends an inner event of the given kind to an MLS group.
+///
+/// Sends once, bypassing the outbox; only presence events, reactions and deletions, which
+/// are not worth retrying, and the outbox itself should call this directly.
+/// The core send future is not `Send`, so it is driven on a blocking thread.
+pub(crate) async fn send_inner_event(
+    pubkey: PublicKey,
+    group_id: whitenoise::GroupId,
+    content: String,
+    kind: u16,
+    tags: Option<Vec<Tag>>,
+) -> Result<MessageWithTokens, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
-    let pubkey_clone = *pubkey;
+    let sent_group_id = group_id.clone();
     let message_with_tokens = tokio::task::spawn_blocking(move || {
-        tokio::runtime::Handle::current().block_on(whitenoise.send_message_to_group(
-            &pubkey_clone,
-            &group_id,
-            message,
-            kind,
-            tags,
-        ))
+        tokio::runtime::Handle::current()
+            .block_on(whitenoise.send_message_to_group(&pubkey, &group_id, content, kind, tags))
     })
-    .await
-    .map_err(|e| WhitenoiseError::from(std::io::Error::other(e)))??;
-    Ok(convert_message_with_tokens_to_data(&message_with_tokens))
+    .await??;
+    notify_group_changed(&sent_group_id);
+    Ok(message_with_tokens)
+}
+
+/// Looks up a single aggregated message in a group by its event ID (hex).
+pub(crate) async fn find_group_message(
+    pubkey: &PublicKey,
+    group_id: &whitenoise::GroupId,
+    message_id: &str,
+) -> Result<ChatMessage, BridgeError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let messages = whitenoise
+        .fetch_aggregated_messages_for_group(pubkey, group_id)
+        .await?;
+    messages
+        .into_iter()
+        .find(|message| message.id == message_id)
+        .ok_or_else(|| BridgeError::new(BridgeErrorCode::MessageNotFound, "Message not found"))
+}
+
+/// Loads the aggregated messages of a group with message edits applied.
+///
+/// Edits are folded into the message they edit: the message shows the latest content,
+/// and earlier versions are kept in `edit_history`. Only edits made by the original
+/// author are applied, and edits are never applied to deleted messages. A deletion only
+/// counts when it comes from the author or a group admin; messages deleted by anyone
+/// else are shown as they were. Typing indicators and read receipts are not chat
+/// messages and are left out.
+pub(crate) async fn load_chat_messages(
+    pubkey: &PublicKey,
+    group_id: &whitenoise::GroupId,
+) -> Result<Vec<ChatMessageData>, BridgeError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let raw = whitenoise
+        .fetch_messages_for_group(pubkey, group_id)
+        .await?;
+    load_chat_messages_with_raw(pubkey, group_id, &raw).await
+}
+
+/// Same as `load_chat_messages`, reusing raw group messages the caller already fetched.
+async fn load_chat_messages_with_raw(
+    pubkey: &PublicKey,
+    group_id: &whitenoise::GroupId,
+    raw: &[MessageWithTokens],
+) -> Result<Vec<ChatMessageData>, BridgeError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let aggregated = whitenoise
+        .fetch_aggregated_messages_for_group(pubkey, group_id)
+        .await?;
+
+    let admins = group_admins(pubkey, group_id).await?;
+    let changes = RawChanges::collect(raw, admins);
+    Ok(aggregated
+        .iter()
+        .filter(|message| !changes.hides(message))
+        .map(|message| changes.apply(message))
+        .collect())
+}
+
+/// Returns the public keys (hex) of a group's admins.
+async fn group_admins(
+    pubkey: &PublicKey,
+    group_id: &whitenoise::GroupId,
+) -> Result<HashSet<String>, BridgeError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let account = whitenoise.fetch_account(pubkey).await?;
+    let group = find_group(whitenoise, &account, group_id).await?;
+    Ok(group.admin_pubkeys.iter().map(PublicKey::to_hex).collect())
+}
+
+/// Returns the ID of the message an `e` tag with the `edit` marker points at.
+fn edit_tag_target(tag: &Tag) -> Option<&str> {
+    match tag.as_slice() {
+        [name, id, _, marker, ..] if name == "e" && marker == EDIT_MARKER => Some(id.as_str()),
+        _ => None,
+    }
+}
+
+/// Returns whether a deletion requested by `deleters` may remove a message by `author`:
+/// at least one of them must be the author or a group admin.
+fn deletion_is_authorized(deleters: &[String], author: &str, admins: &HashSet<String>) -> bool {
+    deleters
+        .iter()
+        .any(|deleter| deleter == author || admins.contains(deleter))
+}
+
+/// What the raw events of a group change about its aggregated messages: the events that
+/// are not shown as messages, the edits to fold into the messages they edit, and who
+/// asked for each message to be deleted.
+struct RawChanges<'a> {
+    hidden_ids: HashSet<String>,
+    edits: HashMap<&'a str, Vec<&'a MessageWithTokens>>,
+    deleters: HashMap<&'a str, Vec<String>>, // Message ID to the authors of its deletions
+    originals: HashMap<String, &'a MessageWithTokens>, // Raw events by ID
+    admins: HashSet<String>,
+}
+
+impl<'a> RawChanges<'a> {
+    fn collect(raw: &'a [MessageWithTokens], admins: HashSet<String>) -> Self {
+        let mut edits: HashMap<&str, Vec<&MessageWithTokens>> = HashMap::new();
+        let mut deleters: HashMap<&str, Vec<String>> = HashMap::new();
+        let mut hidden_ids = retried_duplicate_ids(raw.iter().filter_map(|message| {
+            let client_id = message
+                .message
+                .tags
+                .iter()
+                .find_map(|tag| tag_value(tag, CLIENT_ID_TAG))?;
+            Some((
+                message.message.id.to_hex(),
+                message.message.pubkey.to_hex(),
+                client_id,
+                message.message.created_at.as_u64(),
+            ))
+        }));
+        for message in raw {
+            if hidden_ids.contains(&message.message.id.to_hex()) {
+                continue;
+            }
+            let kind = message.message.kind.as_u16();
+            if is_presence_kind(kind) {
+                hidden_ids.insert(message.message.id.to_hex());
+            } else if kind == KIND_DELETION {
+                let deleter = message.message.pubkey.to_hex();
+                for target in message
+                    .message
+                    .tags
+                    .iter()
+                    .filter_map(|tag| tag_value(tag, "e"))
+                {
+                    deleters.entry(target).or_default().push(deleter.clone());
+                }
+            } else if let Some(target) = (kind == KIND_CHAT_MESSAGE)
+                .then(|| message.message.tags.iter().find_map(edit_tag_target))
+                .flatten()
+            {
+                hidden_ids.insert(message.message.id.to_hex());
+                edits.entry(target).or_default().push(message);
+            }
+        }
+        let originals = raw
+            .iter()
+            .map(|message| (message.message.id.to_hex(), message))
+            .collect();
+        Self {
+            hidden_ids,
+            edits,
+            deleters,
+            originals,
+            admins,
+        }
+    }
+
+    fn hides(&self, message: &ChatMessage) -> bool {
+        self.hidden_ids.contains(&message.id)
+    }
+
+    fn apply(&self, message: &ChatMessage) -> ChatMessageData {
+        let mut data = convert_chat_message_to_data(message);
+        // Deletions the raw events do not explain are left to the core
+        let unauthorized = self
+            .deleters
+            .get(data.id.as_str())
+            .is_some_and(|deleters| !deletion_is_authorized(deleters, &data.pubkey, &self.admins));
+        if data.is_deleted && unauthorized {
+            // Only members who may not delete the message asked to, so show it as sent
+            if let Some(original) = self.originals.get(&data.id) {
+                data.is_deleted = false;
+                data.content = original.message.content.clone();
+                data.content_tokens = original
+                    .tokens
+                    .iter()
+                    .map(convert_serializable_token)
+                    .collect();
+            }
+        }
+        if let Some(edits) = self.edits.get(data.id.as_str()) {
+            apply_edits(&mut data, edits);
+        }
+        data
+    }
+}
+
+/// Returns the IDs of events that repeat an earlier event from the same author with the
+/// same outbox ID, i.e. retries of an attempt that did go out.
+///
+/// Takes `(event_id, author, client_id, created_at)` and keeps the earliest event of each
+/// group, breaking ties by event ID so every device keeps the same one.
+fn retried_duplicate_ids<'a>(
+    events: impl Iterator<Item = (String, String, &'a str, u64)>,
+) -> HashSet<String> {
+    let mut kept: HashMap<(String, &str), (u64, String)> = HashMap::new();
+    let mut duplicates = HashSet::new();
+    for (event_id, author, client_id, created_at) in events {
+        match kept.entry((author, client_id)) {
+            std::collections::hash_map::Entry::Vacant(entry) => {
+                entry.insert((created_at, event_id));
+            }
+            std::collections::hash_map::Entry::Occupied(mut entry) => {
+                if (created_at, &event_id) < (entry.get().0, &entry.get().1) {
+                    let (_, replaced) = entry.insert((created_at, event_id));
+                    duplicates.insert(replaced);
+                } else {
+                    duplicates.insert(event_id);
+                }
+            }
+        }
+    }
+    duplicates
+}
+
+/// Applies the author's edits to a message, oldest first.
+fn apply_edits(message: &mut ChatMessageData, edits: &[&MessageWithTokens]) {
+    if message.is_deleted {
+        return;
+    }
+    let mut edits: Vec<&MessageWithTokens> = edits
+        .iter()
+        .copied()
+        .filter(|edit| edit.message.pubkey.to_hex() == message.pubkey)
+        .collect();
+    edits.sort_by_key(|edit| edit.message.created_at);
+
+    let mut version_created_at = message.created_at;
+    for edit in edits {
+        let previous = std::mem::replace(&mut message.content, edit.message.content.clone());
+        message.edit_history.push(MessageVersionData {
+            content: previous,
+            created_at: version_created_at,
+        });
+        message.content_tokens = edit.tokens.iter().map(convert_serializable_token).collect();
+        version_created_at = edit.message.created_at.as_u64();
+    }
+    message.is_edited = !message.edit_history.is_empty();
+}
+
+/// Returns the first value of a tag with the given name, e.g. the event ID of an `e` tag.
+pub(crate) fn tag_value<'a>(tag: &'a Tag, name: &str) -> Option<&'a str> {
+    match tag.as_slice() {
+        [tag_name, value, ..] if tag_name == name => Some(value.as_str()),
+        _ => None,
+    }
+}
+
+/// Checks that a reaction is a valid NIP-25 / NIP-30 reaction and returns the extra
+/// tags it needs.
+///
+/// Accepted reactions are `+` (like), `-` (dislike), a single emoji (including ZWJ
+/// sequences, skin tones and keycaps) or a NIP-30 custom emoji shortcode such as
+/// `:soapbox:`, which also requires the URL of the emoji image.
+fn validate_reaction(emoji: &str, custom_emoji_url: Option<&str>) -> Result<Vec<Tag>, BridgeError> {
+    let invalid = |message: &str| BridgeError::new(BridgeErrorCode::InvalidInput, message);
+
+    if let Some(shortcode) = emoji
+        .strip_prefix(':')
+        .and_then(|rest| rest.strip_suffix(':'))
+    {
+        let valid_shortcode = !shortcode.is_empty()
+            && shortcode
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !valid_shortcode {
+            return Err(invalid(
+                "Custom emoji shortcodes may only contain letters, digits, '_' and '-'",
+            ));
+        }
+        let url =
+            custom_emoji_url.ok_or_else(|| invalid("Custom emoji reactions need an image URL"))?;
+        url::Url::parse(url).map_err(|e| invalid("Invalid custom emoji URL").with_details(e))?;
+        let tag = crate::api::utils::tag_from_vec(vec![
+            "emoji".to_string(),
+            shortcode.to_string(),
+            url.to_string(),
+        ])?;
+        return Ok(vec![tag]);
+    }
+
+    if custom_emoji_url.is_some() {
+        return Err(invalid(
+            "A custom emoji URL can only be used with a :shortcode: reaction",
+        ));
+    }
+    if emoji == "+" || emoji == "-" {
+        return Ok(Vec::new());
+    }
+
+    if !is_single_emoji(emoji) {
+        return Err(invalid(
+            "Reactions must be a single emoji, '+', '-' or a :shortcode:",
+        ));
+    }
+    Ok(Vec::new())
+}
+
+/// Reacts to a message in a group.
+///
+/// Sends a NIP-25 reaction (kind 7) as an inner event of the MLS group. The reaction
+/// references the message with an `e` tag, its author with a `p` tag and the reacted
+/// kind with a `k` tag, so it is aggregated into the message's `ReactionSummaryData`.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account reacting (must be a group member)
+/// * `group_id` - The MLS group ID the message belongs to
+/// * `message_id` - The event ID (hex) of the message to react to
+/// * `emoji` - The reaction: `+`, `-`, a single emoji or a NIP-30 `:shortcode:`
+/// * `custom_emoji_url` - Image URL for a NIP-30 custom emoji; required for shortcodes
+///   and not allowed otherwise
+///
+/// # Returns
+/// * `Ok(MessageWithTokensData)` - The sent reaction event
+/// * `Err(BridgeError)` - `InvalidInput` if the emoji is invalid, `MessageNotFound` if the
+///   message is not in the group, or any error from sending the message
+///
+/// # Example
+/// ```dart
+/// await reactToMessage(pubkey: pubkey, groupId: groupId, messageId: message.id, emoji: '👍');
+/// ```
+#[frb]
+pub async fn react_to_message(
+    pubkey: &PublicKey,
+    group_id: whitenoise::GroupId,
+    message_id: String,
+    emoji: String,
+    custom_emoji_url: Option<String>,
+) -> Result<MessageWithTokensData, BridgeError> {
+    let mut tags = validate_reaction(&emoji, custom_emoji_url.as_deref())?;
+    let message = find_group_message(pubkey, &group_id, &message_id).await?;
+
+    tags.push(tag_from_vec(vec!["e".to_string(), message.id.clone()])?);
+    tags.push(tag_from_vec(vec![
+        "p".to_string(),
+        message.author.to_hex(),
+    ])?);
+    tags.push(tag_from_vec(vec![
+        "k".to_string(),
+        message.kind.to_string(),
+    ])?);
+
+    send_group_event(*pubkey, group_id, emoji, KIND_REACTION, tags).await
+}
+
+/// Returns the IDs of the account's reactions `emoji` on a message that no deletion of
+/// the account references yet.
+///
+/// `own_events` are the account's events in the group as (ID, kind, content, `e` tag
+/// values).
+fn undeleted_reaction_ids<'a>(
+    own_events: impl IntoIterator<Item = (String, u16, &'a str, Vec<&'a str>)>,
+    message_id: &str,
+    emoji: &str,
+) -> Vec<String> {
+    let mut reactions = Vec::new();
+    let mut deleted = HashSet::new();
+    for (id, kind, content, targets) in own_events {
+        if kind == KIND_DELETION {
+            deleted.extend(targets.into_iter().map(str::to_string));
+        } else if kind == KIND_REACTION && content == emoji && targets.contains(&message_id) {
+            reactions.push(id);
+        }
+    }
+    reactions.retain(|id| !deleted.contains(id));
+    reactions
+}
+
+/// Removes the account's own reaction from a message in a group.
+///
+/// Per NIP-25 a reaction is retracted by deleting the reaction event, so this sends a
+/// NIP-09 deletion (kind 5) referencing the account's matching kind-7 events that are
+/// not deleted yet. Removing a reaction that does not exist (anymore) does nothing.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account that reacted
+/// * `group_id` - The MLS group ID the message belongs to
+/// * `message_id` - The event ID (hex) of the message the reaction was made on
+/// * `emoji` - The reaction to remove, exactly as it was sent
+///
+/// # Returns
+/// * `Ok(())` - If the deletion for the reaction was sent, or there was nothing to remove
+/// * `Err(BridgeError)` - If the messages could not be read or the deletion not sent
+#[frb]
+pub async fn remove_reaction(
+    pubkey: &PublicKey,
+    group_id: whitenoise::GroupId,
+    message_id: String,
+    emoji: String,
+) -> Result<(), BridgeError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let messages = whitenoise
+        .fetch_messages_for_group(pubkey, &group_id)
+        .await?;
+    let own_events = messages
+        .iter()
+        .map(|message| &message.message)
+        .filter(|event| event.pubkey == *pubkey)
+        .map(|event| {
+            (
+                event.id.to_hex(),
+                event.kind.as_u16(),
+                event.content.as_str(),
+                event
+                    .tags
+                    .iter()
+                    .filter_map(|tag| tag_value(tag, "e"))
+                    .collect(),
+            )
+        });
+    let reaction_ids = undeleted_reaction_ids(own_events, &message_id, &emoji);
+    // Nothing to retract: never reacted, or the reaction was already removed
+    if reaction_ids.is_empty() {
+        return Ok(());
+    }
+
+    let mut tags = reaction_ids
+        .into_iter()
+        .map(|id| tag_from_vec(vec!["e".to_string(), id]))
+        .collect::<Result<Vec<Tag>, BridgeError>>()?;
+    tags.push(tag_from_vec(vec![
+        "k".to_string(),
+        KIND_REACTION.to_string(),
+    ])?);
+
+    send_group_event(*pubkey, group_id, String::new(), KIND_DELETION, tags).await?;
+    Ok(())
 }
 
 /// Fetches all messages for a specific MLS group.
@@ -258,7 +967,7 @@ pub async fn send_message_to_group(
 ///
 /// Returns a `Result` containing:
 /// - `Ok(Vec<MessageWithTokensData>)` - A vector of messages with their token data
-/// - `Err(WhitenoiseError)` - If the operation fails (e.g., network error, access denied,
+/// - `Err(BridgeError)` - If the operation fails (e.g., network error, access denied,
 ///   group not found, or user not a member of the group)
 ///
 /// # Examples
@@ -287,7 +996,7 @@ pub async fn send_message_to_group(
 pub async fn fetch_messages_for_group(
     pubkey: &PublicKey,
     group_id: whitenoise::GroupId,
-) -> Result<Vec<MessageWithTokensData>, WhitenoiseError> {
+) -> Result<Vec<MessageWithTokensData>, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let messages = whitenoise
         .fetch_messages_for_group(pubkey, &group_id)
@@ -323,7 +1032,7 @@ pub async fn fetch_messages_for_group(
 ///
 /// Returns a `Result` containing:
 /// - `Ok(Vec<ChatMessage>)` - A vector of processed chat messages ready for display
-/// - `Err(WhitenoiseError)` - If the operation fails (e.g., network error, access denied,
+/// - `Err(BridgeError)` - If the operation fails (e.g., network error, access denied,
 ///   group not found, user not a member of the group, or message processing error)
 ///
 /// # Examples
@@ -354,12 +1063,148 @@ pub async fn fetch_messages_for_group(
 pub async fn fetch_aggregated_messages_for_group(
     pubkey: &PublicKey,
     group_id: whitenoise::GroupId,
-) -> Result<Vec<ChatMessageData>, WhitenoiseError> {
+) -> Result<Vec<ChatMessageData>, BridgeError> {
+    load_chat_messages(pubkey, &group_id).await
+}
+
+/// Returns the cursor pointing at a message.
+fn cursor_for_message(message: &ChatMessageData) -> MessageCursorData {
+    MessageCursorData {
+        created_at: message.created_at,
+        message_id: Some(message.id.clone()),
+    }
+}
+
+/// Orders a `(created_at, id)` position relative to a cursor.
+fn compare_to_cursor(position: (u64, &str), cursor: &MessageCursorData) -> std::cmp::Ordering {
+    match &cursor.message_id {
+        Some(message_id) => position.cmp(&(cursor.created_at, message_id.as_str())),
+        None => position.0.cmp(&cursor.created_at),
+    }
+}
+
+/// Selects one page of items by their `(created_at, id)` position.
+///
+/// Returns the page in chronological order and whether more items exist beyond it; see
+/// `fetch_aggregated_messages_page` for the paging rules.
+fn select_page<T>(
+    items: impl IntoIterator<Item = T>,
+    position: impl Fn(&T) -> (u64, &str),
+    before: Option<&MessageCursorData>,
+    after: Option<&MessageCursorData>,
+    limit: u32,
+) -> (Vec<T>, bool) {
+    let mut matching: Vec<T> = items
+        .into_iter()
+        .filter(|item| {
+            before.is_none_or(|cursor| compare_to_cursor(position(item), cursor).is_lt())
+                && after.is_none_or(|cursor| compare_to_cursor(position(item), cursor).is_gt())
+        })
+        .collect();
+    matching.sort_by(|a, b| position(a).cmp(&position(b)));
+
+    let limit = (limit as usize).max(1);
+    let has_more = matching.len() > limit;
+    if after.is_some() && before.is_none() {
+        matching.truncate(limit);
+        (matching, has_more)
+    } else {
+        let page = matching.split_off(matching.len().saturating_sub(limit));
+        (page, has_more)
+    }
+}
+
+/// Fetches one page of aggregated messages for a specific MLS group.
+///
+/// This is the paginated counterpart of `fetch_aggregated_messages_for_group`. Instead of
+/// returning the whole history of a group across the bridge, it returns at most `limit`
+/// messages around a cursor, so the chat screen can show the latest messages first and
+/// lazily load older ones while the user scrolls.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account requesting the messages (must be a group member)
+/// * `group_id` - The MLS group ID to fetch messages from
+/// * `before` - Only return messages older than this cursor
+/// * `after` - Only return messages newer than this cursor
+/// * `limit` - Maximum number of messages in the page (at least one message is returned
+///   if any match)
+///
+/// # Paging direction
+/// * Only `after` set - pages forward: returns the oldest `limit` messages newer than
+///   `after`, and `next_cursor` points at the newest message in the page
+/// * Otherwise - pages backward: returns the newest `limit` messages older than `before`
+///   (or the latest messages when no cursor is given), and `next_cursor` points at the
+///   oldest message in the page
+///
+/// When both cursors are given, only messages between them are considered.
+///
+/// # Returns
+/// * `Ok(MessagePageData)` - The page, with messages in chronological order (oldest first)
+/// * `Err(BridgeError)` - If the messages could not be fetched
+///
+/// # Example
+/// ```dart
+/// // Initial load: latest 50 messages
+/// var page = await fetchAggregatedMessagesPage(
+///   pubkey: pubkey, groupId: groupId, limit: 50);
+///
+/// // User scrolled to the top: load older messages
+/// if (page.hasMore) {
+///   page = await fetchAggregatedMessagesPage(
+///     pubkey: pubkey, groupId: groupId, before: page.nextCursor, limit: 50);
+/// }
+/// ```
+///
+/// # Notes
+/// * Edits, deletions and reactions are already aggregated into the returned messages
+/// * Use `subscribe_to_group_messages` to receive messages that arrive after the first page
+/// * The core has no paged message query, so every call still reads the group's whole
+///   history from local storage and its cost grows with the history; only the messages
+///   in the page are converted and sent to Flutter. See
+///   `docs/bridge_core_limitations.md`
+#[frb]
+pub async fn fetch_aggregated_messages_page(
+    pubkey: &PublicKey,
+    group_id: whitenoise::GroupId,
+    before: Option<MessageCursorData>,
+    after: Option<MessageCursorData>,
+    limit: u32,
+) -> Result<MessagePageData, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
-    let messages = whitenoise
+    let raw = whitenoise
+        .fetch_messages_for_group(pubkey, &group_id)
+        .await?;
+    let aggregated = whitenoise
         .fetch_aggregated_messages_for_group(pubkey, &group_id)
         .await?;
-    Ok(messages.iter().map(convert_chat_message_to_data).collect())
+
+    // Only the messages in the page are converted and have their edits applied
+    let admins = group_admins(pubkey, &group_id).await?;
+    let changes = RawChanges::collect(&raw, admins);
+    let (page, has_more) = select_page(
+        aggregated.iter().filter(|message| !changes.hides(message)),
+        |message| (message.created_at.as_u64(), message.id.as_str()),
+        before.as_ref(),
+        after.as_ref(),
+        limit,
+    );
+    let messages: Vec<ChatMessageData> = page
+        .into_iter()
+        .map(|message| changes.apply(message))
+        .collect();
+
+    let forward = after.is_some() && before.is_none();
+    let next_cursor = if forward {
+        messages.last()
+    } else {
+        messages.first()
+    }
+    .map(cursor_for_message);
+    Ok(MessagePageData {
+        messages,
+        next_cursor,
+        has_more,
+    })
 }
 
 /// Send an encrypted direct message using NIP-04
@@ -389,7 +1234,7 @@ pub async fn fetch_aggregated_messages_for_group(
 ///
 /// Returns a `Result` containing:
 /// - `Ok(())` - If the message was successfully encrypted and sent
-/// - `Err(WhitenoiseError)` - If the operation fails (e.g., network error, encryption failure,
+/// - `Err(BridgeError)` - If the operation fails (e.g., network error, encryption failure,
 ///   sender's private key not found, or invalid recipient public key)
 ///
 /// # Examples
@@ -425,9 +1270,862 @@ pub async fn send_direct_message_nip04(
     receiver: &PublicKey,
     content: String,
     tags: Vec<Tag>,
-) -> Result<(), WhitenoiseError> {
+) -> Result<(), BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     whitenoise
         .send_direct_message_nip04(sender, receiver, content, tags)
-        .await
+        .await?;
+    Ok(())
+}
+
+/// How far a group message stream has caught up with the raw events in the message store.
+///
+/// Edits, deletions and reactions are stored as events of their own, so a conversation can
+/// only have changed if the store holds an event the cursor has not seen yet.
+#[derive(Debug, Clone, Default)]
+struct MessageWatchCursor {
+    /// `created_at` of the newest event seen so far
+    since: u64,
+    /// IDs of the events seen at exactly `since`, which may share that second with new ones
+    seen_at_since: HashSet<String>,
+    /// Number of events seen; catches events that arrive late with an older timestamp
+    event_count: usize,
+}
+
+impl MessageWatchCursor {
+    /// Moves the cursor past `events`, given as `(created_at, id)` pairs, and returns whether
+    /// any of them is new since the last call.
+    fn advance<'a>(&mut self, events: impl IntoIterator<Item = (u64, &'a str)>) -> bool {
+        let mut changed = false;
+        let mut event_count = 0;
+        let mut newest = self.since;
+        let mut newest_ids = HashSet::new();
+        for (created_at, id) in events {
+            event_count += 1;
+            if created_at > self.since
+                || (created_at == self.since && !self.seen_at_since.contains(id))
+            {
+                changed = true;
+            }
+            if created_at > newest {
+                newest = created_at;
+                newest_ids.clear();
+            }
+            if created_at == newest {
+                newest_ids.insert(id.to_string());
+            }
+        }
+        changed |= event_count > self.event_count;
+
+        if newest == self.since {
+            self.seen_at_since.extend(newest_ids);
+        } else {
+            self.since = newest;
+            self.seen_at_since = newest_ids;
+        }
+        self.event_count = event_count;
+        changed
+    }
+}
+
+/// Feeds raw group messages to a watch cursor.
+fn advance_cursor(cursor: &mut MessageWatchCursor, raw: &[MessageWithTokens]) -> bool {
+    let ids: Vec<(u64, String)> = raw
+        .iter()
+        .map(|message| {
+            (
+                message.message.created_at.as_u64(),
+                message.message.id.to_hex(),
+            )
+        })
+        .collect();
+    cursor.advance(
+        ids.iter()
+            .map(|(created_at, id)| (*created_at, id.as_str())),
+    )
+}
+
+/// Computes the stream updates needed to go from one snapshot of a conversation to the next.
+fn diff_group_messages(
+    previous: &HashMap<String, ChatMessageData>,
+    current: &[ChatMessageData],
+) -> Vec<GroupMessageUpdate> {
+    let mut updates = Vec::new();
+    for message in current {
+        let Some(known) = previous.get(&message.id) else {
+            updates.push(GroupMessageUpdate::NewMessage {
+                message: message.clone(),
+            });
+            continue;
+        };
+
+        if message.is_deleted && !known.is_deleted {
+            updates.push(GroupMessageUpdate::MessageDeleted {
+                message_id: message.id.clone(),
+            });
+            continue;
+        }
+
+        if message.content != known.content || message.tags != known.tags {
+            updates.push(GroupMessageUpdate::MessageEdited {
+                message: message.clone(),
+            });
+        }
+
+        if message.reactions != known.reactions {
+            updates.push(GroupMessageUpdate::ReactionsUpdated {
+                message_id: message.id.clone(),
+                reactions: message.reactions.clone(),
+            });
+        }
+    }
+
+    let current_ids: HashSet<&str> = current.iter().map(|message| message.id.as_str()).collect();
+    let mut removed: Vec<&String> = previous
+        .keys()
+        .filter(|id| !current_ids.contains(id.as_str()))
+        .collect();
+    removed.sort();
+    updates.extend(
+        removed
+            .into_iter()
+            .map(|message_id| GroupMessageUpdate::MessageRemoved {
+                message_id: message_id.clone(),
+            }),
+    );
+    updates
+}
+
+/// Subscribes to live message updates for a specific MLS group.
+///
+/// Instead of repeatedly calling `fetch_aggregated_messages_for_group` from Flutter, this
+/// function opens a stream that pushes every change to the conversation as soon as the
+/// Whitenoise event processor has decrypted and stored it: new messages, edits, deletions,
+/// removals and reaction updates. Messages that already exist when the stream is opened
+/// are not replayed; load them first with `fetch_aggregated_messages_for_group`.
+///
+/// # Arguments
+/// * `sink` - The stream sink the updates are pushed to (provided by flutter_rust_bridge)
+/// * `pubkey` - The public key of the account subscribing (must be a group member)
+/// * `group_id` - The MLS group ID to watch
+///
+/// # Returns
+/// * `Ok(())` - The subscription was started; updates arrive on the stream
+/// * `Err(BridgeError)` - If the initial message snapshot could not be loaded or the
+///   group's relays could not be subscribed to
+///
+/// # Notes
+/// * The first item on the stream is always `GroupMessageUpdate::Subscribed`, carrying the
+///   handle to pass to `unsubscribe` when the chat screen is closed
+/// * The subscription also ends on its own once the Dart stream is cancelled
+/// * The store is not polled: it is re-read when the bridge sends to the group or one of
+///   the group's relays announces a new event for it (the stream keeps a subscription to
+///   them open), and a few more times over the next 3 seconds, since the core may store
+///   the event a moment later
+/// * Each check only reads the raw events of the group and compares them with a cursor
+///   (the newest `created_at` seen so far); messages are re-aggregated only when an event
+///   the stream has not seen yet was stored
+///
+/// # Example
+/// ```dart
+/// subscribeToGroupMessages(pubkey: pubkey, groupId: groupId).listen((update) {
+///   switch (update) {
+///     case GroupMessageUpdate_Subscribed(:final subscriptionId):
+///       _subscriptionId = subscriptionId;
+///     case GroupMessageUpdate_NewMessage(:final message):
+///       _addMessage(message);
+///     // ...
+///   }
+/// });
+/// ```
+#[frb]
+pub async fn subscribe_to_group_messages(
+    sink: StreamSink<GroupMessageUpdate>,
+    pubkey: PublicKey,
+    group_id: whitenoise::GroupId,
+) -> Result<(), BridgeError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    // Started first, so nothing stored while the snapshot is read goes unnoticed
+    let mut watch = GroupWatch::start(&pubkey, &group_id).await?;
+    let raw = whitenoise
+        .fetch_messages_for_group(&pubkey, &group_id)
+        .await?;
+    let mut cursor = MessageWatchCursor::default();
+    advance_cursor(&mut cursor, &raw);
+    let initial = load_chat_messages_with_raw(&pubkey, &group_id, &raw).await?;
+    let mut known: HashMap<String, ChatMessageData> = initial
+        .into_iter()
+        .map(|message| (message.id.clone(), message))
+        .collect();
+
+    spawn_subscription(move |subscription_id| async move {
+        if sink
+            .add(GroupMessageUpdate::Subscribed { subscription_id })
+            .is_err()
+        {
+            return;
+        }
+
+        while watch.changed().await {
+            for delay in SETTLE_DELAYS {
+                tokio::time::sleep(delay).await;
+                // Transient storage errors are skipped; the next check will try again
+                let Ok(raw) = whitenoise
+                    .fetch_messages_for_group(&pubkey, &group_id)
+                    .await
+                else {
+                    continue;
+                };
+                let mut next_cursor = cursor.clone();
+                if !advance_cursor(&mut next_cursor, &raw) {
+                    continue;
+                }
+                let Ok(current) = load_chat_messages_with_raw(&pubkey, &group_id, &raw).await
+                else {
+                    // Keep the old cursor so the new events are picked up on the next check
+                    continue;
+                };
+                cursor = next_cursor;
+
+                for update in diff_group_messages(&known, &current) {
+                    if sink.add(update).is_err() {
+                        // The Dart side closed the stream
+                        return;
+                    }
+                }
+                known = current
+                    .into_iter()
+                    .map(|message| (message.id.clone(), message))
+                    .collect();
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Builds the NIP-10 marked `e` tags of a reply.
+///
+/// The `root` marker always points at the start of the thread. A direct reply to the
+/// root carries only that tag; deeper replies add a `reply` marker for their parent.
+///
+/// Messages are given as their event ID (hex) and author.
+fn thread_tags(
+    root: (&str, &PublicKey),
+    parent: (&str, &PublicKey),
+) -> Result<Vec<Tag>, BridgeError> {
+    let marked = |(id, author): (&str, &PublicKey), marker: &str| {
+        tag_from_vec(vec![
+            "e".to_string(),
+            id.to_string(),
+            String::new(),
+            marker.to_string(),
+            author.to_hex(),
+        ])
+    };
+    let mut tags = vec![marked(root, "root")?];
+    if parent.0 != root.0 {
+        tags.push(marked(parent, "reply")?);
+    }
+    Ok(tags)
+}
+
+/// Replies to a message in a group.
+///
+/// Sends a chat message (kind 9) with NIP-10 marked tags so it is aggregated as a reply:
+/// an `e` tag with the `root` marker pointing at the start of the thread and, when the
+/// parent is itself a reply, an `e` tag with the `reply` marker pointing at the parent.
+/// The parent's author and everyone the parent mentioned are added as `p` tags.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account replying (must be a group member)
+/// * `group_id` - The MLS group ID the parent message belongs to
+/// * `parent_message_id` - The event ID (hex) of the message being replied to
+/// * `content` - The reply text
+///
+/// # Returns
+/// * `Ok(MessageWithTokensData)` - The sent reply
+/// * `Err(BridgeError)` - `MessageNotFound` if the parent is not in the group, or any
+///   error from sending the message
+///
+/// # Example
+/// ```dart
+/// await replyToMessage(
+///   pubkey: pubkey, groupId: groupId, parentMessageId: message.id, content: 'Agreed!');
+/// ```
+#[frb]
+pub async fn reply_to_message(
+    pubkey: &PublicKey,
+    group_id: whitenoise::GroupId,
+    parent_message_id: String,
+    content: String,
+) -> Result<MessageWithTokensData, BridgeError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let messages = whitenoise
+        .fetch_aggregated_messages_for_group(pubkey, &group_id)
+        .await?;
+    let by_id: HashMap<&str, &ChatMessage> = messages
+        .iter()
+        .map(|message| (message.id.as_str(), message))
+        .collect();
+    let parent = by_id
+        .get(parent_message_id.as_str())
+        .copied()
+        .ok_or_else(|| BridgeError::new(BridgeErrorCode::MessageNotFound, "Message not found"))?;
+
+    // Walk up the reply chain to find the root of the thread
+    let mut root = parent;
+    let mut visited = HashSet::from([parent.id.as_str()]);
+    while let Some(next) = root
+        .reply_to_id
+        .as_deref()
+        .and_then(|id| by_id.get(id).copied())
+    {
+        if !visited.insert(next.id.as_str()) {
+            break;
+        }
+        root = next;
+    }
+
+    let mut tags = thread_tags(
+        (root.id.as_str(), &root.author),
+        (parent.id.as_str(), &parent.author),
+    )?;
+    let own_pubkey = pubkey.to_hex();
+    let mut mentioned = vec![parent.author.to_hex()];
+    mentioned.extend(
+        parent
+            .tags
+            .iter()
+            .filter_map(|tag| tag_value(tag, "p"))
+            .map(str::to_string),
+    );
+    let mut seen = HashSet::new();
+    for mentioned_pubkey in mentioned {
+        if mentioned_pubkey != own_pubkey && seen.insert(mentioned_pubkey.clone()) {
+            tags.push(tag_from_vec(vec!["p".to_string(), mentioned_pubkey])?);
+        }
+    }
+
+    send_group_message(*pubkey, group_id, content, KIND_CHAT_MESSAGE, Some(tags)).await
+}
+
+/// Fetches a message and every reply below it.
+///
+/// Returns the root message followed by all direct and indirect replies to it, i.e. the
+/// full reply chain as resolved by the message aggregator (`reply_to_id`).
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account requesting the thread (must be a group member)
+/// * `group_id` - The MLS group ID the thread belongs to
+/// * `root_message_id` - The event ID (hex) of the message that starts the thread
+///
+/// # Returns
+/// * `Ok(Vec<ChatMessageData>)` - The root message and its replies, in chronological order
+/// * `Err(BridgeError)` - `MessageNotFound` if the root message is not in the group
+#[frb]
+pub async fn fetch_thread(
+    pubkey: &PublicKey,
+    group_id: whitenoise::GroupId,
+    root_message_id: String,
+) -> Result<Vec<ChatMessageData>, BridgeError> {
+    let messages = load_chat_messages(pubkey, &group_id).await?;
+    if !messages.iter().any(|message| message.id == root_message_id) {
+        return Err(BridgeError::new(
+            BridgeErrorCode::MessageNotFound,
+            "Message not found",
+        ));
+    }
+
+    let mut replies: HashMap<&str, Vec<&ChatMessageData>> = HashMap::new();
+    for message in &messages {
+        if let Some(parent_id) = message.reply_to_id.as_deref() {
+            replies.entry(parent_id).or_default().push(message);
+        }
+    }
+
+    let mut in_thread = HashSet::from([root_message_id.as_str()]);
+    let mut pending = vec![root_message_id.as_str()];
+    while let Some(id) = pending.pop() {
+        for reply in replies.get(id).into_iter().flatten() {
+            if in_thread.insert(reply.id.as_str()) {
+                pending.push(reply.id.as_str());
+            }
+        }
+    }
+
+    let mut thread: Vec<ChatMessageData> = messages
+        .iter()
+        .filter(|message| in_thread.contains(message.id.as_str()))
+        .cloned()
+        .collect();
+    thread.sort_by(|a, b| (a.created_at, &a.id).cmp(&(b.created_at, &b.id)));
+    Ok(thread)
+}
+
+/// Deletes a message in a group.
+///
+/// Sends a NIP-09 deletion request (kind 5) for the message as an inner event of the MLS
+/// group. Once processed, the message is returned with `is_deleted` set.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account deleting the message
+/// * `group_id` - The MLS group ID the message belongs to
+/// * `message_id` - The event ID (hex) of the message to delete
+///
+/// # Returns
+/// * `Ok(())` - If the deletion was sent
+/// * `Err(BridgeError)` - `MessageNotFound` if the message is not in the group,
+///   `Unauthorized` if the account is neither the author nor a group admin, or any error
+///   from sending the deletion
+///
+/// # Notes
+/// * Authors can delete their own messages; group admins can delete any message
+#[frb]
+pub async fn delete_message(
+    pubkey: &PublicKey,
+    group_id: whitenoise::GroupId,
+    message_id: String,
+) -> Result<(), BridgeError> {
+    let message = find_group_message(pubkey, &group_id, &message_id).await?;
+    if message.author != *pubkey {
+        let admins = group_admins(pubkey, &group_id).await?;
+        if !admins.contains(&pubkey.to_hex()) {
+            return Err(BridgeError::new(
+                BridgeErrorCode::Unauthorized,
+                "Only the author or a group admin can delete this message",
+            ));
+        }
+    }
+
+    let tags = vec![
+        tag_from_vec(vec!["e".to_string(), message.id.clone()])?,
+        tag_from_vec(vec!["k".to_string(), message.kind.to_string()])?,
+    ];
+    send_group_event(*pubkey, group_id, String::new(), KIND_DELETION, tags).await?;
+    Ok(())
+}
+
+/// Edits one of the account's own messages in a group.
+///
+/// Sends a chat message (kind 9) carrying the complete new content and referencing the
+/// original message with an `e` tag marked `edit`. When messages are fetched the edit is applied: the
+/// message shows the new content, `is_edited` is set and the previous content is added to
+/// `edit_history`.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the message author
+/// * `group_id` - The MLS group ID the message belongs to
+/// * `message_id` - The event ID (hex) of the original message
+/// * `new_content` - The complete new message text
+///
+/// # Returns
+/// * `Ok(MessageWithTokensData)` - The sent edit event
+/// * `Err(BridgeError)` - `MessageNotFound` if the message is not in the group or has been
+///   deleted, `Unauthorized` if the account is not the author, or any error from sending
+#[frb]
+pub async fn edit_message(
+    pubkey: &PublicKey,
+    group_id: whitenoise::GroupId,
+    message_id: String,
+    new_content: String,
+) -> Result<MessageWithTokensData, BridgeError> {
+    let message = find_group_message(pubkey, &group_id, &message_id).await?;
+    if message.is_deleted {
+        return Err(BridgeError::new(
+            BridgeErrorCode::MessageNotFound,
+            "Message has been deleted",
+        ));
+    }
+    if message.author != *pubkey {
+        return Err(BridgeError::new(
+            BridgeErrorCode::Unauthorized,
+            "Only the author can edit this message",
+        ));
+    }
+
+    let tags = vec![tag_from_vec(vec![
+        "e".to_string(),
+        message.id.clone(),
+        String::new(),
+        EDIT_MARKER.to_string(),
+        message.author.to_hex(),
+    ])?];
+    send_group_message(
+        *pubkey,
+        group_id,
+        new_content,
+        KIND_CHAT_MESSAGE,
+        Some(tags),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "a0b1c2d3e4f5a0b1c2d3e4f5a0b1c2d3e4f5a0b1c2d3e4f5a0b1c2d3e4f5a0b1";
+    const BOB: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    fn message(id: &str, content: &str) -> ChatMessageData {
+        ChatMessageData {
+            id: id.to_string(),
+            pubkey: "author".to_string(),
+            content: content.to_string(),
+            created_at: 1,
+            tags: Vec::new(),
+            is_reply: false,
+            reply_to_id: None,
+            is_deleted: false,
+            content_tokens: Vec::new(),
+            reactions: ReactionSummaryData {
+                by_emoji: Vec::new(),
+                user_reactions: Vec::new(),
+            },
+            kind: KIND_CHAT_MESSAGE,
+            is_edited: false,
+            edit_history: Vec::new(),
+            attachments: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn cursor_reports_only_unseen_events() {
+        let mut cursor = MessageWatchCursor::default();
+        assert!(cursor.advance([(10, "a"), (12, "b")]));
+        assert!(!cursor.advance([(10, "a"), (12, "b")]));
+        // A new event in the same second as the newest one seen
+        assert!(cursor.advance([(10, "a"), (12, "b"), (12, "c")]));
+        assert!(!cursor.advance([(10, "a"), (12, "b"), (12, "c")]));
+        assert!(cursor.advance([(10, "a"), (12, "b"), (12, "c"), (13, "d")]));
+    }
+
+    #[test]
+    fn cursor_catches_late_events_with_older_timestamps() {
+        let mut cursor = MessageWatchCursor::default();
+        assert!(cursor.advance([(10, "a"), (20, "b")]));
+        assert!(cursor.advance([(10, "a"), (15, "late"), (20, "b")]));
+        assert_eq!(cursor.since, 20);
+    }
+
+    #[test]
+    fn diff_reports_new_edited_and_deleted_messages() {
+        let previous: HashMap<String, ChatMessageData> = [message("a", "hi"), message("b", "yo")]
+            .into_iter()
+            .map(|message| (message.id.clone(), message))
+            .collect();
+        let mut deleted = message("b", "");
+        deleted.is_deleted = true;
+        let current = vec![message("a", "hello"), deleted, message("c", "new")];
+
+        let updates = diff_group_messages(&previous, &current);
+        assert_eq!(updates.len(), 3);
+        assert!(
+            matches!(&updates[0], GroupMessageUpdate::MessageEdited { message } if message.content == "hello")
+        );
+        assert!(
+            matches!(&updates[1], GroupMessageUpdate::MessageDeleted { message_id } if message_id == "b")
+        );
+        assert!(
+            matches!(&updates[2], GroupMessageUpdate::NewMessage { message } if message.id == "c")
+        );
+    }
+
+    #[test]
+    fn diff_reports_removed_messages() {
+        let previous: HashMap<String, ChatMessageData> =
+            [message("a", "hi"), message("b", "hi"), message("c", "yo")]
+                .into_iter()
+                .map(|message| (message.id.clone(), message))
+                .collect();
+        let current = vec![message("b", "hi")];
+
+        let updates = diff_group_messages(&previous, &current);
+        assert_eq!(updates.len(), 2);
+        assert!(
+            matches!(&updates[0], GroupMessageUpdate::MessageRemoved { message_id } if message_id == "a")
+        );
+        assert!(
+            matches!(&updates[1], GroupMessageUpdate::MessageRemoved { message_id } if message_id == "c")
+        );
+    }
+
+    #[test]
+    fn diff_of_identical_snapshots_is_empty() {
+        let current = vec![message("a", "hi")];
+        let previous = current
+            .iter()
+            .map(|message| (message.id.clone(), message.clone()))
+            .collect();
+        assert!(diff_group_messages(&previous, &current).is_empty());
+    }
+
+    fn retried(events: &[(&str, &str, &str, u64)]) -> Vec<String> {
+        let mut ids: Vec<String> = retried_duplicate_ids(events.iter().map(
+            |(event_id, author, client_id, created_at)| {
+                (
+                    event_id.to_string(),
+                    author.to_string(),
+                    *client_id,
+                    *created_at,
+                )
+            },
+        ))
+        .into_iter()
+        .collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn retried_duplicates_keep_the_earliest_event() {
+        let ids = retried(&[
+            ("b", "alice", "outbox-1", 20),
+            ("a", "alice", "outbox-1", 10),
+            ("c", "alice", "outbox-1", 30),
+        ]);
+        assert_eq!(ids, vec!["b".to_string(), "c".to_string()]);
+    }
+
+    #[test]
+    fn retried_duplicates_break_ties_by_event_id() {
+        let ids = retried(&[
+            ("b", "alice", "outbox-1", 10),
+            ("a", "alice", "outbox-1", 10),
+        ]);
+        assert_eq!(ids, vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn same_client_id_from_other_authors_is_not_a_duplicate() {
+        let ids = retried(&[
+            ("a", "alice", "outbox-1", 10),
+            ("b", "bob", "outbox-1", 20),
+            ("c", "alice", "outbox-2", 30),
+        ]);
+        assert!(ids.is_empty());
+    }
+
+    fn page(
+        before: Option<(u64, &str)>,
+        after: Option<(u64, &str)>,
+        limit: u32,
+    ) -> (Vec<&'static str>, bool) {
+        let items = [(30, "c"), (10, "a"), (20, "b"), (20, "bb"), (40, "d")];
+        let cursor = |(created_at, id): (u64, &str)| MessageCursorData {
+            created_at,
+            message_id: Some(id.to_string()),
+        };
+        let (page, has_more) = select_page(
+            items,
+            |(created_at, id)| (*created_at, *id),
+            before.map(cursor).as_ref(),
+            after.map(cursor).as_ref(),
+            limit,
+        );
+        (page.into_iter().map(|(_, id)| id).collect(), has_more)
+    }
+
+    #[test]
+    fn first_page_is_the_latest_messages() {
+        assert_eq!(page(None, None, 2), (vec!["c", "d"], true));
+        assert_eq!(
+            page(None, None, 10),
+            (vec!["a", "b", "bb", "c", "d"], false)
+        );
+    }
+
+    #[test]
+    fn before_pages_backward_past_equal_timestamps() {
+        assert_eq!(page(Some((20, "bb")), None, 1), (vec!["b"], true));
+        assert_eq!(page(Some((20, "b")), None, 5), (vec!["a"], false));
+    }
+
+    #[test]
+    fn after_pages_forward() {
+        assert_eq!(page(None, Some((20, "b")), 2), (vec!["bb", "c"], true));
+        assert_eq!(
+            page(Some((40, "d")), Some((10, "a")), 5),
+            (vec!["b", "bb", "c"], false)
+        );
+    }
+
+    #[test]
+    fn timestamp_only_cursor_excludes_the_whole_second() {
+        let cursor = MessageCursorData {
+            created_at: 20,
+            message_id: None,
+        };
+        let (page, _) = select_page(
+            [(10, "a"), (20, "b"), (30, "c")],
+            |(created_at, id)| (*created_at, *id),
+            Some(&cursor),
+            None,
+            5,
+        );
+        assert_eq!(page, vec![(10, "a")]);
+    }
+
+    #[test]
+    fn direct_replies_carry_only_the_root_marker() {
+        let alice = PublicKey::from_hex(ALICE).unwrap();
+        let tags = thread_tags(("root", &alice), ("root", &alice)).unwrap();
+        let tags: Vec<&[String]> = tags.iter().map(Tag::as_slice).collect();
+        assert_eq!(tags, vec![["e", "root", "", "root", ALICE]]);
+    }
+
+    #[test]
+    fn nested_replies_mark_the_root_and_the_parent() {
+        let alice = PublicKey::from_hex(ALICE).unwrap();
+        let bob = PublicKey::from_hex(BOB).unwrap();
+        let tags = thread_tags(("root", &alice), ("parent", &bob)).unwrap();
+        let tags: Vec<&[String]> = tags.iter().map(Tag::as_slice).collect();
+        assert_eq!(
+            tags,
+            vec![
+                ["e", "root", "", "root", ALICE],
+                ["e", "parent", "", "reply", BOB]
+            ]
+        );
+    }
+
+    fn tag(values: &[&str]) -> Tag {
+        tag_from_vec(values.iter().map(|value| value.to_string()).collect()).unwrap()
+    }
+
+    #[test]
+    fn edits_are_marked_e_tags() {
+        assert_eq!(
+            edit_tag_target(&tag(&["e", "original", "", "edit", ALICE])),
+            Some("original")
+        );
+        assert_eq!(
+            edit_tag_target(&tag(&["e", "original", "", "edit"])),
+            Some("original")
+        );
+        assert_eq!(edit_tag_target(&tag(&["e", "parent", "", "reply"])), None);
+        assert_eq!(edit_tag_target(&tag(&["e", "parent"])), None);
+        assert_eq!(edit_tag_target(&tag(&["q", "original", "", "edit"])), None);
+    }
+
+    #[test]
+    fn only_authors_and_admins_may_delete() {
+        let admins = HashSet::from([BOB.to_string()]);
+        let author = "c0ffee";
+        assert!(deletion_is_authorized(
+            &[author.to_string()],
+            author,
+            &admins
+        ));
+        assert!(deletion_is_authorized(&[BOB.to_string()], author, &admins));
+        assert!(!deletion_is_authorized(
+            &[ALICE.to_string()],
+            author,
+            &admins
+        ));
+        assert!(deletion_is_authorized(
+            &[ALICE.to_string(), author.to_string()],
+            author,
+            &admins
+        ));
+        assert!(!deletion_is_authorized(&[], author, &admins));
+    }
+
+    #[test]
+    fn accepts_likes_dislikes_and_single_emoji() {
+        for emoji in [
+            "+",
+            "-",
+            "👍",
+            "❤️",
+            "❤",
+            "👍🏽",
+            "👨‍👩‍👧‍👦",
+            "🏳️‍🌈",
+            "👩🏽‍💻",
+            "1️⃣",
+            "#⃣",
+            "🇩🇪",
+            "🏴󠁧󠁢󠁳󠁣󠁴󠁿",
+            "©️",
+        ] {
+            assert!(
+                validate_reaction(emoji, None).unwrap().is_empty(),
+                "{emoji} should be accepted"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_text_and_whitespace_reactions() {
+        let too_long = "👍".repeat(MAX_EMOJI_CODE_POINTS + 1);
+        for emoji in ["", "ok", "👍 ", &too_long, "a\u{FE0F}\u{20E3}", "\u{7}"] {
+            let error = validate_reaction(emoji, None).unwrap_err();
+            assert!(
+                matches!(error.code, BridgeErrorCode::InvalidInput),
+                "{emoji:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_non_ascii_text_and_several_emoji() {
+        for emoji in [
+            "é",
+            "日本",
+            "Ω",
+            "→",
+            "ä👍",
+            "👍👍",
+            "🇩",
+            "🇩🇪🇫🇷",
+            "\u{200D}👍",
+            "👍\u{200D}",
+            "👍\u{FE0F}\u{FE0F}",
+            "🏽",
+            "\u{FE0F}",
+            "\u{20E3}",
+            "1️⃣2",
+            "🏴\u{E0067}",
+            "\u{E0067}\u{E007F}",
+        ] {
+            assert!(
+                validate_reaction(emoji, None).is_err(),
+                "{emoji:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn removes_only_reactions_not_deleted_yet() {
+        let events = vec![
+            ("r1".to_string(), KIND_REACTION, "👍", vec!["m"]),
+            ("r2".to_string(), KIND_REACTION, "👍", vec!["m"]),
+            ("r3".to_string(), KIND_REACTION, "❤️", vec!["m"]),
+            ("r4".to_string(), KIND_REACTION, "👍", vec!["other"]),
+            ("d1".to_string(), KIND_DELETION, "", vec!["r1"]),
+        ];
+        assert_eq!(
+            undeleted_reaction_ids(events.clone(), "m", "👍"),
+            vec!["r2".to_string()]
+        );
+        let mut removed = events;
+        removed.push(("d2".to_string(), KIND_DELETION, "", vec!["r2"]));
+        assert!(undeleted_reaction_ids(removed, "m", "👍").is_empty());
+    }
+
+    #[test]
+    fn custom_emoji_need_a_shortcode_and_url() {
+        let tags = validate_reaction(":soapbox:", Some("https://example.com/soapbox.png")).unwrap();
+        let tags: Vec<&[String]> = tags.iter().map(Tag::as_slice).collect();
+        assert_eq!(
+            tags,
+            vec![["emoji", "soapbox", "https://example.com/soapbox.png"]]
+        );
+        assert!(validate_reaction(":soapbox:", None).is_err());
+        assert!(validate_reaction(":soap box:", Some("https://example.com/a.png")).is_err());
+        assert!(validate_reaction("::", Some("https://example.com/a.png")).is_err());
+        assert!(validate_reaction(":soapbox:", Some("not a url")).is_err());
+        assert!(validate_reaction("👍", Some("https://example.com/a.png")).is_err());
+    }
 }
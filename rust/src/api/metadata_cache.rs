@@ -0,0 +1,551 @@
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use crate::api::events::{emit_event, WhitenoiseEvent};
+use crate::api::utils::{convert_metadata_to_data, unix_now, MetadataData};
+use crate::relay_client::fetch_events;
+use crate::store;
+use flutter_rust_bridge::frb;
+use futures::StreamExt;
+use nostr_sdk::{Filter, JsonUtil, Kind};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Mutex, OnceLock};
+use tokio::sync::watch;
+pub use whitenoise::{Metadata, PublicKey, RelayType, RelayUrl, Whitenoise};
+
+/// Profile metadata of a public key, as held by the metadata cache.
+#[derive(Debug, Clone)]
+pub struct CachedMetadataData {
+    pub pubkey: String,                 // Hex
+    pub metadata: Option<MetadataData>, // None if the user has not published any metadata
+    pub fetched_at: u64,                // 0 if the metadata could never be fetched
+    pub is_stale: bool,                 // Older than the cache TTL, or never fetched
+}
+
+/// Name of the store document holding the metadata cache.
+const METADATA_CACHE_DOCUMENT: &str = "metadata_cache";
+
+/// How long fetched metadata is considered fresh (1 hour).
+const METADATA_TTL_SECS: u64 = 60 * 60;
+
+/// Number of most recently fetched profiles kept in the cache.
+const MAX_CACHED_PROFILES: usize = 5000;
+
+/// Maximum number of authors in one metadata query.
+const AUTHORS_PER_QUERY: usize = 250;
+
+/// Maximum number of metadata queries running at the same time.
+const MAX_CONCURRENT_QUERIES: usize = 4;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    metadata: Option<MetadataData>,
+    fetched_at: u64,
+}
+
+/// Cached metadata keyed by public key (hex).
+type MetadataCache = HashMap<String, CacheEntry>;
+
+/// In-memory copy of the cache document, loaded on first use.
+static MEMORY_CACHE: tokio::sync::Mutex<Option<MetadataCache>> =
+    tokio::sync::Mutex::const_new(None);
+
+/// Public keys (hex) being fetched right now, so concurrent lookups of the same key share
+/// a single relay query. Each entry is closed when its fetch finishes.
+static IN_FLIGHT: OnceLock<Mutex<HashMap<String, watch::Receiver<()>>>> = OnceLock::new();
+
+async fn with_memory_cache<R>(f: impl FnOnce(&mut MetadataCache) -> R) -> Result<R, BridgeError> {
+    let mut memory = MEMORY_CACHE.lock().await;
+    if memory.is_none() {
+        *memory = Some(store::load(METADATA_CACHE_DOCUMENT, None).await?);
+    }
+    Ok(f(memory.as_mut().expect("metadata cache was just loaded")))
+}
+
+/// Writes the in-memory cache to disk, dropping the oldest profiles beyond the limit.
+pub(crate) async fn persist_memory_cache() -> Result<(), BridgeError> {
+    let snapshot = with_memory_cache(|cache| {
+        if cache.len() > MAX_CACHED_PROFILES {
+            let mut by_age: Vec<(u64, String)> = cache
+                .iter()
+                .map(|(pubkey, entry)| (entry.fetched_at, pubkey.clone()))
+                .collect();
+            by_age.sort();
+            for (_, pubkey) in by_age.iter().take(cache.len() - MAX_CACHED_PROFILES) {
+                cache.remove(pubkey);
+            }
+        }
+        cache.clone()
+    })
+    .await?;
+    store::update(
+        METADATA_CACHE_DOCUMENT,
+        None,
+        |cache: &mut MetadataCache| *cache = snapshot,
+    )
+    .await
+}
+
+/// Forgets the in-memory cache so it is reloaded from disk on next use. Called after the
+/// store has been cleared.
+pub(crate) async fn reset_metadata_cache() {
+    *MEMORY_CACHE.lock().await = None;
+}
+
+fn cached(pubkey: &str, entry: Option<&CacheEntry>, now: u64) -> CachedMetadataData {
+    CachedMetadataData {
+        pubkey: pubkey.to_string(),
+        metadata: entry.and_then(|entry| entry.metadata.clone()),
+        fetched_at: entry.map(|entry| entry.fetched_at).unwrap_or_default(),
+        is_stale: entry.is_none_or(|entry| entry.fetched_at + METADATA_TTL_SECS <= now),
+    }
+}
+
+/// Returns every cached profile as (hex public key, metadata) pairs.
+pub(crate) async fn cached_profiles() -> Result<Vec<(String, Option<MetadataData>)>, BridgeError> {
+    with_memory_cache(|cache| {
+        cache
+            .iter()
+            .map(|(pubkey, entry)| (pubkey.clone(), entry.metadata.clone()))
+            .collect()
+    })
+    .await
+}
+
+/// Records the result of a fetch in a cache entry and returns the metadata it held.
+///
+/// A miss keeps the cached metadata: it usually means the relays that were asked do not
+/// have the profile, not that it was deleted.
+fn record_fetch(
+    entry: &mut CacheEntry,
+    metadata: Option<MetadataData>,
+    now: u64,
+) -> Option<MetadataData> {
+    entry.fetched_at = now;
+    match metadata {
+        Some(metadata) => entry.metadata.replace(metadata),
+        None => entry.metadata.clone(),
+    }
+}
+
+/// Stores fetched metadata for a public key, announcing it on the event bus if it changed.
+///
+/// This is the only place `WhitenoiseEvent::MetadataUpdated` is emitted. Metadata that
+/// cannot be cached (the cache document is unreadable) is still announced, and the error
+/// is returned after that.
+pub(crate) async fn cache_metadata(
+    pubkey: &str,
+    metadata: Option<MetadataData>,
+) -> Result<(), BridgeError> {
+    let previous = with_memory_cache(|cache| {
+        let entry = cache.entry(pubkey.to_string()).or_insert(CacheEntry {
+            metadata: None,
+            fetched_at: 0,
+        });
+        record_fetch(entry, metadata.clone(), unix_now())
+    })
+    .await;
+    if let Some(metadata) = metadata {
+        if previous
+            .as_ref()
+            .map_or(true, |previous| previous.as_ref() != Some(&metadata))
+        {
+            emit_event(WhitenoiseEvent::MetadataUpdated {
+                pubkey: pubkey.to_string(),
+                metadata,
+            });
+        }
+    }
+    previous.map(|_| ())
+}
+
+/// Marks keys as being fetched by one lookup until dropped; lookups of the same keys
+/// wait for it instead of querying relays again.
+struct FetchClaim {
+    keys: Vec<String>,
+    _done: watch::Sender<()>,
+}
+
+impl Drop for FetchClaim {
+    fn drop(&mut self) {
+        let mut in_flight = IN_FLIGHT
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        for key in &self.keys {
+            in_flight.remove(key);
+        }
+        // Dropping the sender afterwards wakes everyone waiting for these keys
+    }
+}
+
+/// Claims the keys nobody is fetching yet, all at once.
+///
+/// Returns the claim, holding the keys the caller has to fetch, and the fetches of the
+/// other keys to wait for with `wait_for_fetches`.
+fn claim_keys(keys: &[String]) -> (FetchClaim, Vec<watch::Receiver<()>>) {
+    let (done, _) = watch::channel(());
+    let mut claimed = Vec::new();
+    let mut pending = Vec::new();
+    let mut in_flight = IN_FLIGHT
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    for key in keys {
+        match in_flight.get(key) {
+            Some(fetch) => pending.push(fetch.clone()),
+            None => {
+                in_flight.insert(key.clone(), done.subscribe());
+                claimed.push(key.clone());
+            }
+        }
+    }
+    let claim = FetchClaim {
+        keys: claimed,
+        _done: done,
+    };
+    (claim, pending)
+}
+
+/// Waits until the given fetches have finished (successfully or not).
+async fn wait_for_fetches(fetches: Vec<watch::Receiver<()>>) {
+    for mut fetch in fetches {
+        while fetch.changed().await.is_ok() {}
+    }
+}
+
+/// Whether a cached entry has to be fetched again.
+fn needs_fetch(current: &CachedMetadataData, force_refresh: bool, requested_at: u64) -> bool {
+    current.is_stale || (force_refresh && current.fetched_at < requested_at)
+}
+
+/// Returns the metadata of a public key from the cache, fetching it from relays when it
+/// is missing or stale (or always, with `force_refresh`).
+///
+/// If fetching fails, the cached value is returned with `is_stale` set.
+pub(crate) async fn lookup_metadata(
+    whitenoise: &Whitenoise,
+    pubkey: PublicKey,
+    force_refresh: bool,
+) -> Result<CachedMetadataData, BridgeError> {
+    let hex = pubkey.to_hex();
+    let requested_at = unix_now();
+    let entry = with_memory_cache(|cache| cache.get(&hex).cloned()).await?;
+    let current = cached(&hex, entry.as_ref(), requested_at);
+    if !needs_fetch(&current, force_refresh, requested_at) {
+        return Ok(current);
+    }
+
+    let (claim, pending) = claim_keys(std::slice::from_ref(&hex));
+    if claim.keys.is_empty() {
+        // Another lookup is fetching the key: use its result
+        wait_for_fetches(pending).await;
+    } else if let Ok(metadata) = whitenoise.fetch_metadata(pubkey).await {
+        cache_metadata(&hex, metadata.map(|m| convert_metadata_to_data(&m))).await?;
+    }
+    drop(claim);
+
+    // If fetching failed, the cached value is still due for a fetch and reported as stale
+    let entry = with_memory_cache(|cache| cache.get(&hex).cloned()).await?;
+    let mut current = cached(&hex, entry.as_ref(), unix_now());
+    current.is_stale |= needs_fetch(&current, force_refresh, requested_at);
+    Ok(current)
+}
+
+/// Returns the relays metadata is looked up on: the Nostr relays of every account.
+async fn metadata_relays(whitenoise: &Whitenoise) -> Vec<RelayUrl> {
+    let Ok(accounts) = whitenoise.fetch_accounts().await else {
+        return Vec::new();
+    };
+    let mut relays = BTreeSet::new();
+    for pubkey in accounts.into_keys() {
+        if let Ok(account_relays) = whitenoise.fetch_relays(pubkey, RelayType::Nostr).await {
+            relays.extend(account_relays);
+        }
+    }
+    relays.into_iter().collect()
+}
+
+/// Picks the newest valid metadata of each author from kind-0 events.
+///
+/// Authors without a (valid) event map to `None`.
+fn newest_metadata(
+    authors: &[PublicKey],
+    events: impl IntoIterator<Item = (PublicKey, u64, Option<Metadata>)>,
+) -> HashMap<PublicKey, Option<Metadata>> {
+    let mut newest: HashMap<PublicKey, (u64, Metadata)> = HashMap::new();
+    for (author, created_at, metadata) in events {
+        let Some(metadata) = metadata else { continue };
+        if !authors.contains(&author) {
+            continue;
+        }
+        if newest
+            .get(&author)
+            .is_none_or(|(newest_at, _)| created_at > *newest_at)
+        {
+            newest.insert(author, (created_at, metadata));
+        }
+    }
+    authors
+        .iter()
+        .map(|author| (*author, newest.remove(author).map(|(_, metadata)| metadata)))
+        .collect()
+}
+
+/// Fetches the metadata of many authors with one kind-0 query.
+async fn query_metadata(
+    relays: &[RelayUrl],
+    authors: &[PublicKey],
+) -> Result<HashMap<PublicKey, Option<Metadata>>, BridgeError> {
+    let filter = Filter::new()
+        .kind(Kind::Metadata)
+        .authors(authors.iter().copied());
+    let events = fetch_events(relays, filter).await?;
+    Ok(newest_metadata(
+        authors,
+        events.into_iter().map(|event| {
+            (
+                event.pubkey,
+                event.created_at.as_u64(),
+                Metadata::from_json(&event.content).ok(),
+            )
+        }),
+    ))
+}
+
+/// Fetches the profile metadata of many public keys at once, using the metadata cache.
+///
+/// Fresh cached metadata is returned right away; missing and stale entries are fetched
+/// from the accounts' relays with one query per 250 keys, at most 4 queries at a time.
+/// Concurrent calls asking for the same key share one fetch.
+/// Results are keyed by hex public key, whatever format the key was passed in, so every
+/// user maps to exactly one entry.
+///
+/// # Arguments
+/// * `pubkeys` - Public keys as hex or npub strings
+/// * `force_refresh` - Fetch every key from relays even if its cached metadata is fresh
+///
+/// # Returns
+/// * `Ok(HashMap<String, CachedMetadataData>)` - Metadata keyed by hex public key. If a
+///   key could not be fetched, its last cached metadata is returned with `is_stale` set
+///   (`fetched_at` is 0 and `metadata` is `None` if it was never fetched)
+/// * `Err(BridgeError)` - `InvalidPublicKey` if a key cannot be parsed, or if the cache
+///   could not be read or written
+///
+/// # Notes
+/// * Metadata stays fresh for 1 hour; the cache is kept on disk, so it survives restarts
+/// * Whenever fetched metadata differs from the cached version, a
+///   `WhitenoiseEvent::MetadataUpdated` is pushed on the global event stream
+/// * A profile the relays do not return keeps its cached metadata
+#[frb]
+pub async fn fetch_metadata_batch(
+    pubkeys: Vec<String>,
+    force_refresh: bool,
+) -> Result<HashMap<String, CachedMetadataData>, BridgeError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let mut parsed: Vec<PublicKey> = Vec::with_capacity(pubkeys.len());
+    for pubkey in &pubkeys {
+        let pubkey = PublicKey::parse(pubkey).map_err(|e| {
+            BridgeError::new(BridgeErrorCode::InvalidPublicKey, "Invalid public key")
+                .with_details(e)
+        })?;
+        if !parsed.contains(&pubkey) {
+            parsed.push(pubkey);
+        }
+    }
+
+    let requested_at = unix_now();
+    let mut stale = Vec::new();
+    for pubkey in &parsed {
+        let hex = pubkey.to_hex();
+        let entry = with_memory_cache(|cache| cache.get(&hex).cloned()).await?;
+        if needs_fetch(
+            &cached(&hex, entry.as_ref(), requested_at),
+            force_refresh,
+            requested_at,
+        ) {
+            stale.push(hex);
+        }
+    }
+    // Keys another lookup is already fetching are waited for instead of queried again
+    let (claim, pending) = claim_keys(&stale);
+    let to_fetch: Vec<PublicKey> = parsed
+        .iter()
+        .copied()
+        .filter(|pubkey| claim.keys.contains(&pubkey.to_hex()))
+        .collect();
+
+    let relays = metadata_relays(whitenoise).await;
+    let fetched: Vec<Result<HashMap<PublicKey, Option<Metadata>>, BridgeError>> =
+        if relays.is_empty() {
+            // Without relays of its own, the bridge falls back to the core, one key at a time
+            let queries: Vec<_> = to_fetch
+                .iter()
+                .map(|pubkey| async move {
+                    let metadata = whitenoise.fetch_metadata(*pubkey).await?;
+                    Ok(HashMap::from([(*pubkey, metadata)]))
+                })
+                .collect();
+            futures::stream::iter(queries)
+                .buffer_unordered(MAX_CONCURRENT_QUERIES)
+                .collect()
+                .await
+        } else {
+            let queries: Vec<_> = to_fetch
+                .chunks(AUTHORS_PER_QUERY)
+                .map(|authors| query_metadata(&relays, authors))
+                .collect();
+            futures::stream::iter(queries)
+                .buffer_unordered(MAX_CONCURRENT_QUERIES)
+                .collect()
+                .await
+        };
+    // Keys whose query failed keep their cached metadata and are returned as stale
+    for (pubkey, metadata) in fetched.into_iter().flatten().flatten() {
+        cache_metadata(
+            &pubkey.to_hex(),
+            metadata.map(|metadata| convert_metadata_to_data(&metadata)),
+        )
+        .await?;
+    }
+    persist_memory_cache().await?;
+    drop(claim);
+    wait_for_fetches(pending).await;
+
+    let now = unix_now();
+    let mut results = HashMap::with_capacity(parsed.len());
+    for pubkey in &parsed {
+        let hex = pubkey.to_hex();
+        let entry = with_memory_cache(|cache| cache.get(&hex).cloned()).await?;
+        let mut current = cached(&hex, entry.as_ref(), now);
+        current.is_stale |= needs_fetch(&current, force_refresh, requested_at);
+        results.insert(hex, current);
+    }
+    Ok(results)
+}
+
+/// Returns cached profile metadata without contacting any relay.
+///
+/// # Arguments
+/// * `pubkeys` - Public keys as hex or npub strings
+///
+/// # Returns
+/// * `Ok(HashMap<String, CachedMetadataData>)` - Metadata keyed by hex public key; keys
+///   that were never fetched are returned with `metadata` set to `None` and `is_stale` set
+/// * `Err(BridgeError)` - `InvalidPublicKey` if a key cannot be parsed, or if the cache
+///   could not be read
+#[frb]
+pub async fn fetch_cached_metadata(
+    pubkeys: Vec<String>,
+) -> Result<HashMap<String, CachedMetadataData>, BridgeError> {
+    let now = unix_now();
+    let mut results = HashMap::with_capacity(pubkeys.len());
+    for pubkey in &pubkeys {
+        let hex = PublicKey::parse(pubkey)
+            .map_err(|e| {
+                BridgeError::new(BridgeErrorCode::InvalidPublicKey, "Invalid public key")
+                    .with_details(e)
+            })?
+            .to_hex();
+        let entry = with_memory_cache(|cache| cache.get(&hex).cloned()).await?;
+        results.insert(hex.clone(), cached(&hex, entry.as_ref(), now));
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pubkey(hex: &str) -> PublicKey {
+        PublicKey::from_hex(hex).unwrap()
+    }
+
+    fn named(name: &str) -> Option<Metadata> {
+        Some(Metadata {
+            name: Some(name.to_string()),
+            ..Default::default()
+        })
+    }
+
+    const ALICE: &str = "a0b1c2d3e4f5a0b1c2d3e4f5a0b1c2d3e4f5a0b1c2d3e4f5a0b1c2d3e4f5a0b1";
+    const BOB: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    #[test]
+    fn newest_valid_metadata_wins() {
+        let (alice, bob) = (pubkey(ALICE), pubkey(BOB));
+        let metadata = newest_metadata(
+            &[alice, bob],
+            [
+                (alice, 10, named("old")),
+                (alice, 30, None),
+                (alice, 20, named("new")),
+            ],
+        );
+        assert_eq!(
+            metadata[&alice].as_ref().unwrap().name.as_deref(),
+            Some("new")
+        );
+        assert!(metadata[&bob].is_none());
+    }
+
+    #[test]
+    fn events_from_other_authors_are_ignored() {
+        let (alice, bob) = (pubkey(ALICE), pubkey(BOB));
+        let metadata = newest_metadata(&[alice], [(bob, 10, named("bob"))]);
+        assert_eq!(metadata.len(), 1);
+        assert!(metadata[&alice].is_none());
+    }
+
+    #[test]
+    fn force_refresh_only_refetches_entries_older_than_the_request() {
+        let entry = CacheEntry {
+            metadata: None,
+            fetched_at: 100,
+        };
+        let fresh = cached("key", Some(&entry), 100);
+        assert!(!needs_fetch(&fresh, false, 100));
+        assert!(needs_fetch(&fresh, true, 101));
+        assert!(!needs_fetch(&fresh, true, 100));
+        assert!(needs_fetch(&cached("key", None, 100), false, 100));
+    }
+
+    #[test]
+    fn a_miss_keeps_cached_metadata() {
+        let alice = convert_metadata_to_data(&named("alice").unwrap());
+        let mut entry = CacheEntry {
+            metadata: Some(alice.clone()),
+            fetched_at: 100,
+        };
+        assert_eq!(record_fetch(&mut entry, None, 200), Some(alice.clone()));
+        assert_eq!(entry.metadata, Some(alice.clone()));
+        assert_eq!(entry.fetched_at, 200);
+
+        let renamed = convert_metadata_to_data(&named("alice2").unwrap());
+        assert_eq!(
+            record_fetch(&mut entry, Some(renamed.clone()), 300),
+            Some(alice)
+        );
+        assert_eq!(entry.metadata, Some(renamed));
+    }
+
+    #[tokio::test]
+    async fn concurrent_lookups_share_claimed_keys() {
+        let keys = ["claim-a".to_string(), "claim-b".to_string()];
+        let (first, pending) = claim_keys(&keys);
+        assert_eq!(first.keys, keys);
+        assert!(pending.is_empty());
+
+        let (second, pending) = claim_keys(&["claim-b".to_string(), "claim-c".to_string()]);
+        assert_eq!(second.keys, vec!["claim-c".to_string()]);
+        assert_eq!(pending.len(), 1);
+
+        let waiter = tokio::spawn(wait_for_fetches(pending));
+        tokio::task::yield_now().await;
+        assert!(!waiter.is_finished());
+        drop(first);
+        waiter.await.unwrap();
+
+        let (third, _) = claim_keys(&["claim-a".to_string()]);
+        assert_eq!(third.keys, vec!["claim-a".to_string()]);
+    }
+}
@@ -1,5 +1,10 @@
 // Re-export everything from the whitenoise crate
+use crate::api::events::{refresh_events, start_event_watcher, subscribe_events};
+use crate::api::outbox::start_outbox_worker;
+use crate::api::subscriptions::spawn_subscription;
+use crate::frb_generated::StreamSink;
 use flutter_rust_bridge::frb;
+use tokio::sync::broadcast::error::RecvError;
 pub use whitenoise::{
     Account, AccountSettings, Event, Group, GroupId, GroupState, GroupType, Kind,
     MessageWithTokens, Metadata, OnboardingState, PublicKey, RelayType, RelayUrl, Tag, Whitenoise,
@@ -8,19 +13,46 @@ pub use whitenoise::{
 
 // Declare the modules
 pub mod accounts;
+pub mod blossom;
+pub mod chats;
 pub mod contacts;
+pub mod delivery;
+pub mod errors;
+pub mod events;
 pub mod groups;
+pub mod images;
+pub mod media;
+pub mod media_cache;
 pub mod messages;
+pub mod metadata_cache;
+pub mod nip05;
+pub mod nip19;
+pub mod outbox;
+pub mod presence;
 pub mod relays;
+pub mod subscriptions;
 pub mod utils;
 pub mod welcomes;
 
 // Re-export everything
 pub use accounts::*;
+pub use blossom::*;
+pub use chats::*;
 pub use contacts::*;
+pub use errors::*;
+pub use events::*;
 pub use groups::*;
+pub use images::*;
+pub use media::*;
+pub use media_cache::*;
 pub use messages::*;
+pub use metadata_cache::*;
+pub use nip05::*;
+pub use nip19::*;
+pub use outbox::*;
+pub use presence::*;
 pub use relays::*;
+pub use subscriptions::*;
 pub use utils::*;
 pub use welcomes::*;
 
@@ -29,14 +61,15 @@ pub use welcomes::*;
 /// # CRITICAL: Must be called first
 /// This function MUST be called before any other Whitenoise methods are used.
 /// It sets up the global singleton instance, creates necessary directories,
-/// and initializes the database connections.
+/// and initializes the database connections. It also starts the background tasks that
+/// send queued messages and feed `subscribe_whitenoise_events`.
 ///
 /// # Parameters
 /// * `config` - WhitenoiseConfig object containing setup parameters
 ///
 /// # Returns
 /// * `Ok(())` - System successfully initialized
-/// * `Err(WhitenoiseError)` - If initialization fails (directory creation, database setup, etc.)
+/// * `Err(BridgeError)` - If initialization fails (directory creation, database setup, etc.)
 ///
 /// # Example
 /// ```rust
@@ -55,8 +88,12 @@ pub use welcomes::*;
 /// - Database corruption or locking issues
 /// - Invalid configuration parameters
 #[frb]
-pub async fn initialize_whitenoise(config: WhitenoiseConfig) -> Result<(), WhitenoiseError> {
-    Whitenoise::initialize_whitenoise(config).await
+pub async fn initialize_whitenoise(config: WhitenoiseConfig) -> Result<(), BridgeError> {
+    crate::store::set_data_dir(config.data_dir.clone());
+    Whitenoise::initialize_whitenoise(config).await?;
+    start_outbox_worker();
+    start_event_watcher();
+    Ok(())
 }
 
 /// Deletes all data from the Whitenoise instance.
@@ -67,7 +104,7 @@ pub async fn initialize_whitenoise(config: WhitenoiseConfig) -> Result<(), White
 ///
 /// # Returns
 /// * `Ok(())` - All data successfully deleted
-/// * `Err(WhitenoiseError)` - If deletion fails or instance not initialized
+/// * `Err(BridgeError)` - If deletion fails or instance not initialized
 ///
 /// # Usage
 /// Typically used for:
@@ -91,7 +128,79 @@ pub async fn initialize_whitenoise(config: WhitenoiseConfig) -> Result<(), White
 /// - File system permission issues
 /// - Database locks or corruption
 #[frb]
-pub async fn delete_all_data() -> Result<(), WhitenoiseError> {
+pub async fn delete_all_data() -> Result<(), BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
-    whitenoise.delete_all_data().await
+    whitenoise.delete_all_data().await?;
+    crate::store::clear().await?;
+    let _ = std::fs::remove_dir_all(images::processed_images_dir());
+    metadata_cache::reset_metadata_cache().await;
+    chats::clear_chat_list_caches(None);
+    groups::clear_epoch_members(None);
+    refresh_events();
+    Ok(())
+}
+
+/// Subscribes to the global Whitenoise event bus.
+///
+/// Opens a stream of typed `WhitenoiseEvent`s covering everything that changes outside
+/// of a single chat: new welcomes, groups being created, updated or changing state,
+/// members being added or removed, relay status changes, metadata updates and accounts
+/// logging in or out. Flutter providers can apply these incrementally instead of
+/// re-fetching all state after every action.
+///
+/// # Parameters
+/// * `sink` - The stream sink the events are pushed to (provided by flutter_rust_bridge)
+///
+/// # Returns
+/// * `Ok(())` - The subscription was started; events arrive on the stream
+/// * `Err(BridgeError)` - If Whitenoise has not been initialized
+///
+/// # Notes
+/// * The first item on the stream is always `WhitenoiseEvent::Subscribed`, carrying the
+///   handle to pass to `unsubscribe`
+/// * State that exists when the stream is opened is not replayed; load it with the
+///   regular `fetch_*` functions first
+/// * Subscribers that fall too far behind skip the oldest events rather than blocking
+///   the bus
+///
+/// # Example
+/// ```dart
+/// subscribeWhitenoiseEvents().listen((event) {
+///   switch (event) {
+///     case WhitenoiseEvent_WelcomeReceived(:final welcome):
+///       ref.read(welcomesProvider.notifier).addWelcome(welcome);
+///     case WhitenoiseEvent_GroupUpdated(:final group):
+///       ref.read(groupsProvider.notifier).updateGroup(group);
+///     // ...
+///   }
+/// });
+/// ```
+#[frb]
+pub async fn subscribe_whitenoise_events(
+    sink: StreamSink<WhitenoiseEvent>,
+) -> Result<(), BridgeError> {
+    Whitenoise::get_instance()?;
+    let mut receiver = subscribe_events();
+
+    spawn_subscription(move |subscription_id| async move {
+        if sink
+            .add(WhitenoiseEvent::Subscribed { subscription_id })
+            .is_err()
+        {
+            return;
+        }
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    if sink.add(event).is_err() {
+                        // The Dart side closed the stream
+                        return;
+                    }
+                }
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return,
+            }
+        }
+    });
+    Ok(())
 }
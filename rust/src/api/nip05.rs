@@ -0,0 +1,354 @@
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use crate::api::utils::unix_now;
+use crate::store;
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+pub use whitenoise::PublicKey;
+
+/// The account a NIP-05 identifier points to.
+#[derive(Debug, Clone)]
+pub struct Nip05ProfileData {
+    pub identifier: String, // Normalized, e.g. "alice@example.com"
+    pub pubkey: String,     // Hex
+    pub relays: Vec<String>,
+}
+
+/// Name of the store document caching NIP-05 lookups.
+const NIP05_CACHE_DOCUMENT: &str = "nip05_cache";
+
+/// How long a successful lookup is trusted (24 hours).
+const NIP05_TTL_SECS: u64 = 24 * 60 * 60;
+
+/// How long a lookup that found no matching name is remembered (1 hour).
+const NIP05_NEGATIVE_TTL_SECS: u64 = 60 * 60;
+
+/// Timeout for fetching a `nostr.json` document.
+const NIP05_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Largest `nostr.json` document read (64 KiB); a domain listing a single name needs
+/// far less, and a larger body is not buffered in memory.
+const NIP05_MAX_DOCUMENT_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Nip05CacheEntry {
+    pubkey: Option<String>, // None if the domain does not list the name
+    relays: Vec<String>,
+    checked_at: u64,
+}
+
+/// Lookups keyed by normalized identifier.
+type Nip05Cache = HashMap<String, Nip05CacheEntry>;
+
+/// The `/.well-known/nostr.json` document served by NIP-05 domains.
+#[derive(Debug, Deserialize)]
+struct NostrJson {
+    #[serde(default)]
+    names: HashMap<String, String>,
+    #[serde(default)]
+    relays: HashMap<String, Vec<String>>,
+}
+
+/// Splits an identifier into its lowercase name and domain. A bare domain stands for
+/// its root identifier `_@domain`.
+fn parse_identifier(identifier: &str) -> Result<(String, String), BridgeError> {
+    let identifier = identifier.trim().to_lowercase();
+    let (name, domain) = identifier
+        .split_once('@')
+        .unwrap_or(("_", identifier.as_str()));
+    let name_is_valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    let domain_is_valid = !domain.is_empty()
+        && domain
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | ':' | '[' | ']'));
+    if !name_is_valid || !domain_is_valid {
+        return Err(BridgeError::new(
+            BridgeErrorCode::InvalidInput,
+            format!("Invalid NIP-05 identifier: {identifier}"),
+        ));
+    }
+    Ok((name.to_string(), domain.to_string()))
+}
+
+/// Returns the base URL NIP-05 documents are fetched from for a domain.
+fn domain_base_url(domain: &str) -> String {
+    format!("https://{domain}")
+}
+
+/// Returns the URL of the `nostr.json` document for a name.
+fn well_known_url(base_url: &str, name: &str) -> String {
+    format!("{base_url}/.well-known/nostr.json?name={name}")
+}
+
+/// Reads a response body, failing once it grows past `NIP05_MAX_DOCUMENT_BYTES`.
+async fn read_document(mut response: reqwest::Response) -> Result<Vec<u8>, BridgeError> {
+    let too_large = || {
+        BridgeError::new(
+            BridgeErrorCode::InvalidInput,
+            "NIP-05 document is too large",
+        )
+    };
+    if response
+        .content_length()
+        .is_some_and(|length| length > NIP05_MAX_DOCUMENT_BYTES as u64)
+    {
+        return Err(too_large());
+    }
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|e| {
+        BridgeError::new(BridgeErrorCode::Network, "NIP-05 lookup failed").with_details(e)
+    })? {
+        if body.len() + chunk.len() > NIP05_MAX_DOCUMENT_BYTES {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+/// Fetches the mapping for a name from the `nostr.json` document under `base_url`.
+async fn fetch_nip05(base_url: &str, name: &str) -> Result<Nip05CacheEntry, BridgeError> {
+    let network_error = |e: reqwest::Error| {
+        BridgeError::new(BridgeErrorCode::Network, "NIP-05 lookup failed").with_details(e)
+    };
+    // NIP-05 forbids following redirects
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .timeout(NIP05_REQUEST_TIMEOUT)
+        .build()
+        .map_err(network_error)?;
+    let response = client
+        .get(well_known_url(base_url, name))
+        .send()
+        .await
+        .map_err(network_error)?;
+    // Redirects are returned as is, so anything but a success is a failed lookup
+    if !response.status().is_success() {
+        return Err(
+            BridgeError::new(BridgeErrorCode::Network, "NIP-05 lookup failed")
+                .with_details(response.status()),
+        );
+    }
+    let body = read_document(response).await?;
+    let document: NostrJson = serde_json::from_slice(&body).map_err(|e| {
+        BridgeError::new(BridgeErrorCode::InvalidInput, "Invalid NIP-05 document").with_details(e)
+    })?;
+
+    let pubkey = document
+        .names
+        .get(name)
+        .or_else(|| {
+            document
+                .names
+                .iter()
+                .find_map(|(key, pubkey)| key.eq_ignore_ascii_case(name).then_some(pubkey))
+        })
+        .and_then(|pubkey| PublicKey::from_hex(pubkey).ok())
+        .map(|pubkey| pubkey.to_hex());
+    let relays = pubkey
+        .as_ref()
+        .and_then(|pubkey| document.relays.get(pubkey).cloned())
+        .unwrap_or_default();
+    Ok(Nip05CacheEntry {
+        pubkey,
+        relays,
+        checked_at: unix_now(),
+    })
+}
+
+/// Looks up an identifier, using the cache while its entry has not expired.
+async fn lookup_nip05(
+    identifier: &str,
+    force_refresh: bool,
+) -> Result<(String, Nip05CacheEntry), BridgeError> {
+    let (name, domain) = parse_identifier(identifier)?;
+    let identifier = format!("{name}@{domain}");
+
+    let cache: Nip05Cache = store::load(NIP05_CACHE_DOCUMENT, None).await?;
+    if let Some(entry) = cache.get(&identifier).filter(|_| !force_refresh) {
+        let ttl = if entry.pubkey.is_some() {
+            NIP05_TTL_SECS
+        } else {
+            NIP05_NEGATIVE_TTL_SECS
+        };
+        if entry.checked_at + ttl > unix_now() {
+            return Ok((identifier, entry.clone()));
+        }
+    }
+
+    let entry = fetch_nip05(&domain_base_url(&domain), &name).await?;
+    store::update(NIP05_CACHE_DOCUMENT, None, |cache: &mut Nip05Cache| {
+        let now = unix_now();
+        cache.retain(|_, entry| entry.checked_at + NIP05_TTL_SECS > now);
+        cache.insert(identifier.clone(), entry.clone());
+    })
+    .await?;
+    Ok((identifier, entry))
+}
+
+/// Checks that a NIP-05 identifier belongs to a public key.
+///
+/// Fetches `https://<domain>/.well-known/nostr.json?name=<name>` and checks that it maps
+/// the name to the public key. Results are cached: matches for 24 hours, names the domain
+/// does not list for 1 hour.
+///
+/// # Arguments
+/// * `pubkey` - The public key the identifier should belong to
+/// * `identifier` - The identifier from the profile's metadata, e.g. `alice@example.com`
+///   (a bare domain means `_@domain`)
+///
+/// # Returns
+/// * `Ok(true)` - The domain maps the identifier to the public key
+/// * `Ok(false)` - The domain does not list the name, or maps it to another key
+/// * `Err(BridgeError)` - `InvalidInput` if the identifier is malformed or the domain
+///   serves an invalid document, or `Network` if the domain could not be reached (the
+///   identifier is then neither verified nor refuted)
+///
+/// # Notes
+/// * Redirects are not followed, as required by NIP-05
+/// * Documents are only fetched over HTTPS, and ones larger than 64 KiB are rejected
+#[frb]
+pub async fn verify_nip05(pubkey: &PublicKey, identifier: String) -> Result<bool, BridgeError> {
+    let (_, entry) = lookup_nip05(&identifier, false).await?;
+    Ok(entry.pubkey == Some(pubkey.to_hex()))
+}
+
+/// Resolves a NIP-05 identifier to a public key and relay hints.
+///
+/// Lets users start a chat by typing an address like `alice@example.com` instead of an
+/// npub. Results are cached like those of `verify_nip05`.
+///
+/// # Arguments
+/// * `identifier` - The identifier, e.g. `alice@example.com` (a bare domain means
+///   `_@domain`)
+/// * `force_refresh` - Ignore the cache and ask the domain again
+///
+/// # Returns
+/// * `Ok(Some(Nip05ProfileData))` - The public key and the relays the domain lists for it
+/// * `Ok(None)` - The domain does not list the name
+/// * `Err(BridgeError)` - `InvalidInput` if the identifier is malformed or the domain
+///   serves an invalid document, or `Network` if the domain could not be reached
+#[frb]
+pub async fn resolve_nip05(
+    identifier: String,
+    force_refresh: bool,
+) -> Result<Option<Nip05ProfileData>, BridgeError> {
+    let (identifier, entry) = lookup_nip05(&identifier, force_refresh).await?;
+    Ok(entry.pubkey.map(|pubkey| Nip05ProfileData {
+        identifier,
+        pubkey,
+        relays: entry.relays,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    const ALICE: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    /// Serves a single HTTP response on a local port, standing in for a NIP-05 domain.
+    /// Returns the base URL and a receiver for the request line.
+    fn serve_once(status: &str, headers: &str, body: String) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status}\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = stream.read(&mut buffer).unwrap();
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let request = String::from_utf8_lossy(&request);
+            let _ = sender.send(request.lines().next().unwrap_or_default().to_string());
+            let _ = stream.write_all(response.as_bytes());
+        });
+        (base_url, receiver)
+    }
+
+    #[test]
+    fn fetches_documents_over_https() {
+        assert_eq!(
+            well_known_url(&domain_base_url("example.com"), "alice"),
+            "https://example.com/.well-known/nostr.json?name=alice"
+        );
+        assert_eq!(domain_base_url("localhost:8080"), "https://localhost:8080");
+    }
+
+    #[test]
+    fn parses_identifiers() {
+        assert_eq!(
+            parse_identifier(" Alice@Example.com ").unwrap(),
+            ("alice".to_string(), "example.com".to_string())
+        );
+        assert_eq!(
+            parse_identifier("example.com").unwrap(),
+            ("_".to_string(), "example.com".to_string())
+        );
+        assert!(parse_identifier("al ice@example.com").is_err());
+        assert!(parse_identifier("alice@exa/mple.com").is_err());
+        assert!(parse_identifier("alice@").is_err());
+    }
+
+    #[tokio::test]
+    async fn resolves_names_and_relays() {
+        let body = format!(
+            r#"{{"names":{{"Alice":"{ALICE}"}},"relays":{{"{ALICE}":["wss://relay.example"]}}}}"#
+        );
+        let (base_url, request) = serve_once("200 OK", "", body);
+        let entry = fetch_nip05(&base_url, "alice").await.unwrap();
+        assert_eq!(
+            request.recv().unwrap(),
+            "GET /.well-known/nostr.json?name=alice HTTP/1.1"
+        );
+        assert_eq!(entry.pubkey.as_deref(), Some(ALICE));
+        assert_eq!(entry.relays, vec!["wss://relay.example"]);
+    }
+
+    #[tokio::test]
+    async fn unlisted_names_resolve_to_nothing() {
+        let body = format!(r#"{{"names":{{"bob":"{ALICE}"}}}}"#);
+        let (base_url, _) = serve_once("200 OK", "", body);
+        let entry = fetch_nip05(&base_url, "alice").await.unwrap();
+        assert_eq!(entry.pubkey, None);
+        assert!(entry.relays.is_empty());
+    }
+
+    #[tokio::test]
+    async fn does_not_follow_redirects() {
+        let (base_url, _) = serve_once(
+            "302 Found",
+            "Location: http://127.0.0.1:1/.well-known/nostr.json\r\n",
+            String::new(),
+        );
+        let error = fetch_nip05(&base_url, "alice").await.unwrap_err();
+        assert!(matches!(error.code, BridgeErrorCode::Network));
+    }
+
+    #[tokio::test]
+    async fn rejects_oversized_documents() {
+        let body = format!(
+            r#"{{"names":{{"alice":"{ALICE}"}},"padding":"{}"}}"#,
+            "x".repeat(NIP05_MAX_DOCUMENT_BYTES)
+        );
+        let (base_url, _) = serve_once("200 OK", "", body);
+        let error = fetch_nip05(&base_url, "alice").await.unwrap_err();
+        assert_eq!(error.message, "NIP-05 document is too large");
+    }
+}
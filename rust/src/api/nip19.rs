@@ -0,0 +1,414 @@
+//! NIP-19 bech32 entities (npub, nprofile, note, nevent, naddr, nsec) and NIP-21
+//! `nostr:` URIs.
+
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use bech32::primitives::decode::CheckedHrpstring;
+use bech32::{Bech32, Hrp};
+use flutter_rust_bridge::frb;
+use url::Url;
+
+/// A decoded NIP-19 entity. Keys and event IDs are hex.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NostrEntity {
+    /// `npub`
+    Pubkey { pubkey: String },
+    /// `nprofile`: a public key with relays where the profile can be found
+    Profile { pubkey: String, relays: Vec<String> },
+    /// `note`
+    Note { event_id: String },
+    /// `nevent`: an event ID with relay, author and kind hints
+    Event {
+        event_id: String,
+        relays: Vec<String>,
+        author: Option<String>,
+        kind: Option<u32>,
+    },
+    /// `naddr`: an addressable event
+    Address {
+        identifier: String,
+        pubkey: String,
+        kind: u32,
+        relays: Vec<String>,
+    },
+    /// `nsec`: a private key
+    SecretKey { secret_key: String },
+}
+
+/// Result of `parse_nostr_entity`.
+#[derive(Debug, Clone)]
+pub struct ParsedNostrEntityData {
+    pub entity: NostrEntity,
+    pub is_sensitive: bool, // The input contained a private key; never display or share it
+}
+
+// TLV types used by nprofile, nevent and naddr
+const TLV_SPECIAL: u8 = 0;
+const TLV_RELAY: u8 = 1;
+const TLV_AUTHOR: u8 = 2;
+const TLV_KIND: u8 = 3;
+
+fn invalid(message: impl Into<String>) -> BridgeError {
+    BridgeError::new(BridgeErrorCode::InvalidInput, message)
+}
+
+/// Encodes data as bech32 (NIP-19 does not use bech32m) with the given prefix.
+fn bech32_encode(hrp: &str, data: &[u8]) -> Result<String, BridgeError> {
+    let hrp = Hrp::parse(hrp).map_err(|e| invalid("Invalid bech32 prefix").with_details(e))?;
+    bech32::encode::<Bech32>(hrp, data)
+        .map_err(|e| invalid("Too much data for a bech32 string").with_details(e))
+}
+
+/// Decodes a bech32 string into its lowercase prefix and data bytes.
+///
+/// NIP-19 entities with many relays exceed the 90 character limit of BIP-173, so only
+/// the checksum's own limit of 1023 characters applies.
+fn bech32_decode(input: &str) -> Result<(String, Vec<u8>), BridgeError> {
+    let checked = CheckedHrpstring::new::<Bech32>(input)
+        .map_err(|e| invalid("Invalid bech32 string").with_details(e))?;
+    Ok((checked.hrp().to_lowercase(), checked.byte_iter().collect()))
+}
+
+/// Parses a hex string that must encode exactly 32 bytes (keys and event IDs).
+fn parse_hex32(value: &str, what: &str) -> Result<Vec<u8>, BridgeError> {
+    hex::decode(value.trim())
+        .ok()
+        .filter(|bytes| bytes.len() == 32)
+        .ok_or_else(|| invalid(format!("Invalid {what}: expected 64 hex characters")))
+}
+
+fn bytes32(value: &[u8], what: &str) -> Result<String, BridgeError> {
+    if value.len() != 32 {
+        return Err(invalid(format!("Invalid {what} length")));
+    }
+    Ok(hex::encode(value))
+}
+
+fn parse_tlv(data: &[u8]) -> Result<Vec<(u8, &[u8])>, BridgeError> {
+    let mut entries = Vec::new();
+    let mut rest = data;
+    while !rest.is_empty() {
+        let [kind, length, tail @ ..] = rest else {
+            return Err(invalid("Truncated TLV entry"));
+        };
+        let length = *length as usize;
+        if tail.len() < length {
+            return Err(invalid("Truncated TLV entry"));
+        }
+        entries.push((*kind, &tail[..length]));
+        rest = &tail[length..];
+    }
+    Ok(entries)
+}
+
+fn push_tlv(out: &mut Vec<u8>, kind: u8, value: &[u8]) -> Result<(), BridgeError> {
+    let length = u8::try_from(value.len()).map_err(|_| invalid("TLV value too long"))?;
+    out.push(kind);
+    out.push(length);
+    out.extend_from_slice(value);
+    Ok(())
+}
+
+/// Validates relay hints and returns them as strings.
+fn relay_hints(relays: &[String]) -> Result<Vec<String>, BridgeError> {
+    relays
+        .iter()
+        .map(|relay| match Url::parse(relay.trim()) {
+            Ok(url) if matches!(url.scheme(), "ws" | "wss") => Ok(relay.trim().to_string()),
+            _ => Err(BridgeError::new(
+                BridgeErrorCode::InvalidRelayUrl,
+                format!("Invalid relay URL: {relay}"),
+            )),
+        })
+        .collect()
+}
+
+fn decode_entity(hrp: &str, data: &[u8]) -> Result<NostrEntity, BridgeError> {
+    let entity = match hrp {
+        "npub" => NostrEntity::Pubkey {
+            pubkey: bytes32(data, "public key")?,
+        },
+        "note" => NostrEntity::Note {
+            event_id: bytes32(data, "event ID")?,
+        },
+        "nsec" => NostrEntity::SecretKey {
+            secret_key: bytes32(data, "private key")?,
+        },
+        "nprofile" | "nevent" | "naddr" => {
+            let mut special = None;
+            let mut relays = Vec::new();
+            let mut author = None;
+            let mut kind = None;
+            // Unknown TLV types are ignored, as NIP-19 requires
+            for (tlv_type, value) in parse_tlv(data)? {
+                match tlv_type {
+                    TLV_SPECIAL if special.is_none() => special = Some(value),
+                    TLV_RELAY => relays.push(String::from_utf8_lossy(value).to_string()),
+                    TLV_AUTHOR if author.is_none() => author = Some(bytes32(value, "author")?),
+                    TLV_KIND if kind.is_none() => {
+                        let bytes: [u8; 4] = value
+                            .try_into()
+                            .map_err(|_| invalid("Invalid kind length"))?;
+                        kind = Some(u32::from_be_bytes(bytes));
+                    }
+                    _ => {}
+                }
+            }
+            let special = special.ok_or_else(|| invalid(format!("{hrp} is missing its value")))?;
+            match hrp {
+                "nprofile" => NostrEntity::Profile {
+                    pubkey: bytes32(special, "public key")?,
+                    relays,
+                },
+                "nevent" => NostrEntity::Event {
+                    event_id: bytes32(special, "event ID")?,
+                    relays,
+                    author,
+                    kind,
+                },
+                _ => NostrEntity::Address {
+                    identifier: String::from_utf8_lossy(special).to_string(),
+                    pubkey: author.ok_or_else(|| invalid("naddr is missing its author"))?,
+                    kind: kind.ok_or_else(|| invalid("naddr is missing its kind"))?,
+                    relays,
+                },
+            }
+        }
+        _ => {
+            return Err(BridgeError::new(
+                BridgeErrorCode::Unsupported,
+                format!("Unsupported NIP-19 entity: {hrp}"),
+            ))
+        }
+    };
+    Ok(entity)
+}
+
+/// Parses any NIP-19 entity or NIP-21 `nostr:` URI.
+///
+/// Accepts `npub`, `nprofile`, `note`, `nevent`, `naddr` and `nsec` strings, with or
+/// without a `nostr:` prefix, so it can be used for pasted text, scanned QR codes and
+/// links alike.
+///
+/// # Parameters
+/// * `input` - The text to parse, e.g. `nostr:nprofile1...`
+///
+/// # Returns
+/// * `Ok(ParsedNostrEntityData)` - The decoded entity; `is_sensitive` is set for `nsec`
+/// * `Err(BridgeError)` - `InvalidInput` if the input is not valid bech32 or a field is
+///   malformed, or `Unsupported` for other bech32 prefixes
+///
+/// # Notes
+/// * A private key is never valid in a `nostr:` URI (NIP-21); such input is rejected
+/// * Callers should treat `is_sensitive` results with care: never display, log or share
+///   them, and only use them for logging in
+///
+/// # Example
+/// ```rust
+/// let parsed = parse_nostr_entity("nostr:npub1...".to_string())?;
+/// if let NostrEntity::Pubkey { pubkey } = parsed.entity {
+///     println!("Public key: {}", pubkey);
+/// }
+/// ```
+#[frb]
+pub fn parse_nostr_entity(input: String) -> Result<ParsedNostrEntityData, BridgeError> {
+    let input = input.trim();
+    let (is_uri, bech32) = match input.get(..6) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("nostr:") => (true, &input[6..]),
+        _ => (false, input),
+    };
+    let (hrp, data) = bech32_decode(bech32)?;
+    let entity = decode_entity(&hrp, &data)?;
+    let is_sensitive = matches!(entity, NostrEntity::SecretKey { .. });
+    if is_uri && is_sensitive {
+        return Err(invalid("nostr: URIs must not contain a private key"));
+    }
+    Ok(ParsedNostrEntityData {
+        entity,
+        is_sensitive,
+    })
+}
+
+/// Encodes a public key with relay hints as an `nprofile`.
+///
+/// Share an `nprofile` rather than an `npub` so the recipient knows where to find the
+/// profile.
+///
+/// # Parameters
+/// * `pubkey` - The public key (hex)
+/// * `relays` - Relays where the profile can be found (`ws://` or `wss://` URLs)
+///
+/// # Returns
+/// * `Ok(String)` - The `nprofile1...` string (prefix it with `nostr:` for a URI)
+/// * `Err(BridgeError)` - If the key or a relay URL is invalid
+#[frb]
+pub fn encode_nprofile(pubkey: String, relays: Vec<String>) -> Result<String, BridgeError> {
+    let mut data = Vec::new();
+    push_tlv(&mut data, TLV_SPECIAL, &parse_hex32(&pubkey, "public key")?)?;
+    for relay in relay_hints(&relays)? {
+        push_tlv(&mut data, TLV_RELAY, relay.as_bytes())?;
+    }
+    bech32_encode("nprofile", &data)
+}
+
+/// Encodes an event ID with relay, author and kind hints as an `nevent`.
+///
+/// # Parameters
+/// * `event_id` - The event ID (hex)
+/// * `relays` - Relays where the event can be found (`ws://` or `wss://` URLs)
+/// * `author` - Optional public key (hex) of the event's author
+/// * `kind` - Optional kind of the event
+///
+/// # Returns
+/// * `Ok(String)` - The `nevent1...` string (prefix it with `nostr:` for a URI)
+/// * `Err(BridgeError)` - If the event ID, author or a relay URL is invalid
+#[frb]
+pub fn encode_nevent(
+    event_id: String,
+    relays: Vec<String>,
+    author: Option<String>,
+    kind: Option<u32>,
+) -> Result<String, BridgeError> {
+    let mut data = Vec::new();
+    push_tlv(&mut data, TLV_SPECIAL, &parse_hex32(&event_id, "event ID")?)?;
+    for relay in relay_hints(&relays)? {
+        push_tlv(&mut data, TLV_RELAY, relay.as_bytes())?;
+    }
+    if let Some(author) = author {
+        push_tlv(&mut data, TLV_AUTHOR, &parse_hex32(&author, "author")?)?;
+    }
+    if let Some(kind) = kind {
+        push_tlv(&mut data, TLV_KIND, &kind.to_be_bytes())?;
+    }
+    bech32_encode("nevent", &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from NIP-19
+    const NPUB: &str = "npub10elfcs4fr0l0r8af98jlmgdh9c8tcxjvz9qkw038js35mp4dma8qzvjptg";
+    const NPUB_HEX: &str = "7e7e9c42a91bfef19fa929e5fda1b72e0ebc1a4c1141673e2794234d86addf4e";
+    const NSEC: &str = "nsec1vl029mgpspedva04g90vltkh6fvh240zqtv9k0t9af8935ke9laqsnlfe5";
+    const NSEC_HEX: &str = "67dea2ed018072d675f5415ecfaed7d2597555e202d85b3d65ea4e58d2d92ffa";
+    const NPROFILE: &str = "nprofile1qqsrhuxx8l9ex335q7he0f09aej04zpazpl0ne2cgukyawd24mayt8gpp4mhxue69uhhytnc9e3k7mgpz4mhxue69uhkg6nzv9ejuumpv34kytnrdaksjlyr9p";
+    const NPROFILE_HEX: &str = "3bf0c63fcb93463407af97a5e5ee64fa883d107ef9e558472c4eb9aaaefa459d";
+    const NPROFILE_RELAYS: [&str; 2] = ["wss://r.x.com", "wss://djbas.sadkb.com"];
+
+    fn parse(input: &str) -> Result<ParsedNostrEntityData, BridgeError> {
+        parse_nostr_entity(input.to_string())
+    }
+
+    #[test]
+    fn decodes_nip19_vectors() {
+        let parsed = parse(NPUB).unwrap();
+        assert_eq!(
+            parsed.entity,
+            NostrEntity::Pubkey {
+                pubkey: NPUB_HEX.to_string()
+            }
+        );
+        assert!(!parsed.is_sensitive);
+
+        let parsed = parse(NSEC).unwrap();
+        assert_eq!(
+            parsed.entity,
+            NostrEntity::SecretKey {
+                secret_key: NSEC_HEX.to_string()
+            }
+        );
+        assert!(parsed.is_sensitive);
+
+        assert_eq!(
+            parse(&format!("nostr:{NPROFILE}")).unwrap().entity,
+            NostrEntity::Profile {
+                pubkey: NPROFILE_HEX.to_string(),
+                relays: NPROFILE_RELAYS.map(str::to_string).to_vec(),
+            }
+        );
+    }
+
+    #[test]
+    fn encodes_nprofile_vector() {
+        let relays = NPROFILE_RELAYS.map(str::to_string).to_vec();
+        assert_eq!(
+            encode_nprofile(NPROFILE_HEX.to_string(), relays).unwrap(),
+            NPROFILE
+        );
+    }
+
+    #[test]
+    fn nevent_round_trips() {
+        let relays = vec!["wss://relay.example".to_string()];
+        let encoded = encode_nevent(
+            NSEC_HEX.to_string(),
+            relays.clone(),
+            Some(NPUB_HEX.to_string()),
+            Some(30023),
+        )
+        .unwrap();
+        assert!(encoded.starts_with("nevent1"));
+        assert_eq!(
+            parse(&encoded).unwrap().entity,
+            NostrEntity::Event {
+                event_id: NSEC_HEX.to_string(),
+                relays,
+                author: Some(NPUB_HEX.to_string()),
+                kind: Some(30023),
+            }
+        );
+    }
+
+    #[test]
+    fn accepts_uppercase_but_not_mixed_case() {
+        assert!(parse(&NPUB.to_uppercase()).is_ok());
+        assert!(parse(&format!("NOSTR:{}", NPUB.to_uppercase())).is_ok());
+        let mixed = format!("N{}", &NPUB[1..]);
+        assert!(parse(&mixed).is_err());
+    }
+
+    #[test]
+    fn rejects_bad_checksums_and_nsec_uris() {
+        let corrupted = format!("{}q", &NPUB[..NPUB.len() - 1]);
+        assert!(matches!(
+            parse(&corrupted).unwrap_err().code,
+            BridgeErrorCode::InvalidInput
+        ));
+        assert!(parse(&format!("nostr:{NSEC}")).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_prefixes() {
+        let encoded = bech32_encode("nrelay", b"wss://relay.example").unwrap();
+        assert!(matches!(
+            parse(&encoded).unwrap_err().code,
+            BridgeErrorCode::Unsupported
+        ));
+    }
+
+    #[test]
+    fn parses_tlv_entries_and_rejects_truncated_ones() {
+        let data = [0, 2, 0xaa, 0xbb, 1, 0, 9, 1, 0xcc];
+        assert_eq!(
+            parse_tlv(&data).unwrap(),
+            vec![(0, &[0xaa, 0xbb][..]), (1, &[][..]), (9, &[0xcc][..])]
+        );
+        assert!(parse_tlv(&[0, 3, 0xaa]).is_err());
+        assert!(parse_tlv(&[0]).is_err());
+    }
+
+    #[test]
+    fn ignores_unknown_tlv_types() {
+        let mut data = Vec::new();
+        push_tlv(&mut data, TLV_SPECIAL, &hex::decode(NPROFILE_HEX).unwrap()).unwrap();
+        push_tlv(&mut data, 7, b"future").unwrap();
+        let encoded = bech32_encode("nprofile", &data).unwrap();
+        assert_eq!(
+            parse(&encoded).unwrap().entity,
+            NostrEntity::Profile {
+                pubkey: NPROFILE_HEX.to_string(),
+                relays: Vec::new(),
+            }
+        );
+    }
+}
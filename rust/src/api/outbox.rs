@@ -0,0 +1,581 @@
+use crate::api::delivery::{announce_delivery_status, outbox_delivery_status, record_sent_message};
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use crate::api::events::{emit_event, WhitenoiseEvent};
+use crate::api::groups::{group_id_from_string, group_id_to_string};
+use crate::api::messages::{
+    convert_message_with_tokens_to_data, is_presence_kind, send_inner_event, MessageWithTokensData,
+};
+use crate::api::utils::{tag_from_vec, unix_now};
+use crate::store;
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+use tokio::sync::Notify;
+pub use whitenoise::{GroupId, PublicKey, RelayStatus, Tag, Whitenoise};
+
+/// State of a message waiting in the outbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutboxState {
+    /// The message will be sent automatically
+    Pending,
+    /// Automatic retries gave up; use `retry_message` or `cancel_pending_message`
+    Failed,
+}
+
+/// A message waiting in the outbox to be sent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxMessageData {
+    pub id: String, // Local outbox ID, not an event ID
+    pub group_id: String,
+    pub content: String,
+    pub kind: u16,
+    pub tags: Vec<Vec<String>>,
+    pub created_at: u64,
+    pub state: OutboxState,
+    pub attempts: u32,
+    pub next_attempt_at: u64,
+    pub last_error: Option<String>,
+}
+
+/// Name of the per-account store document holding the outbox.
+const OUTBOX_DOCUMENT: &str = "outbox";
+
+/// How often the outbox worker checks for messages that are due.
+const OUTBOX_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Delay before the first retry; doubled after every failed attempt.
+const RETRY_BASE_DELAY_SECS: u64 = 2;
+
+/// Upper bound for the delay between two retries.
+const RETRY_MAX_DELAY_SECS: u64 = 300;
+
+/// Number of failed attempts after which automatic retries stop.
+const MAX_AUTOMATIC_ATTEMPTS: u32 = 10;
+
+/// Wakes the outbox worker when a message is queued or retried.
+static OUTBOX_WAKEUP: OnceLock<Notify> = OnceLock::new();
+
+/// Counter mixed into outbox IDs so IDs created in the same instant stay unique.
+static NEXT_OUTBOX_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Tag carrying the outbox ID of a queued message.
+///
+/// It is added when the message is queued, before the first attempt, so every attempt to
+/// send the message carries the same ID. When an attempt reports an error but still went
+/// out, the retry produces a second event with the same ID, which `load_chat_messages`
+/// drops. Only messages queued in the outbox carry it; reactions and deletions are sent
+/// directly.
+pub(crate) const CLIENT_ID_TAG: &str = "client-id";
+
+/// Outbox IDs of the messages being sent right now.
+static IN_FLIGHT: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+/// Marks an outbox message as being sent until dropped, so the worker and a direct send
+/// never send the same message at the same time.
+struct InFlight(String);
+
+impl InFlight {
+    fn claim(outbox_id: &str) -> Option<Self> {
+        let mut in_flight = IN_FLIGHT
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        in_flight
+            .insert(outbox_id.to_string())
+            .then(|| Self(outbox_id.to_string()))
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        if let Some(in_flight) = IN_FLIGHT.get() {
+            in_flight
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(&self.0);
+        }
+    }
+}
+
+fn outbox_wakeup() -> &'static Notify {
+    OUTBOX_WAKEUP.get_or_init(Notify::new)
+}
+
+fn new_outbox_id() -> String {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    let counter = NEXT_OUTBOX_ID.fetch_add(1, Ordering::Relaxed);
+    format!("{nanos:x}-{counter:x}")
+}
+
+fn retry_delay(attempts: u32) -> u64 {
+    RETRY_BASE_DELAY_SECS
+        .saturating_mul(1 << attempts.min(16))
+        .min(RETRY_MAX_DELAY_SECS)
+}
+
+pub(crate) async fn load_outbox(
+    account_pubkey: &str,
+) -> Result<Vec<OutboxMessageData>, BridgeError> {
+    store::load(OUTBOX_DOCUMENT, Some(account_pubkey)).await
+}
+
+/// Loads one outbox message, if it is still queued.
+async fn load_outbox_message(
+    account_pubkey: &str,
+    outbox_id: &str,
+) -> Result<Option<OutboxMessageData>, BridgeError> {
+    Ok(load_outbox(account_pubkey)
+        .await?
+        .into_iter()
+        .find(|message| message.id == outbox_id))
+}
+
+/// Applies a change to one outbox message and returns the updated message, if it exists.
+async fn update_outbox_message(
+    account_pubkey: &str,
+    outbox_id: &str,
+    change: impl FnOnce(&mut OutboxMessageData),
+) -> Result<Option<OutboxMessageData>, BridgeError> {
+    store::update(
+        OUTBOX_DOCUMENT,
+        Some(account_pubkey),
+        |outbox: &mut Vec<OutboxMessageData>| {
+            let message = outbox.iter_mut().find(|message| message.id == outbox_id)?;
+            change(message);
+            Some(message.clone())
+        },
+    )
+    .await
+}
+
+/// Starts the background task that sends queued messages. Called from
+/// `initialize_whitenoise`, so messages queued before a restart are picked up again.
+pub(crate) fn start_outbox_worker() {
+    static STARTED: OnceLock<()> = OnceLock::new();
+    if STARTED.set(()).is_ok() {
+        tokio::spawn(run_outbox());
+    }
+}
+
+async fn run_outbox() {
+    let mut interval = tokio::time::interval(OUTBOX_POLL_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = outbox_wakeup().notified() => {}
+        }
+        let Ok(whitenoise) = Whitenoise::get_instance() else {
+            continue;
+        };
+        let Ok(accounts) = whitenoise.fetch_accounts().await else {
+            continue;
+        };
+        for pubkey in accounts.keys() {
+            process_outbox(whitenoise, *pubkey).await;
+        }
+    }
+}
+
+/// Sends the due messages of one account, oldest first.
+///
+/// Messages of a group are sent in order: once one fails, later messages of the same
+/// group wait for the next round. Nothing is attempted while none of the account's relays
+/// is connected, so time spent offline does not count towards the retry limit.
+async fn process_outbox(whitenoise: &Whitenoise, pubkey: PublicKey) {
+    let account_pubkey = pubkey.to_hex();
+    let Ok(mut outbox) = load_outbox(&account_pubkey).await else {
+        return;
+    };
+    let now = unix_now();
+    if !outbox
+        .iter()
+        .any(|message| message.state == OutboxState::Pending && message.next_attempt_at <= now)
+    {
+        return;
+    }
+    let is_online = whitenoise
+        .fetch_relay_status(pubkey)
+        .await
+        .is_ok_and(|statuses| {
+            statuses
+                .iter()
+                .any(|(_, status)| matches!(status, RelayStatus::Connected))
+        });
+    if !is_online {
+        return;
+    }
+
+    outbox.sort_by_key(|message| message.created_at);
+    let mut blocked_groups = HashSet::new();
+    for message in outbox {
+        if message.state != OutboxState::Pending || blocked_groups.contains(&message.group_id) {
+            continue;
+        }
+        if message.next_attempt_at > now {
+            blocked_groups.insert(message.group_id.clone());
+            continue;
+        }
+        // A message that is being sent directly keeps the rest of its group waiting
+        let Some(_claim) = InFlight::claim(&message.id) else {
+            blocked_groups.insert(message.group_id.clone());
+            continue;
+        };
+        // The list was loaded before the claim: since then the message may have been sent
+        // directly, cancelled or rescheduled by a failed direct attempt
+        let message = match load_outbox_message(&account_pubkey, &message.id).await {
+            Ok(Some(current)) if current.state == OutboxState::Pending => current,
+            Ok(None) => continue,
+            _ => {
+                blocked_groups.insert(message.group_id.clone());
+                continue;
+            }
+        };
+        if message.next_attempt_at > unix_now() {
+            blocked_groups.insert(message.group_id.clone());
+            continue;
+        }
+        if attempt_outbox_message(pubkey, &account_pubkey, &message)
+            .await
+            .is_err()
+        {
+            blocked_groups.insert(message.group_id.clone());
+        }
+    }
+}
+
+/// Makes one attempt to send an outbox message the caller has claimed.
+///
+/// On success the message is removed from the outbox; on failure its retry state is
+/// updated. Both are announced on the event bus.
+async fn attempt_outbox_message(
+    pubkey: PublicKey,
+    account_pubkey: &str,
+    message: &OutboxMessageData,
+) -> Result<MessageWithTokensData, BridgeError> {
+    match send_outbox_message(pubkey, account_pubkey, message).await {
+        Ok(sent) => Ok(sent),
+        Err(error) => {
+            let failed = update_outbox_message(account_pubkey, &message.id, |message| {
+                message.attempts += 1;
+                message.next_attempt_at = unix_now() + retry_delay(message.attempts);
+                message.last_error = Some(error.to_string());
+                if message.attempts >= MAX_AUTOMATIC_ATTEMPTS {
+                    message.state = OutboxState::Failed;
+                }
+            })
+            .await;
+            if let Ok(Some(message)) = failed {
+                announce_delivery_status(account_pubkey, outbox_delivery_status(&message));
+                emit_event(WhitenoiseEvent::OutboxMessageUpdated {
+                    account_pubkey: account_pubkey.to_string(),
+                    message,
+                });
+            }
+            Err(error)
+        }
+    }
+}
+
+async fn send_outbox_message(
+    pubkey: PublicKey,
+    account_pubkey: &str,
+    message: &OutboxMessageData,
+) -> Result<MessageWithTokensData, BridgeError> {
+    let group_id = group_id_from_string(message.group_id.clone())?;
+    let tags = message
+        .tags
+        .iter()
+        .cloned()
+        .map(tag_from_vec)
+        .collect::<Result<Vec<Tag>, _>>()?;
+    let tags = (!tags.is_empty()).then_some(tags);
+    let sent = send_inner_event(
+        pubkey,
+        group_id,
+        message.content.clone(),
+        message.kind,
+        tags,
+    )
+    .await?;
+    let sent = convert_message_with_tokens_to_data(&sent);
+
+    record_sent_message(account_pubkey, message, sent.id.clone()).await;
+    store::update(
+        OUTBOX_DOCUMENT,
+        Some(account_pubkey),
+        |outbox: &mut Vec<OutboxMessageData>| outbox.retain(|queued| queued.id != message.id),
+    )
+    .await?;
+    emit_event(WhitenoiseEvent::OutboxMessageSent {
+        account_pubkey: account_pubkey.to_string(),
+        outbox_id: message.id.clone(),
+        message: sent.clone(),
+    });
+    Ok(sent)
+}
+
+/// Builds a new pending outbox message, tagged with its outbox ID.
+fn new_outbox_message(
+    outbox_id: String,
+    group_id: &GroupId,
+    content: String,
+    kind: u16,
+    tags: Option<Vec<Tag>>,
+) -> Result<OutboxMessageData, BridgeError> {
+    if is_presence_kind(kind) {
+        // Presence is only meaningful right away; a late retry would show stale state
+        return Err(BridgeError::new(
+            BridgeErrorCode::InvalidInput,
+            "Typing indicators and read receipts cannot be queued",
+        ));
+    }
+    let mut tags: Vec<Vec<String>> = tags
+        .unwrap_or_default()
+        .iter()
+        .map(|tag| tag.as_slice().to_vec())
+        .filter(|tag| tag.first().map(String::as_str) != Some(CLIENT_ID_TAG))
+        .collect();
+    tags.push(vec![CLIENT_ID_TAG.to_string(), outbox_id.clone()]);
+    let now = unix_now();
+    Ok(OutboxMessageData {
+        id: outbox_id,
+        group_id: group_id_to_string(group_id),
+        content,
+        kind,
+        tags,
+        created_at: now,
+        state: OutboxState::Pending,
+        attempts: 0,
+        next_attempt_at: now,
+        last_error: None,
+    })
+}
+
+async fn save_outbox_message(
+    account_pubkey: &str,
+    queued: &OutboxMessageData,
+) -> Result<(), BridgeError> {
+    store::update(
+        OUTBOX_DOCUMENT,
+        Some(account_pubkey),
+        |outbox: &mut Vec<OutboxMessageData>| outbox.push(queued.clone()),
+    )
+    .await
+}
+
+/// Sends a message through the outbox and waits for the first attempt.
+///
+/// The message is queued before it is sent, so when the attempt fails it is not lost:
+/// the error is returned with the message's `outbox_id` set and the worker keeps retrying
+/// it like any queued message.
+pub(crate) async fn send_through_outbox(
+    pubkey: PublicKey,
+    group_id: GroupId,
+    content: String,
+    kind: u16,
+    tags: Option<Vec<Tag>>,
+) -> Result<MessageWithTokensData, BridgeError> {
+    let account_pubkey = pubkey.to_hex();
+    let outbox_id = new_outbox_id();
+    // Claimed before it is saved, so the worker cannot pick it up in between
+    let _claim = InFlight::claim(&outbox_id);
+    let queued = new_outbox_message(outbox_id, &group_id, content, kind, tags)?;
+    save_outbox_message(&account_pubkey, &queued).await?;
+    announce_delivery_status(&account_pubkey, outbox_delivery_status(&queued));
+    attempt_outbox_message(pubkey, &account_pubkey, &queued)
+        .await
+        .map_err(|error| error.with_outbox_id(queued.id.clone()))
+}
+
+/// Queues a message to be sent to a group, retrying until it is delivered.
+///
+/// Unlike `send_message_to_group`, which waits for the first attempt, the call returns as
+/// soon as the message is stored locally; a background worker sends it as soon as a relay
+/// is connected. Failed attempts are retried with exponential backoff (up to 5 minutes
+/// apart); the outbox survives app restarts.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account sending the message
+/// * `group_id` - The MLS group ID to send the message to
+/// * `message` - The message content
+/// * `kind` - The Nostr event kind
+/// * `tags` - Optional Nostr tags to include with the message
+///
+/// # Returns
+/// * `Ok(OutboxMessageData)` - The queued message; show it as pending until
+///   `WhitenoiseEvent::OutboxMessageSent` arrives with its ID
+/// * `Err(BridgeError)` - `InvalidInput` for typing indicators and read receipts, or a
+///   `Storage` error if the message could not be stored
+///
+/// # Notes
+/// * Messages to the same group are sent in the order they were queued
+/// * After 10 failed attempts the message is marked `OutboxState::Failed` and is only
+///   sent again through `retry_message`
+#[frb]
+pub async fn enqueue_message_to_group(
+    pubkey: &PublicKey,
+    group_id: whitenoise::GroupId,
+    message: String,
+    kind: u16,
+    tags: Option<Vec<Tag>>,
+) -> Result<OutboxMessageData, BridgeError> {
+    let queued = new_outbox_message(new_outbox_id(), &group_id, message, kind, tags)?;
+    let account_pubkey = pubkey.to_hex();
+    save_outbox_message(&account_pubkey, &queued).await?;
+    announce_delivery_status(&account_pubkey, outbox_delivery_status(&queued));
+    outbox_wakeup().notify_one();
+    Ok(queued)
+}
+
+/// Fetches the messages waiting in the outbox of an account.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `group_id` - Only return messages for this group, or `None` for all groups
+///
+/// # Returns
+/// * `Ok(Vec<OutboxMessageData>)` - The queued messages, oldest first
+/// * `Err(BridgeError)` - If the outbox could not be read
+#[frb]
+pub async fn fetch_outbox(
+    pubkey: &PublicKey,
+    group_id: Option<whitenoise::GroupId>,
+) -> Result<Vec<OutboxMessageData>, BridgeError> {
+    let group_id = group_id.map(|group_id| group_id_to_string(&group_id));
+    let mut outbox = load_outbox(&pubkey.to_hex()).await?;
+    outbox.retain(|message| group_id.as_ref().is_none_or(|id| *id == message.group_id));
+    outbox.sort_by_key(|message| message.created_at);
+    Ok(outbox)
+}
+
+/// Sends a queued message again right away.
+///
+/// Resets the retry counter, so a message marked `OutboxState::Failed` goes back to
+/// being retried automatically.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `outbox_id` - The outbox ID of the message
+///
+/// # Returns
+/// * `Ok(OutboxMessageData)` - The message, now pending
+/// * `Err(BridgeError)` - `MessageNotFound` if the message is no longer in the outbox
+///   (e.g. it has been sent in the meantime)
+#[frb]
+pub async fn retry_message(
+    pubkey: &PublicKey,
+    outbox_id: String,
+) -> Result<OutboxMessageData, BridgeError> {
+    let account_pubkey = pubkey.to_hex();
+    let message = update_outbox_message(&account_pubkey, &outbox_id, |message| {
+        message.state = OutboxState::Pending;
+        message.attempts = 0;
+        message.next_attempt_at = unix_now();
+    })
+    .await?
+    .ok_or_else(|| {
+        BridgeError::new(
+            BridgeErrorCode::MessageNotFound,
+            "Message is not in the outbox",
+        )
+    })?;
+    announce_delivery_status(&account_pubkey, outbox_delivery_status(&message));
+    outbox_wakeup().notify_one();
+    Ok(message)
+}
+
+/// Removes a message from the outbox without sending it.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `outbox_id` - The outbox ID of the message
+///
+/// # Returns
+/// * `Ok(true)` - If the message was removed
+/// * `Ok(false)` - If the message was no longer in the outbox
+/// * `Err(BridgeError)` - If the outbox could not be updated
+///
+/// # Notes
+/// * A message that is being sent at the moment of the call may still be delivered
+#[frb]
+pub async fn cancel_pending_message(
+    pubkey: &PublicKey,
+    outbox_id: String,
+) -> Result<bool, BridgeError> {
+    store::update(
+        OUTBOX_DOCUMENT,
+        Some(&pubkey.to_hex()),
+        |outbox: &mut Vec<OutboxMessageData>| {
+            let len = outbox.len();
+            outbox.retain(|message| message.id != outbox_id);
+            outbox.len() != len
+        },
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::utils::tag_from_vec;
+
+    fn tag(values: &[&str]) -> Tag {
+        tag_from_vec(values.iter().map(|value| value.to_string()).collect()).unwrap()
+    }
+
+    #[test]
+    fn queued_message_carries_its_outbox_id() {
+        let group_id = GroupId::from_slice(&[1, 2, 3]);
+        let queued = new_outbox_message(
+            "outbox-1".to_string(),
+            &group_id,
+            "hello".to_string(),
+            9,
+            Some(vec![tag(&["e", "abc"]), tag(&[CLIENT_ID_TAG, "stale"])]),
+        )
+        .unwrap();
+        assert_eq!(
+            queued.tags,
+            vec![
+                vec!["e".to_string(), "abc".to_string()],
+                vec![CLIENT_ID_TAG.to_string(), "outbox-1".to_string()],
+            ]
+        );
+        assert_eq!(queued.state, OutboxState::Pending);
+    }
+
+    #[test]
+    fn presence_is_never_queued() {
+        let group_id = GroupId::from_slice(&[1]);
+        let error = new_outbox_message(
+            "outbox-1".to_string(),
+            &group_id,
+            String::new(),
+            crate::api::messages::KIND_TYPING_INDICATOR,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(error.code, BridgeErrorCode::InvalidInput);
+    }
+
+    #[test]
+    fn in_flight_claims_are_exclusive_until_dropped() {
+        let claim = InFlight::claim("outbox-test").unwrap();
+        assert!(InFlight::claim("outbox-test").is_none());
+        drop(claim);
+        assert!(InFlight::claim("outbox-test").is_some());
+    }
+
+    #[test]
+    fn retry_delay_grows_and_is_capped() {
+        assert!(retry_delay(1) < retry_delay(2));
+        assert_eq!(retry_delay(30), RETRY_MAX_DELAY_SECS);
+    }
+}
@@ -0,0 +1,473 @@
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use crate::api::groups::{find_group, group_id_to_string};
+use crate::api::messages::{
+    find_group_message, tag_value, KIND_READ_RECEIPT, KIND_TYPING_INDICATOR,
+};
+use crate::api::subscriptions::spawn_subscription;
+use crate::api::utils::{tag_from_vec, unix_now};
+use crate::frb_generated::StreamSink;
+use crate::group_watch::group_relays;
+use crate::relay_client::{publish_event, subscribe};
+use crate::signer::AccountSigner;
+use crate::store;
+use flutter_rust_bridge::frb;
+use nostr::nips::nip44;
+use nostr_sdk::{
+    Alphabet, Event, EventBuilder, Filter, JsonUtil, Keys, Kind, SingleLetterTag, Timestamp,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+pub use whitenoise::{GroupId, PublicKey, Whitenoise};
+
+/// An update pushed on the stream created by `subscribe_to_group_presence`.
+#[derive(Debug, Clone)]
+pub enum GroupPresenceUpdate {
+    /// First item on every stream; carries the handle to pass to `unsubscribe`
+    Subscribed { subscription_id: u64 },
+    /// A member started typing; hide the indicator after `expires_at` (unix seconds)
+    Typing {
+        member_pubkey: String,
+        expires_at: u64,
+    },
+    /// A member read the group up to a message
+    ReadReceipt {
+        member_pubkey: String,
+        message_id: String,
+        read_at: u64,
+    },
+}
+
+/// Per-account opt-outs for typing indicators and read receipts.
+///
+/// Kept by the bridge next to the core's `AccountSettings`, which it cannot extend.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PresenceSettingsData {
+    pub disable_read_receipts: bool, // Stop sending read receipts to groups
+    pub disable_typing_indicators: bool, // Stop sending typing indicators to groups
+}
+
+/// Name of the per-account store document holding the presence settings.
+const PRESENCE_SETTINGS_DOCUMENT: &str = "account_settings";
+
+/// How long a typing indicator stays visible after it was sent.
+const TYPING_INDICATOR_TIMEOUT_SECS: u64 = 5;
+
+/// Minimum delay between two typing indicators sent to the same group.
+const TYPING_INDICATOR_THROTTLE: Duration = Duration::from_secs(3);
+
+/// Nostr kind of the ephemeral gift wrap a presence event is sent in. Relays forward
+/// ephemeral kinds (20000-29999) to subscribers without storing them.
+const KIND_EPHEMERAL_GIFT_WRAP: u16 = 21_059;
+
+/// Name of the per-account store document holding the latest read receipt of every
+/// member, keyed by group ID (hex) and member public key (hex).
+const READ_RECEIPTS_DOCUMENT: &str = "read_receipts";
+
+/// A read receipt received from a member.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredReadReceipt {
+    message_id: String,
+    read_at: u64,
+}
+
+type ReadReceipts = HashMap<String, HashMap<String, StoredReadReceipt>>;
+
+/// When a typing indicator was last sent, keyed by (account hex, group ID hex).
+static LAST_TYPING_SENT: OnceLock<Mutex<HashMap<(String, String), Instant>>> = OnceLock::new();
+
+async fn load_presence_settings(pubkey: &PublicKey) -> Result<PresenceSettingsData, BridgeError> {
+    store::load(PRESENCE_SETTINGS_DOCUMENT, Some(&pubkey.to_hex())).await
+}
+
+/// Fetches whether an account sends typing indicators and read receipts.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+///
+/// # Returns
+/// * `Ok(PresenceSettingsData)` - The settings; both are sent until disabled
+/// * `Err(BridgeError)` - If the settings could not be read
+#[frb]
+pub async fn fetch_presence_settings(
+    pubkey: &PublicKey,
+) -> Result<PresenceSettingsData, BridgeError> {
+    load_presence_settings(pubkey).await
+}
+
+/// Updates whether an account sends typing indicators and read receipts.
+///
+/// Only affects what the account sends; indicators and receipts from other members are
+/// still received.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `settings` - The new settings
+///
+/// # Returns
+/// * `Ok(())` - If the settings were saved
+/// * `Err(BridgeError)` - If the settings could not be saved
+#[frb]
+pub async fn update_presence_settings(
+    pubkey: &PublicKey,
+    settings: PresenceSettingsData,
+) -> Result<(), BridgeError> {
+    store::update(
+        PRESENCE_SETTINGS_DOCUMENT,
+        Some(&pubkey.to_hex()),
+        |current: &mut PresenceSettingsData| *current = settings,
+    )
+    .await
+}
+
+/// Sends a presence event to the other members of a group.
+///
+/// Presence events are not sent through MLS: the core publishes every MLS message as a
+/// regular kind 445 event, which relays and the core store for good. Instead, the event
+/// is signed by the account, tagged with the group's Nostr ID and sent to each member on
+/// its own, NIP-44 encrypted in an ephemeral gift wrap (kind 21059) signed by a one-time
+/// key, to the group's relays. Relays only see that someone sent an event to the member.
+async fn send_presence_event(
+    pubkey: &PublicKey,
+    group_id: &GroupId,
+    kind: u16,
+    mut tags: Vec<Vec<String>>,
+) -> Result<(), BridgeError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let account = whitenoise.fetch_account(pubkey).await?;
+    let group = find_group(whitenoise, &account, group_id).await?;
+    let members = whitenoise.fetch_group_members(&account, group_id).await?;
+    let relays = group_relays(whitenoise, &account, &group).await?;
+
+    tags.push(vec!["h".to_string(), hex::encode(group.nostr_group_id)]);
+    let tags = tags
+        .into_iter()
+        .map(tag_from_vec)
+        .collect::<Result<Vec<_>, _>>()?;
+    let signer = AccountSigner::load(pubkey).await?;
+    let event = signer.sign(EventBuilder::new(Kind::from_u16(kind), "").tags(tags))?;
+    let payload = event.as_json();
+
+    let wrap_error = |e: nostr::Error| {
+        BridgeError::new(BridgeErrorCode::Internal, "Could not wrap the event").with_details(e)
+    };
+    let mut wraps = Vec::new();
+    for member in members.iter().filter(|member| *member != pubkey) {
+        let wrapper = Keys::generate();
+        let content = nip44::encrypt(wrapper.secret_key(), member, &payload, nip44::Version::V2)
+            .map_err(wrap_error)?;
+        let wrap = EventBuilder::new(Kind::from_u16(KIND_EPHEMERAL_GIFT_WRAP), content)
+            .tags([tag_from_vec(vec!["p".to_string(), member.to_hex()])?])
+            .sign_with_keys(&wrapper)
+            .map_err(|e| {
+                BridgeError::new(BridgeErrorCode::Internal, "Could not sign the event")
+                    .with_details(e)
+            })?;
+        wraps.push(wrap);
+    }
+
+    let results =
+        futures::future::join_all(wraps.iter().map(|wrap| publish_event(&relays, wrap))).await;
+    // Presence is best effort: it only fails when it reached nobody
+    let (sent, failed): (Vec<_>, Vec<_>) = results.into_iter().partition(Result::is_ok);
+    match failed.into_iter().next() {
+        Some(Err(error)) if sent.is_empty() => Err(error),
+        _ => Ok(()),
+    }
+}
+
+/// Tells the other members of a group that the account is typing.
+///
+/// Call it whenever the user types; indicators are throttled so at most one is sent every
+/// few seconds per group.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account that is typing
+/// * `group_id` - The MLS group ID
+///
+/// # Returns
+/// * `Ok(())` - If the indicator was sent, throttled, or typing indicators are disabled in
+///   the presence settings
+/// * `Err(BridgeError)` - If the indicator could not be sent
+///
+/// # Notes
+/// * Typing indicators and read receipts are ephemeral: they are not MLS messages, relays
+///   do not store them and members only get them while they are online (see
+///   `subscribe_to_group_presence`)
+#[frb]
+pub async fn send_typing_indicator(
+    pubkey: &PublicKey,
+    group_id: whitenoise::GroupId,
+) -> Result<(), BridgeError> {
+    if load_presence_settings(pubkey)
+        .await?
+        .disable_typing_indicators
+    {
+        return Ok(());
+    }
+
+    let key = (pubkey.to_hex(), group_id_to_string(&group_id));
+    {
+        let mut last_sent = LAST_TYPING_SENT
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if last_sent
+            .get(&key)
+            .is_some_and(|at| at.elapsed() < TYPING_INDICATOR_THROTTLE)
+        {
+            return Ok(());
+        }
+        last_sent.insert(key, Instant::now());
+    }
+
+    send_presence_event(pubkey, &group_id, KIND_TYPING_INDICATOR, Vec::new()).await
+}
+
+/// Tells the other members of a group that the account has read a message.
+///
+/// # Arguments
+/// * `pubkey` - The public key of the account
+/// * `group_id` - The MLS group ID
+/// * `message_id` - The event ID (hex) of the newest message the account has read
+///
+/// # Returns
+/// * `Ok(())` - If the receipt was sent or read receipts are disabled in the presence
+///   settings
+/// * `Err(BridgeError)` - `MessageNotFound` if the message is not in the group, or any
+///   error from sending the receipt
+///
+/// # Notes
+/// * Receipts are ephemeral like typing indicators: members who are offline miss them and
+///   catch up with the next one
+#[frb]
+pub async fn send_read_receipt(
+    pubkey: &PublicKey,
+    group_id: whitenoise::GroupId,
+    message_id: String,
+) -> Result<(), BridgeError> {
+    if load_presence_settings(pubkey).await?.disable_read_receipts {
+        return Ok(());
+    }
+    let message = find_group_message(pubkey, &group_id, &message_id).await?;
+    send_presence_event(
+        pubkey,
+        &group_id,
+        KIND_READ_RECEIPT,
+        vec![vec!["e".to_string(), message.id]],
+    )
+    .await
+}
+
+/// Unwraps an ephemeral gift wrap sent to the account.
+///
+/// Returns the presence event inside if its signature is valid and it belongs to the
+/// group with the given Nostr ID (hex).
+fn unwrap_presence_event(
+    signer: &AccountSigner,
+    wrap: &Event,
+    nostr_group_id: &str,
+) -> Option<Event> {
+    let payload = signer.decrypt_from(&wrap.pubkey, &wrap.content).ok()?;
+    let event = Event::from_json(payload).ok()?;
+    event.verify().ok()?;
+    let in_group = event
+        .tags
+        .iter()
+        .any(|tag| tag_value(tag, "h") == Some(nostr_group_id));
+    in_group.then_some(event)
+}
+
+/// Turns a presence event from another member into a stream update.
+fn presence_update(event: &Event, now: u64) -> Option<GroupPresenceUpdate> {
+    let created_at = event.created_at.as_u64();
+    match event.kind.as_u16() {
+        KIND_TYPING_INDICATOR => {
+            let expires_at = created_at + TYPING_INDICATOR_TIMEOUT_SECS;
+            // Indicators that already expired (e.g. delayed) are not worth showing
+            (expires_at > now).then(|| GroupPresenceUpdate::Typing {
+                member_pubkey: event.pubkey.to_hex(),
+                expires_at,
+            })
+        }
+        KIND_READ_RECEIPT => {
+            let message_id = event.tags.iter().find_map(|tag| tag_value(tag, "e"))?;
+            Some(GroupPresenceUpdate::ReadReceipt {
+                member_pubkey: event.pubkey.to_hex(),
+                message_id: message_id.to_string(),
+                // Receipts cannot claim to be from the future
+                read_at: created_at.min(now),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Keeps a received read receipt if it is the newest of its member. Returns whether it
+/// was kept.
+fn record_read_receipt(
+    receipts: &mut ReadReceipts,
+    group_id: &str,
+    member_pubkey: &str,
+    message_id: &str,
+    read_at: u64,
+) -> bool {
+    let receipt = StoredReadReceipt {
+        message_id: message_id.to_string(),
+        read_at,
+    };
+    let group = receipts.entry(group_id.to_string()).or_default();
+    match group.get(member_pubkey) {
+        Some(latest) if latest.read_at > read_at => false,
+        _ => {
+            group.insert(member_pubkey.to_string(), receipt);
+            true
+        }
+    }
+}
+
+/// Subscribes to typing indicators and read receipts of the other members of a group.
+///
+/// When the stream is opened, the latest read receipt received from every member is
+/// pushed so Flutter can show who has read what; after that, new typing indicators and
+/// receipts are pushed as they arrive.
+///
+/// # Arguments
+/// * `sink` - The stream sink the updates are pushed to (provided by flutter_rust_bridge)
+/// * `pubkey` - The public key of the account subscribing (must be a group member)
+/// * `group_id` - The MLS group ID to watch
+///
+/// # Returns
+/// * `Ok(())` - The subscription was started; updates arrive on the stream
+/// * `Err(BridgeError)` - If the group or its relays could not be loaded
+///
+/// # Notes
+/// * The first item on the stream is always `GroupPresenceUpdate::Subscribed`, carrying
+///   the handle to pass to `unsubscribe`
+/// * Presence events are ephemeral and only received while a stream is open; the latest
+///   read receipt of each member is kept on the device, everything else is dropped
+/// * Events from anyone who is not a member of the group are ignored
+#[frb]
+pub async fn subscribe_to_group_presence(
+    sink: StreamSink<GroupPresenceUpdate>,
+    pubkey: PublicKey,
+    group_id: whitenoise::GroupId,
+) -> Result<(), BridgeError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let account = whitenoise.fetch_account(&pubkey).await?;
+    let group = find_group(whitenoise, &account, &group_id).await?;
+    let relays = group_relays(whitenoise, &account, &group).await?;
+    let signer = AccountSigner::load(&pubkey).await?;
+    let nostr_group_id = hex::encode(group.nostr_group_id);
+    let mut members: HashSet<PublicKey> = whitenoise
+        .fetch_group_members(&account, &group_id)
+        .await?
+        .into_iter()
+        .collect();
+
+    let filter = Filter::new()
+        .kind(Kind::from_u16(KIND_EPHEMERAL_GIFT_WRAP))
+        .custom_tag(SingleLetterTag::lowercase(Alphabet::P), pubkey.to_hex())
+        .since(Timestamp::now());
+    let mut subscription = subscribe(&relays, filter).await?;
+
+    let account_pubkey = pubkey.to_hex();
+    let group_key = group_id_to_string(&group_id);
+    let receipts: ReadReceipts = store::load(READ_RECEIPTS_DOCUMENT, Some(&account_pubkey)).await?;
+    let initial_updates: Vec<GroupPresenceUpdate> = receipts
+        .get(&group_key)
+        .into_iter()
+        .flatten()
+        .map(
+            |(member_pubkey, receipt)| GroupPresenceUpdate::ReadReceipt {
+                member_pubkey: member_pubkey.clone(),
+                message_id: receipt.message_id.clone(),
+                read_at: receipt.read_at,
+            },
+        )
+        .collect();
+
+    spawn_subscription(move |subscription_id| async move {
+        if sink
+            .add(GroupPresenceUpdate::Subscribed { subscription_id })
+            .is_err()
+        {
+            return;
+        }
+        for update in initial_updates {
+            if sink.add(update).is_err() {
+                return;
+            }
+        }
+
+        while let Some(wrap) = subscription.next_event().await {
+            let Some(event) = unwrap_presence_event(&signer, &wrap, &nostr_group_id) else {
+                continue;
+            };
+            if event.pubkey == pubkey {
+                continue;
+            }
+            if !members.contains(&event.pubkey) {
+                // The member may have joined since the stream was opened
+                let Ok(current) = whitenoise.fetch_group_members(&account, &group_id).await else {
+                    continue;
+                };
+                members = current.into_iter().collect();
+                if !members.contains(&event.pubkey) {
+                    continue;
+                }
+            }
+            let Some(update) = presence_update(&event, unix_now()) else {
+                continue;
+            };
+            if let GroupPresenceUpdate::ReadReceipt {
+                member_pubkey,
+                message_id,
+                read_at,
+            } = &update
+            {
+                let newest = store::update(
+                    READ_RECEIPTS_DOCUMENT,
+                    Some(&account_pubkey),
+                    |receipts: &mut ReadReceipts| {
+                        record_read_receipt(
+                            receipts,
+                            &group_key,
+                            member_pubkey,
+                            message_id,
+                            *read_at,
+                        )
+                    },
+                )
+                .await
+                .unwrap_or(true);
+                if !newest {
+                    continue;
+                }
+            }
+            if sink.add(update).is_err() {
+                // The Dart side closed the stream
+                return;
+            }
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_only_the_newest_read_receipt_of_each_member() {
+        let mut receipts = ReadReceipts::new();
+        assert!(record_read_receipt(&mut receipts, "g", "alice", "m2", 20));
+        assert!(!record_read_receipt(&mut receipts, "g", "alice", "m1", 10));
+        assert!(record_read_receipt(&mut receipts, "g", "bob", "m1", 10));
+        assert!(record_read_receipt(&mut receipts, "g", "alice", "m3", 30));
+        assert_eq!(receipts["g"]["alice"].message_id, "m3");
+        assert_eq!(receipts["g"]["bob"].message_id, "m1");
+        assert_eq!(receipts["g"].len(), 2);
+    }
+}
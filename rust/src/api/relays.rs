@@ -1,3 +1,5 @@
+use crate::api::errors::BridgeError;
+use crate::api::events::refresh_events;
 use flutter_rust_bridge::frb;
 pub use whitenoise::{
     Event, PublicKey, RelayStatus, RelayType, RelayUrl, Whitenoise, WhitenoiseError,
@@ -51,14 +53,14 @@ pub fn relay_type_key_package() -> RelayType {
 ///
 /// # Returns
 /// * `Ok(Vec<RelayUrl>)` - Vector of relay URLs for the specified type
-/// * `Err(WhitenoiseError)` - If there was an error fetching relays or account not found
+/// * `Err(BridgeError)` - If there was an error fetching relays or account not found
 #[frb]
 pub async fn fetch_relays(
     pubkey: PublicKey,
     relay_type: RelayType,
-) -> Result<Vec<RelayUrl>, WhitenoiseError> {
+) -> Result<Vec<RelayUrl>, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
-    whitenoise.fetch_relays(pubkey, relay_type).await
+    Ok(whitenoise.fetch_relays(pubkey, relay_type).await?)
 }
 
 /// Updates an account's relay list for a specific relay type.
@@ -73,7 +75,7 @@ pub async fn fetch_relays(
 ///
 /// # Returns
 /// * `Ok(())` - If the relays were successfully updated
-/// * `Err(WhitenoiseError)` - If there was an error updating relays
+/// * `Err(BridgeError)` - If there was an error updating relays
 ///
 /// # Notes
 /// * This operation completely replaces the existing relay list for the specified type
@@ -83,10 +85,14 @@ pub async fn update_relays(
     pubkey: &PublicKey,
     relay_type: RelayType,
     relays: Vec<RelayUrl>,
-) -> Result<(), WhitenoiseError> {
+) -> Result<(), BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let account = whitenoise.fetch_account(pubkey).await?;
-    whitenoise.update_relays(&account, relay_type, relays).await
+    whitenoise
+        .update_relays(&account, relay_type, relays)
+        .await?;
+    refresh_events();
+    Ok(())
 }
 
 /// Fetches an account's MLS key package from its configured key package relays.
@@ -101,13 +107,13 @@ pub async fn update_relays(
 /// # Returns
 /// * `Ok(Some(Event))` - The key package event if found
 /// * `Ok(None)` - If no key package was found on the relays
-/// * `Err(WhitenoiseError)` - If there was an error fetching the key package
+/// * `Err(BridgeError)` - If there was an error fetching the key package
 ///
 /// # Notes
 /// * This function automatically uses the account's configured key package relays
 /// * Key packages have expiration times and may need to be refreshed periodically
 #[frb]
-pub async fn fetch_key_package(pubkey: PublicKey) -> Result<Option<Event>, WhitenoiseError> {
+pub async fn fetch_key_package(pubkey: PublicKey) -> Result<Option<Event>, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let relays = whitenoise
         .fetch_relays(pubkey, RelayType::KeyPackage)
@@ -115,7 +121,7 @@ pub async fn fetch_key_package(pubkey: PublicKey) -> Result<Option<Event>, White
     if relays.is_empty() {
         return Ok(None);
     }
-    whitenoise.fetch_key_package_event(pubkey, relays).await
+    Ok(whitenoise.fetch_key_package_event(pubkey, relays).await?)
 }
 
 /// Fetches the connection status of all relays associated with an account.
@@ -131,7 +137,7 @@ pub async fn fetch_key_package(pubkey: PublicKey) -> Result<Option<Event>, White
 ///
 /// # Returns
 /// * `Ok(Vec<(String, String)>)` - Vector of tuples containing each relay URL as a string and its current status as a string
-/// * `Err(WhitenoiseError)` - If there was an error fetching relay statuses or account not found
+/// * `Err(BridgeError)` - If there was an error fetching relay statuses or account not found
 ///
 /// # Notes
 /// * The status reflects the current connection state at the time of the call
@@ -147,9 +153,7 @@ pub async fn fetch_key_package(pubkey: PublicKey) -> Result<Option<Event>, White
 /// }
 /// ```
 #[frb]
-pub async fn fetch_relay_status(
-    pubkey: PublicKey,
-) -> Result<Vec<(String, String)>, WhitenoiseError> {
+pub async fn fetch_relay_status(pubkey: PublicKey) -> Result<Vec<(String, String)>, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let statuses = whitenoise.fetch_relay_status(pubkey).await?;
     let converted_statuses = statuses
@@ -0,0 +1,75 @@
+use flutter_rust_bridge::frb;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use tokio::task::AbortHandle;
+
+/// Monotonic counter used to hand out subscription handles.
+static NEXT_SUBSCRIPTION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Registry of running subscription tasks, keyed by subscription handle.
+static SUBSCRIPTIONS: OnceLock<Mutex<HashMap<u64, AbortHandle>>> = OnceLock::new();
+
+fn subscriptions() -> &'static Mutex<HashMap<u64, AbortHandle>> {
+    SUBSCRIPTIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Spawns a subscription task and registers it so it can be cancelled with `unsubscribe`.
+///
+/// The task receives its own subscription handle so it can report it to the Flutter side
+/// (typically as the first item pushed on its stream). The handle is removed from the
+/// registry once the task finishes on its own, e.g. when the Dart stream is closed.
+pub(crate) fn spawn_subscription<F, Fut>(task: F) -> u64
+where
+    F: FnOnce(u64) -> Fut,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let subscription_id = NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::Relaxed);
+    let future = task(subscription_id);
+    // Hold the registry lock while spawning so a task that finishes immediately
+    // cannot try to deregister itself before it has been registered.
+    let mut registry = subscriptions().lock().unwrap_or_else(|e| e.into_inner());
+    let handle = tokio::spawn(async move {
+        future.await;
+        subscriptions()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&subscription_id);
+    });
+    registry.insert(subscription_id, handle.abort_handle());
+    subscription_id
+}
+
+/// Cancels a running subscription.
+///
+/// Stops the background task that feeds a stream created by one of the `subscribe_*`
+/// functions and releases its resources. The stream on the Flutter side will not
+/// receive any further items after this call.
+///
+/// # Parameters
+/// * `subscription_id` - The handle reported by the stream when it was created
+///
+/// # Returns
+/// * `true` - If a running subscription was found and cancelled
+/// * `false` - If no subscription with this handle exists (already cancelled or finished)
+///
+/// # Example
+/// ```dart
+/// // The first item pushed on the stream carries its handle
+/// final cancelled = await unsubscribe(subscriptionId: subscriptionId);
+/// ```
+#[frb]
+pub fn unsubscribe(subscription_id: u64) -> bool {
+    let handle = subscriptions()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(&subscription_id);
+    match handle {
+        Some(handle) => {
+            handle.abort();
+            true
+        }
+        None => false,
+    }
+}
@@ -11,7 +11,9 @@
 //! - Core system initialization and data management
 //! - Error handling utilities
 
+use crate::api::errors::{BridgeError, BridgeErrorCode};
 use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::path::Path;
 pub use whitenoise::{
@@ -39,7 +41,7 @@ pub struct WhitenoiseConfigData {
 ///
 /// # Nostr Metadata Fields
 /// Most fields correspond to standard Nostr metadata as defined in NIP-01 and related NIPs.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetadataData {
     /// User's name/username
     pub name: Option<String>,
@@ -209,7 +211,7 @@ pub fn convert_metadata_data_to_metadata(metadata_data: &MetadataData) -> Metada
 ///
 /// # Returns
 /// * `Ok(PublicKey)` - Successfully parsed public key
-/// * `Err(WhitenoiseError)` - If parsing fails due to invalid format
+/// * `Err(BridgeError)` - If parsing fails due to invalid format
 ///
 /// # Example
 /// ```rust
@@ -220,8 +222,10 @@ pub fn convert_metadata_data_to_metadata(metadata_data: &MetadataData) -> Metada
 /// let pubkey = public_key_from_string("npub1...".to_string())?;
 /// ```
 #[frb]
-pub fn public_key_from_string(public_key_string: String) -> Result<PublicKey, WhitenoiseError> {
-    PublicKey::parse(&public_key_string).map_err(WhitenoiseError::from)
+pub fn public_key_from_string(public_key_string: String) -> Result<PublicKey, BridgeError> {
+    PublicKey::parse(&public_key_string).map_err(|e| {
+        BridgeError::new(BridgeErrorCode::InvalidPublicKey, "Invalid public key").with_details(e)
+    })
 }
 
 /// Converts a `PublicKey` object to npub (bech32) format.
@@ -234,7 +238,7 @@ pub fn public_key_from_string(public_key_string: String) -> Result<PublicKey, Wh
 ///
 /// # Returns
 /// * `Ok(String)` - npub representation (e.g., "npub1...")
-/// * `Err(WhitenoiseError)` - If conversion fails
+/// * `Err(BridgeError)` - If conversion fails
 ///
 /// # Example
 /// ```rust
@@ -243,8 +247,8 @@ pub fn public_key_from_string(public_key_string: String) -> Result<PublicKey, Wh
 /// println!("npub: {}", npub); // npub1...
 /// ```
 #[frb]
-pub fn npub_from_public_key(public_key: &PublicKey) -> Result<String, WhitenoiseError> {
-    Whitenoise::npub_from_public_key(public_key)
+pub fn npub_from_public_key(public_key: &PublicKey) -> Result<String, BridgeError> {
+    Ok(Whitenoise::npub_from_public_key(public_key)?)
 }
 
 /// Converts a hexadecimal public key string to npub format.
@@ -256,7 +260,7 @@ pub fn npub_from_public_key(public_key: &PublicKey) -> Result<String, Whitenoise
 ///
 /// # Returns
 /// * `Ok(String)` - npub representation
-/// * `Err(WhitenoiseError)` - If parsing or conversion fails
+/// * `Err(BridgeError)` - If parsing or conversion fails
 ///
 /// # Example
 /// ```rust
@@ -264,8 +268,8 @@ pub fn npub_from_public_key(public_key: &PublicKey) -> Result<String, Whitenoise
 /// println!("npub: {}", npub);
 /// ```
 #[frb]
-pub fn npub_from_hex_pubkey(hex_pubkey: &str) -> Result<String, WhitenoiseError> {
-    Whitenoise::npub_from_hex_pubkey(hex_pubkey)
+pub fn npub_from_hex_pubkey(hex_pubkey: &str) -> Result<String, BridgeError> {
+    Ok(Whitenoise::npub_from_hex_pubkey(hex_pubkey)?)
 }
 
 /// Converts an npub (bech32) public key to hexadecimal format.
@@ -278,7 +282,7 @@ pub fn npub_from_hex_pubkey(hex_pubkey: &str) -> Result<String, WhitenoiseError>
 ///
 /// # Returns
 /// * `Ok(String)` - Hexadecimal representation
-/// * `Err(WhitenoiseError)` - If parsing or conversion fails
+/// * `Err(BridgeError)` - If parsing or conversion fails
 ///
 /// # Example
 /// ```rust
@@ -286,8 +290,10 @@ pub fn npub_from_hex_pubkey(hex_pubkey: &str) -> Result<String, WhitenoiseError>
 /// println!("hex: {}", hex);
 /// ```
 #[frb]
-pub fn hex_pubkey_from_npub(npub: &str) -> Result<String, WhitenoiseError> {
-    let pubkey = PublicKey::parse(npub).map_err(WhitenoiseError::from)?;
+pub fn hex_pubkey_from_npub(npub: &str) -> Result<String, BridgeError> {
+    let pubkey = PublicKey::parse(npub).map_err(|e| {
+        BridgeError::new(BridgeErrorCode::InvalidPublicKey, "Invalid npub").with_details(e)
+    })?;
     Ok(pubkey.to_hex())
 }
 
@@ -300,7 +306,7 @@ pub fn hex_pubkey_from_npub(npub: &str) -> Result<String, WhitenoiseError> {
 ///
 /// # Returns
 /// * `Ok(String)` - Hexadecimal representation
-/// * `Err(WhitenoiseError)` - If conversion fails
+/// * `Err(BridgeError)` - If conversion fails
 ///
 /// # Example
 /// ```rust
@@ -308,7 +314,7 @@ pub fn hex_pubkey_from_npub(npub: &str) -> Result<String, WhitenoiseError> {
 /// let hex = hex_pubkey_from_public_key(&pubkey)?;
 /// ```
 #[frb]
-pub fn hex_pubkey_from_public_key(public_key: &PublicKey) -> Result<String, WhitenoiseError> {
+pub fn hex_pubkey_from_public_key(public_key: &PublicKey) -> Result<String, BridgeError> {
     Ok(public_key.to_hex())
 }
 
@@ -322,15 +328,17 @@ pub fn hex_pubkey_from_public_key(public_key: &PublicKey) -> Result<String, Whit
 ///
 /// # Returns
 /// * `Ok(RelayUrl)` - Successfully parsed and validated relay URL
-/// * `Err(WhitenoiseError)` - If the URL is invalid or malformed
+/// * `Err(BridgeError)` - If the URL is invalid or malformed
 ///
 /// # Example
 /// ```rust
 /// let relay = relay_url_from_string("wss://relay.damus.io".to_string())?;
 /// ```
 #[frb]
-pub fn relay_url_from_string(url: String) -> Result<RelayUrl, WhitenoiseError> {
-    RelayUrl::parse(&url).map_err(WhitenoiseError::from)
+pub fn relay_url_from_string(url: String) -> Result<RelayUrl, BridgeError> {
+    RelayUrl::parse(&url).map_err(|e| {
+        BridgeError::new(BridgeErrorCode::InvalidRelayUrl, "Invalid relay URL").with_details(e)
+    })
 }
 
 /// Converts a `RelayUrl` object to its string representation.
@@ -412,21 +420,25 @@ pub fn convert_config_to_data(config: &WhitenoiseConfig) -> WhitenoiseConfigData
 ///
 /// # Returns
 /// * `Ok(Tag)` - Successfully created tag object
-/// * `Err(WhitenoiseError)` - If tag creation fails due to invalid format
+/// * `Err(BridgeError)` - If tag creation fails due to invalid format
 ///
 /// # Example
 /// ```rust
 /// // Create a "p" tag for mentioning a user
 /// let tag = tag_from_vec(vec!["p".to_string(), "pubkey123".to_string()])?;
 /// ```
-pub fn tag_from_vec(vec: Vec<String>) -> Result<Tag, WhitenoiseError> {
-    Tag::parse(vec).map_err(WhitenoiseError::from)
+#[frb]
+pub fn tag_from_vec(vec: Vec<String>) -> Result<Tag, BridgeError> {
+    Tag::parse(vec)
+        .map_err(|e| BridgeError::new(BridgeErrorCode::InvalidInput, "Invalid tag").with_details(e))
 }
 
 /// Converts a `WhitenoiseError` to a human-readable string representation.
 ///
 /// This function provides error information that can be displayed in the Flutter UI,
-/// using debug formatting to include detailed error context.
+/// using debug formatting to include detailed error context. Bridge functions return
+/// `BridgeError`, which already carries a code and message; this is only needed for
+/// a raw `WhitenoiseError` value.
 ///
 /// # Parameters
 /// * `error` - Reference to a WhitenoiseError
@@ -549,7 +561,7 @@ pub fn image_type_webp() -> ImageType {
 ///
 /// # Returns
 /// * `Ok(ImageType)` - Appropriate ImageType for the extension
-/// * `Err(WhitenoiseError)` - If the extension is not supported
+/// * `Err(BridgeError)` - If the extension is not supported
 ///
 /// # Example
 /// ```dart
@@ -557,7 +569,7 @@ pub fn image_type_webp() -> ImageType {
 /// await uploadProfilePicture(pubkey, serverUrl, filePath, imageType);
 /// ```
 #[frb]
-pub fn image_type_from_extension(extension: String) -> Result<ImageType, WhitenoiseError> {
+pub fn image_type_from_extension(extension: String) -> Result<ImageType, BridgeError> {
     let ext = extension.trim_start_matches('.').to_lowercase();
     match ext.as_str() {
         "png" => Ok(ImageType::Png),
@@ -565,13 +577,91 @@ pub fn image_type_from_extension(extension: String) -> Result<ImageType, Whiteno
         "jpeg" => Ok(ImageType::Jpeg),
         "gif" => Ok(ImageType::Gif),
         "webp" => Ok(ImageType::Webp),
-        _ => Err(WhitenoiseError::from(std::io::Error::other(format!(
-            "Unsupported image extension: {}",
-            extension
-        )))),
+        _ => Err(BridgeError::new(
+            BridgeErrorCode::Unsupported,
+            format!("Unsupported image extension: {extension}"),
+        )),
+    }
+}
+
+/// Identifies an image format from the first bytes of a file.
+///
+/// Recognises PNG, JPEG, GIF and WebP. Common formats that cannot be uploaded (HEIC and
+/// HEIF from iOS cameras, AVIF, TIFF, BMP) are reported by name so the user can be told
+/// what to convert.
+pub(crate) fn image_type_from_bytes(bytes: &[u8]) -> Result<ImageType, BridgeError> {
+    let unsupported = |format: &str| {
+        Err(BridgeError::new(
+            BridgeErrorCode::Unsupported,
+            format!("Unsupported image format: {format}. Use PNG, JPEG, GIF or WebP"),
+        ))
+    };
+    match bytes {
+        [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Ok(ImageType::Png),
+        [0xFF, 0xD8, 0xFF, ..] => Ok(ImageType::Jpeg),
+        [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Ok(ImageType::Gif),
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Ok(ImageType::Webp),
+        // ISO base media files: the brand after "ftyp" tells the format apart
+        [_, _, _, _, b'f', b't', b'y', b'p', brand @ ..] if brand.len() >= 4 => match &brand[..4] {
+            b"heic" | b"heix" | b"hevc" | b"hevx" | b"heim" | b"heis" => unsupported("HEIC"),
+            b"mif1" | b"msf1" => unsupported("HEIF"),
+            b"avif" | b"avis" => unsupported("AVIF"),
+            _ => unsupported("unknown"),
+        },
+        [b'I', b'I', 0x2A, 0x00, ..] | [b'M', b'M', 0x00, 0x2A, ..] => unsupported("TIFF"),
+        [b'B', b'M', ..] => unsupported("BMP"),
+        _ => unsupported("unknown"),
     }
 }
 
+/// Returns whether two image types describe the same format (`Jpg` and `Jpeg` are the
+/// same format).
+pub(crate) fn same_image_format(a: ImageType, b: ImageType) -> bool {
+    matches!(
+        (a, b),
+        (ImageType::Png, ImageType::Png)
+            | (ImageType::Gif, ImageType::Gif)
+            | (ImageType::Webp, ImageType::Webp)
+            | (
+                ImageType::Jpeg | ImageType::Jpg,
+                ImageType::Jpeg | ImageType::Jpg
+            )
+    )
+}
+
+/// Determines the ImageType of a file from its contents.
+///
+/// Unlike `image_type_from_extension`, this looks at the file's magic bytes, so misnamed
+/// files (e.g. a PNG saved as `photo.jpg`) are detected correctly.
+///
+/// # Parameters
+/// * `file_path` - The local path to the image file
+///
+/// # Returns
+/// * `Ok(ImageType)` - The detected type (`Jpeg` for JPEG files)
+/// * `Err(BridgeError)` - `Unsupported` if the file is not a PNG, JPEG, GIF or WebP image;
+///   the message names the format when it is recognised (e.g. HEIC), or `Storage` if the
+///   file could not be read
+///
+/// # Example
+/// ```dart
+/// final imageType = await detectImageType(filePath: filePath);
+/// await uploadProfilePicture(pubkey, serverUrl, filePath, imageType);
+/// ```
+#[frb]
+pub fn detect_image_type(file_path: String) -> Result<ImageType, BridgeError> {
+    use std::io::Read;
+
+    let mut header = Vec::with_capacity(16);
+    std::fs::File::open(&file_path)
+        .and_then(|file| file.take(16).read_to_end(&mut header))
+        .map_err(|e| {
+            BridgeError::new(BridgeErrorCode::Storage, "Could not read the image file")
+                .with_details(e)
+        })?;
+    image_type_from_bytes(&header)
+}
+
 /// Returns the default Blossom server URL based on build configuration.
 ///
 /// This function provides the appropriate Blossom server URL for image uploads:
@@ -594,3 +684,63 @@ pub fn get_default_blossom_server_url() -> String {
         "https://blossom.primal.net".to_string()
     }
 }
+
+/// Returns the current time as unix seconds.
+pub(crate) fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(bytes: &[u8]) -> Result<ImageType, BridgeError> {
+        image_type_from_bytes(bytes)
+    }
+
+    #[test]
+    fn detects_supported_images_by_magic_bytes() {
+        assert!(matches!(
+            detect(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0]),
+            Ok(ImageType::Png)
+        ));
+        assert!(matches!(
+            detect(&[0xFF, 0xD8, 0xFF, 0xE0]),
+            Ok(ImageType::Jpeg)
+        ));
+        assert!(matches!(detect(b"GIF87a..."), Ok(ImageType::Gif)));
+        assert!(matches!(detect(b"GIF89a..."), Ok(ImageType::Gif)));
+        assert!(matches!(
+            detect(b"RIFF\0\0\0\0WEBPVP8 "),
+            Ok(ImageType::Webp)
+        ));
+    }
+
+    #[test]
+    fn names_unsupported_formats() {
+        let message = |bytes: &[u8]| detect(bytes).unwrap_err().message;
+        assert!(message(b"\0\0\0\x18ftypheic\0\0").contains("HEIC"));
+        assert!(message(b"\0\0\0\x18ftypmif1\0\0").contains("HEIF"));
+        assert!(message(b"\0\0\0\x18ftypavif\0\0").contains("AVIF"));
+        assert!(message(b"II*\0....").contains("TIFF"));
+        assert!(message(b"BM......").contains("BMP"));
+        assert!(message(b"RIFF\0\0\0\0WAVE").contains("unknown"));
+        assert!(message(b"").contains("unknown"));
+        assert!(matches!(
+            detect(b"GIF88a").unwrap_err().code,
+            BridgeErrorCode::Unsupported
+        ));
+    }
+
+    #[test]
+    fn treats_jpg_and_jpeg_as_the_same_format() {
+        assert!(same_image_format(ImageType::Jpg, ImageType::Jpeg));
+        assert!(same_image_format(ImageType::Jpeg, ImageType::Jpg));
+        assert!(same_image_format(ImageType::Webp, ImageType::Webp));
+        assert!(!same_image_format(ImageType::Png, ImageType::Jpeg));
+        assert!(!same_image_format(ImageType::Gif, ImageType::Webp));
+    }
+}
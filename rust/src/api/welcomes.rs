@@ -1,3 +1,5 @@
+use crate::api::errors::BridgeError;
+use crate::api::events::refresh_events;
 use crate::api::groups::group_id_to_string;
 use flutter_rust_bridge::frb;
 pub use whitenoise::{PublicKey, Welcome, WelcomeState, Whitenoise, WhitenoiseError};
@@ -63,7 +65,7 @@ pub fn convert_welcome_to_data(welcome: &Welcome) -> WelcomeData {
 ///
 /// Returns a `Result` containing:
 /// * `Ok(Vec<WelcomeData>)` - A vector of welcome invitation data if successful
-/// * `Err(WhitenoiseError)` - An error if the operation fails
+/// * `Err(BridgeError)` - An error if the operation fails
 ///
 /// # Errors
 ///
@@ -72,7 +74,7 @@ pub fn convert_welcome_to_data(welcome: &Welcome) -> WelcomeData {
 /// * The network request to fetch welcomes fails
 /// * The account associated with the public key is not found
 #[frb]
-pub async fn fetch_welcomes(pubkey: &PublicKey) -> Result<Vec<WelcomeData>, WhitenoiseError> {
+pub async fn fetch_welcomes(pubkey: &PublicKey) -> Result<Vec<WelcomeData>, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let welcomes = whitenoise.fetch_welcomes(pubkey).await?;
     Ok(welcomes.iter().map(convert_welcome_to_data).collect())
@@ -92,7 +94,7 @@ pub async fn fetch_welcomes(pubkey: &PublicKey) -> Result<Vec<WelcomeData>, Whit
 ///
 /// Returns a `Result` containing:
 /// * `Ok(WelcomeData)` - The welcome invitation data if found
-/// * `Err(WhitenoiseError)` - An error if the operation fails
+/// * `Err(BridgeError)` - An error if the operation fails
 ///
 /// # Errors
 ///
@@ -105,7 +107,7 @@ pub async fn fetch_welcomes(pubkey: &PublicKey) -> Result<Vec<WelcomeData>, Whit
 pub async fn fetch_welcome(
     pubkey: &PublicKey,
     welcome_event_id: String,
-) -> Result<WelcomeData, WhitenoiseError> {
+) -> Result<WelcomeData, BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
     let welcome = whitenoise.fetch_welcome(pubkey, welcome_event_id).await?;
     Ok(convert_welcome_to_data(&welcome))
@@ -127,7 +129,7 @@ pub async fn fetch_welcome(
 ///
 /// Returns a `Result` containing:
 /// * `Ok(())` - Success indicator if the welcome was accepted
-/// * `Err(WhitenoiseError)` - An error if the operation fails
+/// * `Err(BridgeError)` - An error if the operation fails
 ///
 /// # Errors
 ///
@@ -142,9 +144,11 @@ pub async fn fetch_welcome(
 pub async fn accept_welcome(
     pubkey: &PublicKey,
     welcome_event_id: String,
-) -> Result<(), WhitenoiseError> {
+) -> Result<(), BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
-    whitenoise.accept_welcome(pubkey, welcome_event_id).await
+    whitenoise.accept_welcome(pubkey, welcome_event_id).await?;
+    refresh_events();
+    Ok(())
 }
 
 /// Declines a group welcome invitation.
@@ -163,7 +167,7 @@ pub async fn accept_welcome(
 ///
 /// Returns a `Result` containing:
 /// * `Ok(())` - Success indicator if the welcome was declined
-/// * `Err(WhitenoiseError)` - An error if the operation fails
+/// * `Err(BridgeError)` - An error if the operation fails
 ///
 /// # Errors
 ///
@@ -177,7 +181,9 @@ pub async fn accept_welcome(
 pub async fn decline_welcome(
     pubkey: &PublicKey,
     welcome_event_id: String,
-) -> Result<(), WhitenoiseError> {
+) -> Result<(), BridgeError> {
     let whitenoise = Whitenoise::get_instance()?;
-    whitenoise.decline_welcome(pubkey, welcome_event_id).await
+    whitenoise.decline_welcome(pubkey, welcome_event_id).await?;
+    refresh_events();
+    Ok(())
 }
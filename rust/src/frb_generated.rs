@@ -26,8 +26,22 @@
 // Section: imports
 
 use crate::api::accounts::*;
+use crate::api::blossom::*;
+use crate::api::chats::*;
+use crate::api::contacts::*;
+use crate::api::delivery::*;
+use crate::api::errors::*;
+use crate::api::events::*;
 use crate::api::groups::*;
+use crate::api::images::*;
+use crate::api::media::*;
+use crate::api::media_cache::*;
 use crate::api::messages::*;
+use crate::api::metadata_cache::*;
+use crate::api::nip05::*;
+use crate::api::nip19::*;
+use crate::api::outbox::*;
+use crate::api::presence::*;
 use crate::api::relays::*;
 use crate::api::utils::*;
 use crate::api::welcomes::*;
@@ -44,7 +58,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1085588867;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1360144240;
 
 // Section: executor
 
@@ -1060,7 +1074,7 @@ fn wire__crate__api__welcomes__accept_welcome_impl(
             let api_welcome_event_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -1122,7 +1136,7 @@ fn wire__crate__api__contacts__add_contact_impl(
             let api_contact_pubkey = <PublicKey>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -1185,7 +1199,7 @@ fn wire__crate__api__groups__add_members_to_group_impl(
             let api_member_pubkeys = <Vec<PublicKey>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -1220,6 +1234,140 @@ fn wire__crate__api__groups__add_members_to_group_impl(
         },
     )
 }
+fn wire__crate__api__outbox__cancel_pending_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cancel_pending_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_outbox_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::outbox::cancel_pending_message(
+                            &*api_pubkey_guard,
+                            api_outbox_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media_cache__clear_group_media_cache_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_group_media_cache",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::media_cache::clear_group_media_cache(api_group_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media_cache__clear_media_cache_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_media_cache",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::media_cache::clear_media_cache().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__accounts__convert_account_to_data_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -1686,7 +1834,7 @@ fn wire__crate__api__groups__create_group_impl(
             let api_group_description = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_creator_pubkey_guard = None;
                         let decode_indices_ =
@@ -1747,7 +1895,7 @@ fn wire__crate__api__accounts__create_identity_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let output_ok = crate::api::accounts::create_identity().await?;
                         Ok(output_ok)
@@ -1822,7 +1970,7 @@ fn wire__crate__api__welcomes__decline_welcome_impl(
             let api_welcome_event_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -1880,7 +2028,7 @@ fn wire__crate__api__delete_all_data_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let output_ok = crate::api::delete_all_data().await?;
                         Ok(output_ok)
@@ -1891,7 +2039,7 @@ fn wire__crate__api__delete_all_data_impl(
         },
     )
 }
-fn wire__crate__api__accounts__export_account_npub_impl(
+fn wire__crate__api__messages__delete_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1899,7 +2047,7 @@ fn wire__crate__api__accounts__export_account_npub_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "export_account_npub",
+            debug_name: "delete_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1916,9 +2064,11 @@ fn wire__crate__api__accounts__export_account_npub_impl(
             let api_pubkey = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -1939,8 +2089,12 @@ fn wire__crate__api__accounts__export_account_npub_impl(
                             }
                         }
                         let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok =
-                            crate::api::accounts::export_account_npub(&*api_pubkey_guard).await?;
+                        let output_ok = crate::api::messages::delete_message(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                            api_message_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1949,7 +2103,75 @@ fn wire__crate__api__accounts__export_account_npub_impl(
         },
     )
 }
-fn wire__crate__api__accounts__export_account_nsec_impl(
+fn wire__crate__api__utils__detect_image_type_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "detect_image_type",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::errors::BridgeError>((move || {
+                    let output_ok = crate::api::utils::detect_image_type(api_file_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__images__discard_processed_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "discard_processed_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_image = <crate::api::images::ProcessedImageData>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::images::discard_processed_image(api_image);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__media__download_media_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1957,7 +2179,7 @@ fn wire__crate__api__accounts__export_account_nsec_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "export_account_nsec",
+            debug_name: "download_media",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1974,9 +2196,12 @@ fn wire__crate__api__accounts__export_account_nsec_impl(
             let api_pubkey = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_attachment_index = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -1997,8 +2222,13 @@ fn wire__crate__api__accounts__export_account_nsec_impl(
                             }
                         }
                         let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok =
-                            crate::api::accounts::export_account_nsec(&*api_pubkey_guard).await?;
+                        let output_ok = crate::api::media::download_media(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                            api_message_id,
+                            api_attachment_index,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2007,7 +2237,7 @@ fn wire__crate__api__accounts__export_account_nsec_impl(
         },
     )
 }
-fn wire__crate__api__accounts__fetch_account_impl(
+fn wire__crate__api__messages__edit_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2015,7 +2245,7 @@ fn wire__crate__api__accounts__fetch_account_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_account",
+            debug_name: "edit_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2032,9 +2262,12 @@ fn wire__crate__api__accounts__fetch_account_impl(
             let api_pubkey = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_new_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -2055,8 +2288,13 @@ fn wire__crate__api__accounts__fetch_account_impl(
                             }
                         }
                         let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok =
-                            crate::api::accounts::fetch_account(&*api_pubkey_guard).await?;
+                        let output_ok = crate::api::messages::edit_message(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                            api_message_id,
+                            api_new_content,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2065,15 +2303,15 @@ fn wire__crate__api__accounts__fetch_account_impl(
         },
     )
 }
-fn wire__crate__api__accounts__fetch_accounts_impl(
+fn wire__crate__api__nip19__encode_nevent_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_accounts",
+            debug_name: "encode_nevent",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2087,20 +2325,60 @@ fn wire__crate__api__accounts__fetch_accounts_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_event_id = <String>::sse_decode(&mut deserializer);
+            let api_relays = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_author = <Option<String>>::sse_decode(&mut deserializer);
+            let api_kind = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
-                    (move || async move {
-                        let output_ok = crate::api::accounts::fetch_accounts().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
+            move |context| {
+                transform_result_sse::<_, crate::api::errors::BridgeError>((move || {
+                    let output_ok = crate::api::nip19::encode_nevent(
+                        api_event_id,
+                        api_relays,
+                        api_author,
+                        api_kind,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__nip19__encode_nprofile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "encode_nprofile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_relays = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::errors::BridgeError>((move || {
+                    let output_ok = crate::api::nip19::encode_nprofile(api_pubkey, api_relays)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
+fn wire__crate__api__outbox__enqueue_message_to_group_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2108,7 +2386,7 @@ fn wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_aggregated_messages_for_group",
+            debug_name: "enqueue_message_to_group",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2126,9 +2404,12 @@ fn wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
             let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_kind = <u16>::sse_decode(&mut deserializer);
+            let api_tags = <Option<Vec<Tag>>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -2149,11 +2430,14 @@ fn wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
                             }
                         }
                         let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok = crate::api::messages::fetch_aggregated_messages_for_group(
+                        let output_ok = crate::api::outbox::enqueue_message_to_group(
                             &*api_pubkey_guard,
                             api_group_id,
-                        )
-                        .await?;
+                            api_message,
+                            api_kind,
+                            api_tags,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2162,7 +2446,7 @@ fn wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
         },
     )
 }
-fn wire__crate__api__contacts__fetch_contacts_impl(
+fn wire__crate__api__accounts__export_account_npub_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2170,7 +2454,7 @@ fn wire__crate__api__contacts__fetch_contacts_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_contacts",
+            debug_name: "export_account_npub",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2184,12 +2468,34 @@ fn wire__crate__api__contacts__fetch_contacts_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::contacts::fetch_contacts(api_pubkey).await?;
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok =
+                            crate::api::accounts::export_account_npub(&*api_pubkey_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2198,7 +2504,7 @@ fn wire__crate__api__contacts__fetch_contacts_impl(
         },
     )
 }
-fn wire__crate__api__groups__fetch_group_admins_impl(
+fn wire__crate__api__accounts__export_account_nsec_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2206,7 +2512,7 @@ fn wire__crate__api__groups__fetch_group_admins_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_group_admins",
+            debug_name: "export_account_nsec",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2223,10 +2529,9 @@ fn wire__crate__api__groups__fetch_group_admins_impl(
             let api_pubkey = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
-            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -2247,11 +2552,8 @@ fn wire__crate__api__groups__fetch_group_admins_impl(
                             }
                         }
                         let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok = crate::api::groups::fetch_group_admins(
-                            &*api_pubkey_guard,
-                            api_group_id,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::accounts::export_account_nsec(&*api_pubkey_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2260,7 +2562,7 @@ fn wire__crate__api__groups__fetch_group_admins_impl(
         },
     )
 }
-fn wire__crate__api__groups__fetch_group_members_impl(
+fn wire__crate__api__accounts__fetch_account_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2268,7 +2570,7 @@ fn wire__crate__api__groups__fetch_group_members_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_group_members",
+            debug_name: "fetch_account",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2285,10 +2587,9 @@ fn wire__crate__api__groups__fetch_group_members_impl(
             let api_pubkey = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
-            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -2309,11 +2610,8 @@ fn wire__crate__api__groups__fetch_group_members_impl(
                             }
                         }
                         let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok = crate::api::groups::fetch_group_members(
-                            &*api_pubkey_guard,
-                            api_group_id,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::accounts::fetch_account(&*api_pubkey_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2322,7 +2620,7 @@ fn wire__crate__api__groups__fetch_group_members_impl(
         },
     )
 }
-fn wire__crate__api__groups__fetch_groups_impl(
+fn wire__crate__api__accounts__fetch_accounts_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2330,7 +2628,42 @@ fn wire__crate__api__groups__fetch_groups_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_groups",
+            debug_name: "fetch_accounts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::accounts::fetch_accounts().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "fetch_aggregated_messages_for_group",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2347,9 +2680,10 @@ fn wire__crate__api__groups__fetch_groups_impl(
             let api_pubkey = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -2370,8 +2704,11 @@ fn wire__crate__api__groups__fetch_groups_impl(
                             }
                         }
                         let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok =
-                            crate::api::groups::fetch_groups(&*api_pubkey_guard).await?;
+                        let output_ok = crate::api::messages::fetch_aggregated_messages_for_group(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2380,7 +2717,7 @@ fn wire__crate__api__groups__fetch_groups_impl(
         },
     )
 }
-fn wire__crate__api__relays__fetch_key_package_impl(
+fn wire__crate__api__messages__fetch_aggregated_messages_page_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2388,7 +2725,7 @@ fn wire__crate__api__relays__fetch_key_package_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_key_package",
+            debug_name: "fetch_aggregated_messages_page",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2402,12 +2739,46 @@ fn wire__crate__api__relays__fetch_key_package_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            let api_before =
+                <Option<crate::api::messages::MessageCursorData>>::sse_decode(&mut deserializer);
+            let api_after =
+                <Option<crate::api::messages::MessageCursorData>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::relays::fetch_key_package(api_pubkey).await?;
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::messages::fetch_aggregated_messages_page(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                            api_before,
+                            api_after,
+                            api_limit,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2416,7 +2787,7 @@ fn wire__crate__api__relays__fetch_key_package_impl(
         },
     )
 }
-fn wire__crate__api__messages__fetch_messages_for_group_impl(
+fn wire__crate__api__blossom__fetch_blossom_servers_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2424,7 +2795,7 @@ fn wire__crate__api__messages__fetch_messages_for_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_messages_for_group",
+            debug_name: "fetch_blossom_servers",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2441,10 +2812,9 @@ fn wire__crate__api__messages__fetch_messages_for_group_impl(
             let api_pubkey = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
-            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -2465,11 +2835,8 @@ fn wire__crate__api__messages__fetch_messages_for_group_impl(
                             }
                         }
                         let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok = crate::api::messages::fetch_messages_for_group(
-                            &*api_pubkey_guard,
-                            api_group_id,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::blossom::fetch_blossom_servers(&*api_pubkey_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2478,7 +2845,7 @@ fn wire__crate__api__messages__fetch_messages_for_group_impl(
         },
     )
 }
-fn wire__crate__api__accounts__fetch_metadata_impl(
+fn wire__crate__api__metadata_cache__fetch_cached_metadata_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2486,7 +2853,7 @@ fn wire__crate__api__accounts__fetch_metadata_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_metadata",
+            debug_name: "fetch_cached_metadata",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2500,12 +2867,13 @@ fn wire__crate__api__accounts__fetch_metadata_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
+            let api_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::accounts::fetch_metadata(api_pubkey).await?;
+                        let output_ok =
+                            crate::api::metadata_cache::fetch_cached_metadata(api_pubkeys).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2514,7 +2882,7 @@ fn wire__crate__api__accounts__fetch_metadata_impl(
         },
     )
 }
-fn wire__crate__api__accounts__fetch_onboarding_state_impl(
+fn wire__crate__api__chats__fetch_chat_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2522,7 +2890,7 @@ fn wire__crate__api__accounts__fetch_onboarding_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_onboarding_state",
+            debug_name: "fetch_chat_list",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2536,13 +2904,34 @@ fn wire__crate__api__accounts__fetch_onboarding_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
                         let output_ok =
-                            crate::api::accounts::fetch_onboarding_state(api_pubkey).await?;
+                            crate::api::chats::fetch_chat_list(&*api_pubkey_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2551,7 +2940,7 @@ fn wire__crate__api__accounts__fetch_onboarding_state_impl(
         },
     )
 }
-fn wire__crate__api__relays__fetch_relay_status_impl(
+fn wire__crate__api__contacts__fetch_contacts_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2559,7 +2948,7 @@ fn wire__crate__api__relays__fetch_relay_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_relay_status",
+            debug_name: "fetch_contacts",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2576,9 +2965,9 @@ fn wire__crate__api__relays__fetch_relay_status_impl(
             let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::relays::fetch_relay_status(api_pubkey).await?;
+                        let output_ok = crate::api::contacts::fetch_contacts(api_pubkey).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2587,7 +2976,7 @@ fn wire__crate__api__relays__fetch_relay_status_impl(
         },
     )
 }
-fn wire__crate__api__relays__fetch_relays_impl(
+fn wire__crate__api__groups__fetch_group_admins_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2595,7 +2984,7 @@ fn wire__crate__api__relays__fetch_relays_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_relays",
+            debug_name: "fetch_group_admins",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2609,14 +2998,38 @@ fn wire__crate__api__relays__fetch_relays_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
-            let api_relay_type = <RelayType>::sse_decode(&mut deserializer);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::relays::fetch_relays(api_pubkey, api_relay_type).await?;
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::groups::fetch_group_admins(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2625,7 +3038,7 @@ fn wire__crate__api__relays__fetch_relays_impl(
         },
     )
 }
-fn wire__crate__api__welcomes__fetch_welcome_impl(
+fn wire__crate__api__groups__fetch_group_members_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2633,7 +3046,7 @@ fn wire__crate__api__welcomes__fetch_welcome_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_welcome",
+            debug_name: "fetch_group_members",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2650,10 +3063,10 @@ fn wire__crate__api__welcomes__fetch_welcome_impl(
             let api_pubkey = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
-            let api_welcome_event_id = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -2674,9 +3087,9 @@ fn wire__crate__api__welcomes__fetch_welcome_impl(
                             }
                         }
                         let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok = crate::api::welcomes::fetch_welcome(
+                        let output_ok = crate::api::groups::fetch_group_members(
                             &*api_pubkey_guard,
-                            api_welcome_event_id,
+                            api_group_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -2687,7 +3100,7 @@ fn wire__crate__api__welcomes__fetch_welcome_impl(
         },
     )
 }
-fn wire__crate__api__welcomes__fetch_welcomes_impl(
+fn wire__crate__api__groups__fetch_groups_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2695,7 +3108,7 @@ fn wire__crate__api__welcomes__fetch_welcomes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_welcomes",
+            debug_name: "fetch_groups",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2714,7 +3127,7 @@ fn wire__crate__api__welcomes__fetch_welcomes_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -2736,7 +3149,7 @@ fn wire__crate__api__welcomes__fetch_welcomes_impl(
                         }
                         let api_pubkey_guard = api_pubkey_guard.unwrap();
                         let output_ok =
-                            crate::api::welcomes::fetch_welcomes(&*api_pubkey_guard).await?;
+                            crate::api::groups::fetch_groups(&*api_pubkey_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2745,15 +3158,15 @@ fn wire__crate__api__welcomes__fetch_welcomes_impl(
         },
     )
 }
-fn wire__crate__api__utils__get_default_blossom_server_url_impl(
+fn wire__crate__api__relays__fetch_key_package_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_default_blossom_server_url",
+            debug_name: "fetch_key_package",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2767,26 +3180,29 @@ fn wire__crate__api__utils__get_default_blossom_server_url_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::utils::get_default_blossom_server_url())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::relays::fetch_key_package(api_pubkey).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__groups__group_id_from_string_impl(
+fn wire__crate__api__delivery__fetch_message_delivery_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "group_id_from_string",
+            debug_name: "fetch_message_delivery_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2800,26 +3216,55 @@ fn wire__crate__api__groups__group_id_from_string_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_hex_string = <String>::sse_decode(&mut deserializer);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, WhitenoiseError>((move || {
-                    let output_ok = crate::api::groups::group_id_from_string(api_hex_string)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::delivery::fetch_message_delivery_status(
+                            &*api_pubkey_guard,
+                            api_message_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__groups__group_id_to_string_impl(
+fn wire__crate__api__messages__fetch_messages_for_group_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "group_id_to_string",
+            debug_name: "fetch_messages_for_group",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2833,46 +3278,55 @@ fn wire__crate__api__groups__group_id_to_string_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GroupId>,
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_group_id_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_group_id,
-                                0,
-                                false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_group_id_guard = Some(api_group_id.lockable_decode_sync_ref()),
-                            _ => unreachable!(),
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
                         }
-                    }
-                    let api_group_id_guard = api_group_id_guard.unwrap();
-                    let output_ok = Result::<_, ()>::Ok(crate::api::groups::group_id_to_string(
-                        &*api_group_id_guard,
-                    ))?;
-                    Ok(output_ok)
-                })())
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::messages::fetch_messages_for_group(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__utils__hex_pubkey_from_npub_impl(
+fn wire__crate__api__accounts__fetch_metadata_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "hex_pubkey_from_npub",
+            debug_name: "fetch_metadata",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2886,26 +3340,29 @@ fn wire__crate__api__utils__hex_pubkey_from_npub_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_npub = <String>::sse_decode(&mut deserializer);
+            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, WhitenoiseError>((move || {
-                    let output_ok = crate::api::utils::hex_pubkey_from_npub(&api_npub)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::accounts::fetch_metadata(api_pubkey).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__utils__hex_pubkey_from_public_key_impl(
+fn wire__crate__api__metadata_cache__fetch_metadata_batch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "hex_pubkey_from_public_key",
+            debug_name: "fetch_metadata_batch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2919,48 +3376,34 @@ fn wire__crate__api__utils__hex_pubkey_from_public_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_public_key = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
-            >>::sse_decode(&mut deserializer);
+            let api_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_force_refresh = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, WhitenoiseError>((move || {
-                    let mut api_public_key_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_public_key,
-                                0,
-                                false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => {
-                                api_public_key_guard =
-                                    Some(api_public_key.lockable_decode_sync_ref())
-                            }
-                            _ => unreachable!(),
-                        }
-                    }
-                    let api_public_key_guard = api_public_key_guard.unwrap();
-                    let output_ok =
-                        crate::api::utils::hex_pubkey_from_public_key(&*api_public_key_guard)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::metadata_cache::fetch_metadata_batch(
+                            api_pubkeys,
+                            api_force_refresh,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__utils__image_type_from_extension_impl(
+fn wire__crate__api__accounts__fetch_onboarding_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "image_type_from_extension",
+            debug_name: "fetch_onboarding_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2974,26 +3417,30 @@ fn wire__crate__api__utils__image_type_from_extension_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_extension = <String>::sse_decode(&mut deserializer);
+            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, WhitenoiseError>((move || {
-                    let output_ok = crate::api::utils::image_type_from_extension(api_extension)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::accounts::fetch_onboarding_state(api_pubkey).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__utils__image_type_gif_impl(
+fn wire__crate__api__outbox__fetch_outbox_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "image_type_gif",
+            debug_name: "fetch_outbox",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3007,25 +3454,53 @@ fn wire__crate__api__utils__image_type_gif_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_group_id = <Option<GroupId>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::image_type_gif())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok =
+                            crate::api::outbox::fetch_outbox(&*api_pubkey_guard, api_group_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__utils__image_type_jpeg_impl(
+fn wire__crate__api__presence__fetch_presence_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "image_type_jpeg",
+            debug_name: "fetch_presence_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3039,25 +3514,52 @@ fn wire__crate__api__utils__image_type_jpeg_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::image_type_jpeg())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok =
+                            crate::api::presence::fetch_presence_settings(&*api_pubkey_guard)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__utils__image_type_jpg_impl(
+fn wire__crate__api__relays__fetch_relay_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "image_type_jpg",
+            debug_name: "fetch_relay_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3071,25 +3573,29 @@ fn wire__crate__api__utils__image_type_jpg_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::image_type_jpg())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::relays::fetch_relay_status(api_pubkey).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__utils__image_type_png_impl(
+fn wire__crate__api__relays__fetch_relays_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "image_type_png",
+            debug_name: "fetch_relays",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3103,25 +3609,31 @@ fn wire__crate__api__utils__image_type_png_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
+            let api_relay_type = <RelayType>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::image_type_png())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::relays::fetch_relays(api_pubkey, api_relay_type).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__utils__image_type_webp_impl(
+fn wire__crate__api__messages__fetch_thread_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "image_type_webp",
+            debug_name: "fetch_thread",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3135,17 +3647,49 @@ fn wire__crate__api__utils__image_type_webp_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            let api_root_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::image_type_webp())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::messages::fetch_thread(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                            api_root_message_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__initialize_whitenoise_impl(
+fn wire__crate__api__chats__fetch_unread_counts_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3153,7 +3697,7 @@ fn wire__crate__api__initialize_whitenoise_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "initialize_whitenoise",
+            debug_name: "fetch_unread_counts",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3167,12 +3711,34 @@ fn wire__crate__api__initialize_whitenoise_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_config = <WhitenoiseConfig>::sse_decode(&mut deserializer);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::initialize_whitenoise(api_config).await?;
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok =
+                            crate::api::chats::fetch_unread_counts(&*api_pubkey_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3181,7 +3747,7 @@ fn wire__crate__api__initialize_whitenoise_impl(
         },
     )
 }
-fn wire__crate__api__accounts__login_impl(
+fn wire__crate__api__welcomes__fetch_welcome_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3189,7 +3755,7 @@ fn wire__crate__api__accounts__login_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "login",
+            debug_name: "fetch_welcome",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3203,13 +3769,38 @@ fn wire__crate__api__accounts__login_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_nsec_or_hex_privkey = <String>::sse_decode(&mut deserializer);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_welcome_event_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::accounts::login(api_nsec_or_hex_privkey).await?;
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::welcomes::fetch_welcome(
+                            &*api_pubkey_guard,
+                            api_welcome_event_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3218,7 +3809,7 @@ fn wire__crate__api__accounts__login_impl(
         },
     )
 }
-fn wire__crate__api__accounts__logout_impl(
+fn wire__crate__api__welcomes__fetch_welcomes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3226,7 +3817,7 @@ fn wire__crate__api__accounts__logout_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "logout",
+            debug_name: "fetch_welcomes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3245,7 +3836,7 @@ fn wire__crate__api__accounts__logout_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -3266,7 +3857,8 @@ fn wire__crate__api__accounts__logout_impl(
                             }
                         }
                         let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok = crate::api::accounts::logout(&*api_pubkey_guard).await?;
+                        let output_ok =
+                            crate::api::welcomes::fetch_welcomes(&*api_pubkey_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3275,7 +3867,7 @@ fn wire__crate__api__accounts__logout_impl(
         },
     )
 }
-fn wire__crate__api__utils__npub_from_hex_pubkey_impl(
+fn wire__crate__api__utils__get_default_blossom_server_url_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3283,7 +3875,7 @@ fn wire__crate__api__utils__npub_from_hex_pubkey_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "npub_from_hex_pubkey",
+            debug_name: "get_default_blossom_server_url",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3297,26 +3889,26 @@ fn wire__crate__api__utils__npub_from_hex_pubkey_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_hex_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, WhitenoiseError>((move || {
-                    let output_ok = crate::api::utils::npub_from_hex_pubkey(&api_hex_pubkey)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::utils::get_default_blossom_server_url())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__utils__npub_from_public_key_impl(
+fn wire__crate__api__groups__get_or_create_dm_group_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "npub_from_public_key",
+            debug_name: "get_or_create_dm_group",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3330,40 +3922,47 @@ fn wire__crate__api__utils__npub_from_public_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_public_key = <RustOpaqueMoi<
+            let api_pubkey = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
+            let api_other_pubkey = <PublicKey>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, WhitenoiseError>((move || {
-                    let mut api_public_key_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_public_key,
-                                0,
-                                false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => {
-                                api_public_key_guard =
-                                    Some(api_public_key.lockable_decode_sync_ref())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
                             }
-                            _ => unreachable!(),
                         }
-                    }
-                    let api_public_key_guard = api_public_key_guard.unwrap();
-                    let output_ok =
-                        crate::api::utils::npub_from_public_key(&*api_public_key_guard)?;
-                    Ok(output_ok)
-                })())
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::groups::get_or_create_dm_group(
+                            &*api_pubkey_guard,
+                            api_other_pubkey,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__utils__public_key_from_string_impl(
+fn wire__crate__api__groups__group_id_from_string_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3371,7 +3970,7 @@ fn wire__crate__api__utils__public_key_from_string_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "public_key_from_string",
+            debug_name: "group_id_from_string",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3385,27 +3984,26 @@ fn wire__crate__api__utils__public_key_from_string_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_public_key_string = <String>::sse_decode(&mut deserializer);
+            let api_hex_string = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, WhitenoiseError>((move || {
-                    let output_ok =
-                        crate::api::utils::public_key_from_string(api_public_key_string)?;
+                transform_result_sse::<_, crate::api::errors::BridgeError>((move || {
+                    let output_ok = crate::api::groups::group_id_from_string(api_hex_string)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__contacts__query_contacts_impl(
+fn wire__crate__api__groups__group_id_to_string_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "query_contacts",
+            debug_name: "group_id_to_string",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3419,34 +4017,51 @@ fn wire__crate__api__contacts__query_contacts_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
+            let api_group_id = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GroupId>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
-                    (move || async move {
-                        let output_ok = crate::api::contacts::query_contacts(api_pubkey).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__relays__relay_type_inbox_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "relay_type_inbox",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_group_id_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_group_id,
+                                0,
+                                false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_group_id_guard = Some(api_group_id.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_group_id_guard = api_group_id_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(crate::api::groups::group_id_to_string(
+                        &*api_group_id_guard,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__utils__hex_pubkey_from_npub_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hex_pubkey_from_npub",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
                 flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
                     ptr_,
                     rust_vec_len_,
@@ -3455,17 +4070,18 @@ fn wire__crate__api__relays__relay_type_inbox_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_npub = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::relays::relay_type_inbox())?;
+                transform_result_sse::<_, crate::api::errors::BridgeError>((move || {
+                    let output_ok = crate::api::utils::hex_pubkey_from_npub(&api_npub)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__relays__relay_type_key_package_impl(
+fn wire__crate__api__utils__hex_pubkey_from_public_key_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3473,7 +4089,7 @@ fn wire__crate__api__relays__relay_type_key_package_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "relay_type_key_package",
+            debug_name: "hex_pubkey_from_public_key",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3487,18 +4103,40 @@ fn wire__crate__api__relays__relay_type_key_package_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_public_key = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
+                transform_result_sse::<_, crate::api::errors::BridgeError>((move || {
+                    let mut api_public_key_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_public_key,
+                                0,
+                                false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => {
+                                api_public_key_guard =
+                                    Some(api_public_key.lockable_decode_sync_ref())
+                            }
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_public_key_guard = api_public_key_guard.unwrap();
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::relays::relay_type_key_package())?;
+                        crate::api::utils::hex_pubkey_from_public_key(&*api_public_key_guard)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__relays__relay_type_nostr_impl(
+fn wire__crate__api__utils__image_type_from_extension_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3506,7 +4144,7 @@ fn wire__crate__api__relays__relay_type_nostr_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "relay_type_nostr",
+            debug_name: "image_type_from_extension",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3520,17 +4158,18 @@ fn wire__crate__api__relays__relay_type_nostr_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_extension = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::relays::relay_type_nostr())?;
+                transform_result_sse::<_, crate::api::errors::BridgeError>((move || {
+                    let output_ok = crate::api::utils::image_type_from_extension(api_extension)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__utils__relay_url_from_string_impl(
+fn wire__crate__api__utils__image_type_gif_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3538,7 +4177,7 @@ fn wire__crate__api__utils__relay_url_from_string_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "relay_url_from_string",
+            debug_name: "image_type_gif",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3552,26 +4191,25 @@ fn wire__crate__api__utils__relay_url_from_string_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, WhitenoiseError>((move || {
-                    let output_ok = crate::api::utils::relay_url_from_string(api_url)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::image_type_gif())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__contacts__remove_contact_impl(
+fn wire__crate__api__utils__image_type_jpeg_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_contact",
+            debug_name: "image_type_jpeg",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3585,55 +4223,25 @@ fn wire__crate__api__contacts__remove_contact_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_contact_pubkey = <PublicKey>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
-                    (move || async move {
-                        let mut api_pubkey_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_pubkey,
-                                    0,
-                                    false,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => {
-                                    api_pubkey_guard =
-                                        Some(api_pubkey.lockable_decode_async_ref().await)
-                                }
-                                _ => unreachable!(),
-                            }
-                        }
-                        let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok = crate::api::contacts::remove_contact(
-                            &*api_pubkey_guard,
-                            api_contact_pubkey,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::image_type_jpeg())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__groups__remove_members_from_group_impl(
+fn wire__crate__api__utils__image_type_jpg_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_members_from_group",
+            debug_name: "image_type_jpg",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3647,57 +4255,25 @@ fn wire__crate__api__groups__remove_members_from_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
-            let api_member_pubkeys = <Vec<PublicKey>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
-                    (move || async move {
-                        let mut api_pubkey_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_pubkey,
-                                    0,
-                                    false,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => {
-                                    api_pubkey_guard =
-                                        Some(api_pubkey.lockable_decode_async_ref().await)
-                                }
-                                _ => unreachable!(),
-                            }
-                        }
-                        let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok = crate::api::groups::remove_members_from_group(
-                            &*api_pubkey_guard,
-                            api_group_id,
-                            api_member_pubkeys,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::image_type_jpg())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__messages__send_direct_message_nip04_impl(
+fn wire__crate__api__utils__image_type_png_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_direct_message_nip04",
+            debug_name: "image_type_png",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3711,74 +4287,25 @@ fn wire__crate__api__messages__send_direct_message_nip04_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sender = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_receiver = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
-            let api_tags = <Vec<Tag>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
-                    (move || async move {
-                        let mut api_sender_guard = None;
-                        let mut api_receiver_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![
-                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                        &api_sender,
-                                        0,
-                                        false,
-                                    ),
-                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                        &api_receiver,
-                                        1,
-                                        false,
-                                    ),
-                                ],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => {
-                                    api_sender_guard =
-                                        Some(api_sender.lockable_decode_async_ref().await)
-                                }
-                                1 => {
-                                    api_receiver_guard =
-                                        Some(api_receiver.lockable_decode_async_ref().await)
-                                }
-                                _ => unreachable!(),
-                            }
-                        }
-                        let api_sender_guard = api_sender_guard.unwrap();
-                        let api_receiver_guard = api_receiver_guard.unwrap();
-                        let output_ok = crate::api::messages::send_direct_message_nip04(
-                            &*api_sender_guard,
-                            &*api_receiver_guard,
-                            api_content,
-                            api_tags,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::image_type_png())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__messages__send_message_to_group_impl(
+fn wire__crate__api__utils__image_type_webp_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_message_to_group",
+            debug_name: "image_type_webp",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3792,61 +4319,25 @@ fn wire__crate__api__messages__send_message_to_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
-            let api_message = <String>::sse_decode(&mut deserializer);
-            let api_kind = <u16>::sse_decode(&mut deserializer);
-            let api_tags = <Option<Vec<Tag>>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
-                    (move || async move {
-                        let mut api_pubkey_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_pubkey,
-                                    0,
-                                    false,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => {
-                                    api_pubkey_guard =
-                                        Some(api_pubkey.lockable_decode_async_ref().await)
-                                }
-                                _ => unreachable!(),
-                            }
-                        }
-                        let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok = crate::api::messages::send_message_to_group(
-                            &*api_pubkey_guard,
-                            api_group_id,
-                            api_message,
-                            api_kind,
-                            api_tags,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::image_type_webp())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__utils__string_from_relay_url_impl(
+fn wire__crate__api__initialize_whitenoise_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "string_from_relay_url",
+            debug_name: "initialize_whitenoise",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3860,48 +4351,29 @@ fn wire__crate__api__utils__string_from_relay_url_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_relay_url = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayUrl>,
-            >>::sse_decode(&mut deserializer);
+            let api_config = <WhitenoiseConfig>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_relay_url_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_relay_url,
-                                0,
-                                false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => {
-                                api_relay_url_guard = Some(api_relay_url.lockable_decode_sync_ref())
-                            }
-                            _ => unreachable!(),
-                        }
-                    }
-                    let api_relay_url_guard = api_relay_url_guard.unwrap();
-                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::string_from_relay_url(
-                        &*api_relay_url_guard,
-                    ))?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::initialize_whitenoise(api_config).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__utils__tag_from_vec_impl(
+fn wire__crate__api__accounts__login_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tag_from_vec",
+            debug_name: "login",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3915,18 +4387,22 @@ fn wire__crate__api__utils__tag_from_vec_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_vec = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_nsec_or_hex_privkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, WhitenoiseError>((move || {
-                    let output_ok = crate::api::utils::tag_from_vec(api_vec)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::accounts::login(api_nsec_or_hex_privkey).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__contacts__update_contacts_impl(
+fn wire__crate__api__accounts__logout_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3934,7 +4410,7 @@ fn wire__crate__api__contacts__update_contacts_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_contacts",
+            debug_name: "logout",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3951,10 +4427,9 @@ fn wire__crate__api__contacts__update_contacts_impl(
             let api_pubkey = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
-            let api_contact_pubkeys = <Vec<PublicKey>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
@@ -3975,11 +4450,7 @@ fn wire__crate__api__contacts__update_contacts_impl(
                             }
                         }
                         let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok = crate::api::contacts::update_contacts(
-                            &*api_pubkey_guard,
-                            api_contact_pubkeys,
-                        )
-                        .await?;
+                        let output_ok = crate::api::accounts::logout(&*api_pubkey_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3988,7 +4459,7 @@ fn wire__crate__api__contacts__update_contacts_impl(
         },
     )
 }
-fn wire__crate__api__accounts__update_metadata_impl(
+fn wire__crate__api__chats__mark_group_read_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3996,7 +4467,7 @@ fn wire__crate__api__accounts__update_metadata_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_metadata",
+            debug_name: "mark_group_read",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4010,51 +4481,38 @@ fn wire__crate__api__accounts__update_metadata_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_metadata = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MetadataData>,
-            >>::sse_decode(&mut deserializer);
             let api_pubkey = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            let api_up_to_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
-                        let mut api_metadata_guard = None;
                         let mut api_pubkey_guard = None;
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![
-                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                        &api_metadata,
-                                        0,
-                                        false,
-                                    ),
-                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                        &api_pubkey,
-                                        1,
-                                        false,
-                                    ),
-                                ],
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
                             );
                         for i in decode_indices_ {
                             match i {
                                 0 => {
-                                    api_metadata_guard =
-                                        Some(api_metadata.lockable_decode_async_ref().await)
-                                }
-                                1 => {
                                     api_pubkey_guard =
                                         Some(api_pubkey.lockable_decode_async_ref().await)
                                 }
                                 _ => unreachable!(),
                             }
                         }
-                        let api_metadata_guard = api_metadata_guard.unwrap();
                         let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok = crate::api::accounts::update_metadata(
-                            &*api_metadata_guard,
+                        let output_ok = crate::api::chats::mark_group_read(
                             &*api_pubkey_guard,
+                            api_group_id,
+                            api_up_to_message_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -4065,7 +4523,7 @@ fn wire__crate__api__accounts__update_metadata_impl(
         },
     )
 }
-fn wire__crate__api__relays__update_relays_impl(
+fn wire__crate__api__media_cache__media_cache_stats_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4073,7 +4531,7 @@ fn wire__crate__api__relays__update_relays_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_relays",
+            debug_name: "media_cache_stats",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4087,40 +4545,11 @@ fn wire__crate__api__relays__update_relays_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_relay_type = <RelayType>::sse_decode(&mut deserializer);
-            let api_relays = <Vec<RelayUrl>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
                     (move || async move {
-                        let mut api_pubkey_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_pubkey,
-                                    0,
-                                    false,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => {
-                                    api_pubkey_guard =
-                                        Some(api_pubkey.lockable_decode_async_ref().await)
-                                }
-                                _ => unreachable!(),
-                            }
-                        }
-                        let api_pubkey_guard = api_pubkey_guard.unwrap();
-                        let output_ok = crate::api::relays::update_relays(
-                            &*api_pubkey_guard,
-                            api_relay_type,
-                            api_relays,
-                        )
-                        .await?;
+                        let output_ok = crate::api::media_cache::media_cache_stats().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4129,15 +4558,15 @@ fn wire__crate__api__relays__update_relays_impl(
         },
     )
 }
-fn wire__crate__api__accounts__upload_profile_picture_impl(
+fn wire__crate__api__utils__npub_from_hex_pubkey_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "upload_profile_picture",
+            debug_name: "npub_from_hex_pubkey",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4151,30 +4580,18 @@ fn wire__crate__api__accounts__upload_profile_picture_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
-            let api_server_url = <String>::sse_decode(&mut deserializer);
-            let api_file_path = <String>::sse_decode(&mut deserializer);
-            let api_image_type = <ImageType>::sse_decode(&mut deserializer);
+            let api_hex_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, WhitenoiseError>(
-                    (move || async move {
-                        let output_ok = crate::api::accounts::upload_profile_picture(
-                            api_pubkey,
-                            api_server_url,
-                            &api_file_path,
-                            api_image_type,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, crate::api::errors::BridgeError>((move || {
+                    let output_ok = crate::api::utils::npub_from_hex_pubkey(&api_hex_pubkey)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__utils__whitenoise_error_to_string_impl(
+fn wire__crate__api__utils__npub_from_public_key_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4182,7 +4599,7 @@ fn wire__crate__api__utils__whitenoise_error_to_string_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "whitenoise_error_to_string",
+            debug_name: "npub_from_public_key",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4196,60 +4613,1916 @@ fn wire__crate__api__utils__whitenoise_error_to_string_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_error = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WhitenoiseError>,
+            let api_public_key = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_error_guard = None;
+                transform_result_sse::<_, crate::api::errors::BridgeError>((move || {
+                    let mut api_public_key_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
                             flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_error, 0, false,
+                                &api_public_key,
+                                0,
+                                false,
                             ),
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_error_guard = Some(api_error.lockable_decode_sync_ref()),
+                            0 => {
+                                api_public_key_guard =
+                                    Some(api_public_key.lockable_decode_sync_ref())
+                            }
                             _ => unreachable!(),
                         }
                     }
-                    let api_error_guard = api_error_guard.unwrap();
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::utils::whitenoise_error_to_string(&*api_error_guard),
-                    )?;
+                    let api_public_key_guard = api_public_key_guard.unwrap();
+                    let output_ok =
+                        crate::api::utils::npub_from_public_key(&*api_public_key_guard)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-
-// Section: static_checks
-
-#[allow(clippy::unnecessary_literal_unwrap)]
-const _: fn() = || {
-    {
-        let AccountSettings = None::<crate::api::accounts::AccountSettings>.unwrap();
-        let _: bool = AccountSettings.dark_theme;
-        let _: bool = AccountSettings.dev_mode;
-        let _: bool = AccountSettings.lockdown_mode;
-    }
-    {
-        let OnboardingState = None::<crate::api::accounts::OnboardingState>.unwrap();
-        let _: bool = OnboardingState.inbox_relays;
-        let _: bool = OnboardingState.key_package_relays;
-        let _: bool = OnboardingState.key_package_published;
-    }
-};
-
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Account>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+fn wire__crate__api__nip19__parse_nostr_entity_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_nostr_entity",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::errors::BridgeError>((move || {
+                    let output_ok = crate::api::nip19::parse_nostr_entity(api_input)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__images__process_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "process_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_options =
+                <Option<crate::api::images::ImageProcessingOptions>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::images::process_image(api_file_path, api_options).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__utils__public_key_from_string_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "public_key_from_string",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_public_key_string = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::errors::BridgeError>((move || {
+                    let output_ok =
+                        crate::api::utils::public_key_from_string(api_public_key_string)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__contacts__query_contacts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_contacts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::contacts::query_contacts(api_pubkey).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__messages__react_to_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "react_to_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_emoji = <String>::sse_decode(&mut deserializer);
+            let api_custom_emoji_url = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::messages::react_to_message(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                            api_message_id,
+                            api_emoji,
+                            api_custom_emoji_url,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__relay_type_inbox_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relay_type_inbox",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::relays::relay_type_inbox())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__relay_type_key_package_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relay_type_key_package",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::relays::relay_type_key_package())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__relay_type_nostr_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relay_type_nostr",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::relays::relay_type_nostr())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__utils__relay_url_from_string_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relay_url_from_string",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::errors::BridgeError>((move || {
+                    let output_ok = crate::api::utils::relay_url_from_string(api_url)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__contacts__remove_contact_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_contact",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_contact_pubkey = <PublicKey>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::contacts::remove_contact(
+                            &*api_pubkey_guard,
+                            api_contact_pubkey,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__groups__remove_members_from_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_members_from_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            let api_member_pubkeys = <Vec<PublicKey>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::groups::remove_members_from_group(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                            api_member_pubkeys,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__messages__remove_reaction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_reaction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_emoji = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::messages::remove_reaction(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                            api_message_id,
+                            api_emoji,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__messages__reply_to_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reply_to_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            let api_parent_message_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::messages::reply_to_message(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                            api_parent_message_id,
+                            api_content,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__nip05__resolve_nip05_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resolve_nip05",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identifier = <String>::sse_decode(&mut deserializer);
+            let api_force_refresh = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::nip05::resolve_nip05(api_identifier, api_force_refresh)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__outbox__retry_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "retry_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_outbox_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok =
+                            crate::api::outbox::retry_message(&*api_pubkey_guard, api_outbox_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__contacts__search_contacts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_contacts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_include_cached_profiles = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::contacts::search_contacts(
+                            &*api_pubkey_guard,
+                            api_query,
+                            api_include_cached_profiles,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__messages__send_direct_message_nip04_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_direct_message_nip04",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sender = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_receiver = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_tags = <Vec<Tag>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_sender_guard = None;
+                        let mut api_receiver_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_sender,
+                                        0,
+                                        false,
+                                    ),
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_receiver,
+                                        1,
+                                        false,
+                                    ),
+                                ],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_sender_guard =
+                                        Some(api_sender.lockable_decode_async_ref().await)
+                                }
+                                1 => {
+                                    api_receiver_guard =
+                                        Some(api_receiver.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_sender_guard = api_sender_guard.unwrap();
+                        let api_receiver_guard = api_receiver_guard.unwrap();
+                        let output_ok = crate::api::messages::send_direct_message_nip04(
+                            &*api_sender_guard,
+                            &*api_receiver_guard,
+                            api_content,
+                            api_tags,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__messages__send_message_to_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_message_to_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_kind = <u16>::sse_decode(&mut deserializer);
+            let api_tags = <Option<Vec<Tag>>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::messages::send_message_to_group(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                            api_message,
+                            api_kind,
+                            api_tags,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__presence__send_read_receipt_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_read_receipt",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::presence::send_read_receipt(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                            api_message_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__presence__send_typing_indicator_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_typing_indicator",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::presence::send_typing_indicator(
+                            &*api_pubkey_guard,
+                            api_group_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media_cache__set_media_cache_max_size_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_media_cache_max_size",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_max_size_bytes = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::media_cache::set_media_cache_max_size(api_max_size_bytes)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__utils__string_from_relay_url_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "string_from_relay_url",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_relay_url = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayUrl>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_relay_url_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_relay_url,
+                                0,
+                                false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => {
+                                api_relay_url_guard = Some(api_relay_url.lockable_decode_sync_ref())
+                            }
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_relay_url_guard = api_relay_url_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::string_from_relay_url(
+                        &*api_relay_url_guard,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__messages__subscribe_to_group_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_to_group_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::messages::GroupMessageUpdate,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::messages::subscribe_to_group_messages(
+                            api_sink,
+                            api_pubkey,
+                            api_group_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__presence__subscribe_to_group_presence_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_to_group_presence",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::presence::GroupPresenceUpdate,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
+            let api_group_id = <GroupId>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::presence::subscribe_to_group_presence(
+                            api_sink,
+                            api_pubkey,
+                            api_group_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__subscribe_whitenoise_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_whitenoise_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::events::WhitenoiseEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::subscribe_whitenoise_events(api_sink).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__utils__tag_from_vec_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tag_from_vec",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_vec = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::errors::BridgeError>((move || {
+                    let output_ok = crate::api::utils::tag_from_vec(api_vec)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__subscriptions__unsubscribe_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unsubscribe",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscription_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::subscriptions::unsubscribe(
+                        api_subscription_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__blossom__update_blossom_servers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_blossom_servers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_server_list =
+                <crate::api::blossom::BlossomServerListData>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::blossom::update_blossom_servers(
+                            &*api_pubkey_guard,
+                            api_server_list,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__contacts__update_contacts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_contacts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_contact_pubkeys = <Vec<PublicKey>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::contacts::update_contacts(
+                            &*api_pubkey_guard,
+                            api_contact_pubkeys,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__accounts__update_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_metadata = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MetadataData>,
+            >>::sse_decode(&mut deserializer);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_metadata_guard = None;
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_metadata,
+                                        0,
+                                        false,
+                                    ),
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_pubkey,
+                                        1,
+                                        false,
+                                    ),
+                                ],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_metadata_guard =
+                                        Some(api_metadata.lockable_decode_async_ref().await)
+                                }
+                                1 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_metadata_guard = api_metadata_guard.unwrap();
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::accounts::update_metadata(
+                            &*api_metadata_guard,
+                            &*api_pubkey_guard,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__presence__update_presence_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_presence_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_settings =
+                <crate::api::presence::PresenceSettingsData>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::presence::update_presence_settings(
+                            &*api_pubkey_guard,
+                            api_settings,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__update_relays_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_relays",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_relay_type = <RelayType>::sse_decode(&mut deserializer);
+            let api_relays = <Vec<RelayUrl>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok = crate::api::relays::update_relays(
+                            &*api_pubkey_guard,
+                            api_relay_type,
+                            api_relays,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__images__upload_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
+            let api_image = <crate::api::images::ProcessedImageData>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::images::upload_image(api_pubkey, api_image).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__accounts__upload_profile_picture_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_profile_picture",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <PublicKey>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_image_type = <ImageType>::sse_decode(&mut deserializer);
+            let api_options =
+                <Option<crate::api::images::ImageProcessingOptions>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::accounts::upload_profile_picture(
+                            api_pubkey,
+                            &api_file_path,
+                            api_image_type,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__nip05__verify_nip05_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_nip05",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PublicKey>,
+            >>::sse_decode(&mut deserializer);
+            let api_identifier = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::errors::BridgeError>(
+                    (move || async move {
+                        let mut api_pubkey_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_pubkey,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_pubkey_guard =
+                                        Some(api_pubkey.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_pubkey_guard = api_pubkey_guard.unwrap();
+                        let output_ok =
+                            crate::api::nip05::verify_nip05(&*api_pubkey_guard, api_identifier)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__utils__whitenoise_error_to_string_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "whitenoise_error_to_string",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_error = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WhitenoiseError>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_error_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_error, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_error_guard = Some(api_error.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_error_guard = api_error_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::utils::whitenoise_error_to_string(&*api_error_guard),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: static_checks
+
+#[allow(clippy::unnecessary_literal_unwrap)]
+const _: fn() = || {
+    {
+        let AccountSettings = None::<crate::api::accounts::AccountSettings>.unwrap();
+        let _: bool = AccountSettings.dark_theme;
+        let _: bool = AccountSettings.dev_mode;
+        let _: bool = AccountSettings.lockdown_mode;
+    }
+    {
+        let OnboardingState = None::<crate::api::accounts::OnboardingState>.unwrap();
+        let _: bool = OnboardingState.inbox_relays;
+        let _: bool = OnboardingState.key_package_relays;
+        let _: bool = OnboardingState.key_package_published;
+    }
+};
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Account>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChatMessage>
 );
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
@@ -4297,6 +6570,14 @@ flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
 
 // Section: dart2rust
 
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
 impl SseDecode for Account {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4405,28 +6686,38 @@ impl SseDecode for WhitenoiseConfig {
     }
 }
 
-impl SseDecode for WhitenoiseError {
+impl SseDecode for std::collections::HashMap<PublicKey, Option<MetadataData>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WhitenoiseError>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut inner = <Vec<(PublicKey, Option<MetadataData>)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
     }
 }
 
-impl SseDecode for std::collections::HashMap<PublicKey, Option<MetadataData>> {
+impl SseDecode for std::collections::HashMap<String, String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<(PublicKey, Option<MetadataData>)>>::sse_decode(deserializer);
+        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
         return inner.into_iter().collect();
     }
 }
 
-impl SseDecode for std::collections::HashMap<String, String> {
+impl SseDecode
+    for std::collections::HashMap<String, crate::api::metadata_cache::CachedMetadataData>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
+        let mut inner = <Vec<(String, crate::api::metadata_cache::CachedMetadataData)>>::sse_decode(
+            deserializer,
+        );
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode for std::collections::HashMap<String, u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<(String, u32)>>::sse_decode(deserializer);
         return inner.into_iter().collect();
     }
 }
@@ -4579,6 +6870,45 @@ impl SseDecode
     }
 }
 
+impl SseDecode
+    for StreamSink<
+        crate::api::messages::GroupMessageUpdate,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::presence::GroupPresenceUpdate,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::events::WhitenoiseEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4617,6 +6947,18 @@ impl SseDecode for crate::api::accounts::AccountSettings {
     }
 }
 
+impl SseDecode for crate::api::blossom::BlossomServerListData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_servers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_mirrorUploads = <bool>::sse_decode(deserializer);
+        return crate::api::blossom::BlossomServerListData {
+            servers: var_servers,
+            mirror_uploads: var_mirrorUploads,
+        };
+    }
+}
+
 impl SseDecode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4624,6 +6966,67 @@ impl SseDecode for bool {
     }
 }
 
+impl SseDecode for crate::api::errors::BridgeError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_code = <crate::api::errors::BridgeErrorCode>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_details = <Option<String>>::sse_decode(deserializer);
+        let mut var_outboxId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::errors::BridgeError {
+            code: var_code,
+            message: var_message,
+            details: var_details,
+            outbox_id: var_outboxId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::errors::BridgeErrorCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::errors::BridgeErrorCode::NotInitialized,
+            1 => crate::api::errors::BridgeErrorCode::AccountNotFound,
+            2 => crate::api::errors::BridgeErrorCode::Unauthorized,
+            3 => crate::api::errors::BridgeErrorCode::GroupNotFound,
+            4 => crate::api::errors::BridgeErrorCode::WelcomeNotFound,
+            5 => crate::api::errors::BridgeErrorCode::MessageNotFound,
+            6 => crate::api::errors::BridgeErrorCode::MemberNotInGroup,
+            7 => crate::api::errors::BridgeErrorCode::InvalidPublicKey,
+            8 => crate::api::errors::BridgeErrorCode::InvalidHex,
+            9 => crate::api::errors::BridgeErrorCode::InvalidRelayUrl,
+            10 => crate::api::errors::BridgeErrorCode::InvalidInput,
+            11 => crate::api::errors::BridgeErrorCode::Unsupported,
+            12 => crate::api::errors::BridgeErrorCode::RelayUnreachable,
+            13 => crate::api::errors::BridgeErrorCode::NoKeyPackage,
+            14 => crate::api::errors::BridgeErrorCode::MlsError,
+            15 => crate::api::errors::BridgeErrorCode::Network,
+            16 => crate::api::errors::BridgeErrorCode::Storage,
+            17 => crate::api::errors::BridgeErrorCode::Configuration,
+            18 => crate::api::errors::BridgeErrorCode::Internal,
+            _ => unreachable!("Invalid variant for BridgeErrorCode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::metadata_cache::CachedMetadataData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_metadata = <Option<MetadataData>>::sse_decode(deserializer);
+        let mut var_fetchedAt = <u64>::sse_decode(deserializer);
+        let mut var_isStale = <bool>::sse_decode(deserializer);
+        return crate::api::metadata_cache::CachedMetadataData {
+            pubkey: var_pubkey,
+            metadata: var_metadata,
+            fetched_at: var_fetchedAt,
+            is_stale: var_isStale,
+        };
+    }
+}
+
 impl SseDecode for crate::api::messages::ChatMessageData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4640,6 +7043,11 @@ impl SseDecode for crate::api::messages::ChatMessageData {
         let mut var_reactions =
             <crate::api::messages::ReactionSummaryData>::sse_decode(deserializer);
         let mut var_kind = <u16>::sse_decode(deserializer);
+        let mut var_isEdited = <bool>::sse_decode(deserializer);
+        let mut var_editHistory =
+            <Vec<crate::api::messages::MessageVersionData>>::sse_decode(deserializer);
+        let mut var_attachments =
+            <Vec<crate::api::media::MediaAttachmentData>>::sse_decode(deserializer);
         return crate::api::messages::ChatMessageData {
             id: var_id,
             pubkey: var_pubkey,
@@ -4652,6 +7060,88 @@ impl SseDecode for crate::api::messages::ChatMessageData {
             content_tokens: var_contentTokens,
             reactions: var_reactions,
             kind: var_kind,
+            is_edited: var_isEdited,
+            edit_history: var_editHistory,
+            attachments: var_attachments,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chats::ChatSummaryData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_group = <crate::api::groups::GroupData>::sse_decode(deserializer);
+        let mut var_lastMessageId = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastMessagePreview = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastMessageAuthor = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastActivityAt = <Option<u64>>::sse_decode(deserializer);
+        let mut var_unreadCount = <u32>::sse_decode(deserializer);
+        let mut var_memberCount = <u32>::sse_decode(deserializer);
+        let mut var_dmCounterpartPubkey = <Option<String>>::sse_decode(deserializer);
+        let mut var_dmCounterpartMetadata = <Option<MetadataData>>::sse_decode(deserializer);
+        return crate::api::chats::ChatSummaryData {
+            group: var_group,
+            last_message_id: var_lastMessageId,
+            last_message_preview: var_lastMessagePreview,
+            last_message_author: var_lastMessageAuthor,
+            last_activity_at: var_lastActivityAt,
+            unread_count: var_unreadCount,
+            member_count: var_memberCount,
+            dm_counterpart_pubkey: var_dmCounterpartPubkey,
+            dm_counterpart_metadata: var_dmCounterpartMetadata,
+        };
+    }
+}
+
+impl SseDecode for crate::api::contacts::ContactSearchResultData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_npub = <String>::sse_decode(deserializer);
+        let mut var_metadata = <Option<MetadataData>>::sse_decode(deserializer);
+        let mut var_isContact = <bool>::sse_decode(deserializer);
+        let mut var_score = <u32>::sse_decode(deserializer);
+        return crate::api::contacts::ContactSearchResultData {
+            pubkey: var_pubkey,
+            npub: var_npub,
+            metadata: var_metadata,
+            is_contact: var_isContact,
+            score: var_score,
+        };
+    }
+}
+
+impl SseDecode for crate::api::delivery::DeliveryState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::delivery::DeliveryState::Pending,
+            1 => crate::api::delivery::DeliveryState::Sent,
+            2 => crate::api::delivery::DeliveryState::Failed,
+            _ => unreachable!("Invalid variant for DeliveryState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::delivery::DeliveryStatusData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_outboxId = <String>::sse_decode(deserializer);
+        let mut var_messageId = <Option<String>>::sse_decode(deserializer);
+        let mut var_groupId = <String>::sse_decode(deserializer);
+        let mut var_state = <crate::api::delivery::DeliveryState>::sse_decode(deserializer);
+        let mut var_attempts = <u32>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <u64>::sse_decode(deserializer);
+        return crate::api::delivery::DeliveryStatusData {
+            outbox_id: var_outboxId,
+            message_id: var_messageId,
+            group_id: var_groupId,
+            state: var_state,
+            attempts: var_attempts,
+            error: var_error,
+            updated_at: var_updatedAt,
         };
     }
 }
@@ -4677,6 +7167,7 @@ impl SseDecode for crate::api::groups::GroupData {
         let mut var_nostrGroupId = <String>::sse_decode(deserializer);
         let mut var_name = <String>::sse_decode(deserializer);
         let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_imageUrl = <Option<String>>::sse_decode(deserializer);
         let mut var_adminPubkeys = <Vec<String>>::sse_decode(deserializer);
         let mut var_lastMessageId = <Option<String>>::sse_decode(deserializer);
         let mut var_lastMessageAt = <Option<u64>>::sse_decode(deserializer);
@@ -4688,6 +7179,7 @@ impl SseDecode for crate::api::groups::GroupData {
             nostr_group_id: var_nostrGroupId,
             name: var_name,
             description: var_description,
+            image_url: var_imageUrl,
             admin_pubkeys: var_adminPubkeys,
             last_message_id: var_lastMessageId,
             last_message_at: var_lastMessageAt,
@@ -4698,6 +7190,95 @@ impl SseDecode for crate::api::groups::GroupData {
     }
 }
 
+impl SseDecode for crate::api::messages::GroupMessageUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_subscriptionId = <u64>::sse_decode(deserializer);
+                return crate::api::messages::GroupMessageUpdate::Subscribed {
+                    subscription_id: var_subscriptionId,
+                };
+            }
+            1 => {
+                let mut var_message =
+                    <crate::api::messages::ChatMessageData>::sse_decode(deserializer);
+                return crate::api::messages::GroupMessageUpdate::NewMessage {
+                    message: var_message,
+                };
+            }
+            2 => {
+                let mut var_message =
+                    <crate::api::messages::ChatMessageData>::sse_decode(deserializer);
+                return crate::api::messages::GroupMessageUpdate::MessageEdited {
+                    message: var_message,
+                };
+            }
+            3 => {
+                let mut var_messageId = <String>::sse_decode(deserializer);
+                return crate::api::messages::GroupMessageUpdate::MessageDeleted {
+                    message_id: var_messageId,
+                };
+            }
+            4 => {
+                let mut var_messageId = <String>::sse_decode(deserializer);
+                return crate::api::messages::GroupMessageUpdate::MessageRemoved {
+                    message_id: var_messageId,
+                };
+            }
+            5 => {
+                let mut var_messageId = <String>::sse_decode(deserializer);
+                let mut var_reactions =
+                    <crate::api::messages::ReactionSummaryData>::sse_decode(deserializer);
+                return crate::api::messages::GroupMessageUpdate::ReactionsUpdated {
+                    message_id: var_messageId,
+                    reactions: var_reactions,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::presence::GroupPresenceUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_subscriptionId = <u64>::sse_decode(deserializer);
+                return crate::api::presence::GroupPresenceUpdate::Subscribed {
+                    subscription_id: var_subscriptionId,
+                };
+            }
+            1 => {
+                let mut var_memberPubkey = <String>::sse_decode(deserializer);
+                let mut var_expiresAt = <u64>::sse_decode(deserializer);
+                return crate::api::presence::GroupPresenceUpdate::Typing {
+                    member_pubkey: var_memberPubkey,
+                    expires_at: var_expiresAt,
+                };
+            }
+            2 => {
+                let mut var_memberPubkey = <String>::sse_decode(deserializer);
+                let mut var_messageId = <String>::sse_decode(deserializer);
+                let mut var_readAt = <u64>::sse_decode(deserializer);
+                return crate::api::presence::GroupPresenceUpdate::ReadReceipt {
+                    member_pubkey: var_memberPubkey,
+                    message_id: var_messageId,
+                    read_at: var_readAt,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseDecode for crate::api::groups::GroupState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4723,82 +7304,178 @@ impl SseDecode for crate::api::groups::GroupType {
     }
 }
 
-impl SseDecode for i32 {
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::images::ImageProcessingOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxDimension = <u32>::sse_decode(deserializer);
+        let mut var_convertToWebp = <bool>::sse_decode(deserializer);
+        let mut var_quality = <u8>::sse_decode(deserializer);
+        let mut var_thumbnailDimension = <u32>::sse_decode(deserializer);
+        return crate::api::images::ImageProcessingOptions {
+            max_dimension: var_maxDimension,
+            convert_to_webp: var_convertToWebp,
+            quality: var_quality,
+            thumbnail_dimension: var_thumbnailDimension,
+        };
+    }
+}
+
+impl SseDecode for Vec<PublicKey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<PublicKey>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<RelayUrl> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<RelayUrl>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Tag> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Tag>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::accounts::AccountData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::accounts::AccountData>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::messages::ChatMessageData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::messages::ChatMessageData>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for Vec<PublicKey> {
+impl SseDecode for Vec<crate::api::chats::ChatSummaryData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<PublicKey>::sse_decode(deserializer));
+            ans_.push(<crate::api::chats::ChatSummaryData>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<RelayUrl> {
+impl SseDecode for Vec<crate::api::contacts::ContactSearchResultData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<RelayUrl>::sse_decode(deserializer));
+            ans_.push(<crate::api::contacts::ContactSearchResultData>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<Tag> {
+impl SseDecode for Vec<crate::api::messages::EmojiReactionData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<Tag>::sse_decode(deserializer));
+            ans_.push(<crate::api::messages::EmojiReactionData>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseDecode for Vec<crate::api::groups::GroupData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+            ans_.push(<crate::api::groups::GroupData>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::accounts::AccountData> {
+impl SseDecode for Vec<Vec<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::accounts::AccountData>::sse_decode(
-                deserializer,
-            ));
+            ans_.push(<Vec<String>>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::messages::ChatMessageData> {
+impl SseDecode for Vec<crate::api::media::MediaAttachmentData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::messages::ChatMessageData>::sse_decode(
+            ans_.push(<crate::api::media::MediaAttachmentData>::sse_decode(
                 deserializer,
             ));
         }
@@ -4806,13 +7483,13 @@ impl SseDecode for Vec<crate::api::messages::ChatMessageData> {
     }
 }
 
-impl SseDecode for Vec<crate::api::messages::EmojiReactionData> {
+impl SseDecode for Vec<crate::api::messages::MessageVersionData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::messages::EmojiReactionData>::sse_decode(
+            ans_.push(<crate::api::messages::MessageVersionData>::sse_decode(
                 deserializer,
             ));
         }
@@ -4820,25 +7497,27 @@ impl SseDecode for Vec<crate::api::messages::EmojiReactionData> {
     }
 }
 
-impl SseDecode for Vec<crate::api::groups::GroupData> {
+impl SseDecode for Vec<crate::api::messages::MessageWithTokensData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::groups::GroupData>::sse_decode(deserializer));
+            ans_.push(<crate::api::messages::MessageWithTokensData>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::messages::MessageWithTokensData> {
+impl SseDecode for Vec<crate::api::outbox::OutboxMessageData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::messages::MessageWithTokensData>::sse_decode(
+            ans_.push(<crate::api::outbox::OutboxMessageData>::sse_decode(
                 deserializer,
             ));
         }
@@ -4872,6 +7551,22 @@ impl SseDecode for Vec<(PublicKey, Option<MetadataData>)> {
     }
 }
 
+impl SseDecode for Vec<(String, crate::api::metadata_cache::CachedMetadataData)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <(String, crate::api::metadata_cache::CachedMetadataData)>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4884,6 +7579,18 @@ impl SseDecode for Vec<(String, String)> {
     }
 }
 
+impl SseDecode for Vec<(String, u32)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, u32)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::messages::SerializableTokenData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4926,6 +7633,82 @@ impl SseDecode for Vec<crate::api::welcomes::WelcomeData> {
     }
 }
 
+impl SseDecode for crate::api::media::MediaAttachmentData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_sha256 = <String>::sse_decode(deserializer);
+        let mut var_size = <Option<u64>>::sse_decode(deserializer);
+        let mut var_dimensions = <Option<String>>::sse_decode(deserializer);
+        let mut var_blurhash = <Option<String>>::sse_decode(deserializer);
+        let mut var_isEncrypted = <bool>::sse_decode(deserializer);
+        return crate::api::media::MediaAttachmentData {
+            url: var_url,
+            mime_type: var_mimeType,
+            sha256: var_sha256,
+            size: var_size,
+            dimensions: var_dimensions,
+            blurhash: var_blurhash,
+            is_encrypted: var_isEncrypted,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media_cache::MediaCacheStatsData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fileCount = <u32>::sse_decode(deserializer);
+        let mut var_totalSizeBytes = <u64>::sse_decode(deserializer);
+        let mut var_maxSizeBytes = <u64>::sse_decode(deserializer);
+        return crate::api::media_cache::MediaCacheStatsData {
+            file_count: var_fileCount,
+            total_size_bytes: var_totalSizeBytes,
+            max_size_bytes: var_maxSizeBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::messages::MessageCursorData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        let mut var_messageId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::messages::MessageCursorData {
+            created_at: var_createdAt,
+            message_id: var_messageId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::messages::MessagePageData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_messages =
+            <Vec<crate::api::messages::ChatMessageData>>::sse_decode(deserializer);
+        let mut var_nextCursor =
+            <Option<crate::api::messages::MessageCursorData>>::sse_decode(deserializer);
+        let mut var_hasMore = <bool>::sse_decode(deserializer);
+        return crate::api::messages::MessagePageData {
+            messages: var_messages,
+            next_cursor: var_nextCursor,
+            has_more: var_hasMore,
+        };
+    }
+}
+
+impl SseDecode for crate::api::messages::MessageVersionData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        return crate::api::messages::MessageVersionData {
+            content: var_content,
+            created_at: var_createdAt,
+        };
+    }
+}
+
 impl SseDecode for crate::api::messages::MessageWithTokensData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4946,6 +7729,80 @@ impl SseDecode for crate::api::messages::MessageWithTokensData {
     }
 }
 
+impl SseDecode for crate::api::nip05::Nip05ProfileData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_identifier = <String>::sse_decode(deserializer);
+        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_relays = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::nip05::Nip05ProfileData {
+            identifier: var_identifier,
+            pubkey: var_pubkey,
+            relays: var_relays,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nip19::NostrEntity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_pubkey = <String>::sse_decode(deserializer);
+                return crate::api::nip19::NostrEntity::Pubkey { pubkey: var_pubkey };
+            }
+            1 => {
+                let mut var_pubkey = <String>::sse_decode(deserializer);
+                let mut var_relays = <Vec<String>>::sse_decode(deserializer);
+                return crate::api::nip19::NostrEntity::Profile {
+                    pubkey: var_pubkey,
+                    relays: var_relays,
+                };
+            }
+            2 => {
+                let mut var_eventId = <String>::sse_decode(deserializer);
+                return crate::api::nip19::NostrEntity::Note {
+                    event_id: var_eventId,
+                };
+            }
+            3 => {
+                let mut var_eventId = <String>::sse_decode(deserializer);
+                let mut var_relays = <Vec<String>>::sse_decode(deserializer);
+                let mut var_author = <Option<String>>::sse_decode(deserializer);
+                let mut var_kind = <Option<u32>>::sse_decode(deserializer);
+                return crate::api::nip19::NostrEntity::Event {
+                    event_id: var_eventId,
+                    relays: var_relays,
+                    author: var_author,
+                    kind: var_kind,
+                };
+            }
+            4 => {
+                let mut var_identifier = <String>::sse_decode(deserializer);
+                let mut var_pubkey = <String>::sse_decode(deserializer);
+                let mut var_kind = <u32>::sse_decode(deserializer);
+                let mut var_relays = <Vec<String>>::sse_decode(deserializer);
+                return crate::api::nip19::NostrEntity::Address {
+                    identifier: var_identifier,
+                    pubkey: var_pubkey,
+                    kind: var_kind,
+                    relays: var_relays,
+                };
+            }
+            5 => {
+                let mut var_secretKey = <String>::sse_decode(deserializer);
+                return crate::api::nip19::NostrEntity::SecretKey {
+                    secret_key: var_secretKey,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseDecode for crate::api::accounts::OnboardingState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4971,47 +7828,211 @@ impl SseDecode for Option<String> {
     }
 }
 
-impl SseDecode for Option<Event> {
+impl SseDecode for Option<Event> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Event>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<GroupId> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<GroupId>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<MetadataData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<MetadataData>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::delivery::DeliveryStatusData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::delivery::DeliveryStatusData>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::images::ImageProcessingOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::images::ImageProcessingOptions>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::messages::MessageCursorData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::messages::MessageCursorData>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::nip05::Nip05ProfileData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::nip05::Nip05ProfileData>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<Tag>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<Tag>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::outbox::OutboxMessageData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_groupId = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_kind = <u16>::sse_decode(deserializer);
+        let mut var_tags = <Vec<Vec<String>>>::sse_decode(deserializer);
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        let mut var_state = <crate::api::outbox::OutboxState>::sse_decode(deserializer);
+        let mut var_attempts = <u32>::sse_decode(deserializer);
+        let mut var_nextAttemptAt = <u64>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        return crate::api::outbox::OutboxMessageData {
+            id: var_id,
+            group_id: var_groupId,
+            content: var_content,
+            kind: var_kind,
+            tags: var_tags,
+            created_at: var_createdAt,
+            state: var_state,
+            attempts: var_attempts,
+            next_attempt_at: var_nextAttemptAt,
+            last_error: var_lastError,
+        };
+    }
+}
+
+impl SseDecode for crate::api::outbox::OutboxState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Event>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::outbox::OutboxState::Pending,
+            1 => crate::api::outbox::OutboxState::Failed,
+            _ => unreachable!("Invalid variant for OutboxState: {}", inner),
+        };
     }
 }
 
-impl SseDecode for Option<MetadataData> {
+impl SseDecode for crate::api::nip19::ParsedNostrEntityData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<MetadataData>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        let mut var_entity = <crate::api::nip19::NostrEntity>::sse_decode(deserializer);
+        let mut var_isSensitive = <bool>::sse_decode(deserializer);
+        return crate::api::nip19::ParsedNostrEntityData {
+            entity: var_entity,
+            is_sensitive: var_isSensitive,
+        };
     }
 }
 
-impl SseDecode for Option<u64> {
+impl SseDecode for crate::api::presence::PresenceSettingsData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        let mut var_disableReadReceipts = <bool>::sse_decode(deserializer);
+        let mut var_disableTypingIndicators = <bool>::sse_decode(deserializer);
+        return crate::api::presence::PresenceSettingsData {
+            disable_read_receipts: var_disableReadReceipts,
+            disable_typing_indicators: var_disableTypingIndicators,
+        };
     }
 }
 
-impl SseDecode for Option<Vec<Tag>> {
+impl SseDecode for crate::api::images::ProcessedImageData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<Tag>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        let mut var_filePath = <String>::sse_decode(deserializer);
+        let mut var_imageType = <ImageType>::sse_decode(deserializer);
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_sha256 = <String>::sse_decode(deserializer);
+        let mut var_blurhash = <String>::sse_decode(deserializer);
+        let mut var_thumbnailPath = <Option<String>>::sse_decode(deserializer);
+        return crate::api::images::ProcessedImageData {
+            file_path: var_filePath,
+            image_type: var_imageType,
+            mime_type: var_mimeType,
+            width: var_width,
+            height: var_height,
+            size: var_size,
+            sha256: var_sha256,
+            blurhash: var_blurhash,
+            thumbnail_path: var_thumbnailPath,
+        };
     }
 }
 
@@ -5038,6 +8059,16 @@ impl SseDecode for (PublicKey, Option<MetadataData>) {
     }
 }
 
+impl SseDecode for (String, crate::api::metadata_cache::CachedMetadataData) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 =
+            <crate::api::metadata_cache::CachedMetadataData>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
 impl SseDecode for (String, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5047,6 +8078,15 @@ impl SseDecode for (String, String) {
     }
 }
 
+impl SseDecode for (String, u32) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <u32>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
 impl SseDecode for crate::api::messages::SerializableTokenData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5092,6 +8132,20 @@ impl SseDecode for () {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
 }
 
+impl SseDecode for crate::api::images::UploadedImageData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_mirrorUrls = <Vec<String>>::sse_decode(deserializer);
+        let mut var_thumbnailUrl = <Option<String>>::sse_decode(deserializer);
+        return crate::api::images::UploadedImageData {
+            url: var_url,
+            mirror_urls: var_mirrorUrls,
+            thumbnail_url: var_thumbnailUrl,
+        };
+    }
+}
+
 impl SseDecode for crate::api::messages::UserReactionData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5169,6 +8223,135 @@ impl SseDecode for crate::api::utils::WhitenoiseConfigData {
     }
 }
 
+impl SseDecode for crate::api::events::WhitenoiseEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_subscriptionId = <u64>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::Subscribed {
+                    subscription_id: var_subscriptionId,
+                };
+            }
+            1 => {
+                let mut var_accountPubkey = <String>::sse_decode(deserializer);
+                let mut var_welcome = <crate::api::welcomes::WelcomeData>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::WelcomeReceived {
+                    account_pubkey: var_accountPubkey,
+                    welcome: var_welcome,
+                };
+            }
+            2 => {
+                let mut var_accountPubkey = <String>::sse_decode(deserializer);
+                let mut var_group = <crate::api::groups::GroupData>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::GroupCreated {
+                    account_pubkey: var_accountPubkey,
+                    group: var_group,
+                };
+            }
+            3 => {
+                let mut var_accountPubkey = <String>::sse_decode(deserializer);
+                let mut var_group = <crate::api::groups::GroupData>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::GroupUpdated {
+                    account_pubkey: var_accountPubkey,
+                    group: var_group,
+                };
+            }
+            4 => {
+                let mut var_accountPubkey = <String>::sse_decode(deserializer);
+                let mut var_group = <crate::api::groups::GroupData>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::GroupStateChanged {
+                    account_pubkey: var_accountPubkey,
+                    group: var_group,
+                };
+            }
+            5 => {
+                let mut var_accountPubkey = <String>::sse_decode(deserializer);
+                let mut var_groupId = <String>::sse_decode(deserializer);
+                let mut var_memberPubkey = <String>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::MemberAdded {
+                    account_pubkey: var_accountPubkey,
+                    group_id: var_groupId,
+                    member_pubkey: var_memberPubkey,
+                };
+            }
+            6 => {
+                let mut var_accountPubkey = <String>::sse_decode(deserializer);
+                let mut var_groupId = <String>::sse_decode(deserializer);
+                let mut var_memberPubkey = <String>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::MemberRemoved {
+                    account_pubkey: var_accountPubkey,
+                    group_id: var_groupId,
+                    member_pubkey: var_memberPubkey,
+                };
+            }
+            7 => {
+                let mut var_accountPubkey = <String>::sse_decode(deserializer);
+                let mut var_relayUrl = <String>::sse_decode(deserializer);
+                let mut var_status = <String>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::RelayStatusChanged {
+                    account_pubkey: var_accountPubkey,
+                    relay_url: var_relayUrl,
+                    status: var_status,
+                };
+            }
+            8 => {
+                let mut var_pubkey = <String>::sse_decode(deserializer);
+                let mut var_metadata = <MetadataData>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::MetadataUpdated {
+                    pubkey: var_pubkey,
+                    metadata: var_metadata,
+                };
+            }
+            9 => {
+                let mut var_account = <crate::api::accounts::AccountData>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::AccountLoggedIn {
+                    account: var_account,
+                };
+            }
+            10 => {
+                let mut var_pubkey = <String>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::AccountLoggedOut {
+                    pubkey: var_pubkey,
+                };
+            }
+            11 => {
+                let mut var_accountPubkey = <String>::sse_decode(deserializer);
+                let mut var_status =
+                    <crate::api::delivery::DeliveryStatusData>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::MessageDeliveryStatusChanged {
+                    account_pubkey: var_accountPubkey,
+                    status: var_status,
+                };
+            }
+            12 => {
+                let mut var_accountPubkey = <String>::sse_decode(deserializer);
+                let mut var_message =
+                    <crate::api::outbox::OutboxMessageData>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::OutboxMessageUpdated {
+                    account_pubkey: var_accountPubkey,
+                    message: var_message,
+                };
+            }
+            13 => {
+                let mut var_accountPubkey = <String>::sse_decode(deserializer);
+                let mut var_outboxId = <String>::sse_decode(deserializer);
+                let mut var_message =
+                    <crate::api::messages::MessageWithTokensData>::sse_decode(deserializer);
+                return crate::api::events::WhitenoiseEvent::OutboxMessageSent {
+                    account_pubkey: var_accountPubkey,
+                    outbox_id: var_outboxId,
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 fn pde_ffi_dispatcher_primary_impl(
     func_id: i32,
     port: flutter_rust_bridge::for_generated::MessagePort,
@@ -5189,172 +8372,299 @@ fn pde_ffi_dispatcher_primary_impl(
         23 => {
             wire__crate__api__groups__add_members_to_group_impl(port, ptr, rust_vec_len, data_len)
         }
-        24 => wire__crate__api__accounts__convert_account_to_data_impl(
+        24 => {
+            wire__crate__api__outbox__cancel_pending_message_impl(port, ptr, rust_vec_len, data_len)
+        }
+        25 => wire__crate__api__media_cache__clear_group_media_cache_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        25 => wire__crate__api__messages__convert_chat_message_to_data_impl(
+        26 => {
+            wire__crate__api__media_cache__clear_media_cache_impl(port, ptr, rust_vec_len, data_len)
+        }
+        27 => wire__crate__api__accounts__convert_account_to_data_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        26 => {
+        28 => wire__crate__api__messages__convert_chat_message_to_data_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => {
             wire__crate__api__utils__convert_config_to_data_impl(port, ptr, rust_vec_len, data_len)
         }
-        27 => {
+        30 => {
             wire__crate__api__groups__convert_group_to_data_impl(port, ptr, rust_vec_len, data_len)
         }
-        28 => wire__crate__api__messages__convert_message_with_tokens_to_data_impl(
+        31 => wire__crate__api__messages__convert_message_with_tokens_to_data_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__utils__convert_metadata_data_to_metadata_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__utils__convert_metadata_to_data_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        29 => wire__crate__api__utils__convert_metadata_data_to_metadata_impl(
+        34 => wire__crate__api__welcomes__convert_welcome_to_data_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        30 => wire__crate__api__utils__convert_metadata_to_data_impl(
+        35 => wire__crate__api__groups__create_group_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__accounts__create_identity_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__utils__create_whitenoise_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        31 => wire__crate__api__welcomes__convert_welcome_to_data_impl(
+        38 => wire__crate__api__welcomes__decline_welcome_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__delete_all_data_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__messages__delete_message_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__utils__detect_image_type_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__images__discard_processed_image_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        32 => wire__crate__api__groups__create_group_impl(port, ptr, rust_vec_len, data_len),
-        33 => wire__crate__api__accounts__create_identity_impl(port, ptr, rust_vec_len, data_len),
-        34 => wire__crate__api__utils__create_whitenoise_config_impl(
+        43 => wire__crate__api__media__download_media_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__messages__edit_message_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__nip19__encode_nevent_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__nip19__encode_nprofile_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__outbox__enqueue_message_to_group_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        35 => wire__crate__api__welcomes__decline_welcome_impl(port, ptr, rust_vec_len, data_len),
-        36 => wire__crate__api__delete_all_data_impl(port, ptr, rust_vec_len, data_len),
-        37 => {
+        48 => {
             wire__crate__api__accounts__export_account_npub_impl(port, ptr, rust_vec_len, data_len)
         }
-        38 => {
+        49 => {
             wire__crate__api__accounts__export_account_nsec_impl(port, ptr, rust_vec_len, data_len)
         }
-        39 => wire__crate__api__accounts__fetch_account_impl(port, ptr, rust_vec_len, data_len),
-        40 => wire__crate__api__accounts__fetch_accounts_impl(port, ptr, rust_vec_len, data_len),
-        41 => wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
+        50 => wire__crate__api__accounts__fetch_account_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__accounts__fetch_accounts_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        53 => wire__crate__api__messages__fetch_aggregated_messages_page_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        54 => {
+            wire__crate__api__blossom__fetch_blossom_servers_impl(port, ptr, rust_vec_len, data_len)
+        }
+        55 => wire__crate__api__metadata_cache__fetch_cached_metadata_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        56 => wire__crate__api__chats__fetch_chat_list_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__contacts__fetch_contacts_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__groups__fetch_group_admins_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__groups__fetch_group_members_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__groups__fetch_groups_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__relays__fetch_key_package_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__delivery__fetch_message_delivery_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        63 => wire__crate__api__messages__fetch_messages_for_group_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        42 => wire__crate__api__contacts__fetch_contacts_impl(port, ptr, rust_vec_len, data_len),
-        43 => wire__crate__api__groups__fetch_group_admins_impl(port, ptr, rust_vec_len, data_len),
-        44 => wire__crate__api__groups__fetch_group_members_impl(port, ptr, rust_vec_len, data_len),
-        45 => wire__crate__api__groups__fetch_groups_impl(port, ptr, rust_vec_len, data_len),
-        46 => wire__crate__api__relays__fetch_key_package_impl(port, ptr, rust_vec_len, data_len),
-        47 => wire__crate__api__messages__fetch_messages_for_group_impl(
+        64 => wire__crate__api__accounts__fetch_metadata_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__metadata_cache__fetch_metadata_batch_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        48 => wire__crate__api__accounts__fetch_metadata_impl(port, ptr, rust_vec_len, data_len),
-        49 => wire__crate__api__accounts__fetch_onboarding_state_impl(
+        66 => wire__crate__api__accounts__fetch_onboarding_state_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        50 => wire__crate__api__relays__fetch_relay_status_impl(port, ptr, rust_vec_len, data_len),
-        51 => wire__crate__api__relays__fetch_relays_impl(port, ptr, rust_vec_len, data_len),
-        52 => wire__crate__api__welcomes__fetch_welcome_impl(port, ptr, rust_vec_len, data_len),
-        53 => wire__crate__api__welcomes__fetch_welcomes_impl(port, ptr, rust_vec_len, data_len),
-        54 => wire__crate__api__utils__get_default_blossom_server_url_impl(
+        67 => wire__crate__api__outbox__fetch_outbox_impl(port, ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__presence__fetch_presence_settings_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        55 => {
+        69 => wire__crate__api__relays__fetch_relay_status_impl(port, ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__relays__fetch_relays_impl(port, ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__messages__fetch_thread_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__chats__fetch_unread_counts_impl(port, ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__welcomes__fetch_welcome_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__welcomes__fetch_welcomes_impl(port, ptr, rust_vec_len, data_len),
+        75 => wire__crate__api__utils__get_default_blossom_server_url_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        76 => {
+            wire__crate__api__groups__get_or_create_dm_group_impl(port, ptr, rust_vec_len, data_len)
+        }
+        77 => {
             wire__crate__api__groups__group_id_from_string_impl(port, ptr, rust_vec_len, data_len)
         }
-        56 => wire__crate__api__groups__group_id_to_string_impl(port, ptr, rust_vec_len, data_len),
-        57 => wire__crate__api__utils__hex_pubkey_from_npub_impl(port, ptr, rust_vec_len, data_len),
-        58 => wire__crate__api__utils__hex_pubkey_from_public_key_impl(
+        78 => wire__crate__api__groups__group_id_to_string_impl(port, ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__utils__hex_pubkey_from_npub_impl(port, ptr, rust_vec_len, data_len),
+        80 => wire__crate__api__utils__hex_pubkey_from_public_key_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        59 => wire__crate__api__utils__image_type_from_extension_impl(
+        81 => wire__crate__api__utils__image_type_from_extension_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        60 => wire__crate__api__utils__image_type_gif_impl(port, ptr, rust_vec_len, data_len),
-        61 => wire__crate__api__utils__image_type_jpeg_impl(port, ptr, rust_vec_len, data_len),
-        62 => wire__crate__api__utils__image_type_jpg_impl(port, ptr, rust_vec_len, data_len),
-        63 => wire__crate__api__utils__image_type_png_impl(port, ptr, rust_vec_len, data_len),
-        64 => wire__crate__api__utils__image_type_webp_impl(port, ptr, rust_vec_len, data_len),
-        65 => wire__crate__api__initialize_whitenoise_impl(port, ptr, rust_vec_len, data_len),
-        66 => wire__crate__api__accounts__login_impl(port, ptr, rust_vec_len, data_len),
-        67 => wire__crate__api__accounts__logout_impl(port, ptr, rust_vec_len, data_len),
-        68 => wire__crate__api__utils__npub_from_hex_pubkey_impl(port, ptr, rust_vec_len, data_len),
-        69 => wire__crate__api__utils__npub_from_public_key_impl(port, ptr, rust_vec_len, data_len),
-        70 => {
+        82 => wire__crate__api__utils__image_type_gif_impl(port, ptr, rust_vec_len, data_len),
+        83 => wire__crate__api__utils__image_type_jpeg_impl(port, ptr, rust_vec_len, data_len),
+        84 => wire__crate__api__utils__image_type_jpg_impl(port, ptr, rust_vec_len, data_len),
+        85 => wire__crate__api__utils__image_type_png_impl(port, ptr, rust_vec_len, data_len),
+        86 => wire__crate__api__utils__image_type_webp_impl(port, ptr, rust_vec_len, data_len),
+        87 => wire__crate__api__initialize_whitenoise_impl(port, ptr, rust_vec_len, data_len),
+        88 => wire__crate__api__accounts__login_impl(port, ptr, rust_vec_len, data_len),
+        89 => wire__crate__api__accounts__logout_impl(port, ptr, rust_vec_len, data_len),
+        90 => wire__crate__api__chats__mark_group_read_impl(port, ptr, rust_vec_len, data_len),
+        91 => {
+            wire__crate__api__media_cache__media_cache_stats_impl(port, ptr, rust_vec_len, data_len)
+        }
+        92 => wire__crate__api__utils__npub_from_hex_pubkey_impl(port, ptr, rust_vec_len, data_len),
+        93 => wire__crate__api__utils__npub_from_public_key_impl(port, ptr, rust_vec_len, data_len),
+        94 => wire__crate__api__nip19__parse_nostr_entity_impl(port, ptr, rust_vec_len, data_len),
+        95 => wire__crate__api__images__process_image_impl(port, ptr, rust_vec_len, data_len),
+        96 => {
             wire__crate__api__utils__public_key_from_string_impl(port, ptr, rust_vec_len, data_len)
         }
-        71 => wire__crate__api__contacts__query_contacts_impl(port, ptr, rust_vec_len, data_len),
-        72 => wire__crate__api__relays__relay_type_inbox_impl(port, ptr, rust_vec_len, data_len),
-        73 => {
+        97 => wire__crate__api__contacts__query_contacts_impl(port, ptr, rust_vec_len, data_len),
+        98 => wire__crate__api__messages__react_to_message_impl(port, ptr, rust_vec_len, data_len),
+        99 => wire__crate__api__relays__relay_type_inbox_impl(port, ptr, rust_vec_len, data_len),
+        100 => {
             wire__crate__api__relays__relay_type_key_package_impl(port, ptr, rust_vec_len, data_len)
         }
-        74 => wire__crate__api__relays__relay_type_nostr_impl(port, ptr, rust_vec_len, data_len),
-        75 => {
+        101 => wire__crate__api__relays__relay_type_nostr_impl(port, ptr, rust_vec_len, data_len),
+        102 => {
             wire__crate__api__utils__relay_url_from_string_impl(port, ptr, rust_vec_len, data_len)
         }
-        76 => wire__crate__api__contacts__remove_contact_impl(port, ptr, rust_vec_len, data_len),
-        77 => wire__crate__api__groups__remove_members_from_group_impl(
+        103 => wire__crate__api__contacts__remove_contact_impl(port, ptr, rust_vec_len, data_len),
+        104 => wire__crate__api__groups__remove_members_from_group_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        105 => wire__crate__api__messages__remove_reaction_impl(port, ptr, rust_vec_len, data_len),
+        106 => wire__crate__api__messages__reply_to_message_impl(port, ptr, rust_vec_len, data_len),
+        107 => wire__crate__api__nip05__resolve_nip05_impl(port, ptr, rust_vec_len, data_len),
+        108 => wire__crate__api__outbox__retry_message_impl(port, ptr, rust_vec_len, data_len),
+        109 => wire__crate__api__contacts__search_contacts_impl(port, ptr, rust_vec_len, data_len),
+        110 => wire__crate__api__messages__send_direct_message_nip04_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        111 => wire__crate__api__messages__send_message_to_group_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        78 => wire__crate__api__messages__send_direct_message_nip04_impl(
+        112 => {
+            wire__crate__api__presence__send_read_receipt_impl(port, ptr, rust_vec_len, data_len)
+        }
+        113 => wire__crate__api__presence__send_typing_indicator_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        79 => wire__crate__api__messages__send_message_to_group_impl(
+        114 => wire__crate__api__media_cache__set_media_cache_max_size_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        80 => {
+        115 => {
             wire__crate__api__utils__string_from_relay_url_impl(port, ptr, rust_vec_len, data_len)
         }
-        81 => wire__crate__api__utils__tag_from_vec_impl(port, ptr, rust_vec_len, data_len),
-        82 => wire__crate__api__contacts__update_contacts_impl(port, ptr, rust_vec_len, data_len),
-        83 => wire__crate__api__accounts__update_metadata_impl(port, ptr, rust_vec_len, data_len),
-        84 => wire__crate__api__relays__update_relays_impl(port, ptr, rust_vec_len, data_len),
-        85 => wire__crate__api__accounts__upload_profile_picture_impl(
+        116 => wire__crate__api__messages__subscribe_to_group_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        117 => wire__crate__api__presence__subscribe_to_group_presence_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        118 => {
+            wire__crate__api__subscribe_whitenoise_events_impl(port, ptr, rust_vec_len, data_len)
+        }
+        119 => wire__crate__api__utils__tag_from_vec_impl(port, ptr, rust_vec_len, data_len),
+        120 => wire__crate__api__subscriptions__unsubscribe_impl(port, ptr, rust_vec_len, data_len),
+        121 => wire__crate__api__blossom__update_blossom_servers_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        122 => wire__crate__api__contacts__update_contacts_impl(port, ptr, rust_vec_len, data_len),
+        123 => wire__crate__api__accounts__update_metadata_impl(port, ptr, rust_vec_len, data_len),
+        124 => wire__crate__api__presence__update_presence_settings_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        125 => wire__crate__api__relays__update_relays_impl(port, ptr, rust_vec_len, data_len),
+        126 => wire__crate__api__images__upload_image_impl(port, ptr, rust_vec_len, data_len),
+        127 => wire__crate__api__accounts__upload_profile_picture_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        86 => wire__crate__api__utils__whitenoise_error_to_string_impl(
+        128 => wire__crate__api__nip05__verify_nip05_impl(port, ptr, rust_vec_len, data_len),
+        129 => wire__crate__api__utils__whitenoise_error_to_string_impl(
             port,
             ptr,
             rust_vec_len,
@@ -5466,399 +8776,1021 @@ fn pde_ffi_dispatcher_sync_impl(
     }
 }
 
-// Section: rust2dart
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Account> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Account> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Account>> for Account {
+    fn into_into_dart(self) -> FrbWrapper<Account> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Event> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Event> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Event>> for Event {
+    fn into_into_dart(self) -> FrbWrapper<Event> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<GroupId> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<GroupId> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GroupId>> for GroupId {
+    fn into_into_dart(self) -> FrbWrapper<GroupId> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ImageType> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ImageType> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ImageType>> for ImageType {
+    fn into_into_dart(self) -> FrbWrapper<ImageType> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Metadata> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Metadata> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Metadata>> for Metadata {
+    fn into_into_dart(self) -> FrbWrapper<Metadata> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<MetadataData> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<MetadataData> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<MetadataData>> for MetadataData {
+    fn into_into_dart(self) -> FrbWrapper<MetadataData> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<PublicKey> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<PublicKey> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<PublicKey>> for PublicKey {
+    fn into_into_dart(self) -> FrbWrapper<PublicKey> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<RelayType> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RelayType> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RelayType>> for RelayType {
+    fn into_into_dart(self) -> FrbWrapper<RelayType> {
+        self.into()
+    }
+}
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Account> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<RelayUrl> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Account> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RelayUrl> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Account>> for Account {
-    fn into_into_dart(self) -> FrbWrapper<Account> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RelayUrl>> for RelayUrl {
+    fn into_into_dart(self) -> FrbWrapper<RelayUrl> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Event> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Tag> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Event> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Tag> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Event>> for Event {
-    fn into_into_dart(self) -> FrbWrapper<Event> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Tag>> for Tag {
+    fn into_into_dart(self) -> FrbWrapper<Tag> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<WhitenoiseConfig> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<WhitenoiseConfig> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<WhitenoiseConfig>> for WhitenoiseConfig {
+    fn into_into_dart(self) -> FrbWrapper<WhitenoiseConfig> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::accounts::AccountData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pubkey.into_into_dart().into_dart(),
+            self.settings.into_into_dart().into_dart(),
+            self.onboarding.into_into_dart().into_dart(),
+            self.last_synced.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::accounts::AccountData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::accounts::AccountData>
+    for crate::api::accounts::AccountData
+{
+    fn into_into_dart(self) -> crate::api::accounts::AccountData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::accounts::AccountSettings> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.dark_theme.into_into_dart().into_dart(),
+            self.0.dev_mode.into_into_dart().into_dart(),
+            self.0.lockdown_mode.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<crate::api::accounts::AccountSettings>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::accounts::AccountSettings>>
+    for crate::api::accounts::AccountSettings
+{
+    fn into_into_dart(self) -> FrbWrapper<crate::api::accounts::AccountSettings> {
         self.into()
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<GroupId> {
+impl flutter_rust_bridge::IntoDart for crate::api::blossom::BlossomServerListData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.servers.into_into_dart().into_dart(),
+            self.mirror_uploads.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blossom::BlossomServerListData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blossom::BlossomServerListData>
+    for crate::api::blossom::BlossomServerListData
+{
+    fn into_into_dart(self) -> crate::api::blossom::BlossomServerListData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::errors::BridgeError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.details.into_into_dart().into_dart(),
+            self.outbox_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::errors::BridgeError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::errors::BridgeError>
+    for crate::api::errors::BridgeError
+{
+    fn into_into_dart(self) -> crate::api::errors::BridgeError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::errors::BridgeErrorCode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::errors::BridgeErrorCode::NotInitialized => 0.into_dart(),
+            crate::api::errors::BridgeErrorCode::AccountNotFound => 1.into_dart(),
+            crate::api::errors::BridgeErrorCode::Unauthorized => 2.into_dart(),
+            crate::api::errors::BridgeErrorCode::GroupNotFound => 3.into_dart(),
+            crate::api::errors::BridgeErrorCode::WelcomeNotFound => 4.into_dart(),
+            crate::api::errors::BridgeErrorCode::MessageNotFound => 5.into_dart(),
+            crate::api::errors::BridgeErrorCode::MemberNotInGroup => 6.into_dart(),
+            crate::api::errors::BridgeErrorCode::InvalidPublicKey => 7.into_dart(),
+            crate::api::errors::BridgeErrorCode::InvalidHex => 8.into_dart(),
+            crate::api::errors::BridgeErrorCode::InvalidRelayUrl => 9.into_dart(),
+            crate::api::errors::BridgeErrorCode::InvalidInput => 10.into_dart(),
+            crate::api::errors::BridgeErrorCode::Unsupported => 11.into_dart(),
+            crate::api::errors::BridgeErrorCode::RelayUnreachable => 12.into_dart(),
+            crate::api::errors::BridgeErrorCode::NoKeyPackage => 13.into_dart(),
+            crate::api::errors::BridgeErrorCode::MlsError => 14.into_dart(),
+            crate::api::errors::BridgeErrorCode::Network => 15.into_dart(),
+            crate::api::errors::BridgeErrorCode::Storage => 16.into_dart(),
+            crate::api::errors::BridgeErrorCode::Configuration => 17.into_dart(),
+            crate::api::errors::BridgeErrorCode::Internal => 18.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::errors::BridgeErrorCode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::errors::BridgeErrorCode>
+    for crate::api::errors::BridgeErrorCode
+{
+    fn into_into_dart(self) -> crate::api::errors::BridgeErrorCode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metadata_cache::CachedMetadataData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pubkey.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.fetched_at.into_into_dart().into_dart(),
+            self.is_stale.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metadata_cache::CachedMetadataData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata_cache::CachedMetadataData>
+    for crate::api::metadata_cache::CachedMetadataData
+{
+    fn into_into_dart(self) -> crate::api::metadata_cache::CachedMetadataData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::messages::ChatMessageData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.pubkey.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.is_reply.into_into_dart().into_dart(),
+            self.reply_to_id.into_into_dart().into_dart(),
+            self.is_deleted.into_into_dart().into_dart(),
+            self.content_tokens.into_into_dart().into_dart(),
+            self.reactions.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.is_edited.into_into_dart().into_dart(),
+            self.edit_history.into_into_dart().into_dart(),
+            self.attachments.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messages::ChatMessageData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::ChatMessageData>
+    for crate::api::messages::ChatMessageData
+{
+    fn into_into_dart(self) -> crate::api::messages::ChatMessageData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chats::ChatSummaryData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.group.into_into_dart().into_dart(),
+            self.last_message_id.into_into_dart().into_dart(),
+            self.last_message_preview.into_into_dart().into_dart(),
+            self.last_message_author.into_into_dart().into_dart(),
+            self.last_activity_at.into_into_dart().into_dart(),
+            self.unread_count.into_into_dart().into_dart(),
+            self.member_count.into_into_dart().into_dart(),
+            self.dm_counterpart_pubkey.into_into_dart().into_dart(),
+            self.dm_counterpart_metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chats::ChatSummaryData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chats::ChatSummaryData>
+    for crate::api::chats::ChatSummaryData
+{
+    fn into_into_dart(self) -> crate::api::chats::ChatSummaryData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::contacts::ContactSearchResultData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pubkey.into_into_dart().into_dart(),
+            self.npub.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.is_contact.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::contacts::ContactSearchResultData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::contacts::ContactSearchResultData>
+    for crate::api::contacts::ContactSearchResultData
+{
+    fn into_into_dart(self) -> crate::api::contacts::ContactSearchResultData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::delivery::DeliveryState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::delivery::DeliveryState::Pending => 0.into_dart(),
+            crate::api::delivery::DeliveryState::Sent => 1.into_dart(),
+            crate::api::delivery::DeliveryState::Failed => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::delivery::DeliveryState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::delivery::DeliveryState>
+    for crate::api::delivery::DeliveryState
+{
+    fn into_into_dart(self) -> crate::api::delivery::DeliveryState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::delivery::DeliveryStatusData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.outbox_id.into_into_dart().into_dart(),
+            self.message_id.into_into_dart().into_dart(),
+            self.group_id.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.attempts.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::delivery::DeliveryStatusData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::delivery::DeliveryStatusData>
+    for crate::api::delivery::DeliveryStatusData
+{
+    fn into_into_dart(self) -> crate::api::delivery::DeliveryStatusData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::messages::EmojiReactionData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.emoji.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.users.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messages::EmojiReactionData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::EmojiReactionData>
+    for crate::api::messages::EmojiReactionData
+{
+    fn into_into_dart(self) -> crate::api::messages::EmojiReactionData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::groups::GroupData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.mls_group_id.into_into_dart().into_dart(),
+            self.nostr_group_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.image_url.into_into_dart().into_dart(),
+            self.admin_pubkeys.into_into_dart().into_dart(),
+            self.last_message_id.into_into_dart().into_dart(),
+            self.last_message_at.into_into_dart().into_dart(),
+            self.group_type.into_into_dart().into_dart(),
+            self.epoch.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<GroupId> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GroupId>> for GroupId {
-    fn into_into_dart(self) -> FrbWrapper<GroupId> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::groups::GroupData {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::GroupData>
+    for crate::api::groups::GroupData
+{
+    fn into_into_dart(self) -> crate::api::groups::GroupData {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<ImageType> {
+impl flutter_rust_bridge::IntoDart for crate::api::messages::GroupMessageUpdate {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            crate::api::messages::GroupMessageUpdate::Subscribed { subscription_id } => {
+                [0.into_dart(), subscription_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::messages::GroupMessageUpdate::NewMessage { message } => {
+                [1.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::messages::GroupMessageUpdate::MessageEdited { message } => {
+                [2.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::messages::GroupMessageUpdate::MessageDeleted { message_id } => {
+                [3.into_dart(), message_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::messages::GroupMessageUpdate::MessageRemoved { message_id } => {
+                [4.into_dart(), message_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::messages::GroupMessageUpdate::ReactionsUpdated {
+                message_id,
+                reactions,
+            } => [
+                5.into_dart(),
+                message_id.into_into_dart().into_dart(),
+                reactions.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ImageType> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ImageType>> for ImageType {
-    fn into_into_dart(self) -> FrbWrapper<ImageType> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messages::GroupMessageUpdate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::GroupMessageUpdate>
+    for crate::api::messages::GroupMessageUpdate
+{
+    fn into_into_dart(self) -> crate::api::messages::GroupMessageUpdate {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Metadata> {
+impl flutter_rust_bridge::IntoDart for crate::api::presence::GroupPresenceUpdate {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            crate::api::presence::GroupPresenceUpdate::Subscribed { subscription_id } => {
+                [0.into_dart(), subscription_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::presence::GroupPresenceUpdate::Typing {
+                member_pubkey,
+                expires_at,
+            } => [
+                1.into_dart(),
+                member_pubkey.into_into_dart().into_dart(),
+                expires_at.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::presence::GroupPresenceUpdate::ReadReceipt {
+                member_pubkey,
+                message_id,
+                read_at,
+            } => [
+                2.into_dart(),
+                member_pubkey.into_into_dart().into_dart(),
+                message_id.into_into_dart().into_dart(),
+                read_at.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Metadata> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Metadata>> for Metadata {
-    fn into_into_dart(self) -> FrbWrapper<Metadata> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::presence::GroupPresenceUpdate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::presence::GroupPresenceUpdate>
+    for crate::api::presence::GroupPresenceUpdate
+{
+    fn into_into_dart(self) -> crate::api::presence::GroupPresenceUpdate {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<MetadataData> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::groups::GroupState> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self.0 {
+            crate::api::groups::GroupState::Active => 0.into_dart(),
+            crate::api::groups::GroupState::Inactive => 1.into_dart(),
+            crate::api::groups::GroupState::Pending => 2.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<MetadataData> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<MetadataData>> for MetadataData {
-    fn into_into_dart(self) -> FrbWrapper<MetadataData> {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<crate::api::groups::GroupState>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::groups::GroupState>>
+    for crate::api::groups::GroupState
+{
+    fn into_into_dart(self) -> FrbWrapper<crate::api::groups::GroupState> {
         self.into()
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<PublicKey> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::groups::GroupType> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self.0 {
+            crate::api::groups::GroupType::DirectMessage => 0.into_dart(),
+            crate::api::groups::GroupType::Group => 1.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<PublicKey> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<PublicKey>> for PublicKey {
-    fn into_into_dart(self) -> FrbWrapper<PublicKey> {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<crate::api::groups::GroupType>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::groups::GroupType>>
+    for crate::api::groups::GroupType
+{
+    fn into_into_dart(self) -> FrbWrapper<crate::api::groups::GroupType> {
         self.into()
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<RelayType> {
+impl flutter_rust_bridge::IntoDart for crate::api::images::ImageProcessingOptions {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.max_dimension.into_into_dart().into_dart(),
+            self.convert_to_webp.into_into_dart().into_dart(),
+            self.quality.into_into_dart().into_dart(),
+            self.thumbnail_dimension.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RelayType> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RelayType>> for RelayType {
-    fn into_into_dart(self) -> FrbWrapper<RelayType> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::images::ImageProcessingOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::images::ImageProcessingOptions>
+    for crate::api::images::ImageProcessingOptions
+{
+    fn into_into_dart(self) -> crate::api::images::ImageProcessingOptions {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<RelayUrl> {
+impl flutter_rust_bridge::IntoDart for crate::api::media::MediaAttachmentData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.url.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.sha256.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.dimensions.into_into_dart().into_dart(),
+            self.blurhash.into_into_dart().into_dart(),
+            self.is_encrypted.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RelayUrl> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RelayUrl>> for RelayUrl {
-    fn into_into_dart(self) -> FrbWrapper<RelayUrl> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::MediaAttachmentData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::MediaAttachmentData>
+    for crate::api::media::MediaAttachmentData
+{
+    fn into_into_dart(self) -> crate::api::media::MediaAttachmentData {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Tag> {
+impl flutter_rust_bridge::IntoDart for crate::api::media_cache::MediaCacheStatsData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.file_count.into_into_dart().into_dart(),
+            self.total_size_bytes.into_into_dart().into_dart(),
+            self.max_size_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Tag> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Tag>> for Tag {
-    fn into_into_dart(self) -> FrbWrapper<Tag> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media_cache::MediaCacheStatsData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media_cache::MediaCacheStatsData>
+    for crate::api::media_cache::MediaCacheStatsData
+{
+    fn into_into_dart(self) -> crate::api::media_cache::MediaCacheStatsData {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<WhitenoiseConfig> {
+impl flutter_rust_bridge::IntoDart for crate::api::messages::MessageCursorData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.created_at.into_into_dart().into_dart(),
+            self.message_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<WhitenoiseConfig> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<WhitenoiseConfig>> for WhitenoiseConfig {
-    fn into_into_dart(self) -> FrbWrapper<WhitenoiseConfig> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messages::MessageCursorData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::MessageCursorData>
+    for crate::api::messages::MessageCursorData
+{
+    fn into_into_dart(self) -> crate::api::messages::MessageCursorData {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<WhitenoiseError> {
+impl flutter_rust_bridge::IntoDart for crate::api::messages::MessagePageData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.messages.into_into_dart().into_dart(),
+            self.next_cursor.into_into_dart().into_dart(),
+            self.has_more.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<WhitenoiseError> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<WhitenoiseError>> for WhitenoiseError {
-    fn into_into_dart(self) -> FrbWrapper<WhitenoiseError> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messages::MessagePageData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::MessagePageData>
+    for crate::api::messages::MessagePageData
+{
+    fn into_into_dart(self) -> crate::api::messages::MessagePageData {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::accounts::AccountData {
+impl flutter_rust_bridge::IntoDart for crate::api::messages::MessageVersionData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.pubkey.into_into_dart().into_dart(),
-            self.settings.into_into_dart().into_dart(),
-            self.onboarding.into_into_dart().into_dart(),
-            self.last_synced.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::accounts::AccountData
+    for crate::api::messages::MessageVersionData
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::accounts::AccountData>
-    for crate::api::accounts::AccountData
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::MessageVersionData>
+    for crate::api::messages::MessageVersionData
 {
-    fn into_into_dart(self) -> crate::api::accounts::AccountData {
+    fn into_into_dart(self) -> crate::api::messages::MessageVersionData {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::accounts::AccountSettings> {
+impl flutter_rust_bridge::IntoDart for crate::api::messages::MessageWithTokensData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.0.dark_theme.into_into_dart().into_dart(),
-            self.0.dev_mode.into_into_dart().into_dart(),
-            self.0.lockdown_mode.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.pubkey.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.tokens.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<crate::api::accounts::AccountSettings>
+    for crate::api::messages::MessageWithTokensData
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::accounts::AccountSettings>>
-    for crate::api::accounts::AccountSettings
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::MessageWithTokensData>
+    for crate::api::messages::MessageWithTokensData
 {
-    fn into_into_dart(self) -> FrbWrapper<crate::api::accounts::AccountSettings> {
-        self.into()
+    fn into_into_dart(self) -> crate::api::messages::MessageWithTokensData {
+        self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::messages::ChatMessageData {
+impl flutter_rust_bridge::IntoDart for crate::api::nip05::Nip05ProfileData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
+            self.identifier.into_into_dart().into_dart(),
             self.pubkey.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
-            self.is_reply.into_into_dart().into_dart(),
-            self.reply_to_id.into_into_dart().into_dart(),
-            self.is_deleted.into_into_dart().into_dart(),
-            self.content_tokens.into_into_dart().into_dart(),
-            self.reactions.into_into_dart().into_dart(),
-            self.kind.into_into_dart().into_dart(),
+            self.relays.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::messages::ChatMessageData
+    for crate::api::nip05::Nip05ProfileData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nip05::Nip05ProfileData>
+    for crate::api::nip05::Nip05ProfileData
+{
+    fn into_into_dart(self) -> crate::api::nip05::Nip05ProfileData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nip19::NostrEntity {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::nip19::NostrEntity::Pubkey { pubkey } => {
+                [0.into_dart(), pubkey.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::nip19::NostrEntity::Profile { pubkey, relays } => [
+                1.into_dart(),
+                pubkey.into_into_dart().into_dart(),
+                relays.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::nip19::NostrEntity::Note { event_id } => {
+                [2.into_dart(), event_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::nip19::NostrEntity::Event {
+                event_id,
+                relays,
+                author,
+                kind,
+            } => [
+                3.into_dart(),
+                event_id.into_into_dart().into_dart(),
+                relays.into_into_dart().into_dart(),
+                author.into_into_dart().into_dart(),
+                kind.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::nip19::NostrEntity::Address {
+                identifier,
+                pubkey,
+                kind,
+                relays,
+            } => [
+                4.into_dart(),
+                identifier.into_into_dart().into_dart(),
+                pubkey.into_into_dart().into_dart(),
+                kind.into_into_dart().into_dart(),
+                relays.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::nip19::NostrEntity::SecretKey { secret_key } => {
+                [5.into_dart(), secret_key.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nip19::NostrEntity
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::ChatMessageData>
-    for crate::api::messages::ChatMessageData
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nip19::NostrEntity>
+    for crate::api::nip19::NostrEntity
 {
-    fn into_into_dart(self) -> crate::api::messages::ChatMessageData {
+    fn into_into_dart(self) -> crate::api::nip19::NostrEntity {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::messages::EmojiReactionData {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::accounts::OnboardingState> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.emoji.into_into_dart().into_dart(),
-            self.count.into_into_dart().into_dart(),
-            self.users.into_into_dart().into_dart(),
+            self.0.inbox_relays.into_into_dart().into_dart(),
+            self.0.key_package_relays.into_into_dart().into_dart(),
+            self.0.key_package_published.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::messages::EmojiReactionData
+    for FrbWrapper<crate::api::accounts::OnboardingState>
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::EmojiReactionData>
-    for crate::api::messages::EmojiReactionData
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::accounts::OnboardingState>>
+    for crate::api::accounts::OnboardingState
 {
-    fn into_into_dart(self) -> crate::api::messages::EmojiReactionData {
-        self
+    fn into_into_dart(self) -> FrbWrapper<crate::api::accounts::OnboardingState> {
+        self.into()
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::groups::GroupData {
+impl flutter_rust_bridge::IntoDart for crate::api::outbox::OutboxMessageData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.mls_group_id.into_into_dart().into_dart(),
-            self.nostr_group_id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.description.into_into_dart().into_dart(),
-            self.admin_pubkeys.into_into_dart().into_dart(),
-            self.last_message_id.into_into_dart().into_dart(),
-            self.last_message_at.into_into_dart().into_dart(),
-            self.group_type.into_into_dart().into_dart(),
-            self.epoch.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.group_id.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
             self.state.into_into_dart().into_dart(),
+            self.attempts.into_into_dart().into_dart(),
+            self.next_attempt_at.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::groups::GroupData {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::GroupData>
-    for crate::api::groups::GroupData
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::outbox::OutboxMessageData
 {
-    fn into_into_dart(self) -> crate::api::groups::GroupData {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::outbox::OutboxMessageData>
+    for crate::api::outbox::OutboxMessageData
+{
+    fn into_into_dart(self) -> crate::api::outbox::OutboxMessageData {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::groups::GroupState> {
+impl flutter_rust_bridge::IntoDart for crate::api::outbox::OutboxState {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self.0 {
-            crate::api::groups::GroupState::Active => 0.into_dart(),
-            crate::api::groups::GroupState::Inactive => 1.into_dart(),
-            crate::api::groups::GroupState::Pending => 2.into_dart(),
+        match self {
+            crate::api::outbox::OutboxState::Pending => 0.into_dart(),
+            crate::api::outbox::OutboxState::Failed => 1.into_dart(),
             _ => unreachable!(),
         }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<crate::api::groups::GroupState>
+    for crate::api::outbox::OutboxState
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::groups::GroupState>>
-    for crate::api::groups::GroupState
+impl flutter_rust_bridge::IntoIntoDart<crate::api::outbox::OutboxState>
+    for crate::api::outbox::OutboxState
 {
-    fn into_into_dart(self) -> FrbWrapper<crate::api::groups::GroupState> {
-        self.into()
+    fn into_into_dart(self) -> crate::api::outbox::OutboxState {
+        self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::groups::GroupType> {
+impl flutter_rust_bridge::IntoDart for crate::api::nip19::ParsedNostrEntityData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self.0 {
-            crate::api::groups::GroupType::DirectMessage => 0.into_dart(),
-            crate::api::groups::GroupType::Group => 1.into_dart(),
-            _ => unreachable!(),
-        }
+        [
+            self.entity.into_into_dart().into_dart(),
+            self.is_sensitive.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<crate::api::groups::GroupType>
+    for crate::api::nip19::ParsedNostrEntityData
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::groups::GroupType>>
-    for crate::api::groups::GroupType
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nip19::ParsedNostrEntityData>
+    for crate::api::nip19::ParsedNostrEntityData
 {
-    fn into_into_dart(self) -> FrbWrapper<crate::api::groups::GroupType> {
-        self.into()
+    fn into_into_dart(self) -> crate::api::nip19::ParsedNostrEntityData {
+        self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::messages::MessageWithTokensData {
+impl flutter_rust_bridge::IntoDart for crate::api::presence::PresenceSettingsData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.pubkey.into_into_dart().into_dart(),
-            self.kind.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.tokens.into_into_dart().into_dart(),
+            self.disable_read_receipts.into_into_dart().into_dart(),
+            self.disable_typing_indicators.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::messages::MessageWithTokensData
+    for crate::api::presence::PresenceSettingsData
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::MessageWithTokensData>
-    for crate::api::messages::MessageWithTokensData
+impl flutter_rust_bridge::IntoIntoDart<crate::api::presence::PresenceSettingsData>
+    for crate::api::presence::PresenceSettingsData
 {
-    fn into_into_dart(self) -> crate::api::messages::MessageWithTokensData {
+    fn into_into_dart(self) -> crate::api::presence::PresenceSettingsData {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::accounts::OnboardingState> {
+impl flutter_rust_bridge::IntoDart for crate::api::images::ProcessedImageData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.0.inbox_relays.into_into_dart().into_dart(),
-            self.0.key_package_relays.into_into_dart().into_dart(),
-            self.0.key_package_published.into_into_dart().into_dart(),
+            self.file_path.into_into_dart().into_dart(),
+            self.image_type.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.sha256.into_into_dart().into_dart(),
+            self.blurhash.into_into_dart().into_dart(),
+            self.thumbnail_path.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<crate::api::accounts::OnboardingState>
+    for crate::api::images::ProcessedImageData
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::accounts::OnboardingState>>
-    for crate::api::accounts::OnboardingState
+impl flutter_rust_bridge::IntoIntoDart<crate::api::images::ProcessedImageData>
+    for crate::api::images::ProcessedImageData
 {
-    fn into_into_dart(self) -> FrbWrapper<crate::api::accounts::OnboardingState> {
-        self.into()
+    fn into_into_dart(self) -> crate::api::images::ProcessedImageData {
+        self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -5904,6 +9836,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::SerializableTokenDa
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::images::UploadedImageData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.mirror_urls.into_into_dart().into_dart(),
+            self.thumbnail_url.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::images::UploadedImageData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::images::UploadedImageData>
+    for crate::api::images::UploadedImageData
+{
+    fn into_into_dart(self) -> crate::api::images::UploadedImageData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::messages::UserReactionData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5999,6 +9953,147 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::utils::WhitenoiseConfigData>
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::events::WhitenoiseEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::events::WhitenoiseEvent::Subscribed { subscription_id } => {
+                [0.into_dart(), subscription_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::events::WhitenoiseEvent::WelcomeReceived {
+                account_pubkey,
+                welcome,
+            } => [
+                1.into_dart(),
+                account_pubkey.into_into_dart().into_dart(),
+                welcome.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::WhitenoiseEvent::GroupCreated {
+                account_pubkey,
+                group,
+            } => [
+                2.into_dart(),
+                account_pubkey.into_into_dart().into_dart(),
+                group.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::WhitenoiseEvent::GroupUpdated {
+                account_pubkey,
+                group,
+            } => [
+                3.into_dart(),
+                account_pubkey.into_into_dart().into_dart(),
+                group.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::WhitenoiseEvent::GroupStateChanged {
+                account_pubkey,
+                group,
+            } => [
+                4.into_dart(),
+                account_pubkey.into_into_dart().into_dart(),
+                group.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::WhitenoiseEvent::MemberAdded {
+                account_pubkey,
+                group_id,
+                member_pubkey,
+            } => [
+                5.into_dart(),
+                account_pubkey.into_into_dart().into_dart(),
+                group_id.into_into_dart().into_dart(),
+                member_pubkey.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::WhitenoiseEvent::MemberRemoved {
+                account_pubkey,
+                group_id,
+                member_pubkey,
+            } => [
+                6.into_dart(),
+                account_pubkey.into_into_dart().into_dart(),
+                group_id.into_into_dart().into_dart(),
+                member_pubkey.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::WhitenoiseEvent::RelayStatusChanged {
+                account_pubkey,
+                relay_url,
+                status,
+            } => [
+                7.into_dart(),
+                account_pubkey.into_into_dart().into_dart(),
+                relay_url.into_into_dart().into_dart(),
+                status.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::WhitenoiseEvent::MetadataUpdated { pubkey, metadata } => [
+                8.into_dart(),
+                pubkey.into_into_dart().into_dart(),
+                metadata.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::WhitenoiseEvent::AccountLoggedIn { account } => {
+                [9.into_dart(), account.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::events::WhitenoiseEvent::AccountLoggedOut { pubkey } => {
+                [10.into_dart(), pubkey.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::events::WhitenoiseEvent::MessageDeliveryStatusChanged {
+                account_pubkey,
+                status,
+            } => [
+                11.into_dart(),
+                account_pubkey.into_into_dart().into_dart(),
+                status.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::WhitenoiseEvent::OutboxMessageUpdated {
+                account_pubkey,
+                message,
+            } => [
+                12.into_dart(),
+                account_pubkey.into_into_dart().into_dart(),
+                message.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::WhitenoiseEvent::OutboxMessageSent {
+                account_pubkey,
+                outbox_id,
+                message,
+            } => [
+                13.into_dart(),
+                account_pubkey.into_into_dart().into_dart(),
+                outbox_id.into_into_dart().into_dart(),
+                message.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::events::WhitenoiseEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::events::WhitenoiseEvent>
+    for crate::api::events::WhitenoiseEvent
+{
+    fn into_into_dart(self) -> crate::api::events::WhitenoiseEvent {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
 
 impl SseEncode for Account {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6083,27 +10178,39 @@ impl SseEncode for WhitenoiseConfig {
     }
 }
 
-impl SseEncode for WhitenoiseError {
+impl SseEncode for std::collections::HashMap<PublicKey, Option<MetadataData>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WhitenoiseError>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <Vec<(PublicKey, Option<MetadataData>)>>::sse_encode(
+            self.into_iter().collect(),
+            serializer,
+        );
     }
 }
 
-impl SseEncode for std::collections::HashMap<PublicKey, Option<MetadataData>> {
+impl SseEncode for std::collections::HashMap<String, String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(PublicKey, Option<MetadataData>)>>::sse_encode(
+        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
+    }
+}
+
+impl SseEncode
+    for std::collections::HashMap<String, crate::api::metadata_cache::CachedMetadataData>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, crate::api::metadata_cache::CachedMetadataData)>>::sse_encode(
             self.into_iter().collect(),
             serializer,
         );
     }
 }
 
-impl SseEncode for std::collections::HashMap<String, String> {
+impl SseEncode for std::collections::HashMap<String, u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
+        <Vec<(String, u32)>>::sse_encode(self.into_iter().collect(), serializer);
     }
 }
 
@@ -6271,6 +10378,42 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        crate::api::messages::GroupMessageUpdate,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::presence::GroupPresenceUpdate,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::events::WhitenoiseEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6297,6 +10440,14 @@ impl SseEncode for crate::api::accounts::AccountSettings {
     }
 }
 
+impl SseEncode for crate::api::blossom::BlossomServerListData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.servers, serializer);
+        <bool>::sse_encode(self.mirror_uploads, serializer);
+    }
+}
+
 impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6304,6 +10455,59 @@ impl SseEncode for bool {
     }
 }
 
+impl SseEncode for crate::api::errors::BridgeError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::errors::BridgeErrorCode>::sse_encode(self.code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.details, serializer);
+        <Option<String>>::sse_encode(self.outbox_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::errors::BridgeErrorCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::errors::BridgeErrorCode::NotInitialized => 0,
+                crate::api::errors::BridgeErrorCode::AccountNotFound => 1,
+                crate::api::errors::BridgeErrorCode::Unauthorized => 2,
+                crate::api::errors::BridgeErrorCode::GroupNotFound => 3,
+                crate::api::errors::BridgeErrorCode::WelcomeNotFound => 4,
+                crate::api::errors::BridgeErrorCode::MessageNotFound => 5,
+                crate::api::errors::BridgeErrorCode::MemberNotInGroup => 6,
+                crate::api::errors::BridgeErrorCode::InvalidPublicKey => 7,
+                crate::api::errors::BridgeErrorCode::InvalidHex => 8,
+                crate::api::errors::BridgeErrorCode::InvalidRelayUrl => 9,
+                crate::api::errors::BridgeErrorCode::InvalidInput => 10,
+                crate::api::errors::BridgeErrorCode::Unsupported => 11,
+                crate::api::errors::BridgeErrorCode::RelayUnreachable => 12,
+                crate::api::errors::BridgeErrorCode::NoKeyPackage => 13,
+                crate::api::errors::BridgeErrorCode::MlsError => 14,
+                crate::api::errors::BridgeErrorCode::Network => 15,
+                crate::api::errors::BridgeErrorCode::Storage => 16,
+                crate::api::errors::BridgeErrorCode::Configuration => 17,
+                crate::api::errors::BridgeErrorCode::Internal => 18,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::metadata_cache::CachedMetadataData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.pubkey, serializer);
+        <Option<MetadataData>>::sse_encode(self.metadata, serializer);
+        <u64>::sse_encode(self.fetched_at, serializer);
+        <bool>::sse_encode(self.is_stale, serializer);
+    }
+}
+
 impl SseEncode for crate::api::messages::ChatMessageData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6321,6 +10525,65 @@ impl SseEncode for crate::api::messages::ChatMessageData {
         );
         <crate::api::messages::ReactionSummaryData>::sse_encode(self.reactions, serializer);
         <u16>::sse_encode(self.kind, serializer);
+        <bool>::sse_encode(self.is_edited, serializer);
+        <Vec<crate::api::messages::MessageVersionData>>::sse_encode(self.edit_history, serializer);
+        <Vec<crate::api::media::MediaAttachmentData>>::sse_encode(self.attachments, serializer);
+    }
+}
+
+impl SseEncode for crate::api::chats::ChatSummaryData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::groups::GroupData>::sse_encode(self.group, serializer);
+        <Option<String>>::sse_encode(self.last_message_id, serializer);
+        <Option<String>>::sse_encode(self.last_message_preview, serializer);
+        <Option<String>>::sse_encode(self.last_message_author, serializer);
+        <Option<u64>>::sse_encode(self.last_activity_at, serializer);
+        <u32>::sse_encode(self.unread_count, serializer);
+        <u32>::sse_encode(self.member_count, serializer);
+        <Option<String>>::sse_encode(self.dm_counterpart_pubkey, serializer);
+        <Option<MetadataData>>::sse_encode(self.dm_counterpart_metadata, serializer);
+    }
+}
+
+impl SseEncode for crate::api::contacts::ContactSearchResultData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.pubkey, serializer);
+        <String>::sse_encode(self.npub, serializer);
+        <Option<MetadataData>>::sse_encode(self.metadata, serializer);
+        <bool>::sse_encode(self.is_contact, serializer);
+        <u32>::sse_encode(self.score, serializer);
+    }
+}
+
+impl SseEncode for crate::api::delivery::DeliveryState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::delivery::DeliveryState::Pending => 0,
+                crate::api::delivery::DeliveryState::Sent => 1,
+                crate::api::delivery::DeliveryState::Failed => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::delivery::DeliveryStatusData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.outbox_id, serializer);
+        <Option<String>>::sse_encode(self.message_id, serializer);
+        <String>::sse_encode(self.group_id, serializer);
+        <crate::api::delivery::DeliveryState>::sse_encode(self.state, serializer);
+        <u32>::sse_encode(self.attempts, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+        <u64>::sse_encode(self.updated_at, serializer);
     }
 }
 
@@ -6333,19 +10596,92 @@ impl SseEncode for crate::api::messages::EmojiReactionData {
     }
 }
 
-impl SseEncode for crate::api::groups::GroupData {
+impl SseEncode for crate::api::groups::GroupData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mls_group_id, serializer);
+        <String>::sse_encode(self.nostr_group_id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.image_url, serializer);
+        <Vec<String>>::sse_encode(self.admin_pubkeys, serializer);
+        <Option<String>>::sse_encode(self.last_message_id, serializer);
+        <Option<u64>>::sse_encode(self.last_message_at, serializer);
+        <crate::api::groups::GroupType>::sse_encode(self.group_type, serializer);
+        <u64>::sse_encode(self.epoch, serializer);
+        <crate::api::groups::GroupState>::sse_encode(self.state, serializer);
+    }
+}
+
+impl SseEncode for crate::api::messages::GroupMessageUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::messages::GroupMessageUpdate::Subscribed { subscription_id } => {
+                <i32>::sse_encode(0, serializer);
+                <u64>::sse_encode(subscription_id, serializer);
+            }
+            crate::api::messages::GroupMessageUpdate::NewMessage { message } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::api::messages::ChatMessageData>::sse_encode(message, serializer);
+            }
+            crate::api::messages::GroupMessageUpdate::MessageEdited { message } => {
+                <i32>::sse_encode(2, serializer);
+                <crate::api::messages::ChatMessageData>::sse_encode(message, serializer);
+            }
+            crate::api::messages::GroupMessageUpdate::MessageDeleted { message_id } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(message_id, serializer);
+            }
+            crate::api::messages::GroupMessageUpdate::MessageRemoved { message_id } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(message_id, serializer);
+            }
+            crate::api::messages::GroupMessageUpdate::ReactionsUpdated {
+                message_id,
+                reactions,
+            } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(message_id, serializer);
+                <crate::api::messages::ReactionSummaryData>::sse_encode(reactions, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::presence::GroupPresenceUpdate {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.mls_group_id, serializer);
-        <String>::sse_encode(self.nostr_group_id, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <String>::sse_encode(self.description, serializer);
-        <Vec<String>>::sse_encode(self.admin_pubkeys, serializer);
-        <Option<String>>::sse_encode(self.last_message_id, serializer);
-        <Option<u64>>::sse_encode(self.last_message_at, serializer);
-        <crate::api::groups::GroupType>::sse_encode(self.group_type, serializer);
-        <u64>::sse_encode(self.epoch, serializer);
-        <crate::api::groups::GroupState>::sse_encode(self.state, serializer);
+        match self {
+            crate::api::presence::GroupPresenceUpdate::Subscribed { subscription_id } => {
+                <i32>::sse_encode(0, serializer);
+                <u64>::sse_encode(subscription_id, serializer);
+            }
+            crate::api::presence::GroupPresenceUpdate::Typing {
+                member_pubkey,
+                expires_at,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(member_pubkey, serializer);
+                <u64>::sse_encode(expires_at, serializer);
+            }
+            crate::api::presence::GroupPresenceUpdate::ReadReceipt {
+                member_pubkey,
+                message_id,
+                read_at,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(member_pubkey, serializer);
+                <String>::sse_encode(message_id, serializer);
+                <u64>::sse_encode(read_at, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
@@ -6389,6 +10725,16 @@ impl SseEncode for i32 {
     }
 }
 
+impl SseEncode for crate::api::images::ImageProcessingOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.max_dimension, serializer);
+        <bool>::sse_encode(self.convert_to_webp, serializer);
+        <u8>::sse_encode(self.quality, serializer);
+        <u32>::sse_encode(self.thumbnail_dimension, serializer);
+    }
+}
+
 impl SseEncode for Vec<PublicKey> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6449,6 +10795,26 @@ impl SseEncode for Vec<crate::api::messages::ChatMessageData> {
     }
 }
 
+impl SseEncode for Vec<crate::api::chats::ChatSummaryData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::chats::ChatSummaryData>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::contacts::ContactSearchResultData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::contacts::ContactSearchResultData>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::messages::EmojiReactionData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6469,6 +10835,36 @@ impl SseEncode for Vec<crate::api::groups::GroupData> {
     }
 }
 
+impl SseEncode for Vec<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<String>>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::media::MediaAttachmentData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::media::MediaAttachmentData>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::messages::MessageVersionData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::messages::MessageVersionData>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::messages::MessageWithTokensData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6479,6 +10875,16 @@ impl SseEncode for Vec<crate::api::messages::MessageWithTokensData> {
     }
 }
 
+impl SseEncode for Vec<crate::api::outbox::OutboxMessageData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::outbox::OutboxMessageData>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6499,6 +10905,18 @@ impl SseEncode for Vec<(PublicKey, Option<MetadataData>)> {
     }
 }
 
+impl SseEncode for Vec<(String, crate::api::metadata_cache::CachedMetadataData)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, crate::api::metadata_cache::CachedMetadataData)>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
 impl SseEncode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6509,6 +10927,16 @@ impl SseEncode for Vec<(String, String)> {
     }
 }
 
+impl SseEncode for Vec<(String, u32)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, u32)>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::messages::SerializableTokenData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6539,6 +10967,53 @@ impl SseEncode for Vec<crate::api::welcomes::WelcomeData> {
     }
 }
 
+impl SseEncode for crate::api::media::MediaAttachmentData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.mime_type, serializer);
+        <String>::sse_encode(self.sha256, serializer);
+        <Option<u64>>::sse_encode(self.size, serializer);
+        <Option<String>>::sse_encode(self.dimensions, serializer);
+        <Option<String>>::sse_encode(self.blurhash, serializer);
+        <bool>::sse_encode(self.is_encrypted, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media_cache::MediaCacheStatsData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.file_count, serializer);
+        <u64>::sse_encode(self.total_size_bytes, serializer);
+        <u64>::sse_encode(self.max_size_bytes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::messages::MessageCursorData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.created_at, serializer);
+        <Option<String>>::sse_encode(self.message_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::messages::MessagePageData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::messages::ChatMessageData>>::sse_encode(self.messages, serializer);
+        <Option<crate::api::messages::MessageCursorData>>::sse_encode(self.next_cursor, serializer);
+        <bool>::sse_encode(self.has_more, serializer);
+    }
+}
+
+impl SseEncode for crate::api::messages::MessageVersionData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.content, serializer);
+        <u64>::sse_encode(self.created_at, serializer);
+    }
+}
+
 impl SseEncode for crate::api::messages::MessageWithTokensData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6551,6 +11026,67 @@ impl SseEncode for crate::api::messages::MessageWithTokensData {
     }
 }
 
+impl SseEncode for crate::api::nip05::Nip05ProfileData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.identifier, serializer);
+        <String>::sse_encode(self.pubkey, serializer);
+        <Vec<String>>::sse_encode(self.relays, serializer);
+    }
+}
+
+impl SseEncode for crate::api::nip19::NostrEntity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::nip19::NostrEntity::Pubkey { pubkey } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(pubkey, serializer);
+            }
+            crate::api::nip19::NostrEntity::Profile { pubkey, relays } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(pubkey, serializer);
+                <Vec<String>>::sse_encode(relays, serializer);
+            }
+            crate::api::nip19::NostrEntity::Note { event_id } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(event_id, serializer);
+            }
+            crate::api::nip19::NostrEntity::Event {
+                event_id,
+                relays,
+                author,
+                kind,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(event_id, serializer);
+                <Vec<String>>::sse_encode(relays, serializer);
+                <Option<String>>::sse_encode(author, serializer);
+                <Option<u32>>::sse_encode(kind, serializer);
+            }
+            crate::api::nip19::NostrEntity::Address {
+                identifier,
+                pubkey,
+                kind,
+                relays,
+            } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(identifier, serializer);
+                <String>::sse_encode(pubkey, serializer);
+                <u32>::sse_encode(kind, serializer);
+                <Vec<String>>::sse_encode(relays, serializer);
+            }
+            crate::api::nip19::NostrEntity::SecretKey { secret_key } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(secret_key, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for crate::api::accounts::OnboardingState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6580,6 +11116,16 @@ impl SseEncode for Option<Event> {
     }
 }
 
+impl SseEncode for Option<GroupId> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <GroupId>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<MetadataData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6590,6 +11136,56 @@ impl SseEncode for Option<MetadataData> {
     }
 }
 
+impl SseEncode for Option<crate::api::delivery::DeliveryStatusData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::delivery::DeliveryStatusData>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::images::ImageProcessingOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::images::ImageProcessingOptions>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::messages::MessageCursorData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::messages::MessageCursorData>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::nip05::Nip05ProfileData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::nip05::Nip05ProfileData>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6610,6 +11206,69 @@ impl SseEncode for Option<Vec<Tag>> {
     }
 }
 
+impl SseEncode for crate::api::outbox::OutboxMessageData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.group_id, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <u16>::sse_encode(self.kind, serializer);
+        <Vec<Vec<String>>>::sse_encode(self.tags, serializer);
+        <u64>::sse_encode(self.created_at, serializer);
+        <crate::api::outbox::OutboxState>::sse_encode(self.state, serializer);
+        <u32>::sse_encode(self.attempts, serializer);
+        <u64>::sse_encode(self.next_attempt_at, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::outbox::OutboxState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::outbox::OutboxState::Pending => 0,
+                crate::api::outbox::OutboxState::Failed => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::nip19::ParsedNostrEntityData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::nip19::NostrEntity>::sse_encode(self.entity, serializer);
+        <bool>::sse_encode(self.is_sensitive, serializer);
+    }
+}
+
+impl SseEncode for crate::api::presence::PresenceSettingsData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.disable_read_receipts, serializer);
+        <bool>::sse_encode(self.disable_typing_indicators, serializer);
+    }
+}
+
+impl SseEncode for crate::api::images::ProcessedImageData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.file_path, serializer);
+        <ImageType>::sse_encode(self.image_type, serializer);
+        <String>::sse_encode(self.mime_type, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <String>::sse_encode(self.sha256, serializer);
+        <String>::sse_encode(self.blurhash, serializer);
+        <Option<String>>::sse_encode(self.thumbnail_path, serializer);
+    }
+}
+
 impl SseEncode for crate::api::messages::ReactionSummaryData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6626,6 +11285,14 @@ impl SseEncode for (PublicKey, Option<MetadataData>) {
     }
 }
 
+impl SseEncode for (String, crate::api::metadata_cache::CachedMetadataData) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.0, serializer);
+        <crate::api::metadata_cache::CachedMetadataData>::sse_encode(self.1, serializer);
+    }
+}
+
 impl SseEncode for (String, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6634,6 +11301,14 @@ impl SseEncode for (String, String) {
     }
 }
 
+impl SseEncode for (String, u32) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.0, serializer);
+        <u32>::sse_encode(self.1, serializer);
+    }
+}
+
 impl SseEncode for crate::api::messages::SerializableTokenData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6675,6 +11350,15 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::images::UploadedImageData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Vec<String>>::sse_encode(self.mirror_urls, serializer);
+        <Option<String>>::sse_encode(self.thumbnail_url, serializer);
+    }
+}
+
 impl SseEncode for crate::api::messages::UserReactionData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6737,6 +11421,122 @@ impl SseEncode for crate::api::utils::WhitenoiseConfigData {
     }
 }
 
+impl SseEncode for crate::api::events::WhitenoiseEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::events::WhitenoiseEvent::Subscribed { subscription_id } => {
+                <i32>::sse_encode(0, serializer);
+                <u64>::sse_encode(subscription_id, serializer);
+            }
+            crate::api::events::WhitenoiseEvent::WelcomeReceived {
+                account_pubkey,
+                welcome,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(account_pubkey, serializer);
+                <crate::api::welcomes::WelcomeData>::sse_encode(welcome, serializer);
+            }
+            crate::api::events::WhitenoiseEvent::GroupCreated {
+                account_pubkey,
+                group,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(account_pubkey, serializer);
+                <crate::api::groups::GroupData>::sse_encode(group, serializer);
+            }
+            crate::api::events::WhitenoiseEvent::GroupUpdated {
+                account_pubkey,
+                group,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(account_pubkey, serializer);
+                <crate::api::groups::GroupData>::sse_encode(group, serializer);
+            }
+            crate::api::events::WhitenoiseEvent::GroupStateChanged {
+                account_pubkey,
+                group,
+            } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(account_pubkey, serializer);
+                <crate::api::groups::GroupData>::sse_encode(group, serializer);
+            }
+            crate::api::events::WhitenoiseEvent::MemberAdded {
+                account_pubkey,
+                group_id,
+                member_pubkey,
+            } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(account_pubkey, serializer);
+                <String>::sse_encode(group_id, serializer);
+                <String>::sse_encode(member_pubkey, serializer);
+            }
+            crate::api::events::WhitenoiseEvent::MemberRemoved {
+                account_pubkey,
+                group_id,
+                member_pubkey,
+            } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(account_pubkey, serializer);
+                <String>::sse_encode(group_id, serializer);
+                <String>::sse_encode(member_pubkey, serializer);
+            }
+            crate::api::events::WhitenoiseEvent::RelayStatusChanged {
+                account_pubkey,
+                relay_url,
+                status,
+            } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(account_pubkey, serializer);
+                <String>::sse_encode(relay_url, serializer);
+                <String>::sse_encode(status, serializer);
+            }
+            crate::api::events::WhitenoiseEvent::MetadataUpdated { pubkey, metadata } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(pubkey, serializer);
+                <MetadataData>::sse_encode(metadata, serializer);
+            }
+            crate::api::events::WhitenoiseEvent::AccountLoggedIn { account } => {
+                <i32>::sse_encode(9, serializer);
+                <crate::api::accounts::AccountData>::sse_encode(account, serializer);
+            }
+            crate::api::events::WhitenoiseEvent::AccountLoggedOut { pubkey } => {
+                <i32>::sse_encode(10, serializer);
+                <String>::sse_encode(pubkey, serializer);
+            }
+            crate::api::events::WhitenoiseEvent::MessageDeliveryStatusChanged {
+                account_pubkey,
+                status,
+            } => {
+                <i32>::sse_encode(11, serializer);
+                <String>::sse_encode(account_pubkey, serializer);
+                <crate::api::delivery::DeliveryStatusData>::sse_encode(status, serializer);
+            }
+            crate::api::events::WhitenoiseEvent::OutboxMessageUpdated {
+                account_pubkey,
+                message,
+            } => {
+                <i32>::sse_encode(12, serializer);
+                <String>::sse_encode(account_pubkey, serializer);
+                <crate::api::outbox::OutboxMessageData>::sse_encode(message, serializer);
+            }
+            crate::api::events::WhitenoiseEvent::OutboxMessageSent {
+                account_pubkey,
+                outbox_id,
+                message,
+            } => {
+                <i32>::sse_encode(13, serializer);
+                <String>::sse_encode(account_pubkey, serializer);
+                <String>::sse_encode(outbox_id, serializer);
+                <crate::api::messages::MessageWithTokensData>::sse_encode(message, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
@@ -6746,8 +11546,22 @@ mod io {
 
     use super::*;
     use crate::api::accounts::*;
+    use crate::api::blossom::*;
+    use crate::api::chats::*;
+    use crate::api::contacts::*;
+    use crate::api::delivery::*;
+    use crate::api::errors::*;
+    use crate::api::events::*;
     use crate::api::groups::*;
+    use crate::api::images::*;
+    use crate::api::media::*;
+    use crate::api::media_cache::*;
     use crate::api::messages::*;
+    use crate::api::metadata_cache::*;
+    use crate::api::nip05::*;
+    use crate::api::nip19::*;
+    use crate::api::outbox::*;
+    use crate::api::presence::*;
     use crate::api::relays::*;
     use crate::api::utils::*;
     use crate::api::welcomes::*;
@@ -0,0 +1,121 @@
+//! Change notifications for the messages of a group.
+//!
+//! The core stores the messages of a group without telling the bridge, so live streams
+//! learn about new events from two sources instead of polling the store: events the
+//! bridge sends itself are announced with `notify_group_changed`, and events from other
+//! members arrive on the bridge's own relay subscription for the group. Either way the
+//! core may store the event a moment later, so watchers re-read the store a few times
+//! (`SETTLE_DELAYS`) until the change shows up.
+
+use crate::api::chats::forget_group_activity;
+use crate::api::errors::BridgeError;
+use crate::api::groups::find_group;
+use crate::relay_client::{subscribe, RelaySubscription};
+use nostr_sdk::{Alphabet, Filter, Kind, SingleLetterTag, Timestamp};
+use std::collections::BTreeSet;
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use whitenoise::{Account, Group, GroupId, PublicKey, RelayType, RelayUrl, Whitenoise};
+
+/// Delays after a notification at which watchers re-read the store, until the event
+/// shows up there. Bounds how long the core may take to process an event.
+pub(crate) const SETTLE_DELAYS: [Duration; 4] = [
+    Duration::ZERO,
+    Duration::from_millis(250),
+    Duration::from_secs(1),
+    Duration::from_secs(3),
+];
+
+/// Number of local change notifications buffered for slow watchers.
+const GROUP_CHANGES_CAPACITY: usize = 64;
+
+static GROUP_CHANGES: OnceLock<broadcast::Sender<GroupId>> = OnceLock::new();
+
+fn group_changes() -> &'static broadcast::Sender<GroupId> {
+    GROUP_CHANGES.get_or_init(|| broadcast::channel(GROUP_CHANGES_CAPACITY).0)
+}
+
+/// Announces that the bridge stored a new event in a group (e.g. after sending one).
+pub(crate) fn notify_group_changed(group_id: &GroupId) {
+    forget_group_activity(group_id);
+    // No receivers just means nobody is watching
+    let _ = group_changes().send(group_id.clone());
+}
+
+/// Returns the relays the messages of a group are published to, as far as the core
+/// reveals them: the account's Nostr relays, which groups created by this app use, and
+/// the relays named in the welcome the account joined the group with.
+pub(crate) async fn group_relays(
+    whitenoise: &Whitenoise,
+    account: &Account,
+    group: &Group,
+) -> Result<Vec<RelayUrl>, BridgeError> {
+    let mut relays: BTreeSet<RelayUrl> = whitenoise
+        .fetch_relays(account.pubkey, RelayType::Nostr)
+        .await?
+        .into_iter()
+        .collect();
+    // Welcomes are only used to find relays, so an error just leaves them out
+    if let Ok(welcomes) = whitenoise.fetch_welcomes(&account.pubkey).await {
+        for welcome in welcomes {
+            if welcome.nostr_group_id == group.nostr_group_id {
+                relays.extend(welcome.group_relays);
+            }
+        }
+    }
+    Ok(relays.into_iter().collect())
+}
+
+/// Wakes a watcher whenever the messages of one group may have changed.
+pub(crate) struct GroupWatch {
+    group_id: GroupId,
+    local: broadcast::Receiver<GroupId>,
+    relay: RelaySubscription,
+}
+
+impl GroupWatch {
+    /// Starts watching a group of the account for new events (kind 445 tagged with the
+    /// group's Nostr ID) from now on.
+    pub(crate) async fn start(pubkey: &PublicKey, group_id: &GroupId) -> Result<Self, BridgeError> {
+        let whitenoise = Whitenoise::get_instance()?;
+        let account = whitenoise.fetch_account(pubkey).await?;
+        let group = find_group(whitenoise, &account, group_id).await?;
+        let relays = group_relays(whitenoise, &account, &group).await?;
+        // Subscribed to before anything is read, so no change falls in between
+        let local = group_changes().subscribe();
+        let filter = Filter::new()
+            .kind(Kind::MlsGroupMessage)
+            .custom_tag(
+                SingleLetterTag::lowercase(Alphabet::H),
+                hex::encode(group.nostr_group_id),
+            )
+            .since(Timestamp::now());
+        let relay = subscribe(&relays, filter).await?;
+        Ok(Self {
+            group_id: group_id.clone(),
+            local,
+            relay,
+        })
+    }
+
+    /// Waits until the group may have changed. Returns `false` once no more changes can
+    /// be reported (the relay client has shut down).
+    pub(crate) async fn changed(&mut self) -> bool {
+        loop {
+            tokio::select! {
+                event = self.relay.next_event() => {
+                    // The event may edit or delete a message the chat list shows
+                    forget_group_activity(&self.group_id);
+                    return event.is_some();
+                }
+                change = self.local.recv() => match change {
+                    Ok(group_id) if group_id != self.group_id => {}
+                    // A lagged receiver may have missed a change of this group
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => return true,
+                    Err(broadcast::error::RecvError::Closed) => {}
+                },
+            }
+        }
+    }
+}
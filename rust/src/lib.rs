@@ -5,6 +5,10 @@ pub use whitenoise::{
 };
 
 pub mod api;
+mod group_watch;
+mod relay_client;
+mod signer;
+mod store;
 
 // Include the generated bridge code
 mod frb_generated;
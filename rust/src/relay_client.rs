@@ -0,0 +1,160 @@
+//! Relay client owned by the bridge itself.
+//!
+//! The Whitenoise core only exposes fixed queries (e.g. the metadata of one user at a
+//! time) and cannot publish arbitrary events. Queries and events it does not cover go
+//! through this client, which keeps its own connections to the relays it is given. The
+//! core does not report incoming events either, so live streams subscribe here to learn
+//! when to re-read the core's store.
+
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use nostr_sdk::{Client, Event, Filter, RelayPoolNotification, RelayUrl, SubscriptionId};
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// How long to wait for a relay to connect.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long to wait for relays to answer a query.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+static CLIENT: OnceLock<Client> = OnceLock::new();
+
+fn client() -> &'static Client {
+    CLIENT.get_or_init(Client::default)
+}
+
+fn no_relays() -> BridgeError {
+    BridgeError::new(
+        BridgeErrorCode::RelayUnreachable,
+        "No relays are configured",
+    )
+}
+
+/// Adds the relays to the pool and waits (briefly) for new ones to connect.
+async fn connect(relays: &[RelayUrl]) {
+    for relay in relays {
+        // Relays that are already in the pool keep their connection
+        if let Ok(true) = client().add_relay(relay.clone()).await {
+            let _ = client()
+                .try_connect_relay(relay.clone(), CONNECT_TIMEOUT)
+                .await;
+        }
+    }
+}
+
+/// Fetches the events matching a filter from the given relays.
+///
+/// Events are de-duplicated across relays and their signatures are verified.
+pub(crate) async fn fetch_events(
+    relays: &[RelayUrl],
+    filter: Filter,
+) -> Result<Vec<Event>, BridgeError> {
+    if relays.is_empty() {
+        return Err(no_relays());
+    }
+    connect(relays).await;
+    let events = client()
+        .fetch_events_from(relays.to_vec(), filter, FETCH_TIMEOUT)
+        .await
+        .map_err(|e| {
+            BridgeError::new(BridgeErrorCode::Network, "Could not query relays").with_details(e)
+        })?;
+    Ok(events.into_iter().collect())
+}
+
+/// Publishes a signed event to the given relays.
+///
+/// Succeeds if at least one relay accepted the event.
+pub(crate) async fn publish_event(relays: &[RelayUrl], event: &Event) -> Result<(), BridgeError> {
+    if relays.is_empty() {
+        return Err(no_relays());
+    }
+    connect(relays).await;
+    let output = client()
+        .send_event_to(relays.to_vec(), event)
+        .await
+        .map_err(|e| {
+            BridgeError::new(BridgeErrorCode::Network, "Could not publish event").with_details(e)
+        })?;
+    if output.success.is_empty() {
+        let failures: Vec<String> = output
+            .failed
+            .iter()
+            .map(|(relay, error)| format!("{relay}: {error}"))
+            .collect();
+        return Err(BridgeError::new(
+            BridgeErrorCode::RelayUnreachable,
+            "No relay accepted the event",
+        )
+        .with_details(failures.join(", ")));
+    }
+    Ok(())
+}
+
+/// A live subscription on the bridge's relay connections, closed when dropped.
+pub(crate) struct RelaySubscription {
+    id: SubscriptionId,
+    notifications: broadcast::Receiver<RelayPoolNotification>,
+}
+
+impl RelaySubscription {
+    /// Waits for the next event matching the subscription. Returns `None` once the client
+    /// has shut down.
+    ///
+    /// Relays may send an event more than once (e.g. after reconnecting), so callers must
+    /// tolerate duplicates.
+    pub(crate) async fn next_event(&mut self) -> Option<Event> {
+        loop {
+            match self.notifications.recv().await {
+                Ok(RelayPoolNotification::Event {
+                    subscription_id,
+                    event,
+                    ..
+                }) if subscription_id == self.id => return Some(*event),
+                Ok(RelayPoolNotification::Shutdown) | Err(broadcast::error::RecvError::Closed) => {
+                    return None
+                }
+                // Notifications of other subscriptions, and skipped ones when this
+                // subscriber falls behind; the events that follow still arrive
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+            }
+        }
+    }
+}
+
+impl Drop for RelaySubscription {
+    fn drop(&mut self) {
+        let id = self.id.clone();
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            runtime.spawn(async move { client().unsubscribe(&id).await });
+        }
+    }
+}
+
+/// Subscribes to the events matching a filter on the given relays.
+///
+/// The subscription stays open until the returned handle is dropped; relays that are not
+/// connected yet receive it once they connect.
+pub(crate) async fn subscribe(
+    relays: &[RelayUrl],
+    filter: Filter,
+) -> Result<RelaySubscription, BridgeError> {
+    if relays.is_empty() {
+        return Err(no_relays());
+    }
+    connect(relays).await;
+    // Listen before subscribing so no event is missed
+    let notifications = client().notifications();
+    let output = client()
+        .subscribe_to(relays.to_vec(), filter, None)
+        .await
+        .map_err(|e| {
+            BridgeError::new(BridgeErrorCode::Network, "Could not subscribe to relays")
+                .with_details(e)
+        })?;
+    Ok(RelaySubscription {
+        id: output.val,
+        notifications,
+    })
+}
@@ -0,0 +1,61 @@
+//! Signing for the events the core does not sign itself.
+//!
+//! The Whitenoise core signs everything it publishes but has no signer API, so events the
+//! bridge publishes on its own (e.g. the Blossom server list and presence events), Blossom
+//! upload authorizations and decrypting presence events need the account's secret key. This module is the only place that
+//! exports it: the keys stay inside `AccountSigner`, which only hands them out as an
+//! opaque `NostrSigner`, and are dropped with it.
+
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use nostr::nips::nip44;
+use nostr::{Keys, NostrSigner};
+use nostr_sdk::{Event, EventBuilder};
+use whitenoise::{PublicKey, Whitenoise};
+
+/// Signs on behalf of one logged-in account.
+pub(crate) struct AccountSigner {
+    keys: Keys,
+}
+
+impl AccountSigner {
+    /// Loads the signer of a logged-in account.
+    pub(crate) async fn load(pubkey: &PublicKey) -> Result<Self, BridgeError> {
+        let whitenoise = Whitenoise::get_instance()?;
+        let account = whitenoise.fetch_account(pubkey).await?;
+        let keys = Keys::parse(&whitenoise.export_account_nsec(&account).await?).map_err(|e| {
+            BridgeError::new(BridgeErrorCode::Storage, "Could not load the account keys")
+                .with_details(e)
+        })?;
+        if keys.public_key() != *pubkey {
+            return Err(BridgeError::new(
+                BridgeErrorCode::Storage,
+                "Stored keys do not match the account",
+            ));
+        }
+        Ok(Self { keys })
+    }
+
+    /// Signs an event built by the bridge.
+    pub(crate) fn sign(&self, builder: EventBuilder) -> Result<Event, BridgeError> {
+        builder.sign_with_keys(&self.keys).map_err(|e| {
+            BridgeError::new(BridgeErrorCode::Internal, "Could not sign the event").with_details(e)
+        })
+    }
+
+    /// Decrypts a NIP-44 payload sent to the account by `sender`.
+    pub(crate) fn decrypt_from(
+        &self,
+        sender: &PublicKey,
+        payload: &str,
+    ) -> Result<String, BridgeError> {
+        nip44::decrypt(self.keys.secret_key(), sender, payload).map_err(|e| {
+            BridgeError::new(BridgeErrorCode::InvalidInput, "Could not decrypt the event")
+                .with_details(e)
+        })
+    }
+
+    /// Returns the signer for clients that sign their own requests (e.g. Blossom uploads).
+    pub(crate) fn nostr_signer(&self) -> &impl NostrSigner {
+        &self.keys
+    }
+}
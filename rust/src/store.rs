@@ -0,0 +1,121 @@
+//! Small persistent key-value store for state owned by the bridge itself.
+//!
+//! The Whitenoise core owns its database and does not expose it, so state that only the
+//! bridge knows about (read markers, preferences, caches, ...) is kept as one JSON
+//! document per name, optionally scoped to an account, under `<data_dir>/bridge/`.
+
+use crate::api::errors::{BridgeError, BridgeErrorCode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tokio::sync::Mutex;
+
+static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Serializes read-modify-write cycles across all documents. Held across the blocking
+/// file operations, which run on tokio's blocking pool so the async workers never wait on
+/// the disk.
+static STORE_LOCK: Mutex<()> = Mutex::const_new(());
+
+/// Sets the directory the store lives in. Called once from `initialize_whitenoise`;
+/// later calls are ignored.
+pub(crate) fn set_data_dir(data_dir: PathBuf) {
+    let _ = DATA_DIR.set(data_dir.join("bridge"));
+}
+
+/// Returns the root directory of the store.
+pub(crate) fn store_dir() -> Result<PathBuf, BridgeError> {
+    DATA_DIR.get().cloned().ok_or_else(|| {
+        BridgeError::new(
+            BridgeErrorCode::NotInitialized,
+            "Whitenoise is not initialized",
+        )
+    })
+}
+
+fn document_path(name: &str, account: Option<&str>) -> Result<PathBuf, BridgeError> {
+    let mut path = store_dir()?;
+    if let Some(account) = account {
+        path.push("accounts");
+        path.push(account);
+    }
+    path.push(format!("{name}.json"));
+    Ok(path)
+}
+
+fn storage_error(message: &str, error: impl std::fmt::Display) -> BridgeError {
+    BridgeError::new(BridgeErrorCode::Storage, message).with_details(error)
+}
+
+/// Runs a file operation on the blocking thread pool.
+async fn blocking<R: Send + 'static>(
+    operation: impl FnOnce() -> Result<R, BridgeError> + Send + 'static,
+) -> Result<R, BridgeError> {
+    tokio::task::spawn_blocking(operation)
+        .await
+        .map_err(|e| storage_error("Could not access local data", e))?
+}
+
+fn read_document<T: DeserializeOwned + Default>(path: &Path) -> Result<T, BridgeError> {
+    match std::fs::read(path) {
+        Ok(bytes) => serde_json::from_slice(&bytes)
+            .map_err(|e| storage_error("Could not read local data", e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(storage_error("Could not read local data", e)),
+    }
+}
+
+fn write_document<T: Serialize>(path: &Path, value: &T) -> Result<(), BridgeError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| storage_error("Could not save local data", e))?;
+    }
+    let bytes =
+        serde_json::to_vec(value).map_err(|e| storage_error("Could not save local data", e))?;
+    // Write to a temporary file first so a crash never leaves a truncated document
+    let tmp_path = path.with_extension("json.tmp");
+    std::fs::write(&tmp_path, bytes).map_err(|e| storage_error("Could not save local data", e))?;
+    std::fs::rename(&tmp_path, path).map_err(|e| storage_error("Could not save local data", e))
+}
+
+/// Loads a document, returning its default value if it has never been saved.
+pub(crate) async fn load<T: DeserializeOwned + Default + Send + 'static>(
+    name: &str,
+    account: Option<&str>,
+) -> Result<T, BridgeError> {
+    let path = document_path(name, account)?;
+    let _guard = STORE_LOCK.lock().await;
+    blocking(move || read_document(&path)).await
+}
+
+/// Loads a document, applies `change` to it and saves the result atomically.
+pub(crate) async fn update<T, R>(
+    name: &str,
+    account: Option<&str>,
+    change: impl FnOnce(&mut T) -> R,
+) -> Result<R, BridgeError>
+where
+    T: Serialize + DeserializeOwned + Default + Send + 'static,
+{
+    let path = document_path(name, account)?;
+    let _guard = STORE_LOCK.lock().await;
+    let read_path = path.clone();
+    let mut value = blocking(move || read_document::<T>(&read_path)).await?;
+    let result = change(&mut value);
+    blocking(move || write_document(&path, &value)).await?;
+    Ok(result)
+}
+
+/// Removes everything in the store.
+pub(crate) async fn clear() -> Result<(), BridgeError> {
+    let dir = store_dir()?;
+    let _guard = STORE_LOCK.lock().await;
+    blocking(move || match std::fs::remove_dir_all(&dir) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            Err(storage_error("Could not delete local data", e))
+        }
+        _ => Ok(()),
+    })
+    .await
+}